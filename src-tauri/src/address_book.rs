@@ -0,0 +1,465 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Address book of labelled external Tari addresses.
+//!
+//! Contacts live in [`ConfigWalletContent::external_tari_addresses_book`] keyed by name and
+//! may carry a payment ID preset that is used when sending to them without an explicit one.
+//! Senders accept a contact name wherever an address is expected, see [`resolve_destination`].
+//!
+//! Import and export support JSON (an array of contacts) and CSV:
+//! `name,address[,payment_id]` with an optional header row.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use log::info;
+use serde::{Deserialize, Serialize};
+use tari_common_types::tari_address::TariAddress;
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_wallet::{
+    ConfigWallet, ConfigWalletContent, EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK,
+    ExternalTariAddressBookRecord,
+};
+use crate::configs::trait_config::ConfigImpl;
+use crate::utils::address_utils::verify_tari_address;
+
+const CSV_HEADER: &str = "name,address,payment_id";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AddressBookContact {
+    pub name: String,
+    /// Base58 when exported, any format `verify_tari_address` accepts when imported
+    pub address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_id: Option<String>,
+}
+
+impl From<&ExternalTariAddressBookRecord> for AddressBookContact {
+    fn from(record: &ExternalTariAddressBookRecord) -> Self {
+        Self {
+            name: record.name.clone(),
+            address: record.address.to_base58(),
+            payment_id: record.payment_id.clone(),
+        }
+    }
+}
+
+impl AddressBookContact {
+    /// Validates the contact and converts it into the stored record
+    pub fn into_record(self) -> Result<ExternalTariAddressBookRecord, anyhow::Error> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err(anyhow!("Contact name cannot be empty"));
+        }
+        if is_reserved(&name) {
+            return Err(anyhow!("Contact name '{name}' is reserved"));
+        }
+        // Destinations that parse as an address are never resolved as a contact name
+        if TariAddress::from_str(&name).is_ok() {
+            return Err(anyhow!("Contact name '{name}' cannot be an address"));
+        }
+        let payment_id = self
+            .payment_id
+            .map(|payment_id| payment_id.trim().to_string())
+            .filter(|payment_id| !payment_id.is_empty());
+        // CSV import reads one contact per line
+        if [Some(&name), payment_id.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|value| value.contains(['\n', '\r']))
+        {
+            return Err(anyhow!("Contact '{name}' cannot contain line breaks"));
+        }
+        let address = verify_tari_address(self.address.trim())
+            .map_err(|e| anyhow!("Invalid address for contact '{name}': {e}"))?;
+        Ok(ExternalTariAddressBookRecord {
+            name,
+            address,
+            payment_id,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressBookFormat {
+    Csv,
+    Json,
+}
+
+/// The exchange address record is managed by `select_external_tari_address`
+fn is_reserved(name: &str) -> bool {
+    name.eq_ignore_ascii_case(EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK)
+}
+
+fn find_key<'a>(
+    book: &'a HashMap<String, ExternalTariAddressBookRecord>,
+    name: &str,
+) -> Option<&'a String> {
+    book.keys()
+        .find(|key| key.eq_ignore_ascii_case(name.trim()))
+}
+
+pub async fn list_contacts() -> Vec<AddressBookContact> {
+    let config = ConfigWallet::content().await;
+    let mut contacts: Vec<AddressBookContact> = config
+        .external_tari_addresses_book()
+        .values()
+        .map(AddressBookContact::from)
+        .collect();
+    contacts.sort_by_key(|contact| contact.name.to_lowercase());
+    contacts
+}
+
+/// Adds a contact, or replaces `previous_name` when editing (which may rename it)
+pub async fn save_contact(
+    contact: AddressBookContact,
+    previous_name: Option<String>,
+) -> Result<(), anyhow::Error> {
+    let record = contact.into_record()?;
+    let mut book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+
+    if let Some(previous_name) = previous_name {
+        let key = find_key(&book, &previous_name)
+            .cloned()
+            .ok_or_else(|| anyhow!("Contact '{previous_name}' not found"))?;
+        if is_reserved(&key) {
+            return Err(anyhow!("Contact '{key}' cannot be edited"));
+        }
+        book.remove(&key);
+    }
+    if find_key(&book, &record.name).is_some() {
+        return Err(anyhow!("A contact named '{}' already exists", record.name));
+    }
+
+    info!(target: LOG_TARGET_APP_LOGIC, "Saving address book contact '{}'", record.name);
+    book.insert(record.name.clone(), record);
+    ConfigWallet::update_field(ConfigWalletContent::set_external_tari_addresses_book, book).await
+}
+
+pub async fn remove_contact(name: String) -> Result<(), anyhow::Error> {
+    let mut book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+    let key = find_key(&book, &name)
+        .cloned()
+        .ok_or_else(|| anyhow!("Contact '{name}' not found"))?;
+    if is_reserved(&key) {
+        return Err(anyhow!("Contact '{key}' cannot be removed"));
+    }
+    book.remove(&key);
+    ConfigWallet::update_field(ConfigWalletContent::set_external_tari_addresses_book, book).await
+}
+
+/// Imports every contact or none of them, existing contacts with the same name are replaced.
+/// Returns the number of imported contacts.
+pub async fn import_contacts(
+    contents: &str,
+    format: AddressBookFormat,
+) -> Result<usize, anyhow::Error> {
+    let records = parse_contacts(contents, format)?
+        .into_iter()
+        .map(AddressBookContact::into_record)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+    for record in &records {
+        if let Some(key) = find_key(&book, &record.name).cloned() {
+            book.remove(&key);
+        }
+        book.insert(record.name.clone(), record.clone());
+    }
+    info!(target: LOG_TARGET_APP_LOGIC, "Imported {} address book contacts", records.len());
+    ConfigWallet::update_field(ConfigWalletContent::set_external_tari_addresses_book, book).await?;
+    Ok(records.len())
+}
+
+pub async fn export_contacts(format: AddressBookFormat) -> Result<String, anyhow::Error> {
+    let contacts: Vec<AddressBookContact> = list_contacts()
+        .await
+        .into_iter()
+        .filter(|contact| !is_reserved(&contact.name))
+        .collect();
+    match format {
+        AddressBookFormat::Json => Ok(serde_json::to_string_pretty(&contacts)?),
+        AddressBookFormat::Csv => Ok(contacts_to_csv(&contacts)),
+    }
+}
+
+/// Resolves a contact name to its address. Destinations that already are addresses, and
+/// unknown names, are returned unchanged. The contact's payment ID preset applies when no
+/// payment ID was given.
+pub async fn resolve_destination(
+    destination: String,
+    payment_id: Option<String>,
+) -> (String, Option<String>) {
+    let config = ConfigWallet::content().await;
+    resolve_in_book(
+        config.external_tari_addresses_book(),
+        destination,
+        payment_id,
+    )
+}
+
+fn resolve_in_book(
+    book: &HashMap<String, ExternalTariAddressBookRecord>,
+    destination: String,
+    payment_id: Option<String>,
+) -> (String, Option<String>) {
+    if TariAddress::from_str(destination.trim()).is_ok() {
+        return (destination, payment_id);
+    }
+    let Some(record) = find_key(book, &destination).and_then(|key| book.get(key)) else {
+        return (destination, payment_id);
+    };
+
+    info!(target: LOG_TARGET_APP_LOGIC, "Resolved address book contact '{}'", record.name);
+    let payment_id = payment_id
+        .filter(|payment_id| !payment_id.is_empty())
+        .or_else(|| record.payment_id.clone());
+    (record.address.to_base58(), payment_id)
+}
+
+fn parse_contacts(
+    contents: &str,
+    format: AddressBookFormat,
+) -> Result<Vec<AddressBookContact>, anyhow::Error> {
+    match format {
+        AddressBookFormat::Json => {
+            serde_json::from_str(contents).map_err(|e| anyhow!("Invalid address book JSON: {e}"))
+        }
+        AddressBookFormat::Csv => parse_csv(contents),
+    }
+}
+
+fn parse_csv(contents: &str) -> Result<Vec<AddressBookContact>, anyhow::Error> {
+    let mut contacts = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let columns = split_csv_line(line);
+        let is_header = columns
+            .first()
+            .is_some_and(|c| c.eq_ignore_ascii_case("name"))
+            && columns
+                .get(1)
+                .is_some_and(|c| c.eq_ignore_ascii_case("address"));
+        if is_header {
+            continue;
+        }
+        let (Some(name), Some(address)) = (columns.first(), columns.get(1)) else {
+            return Err(anyhow!(
+                "Address book CSV line {} has too few columns",
+                index + 1
+            ));
+        };
+        contacts.push(AddressBookContact {
+            name: name.clone(),
+            address: address.clone(),
+            payment_id: columns.get(2).filter(|value| !value.is_empty()).cloned(),
+        });
+    }
+    Ok(contacts)
+}
+
+/// Splits a CSV line on commas outside of double quotes, `""` inside quotes is a quote
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut columns = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => columns.push(std::mem::take(&mut current).trim().to_string()),
+            _ => current.push(c),
+        }
+    }
+    columns.push(current.trim().to_string());
+    columns
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn contacts_to_csv(contacts: &[AddressBookContact]) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+    for contact in contacts {
+        csv.push_str(&format!(
+            "{},{},{}\n",
+            csv_field(&contact.name),
+            csv_field(&contact.address),
+            csv_field(contact.payment_id.as_deref().unwrap_or_default())
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ESME_ADDRESS: &str = "f25eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF3g";
+    const ESME_OTHER_ADDRESS: &str = "f45eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF31";
+
+    fn contact(name: &str, address: &str, payment_id: Option<&str>) -> AddressBookContact {
+        AddressBookContact {
+            name: name.to_string(),
+            address: address.to_string(),
+            payment_id: payment_id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn csv_roundtrip_quotes_names_and_payment_ids() {
+        let contacts = vec![
+            contact("Alice, savings", "addr1", Some("invoice \"42\"")),
+            contact("Bob", "addr2", None),
+        ];
+        let csv = contacts_to_csv(&contacts);
+        assert!(csv.starts_with("name,address,payment_id\n"));
+        assert_eq!(parse_csv(&csv).unwrap(), contacts);
+    }
+
+    #[test]
+    fn parses_csv_without_header_or_payment_id_column() {
+        let contacts = parse_csv("# exported contacts\nAlice,addr1\n\nBob, addr2 ,memo\n").unwrap();
+        assert_eq!(
+            contacts,
+            vec![
+                contact("Alice", "addr1", None),
+                contact("Bob", "addr2", Some("memo")),
+            ]
+        );
+        assert!(parse_csv("Alice\n").is_err());
+    }
+
+    #[test]
+    fn rejects_reserved_and_empty_names() {
+        assert!(contact(" ", "addr", None).into_record().is_err());
+        assert!(
+            contact(
+                EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK,
+                "addr",
+                None
+            )
+            .into_record()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_line_breaks_and_address_names() {
+        assert!(
+            contact("Alice\nBob", ESME_ADDRESS, None)
+                .into_record()
+                .is_err()
+        );
+        assert!(
+            contact("Alice", ESME_ADDRESS, Some("memo\r\nBob,addr"))
+                .into_record()
+                .is_err()
+        );
+        assert!(
+            contact(ESME_ADDRESS, ESME_ADDRESS, None)
+                .into_record()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn resolves_contacts_with_payment_id_preset() {
+        let address = TariAddress::from_str(ESME_ADDRESS).unwrap();
+        let book = HashMap::from([
+            (
+                "Alice".to_string(),
+                ExternalTariAddressBookRecord {
+                    name: "Alice".to_string(),
+                    address: address.clone(),
+                    payment_id: Some("invoice-42".to_string()),
+                },
+            ),
+            // Written before names were validated, the address is used as is
+            (
+                ESME_OTHER_ADDRESS.to_string(),
+                ExternalTariAddressBookRecord {
+                    name: ESME_OTHER_ADDRESS.to_string(),
+                    address: address.clone(),
+                    payment_id: None,
+                },
+            ),
+        ]);
+
+        assert_eq!(
+            resolve_in_book(&book, "alice".to_string(), None),
+            (address.to_base58(), Some("invoice-42".to_string()))
+        );
+        assert_eq!(
+            resolve_in_book(&book, "Alice".to_string(), Some("custom".to_string())),
+            (address.to_base58(), Some("custom".to_string()))
+        );
+        assert_eq!(
+            resolve_in_book(&book, "Alice".to_string(), Some(String::new())),
+            (address.to_base58(), Some("invoice-42".to_string()))
+        );
+        assert_eq!(
+            resolve_in_book(&book, ESME_OTHER_ADDRESS.to_string(), None),
+            (ESME_OTHER_ADDRESS.to_string(), None)
+        );
+        assert_eq!(
+            resolve_in_book(&book, "Carol".to_string(), None),
+            ("Carol".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn parses_json_contacts() {
+        let contacts = parse_contacts(
+            r#"[{"name": "Alice", "address": "addr1", "payment_id": "memo"}, {"name": "Bob", "address": "addr2"}]"#,
+            AddressBookFormat::Json,
+        )
+        .unwrap();
+        assert_eq!(contacts[0], contact("Alice", "addr1", Some("memo")));
+        assert_eq!(contacts[1].payment_id, None);
+    }
+}
@@ -40,6 +40,8 @@ pub enum Binaries {
     Tor,
     BridgeTapplet,
     LolMiner,
+    Graxil,
+    SrbMiner,
 }
 impl Binaries {
     pub fn name(&self) -> &str {
@@ -51,6 +53,8 @@ impl Binaries {
             Binaries::Tor => "tor",
             Binaries::BridgeTapplet => "bridge",
             Binaries::LolMiner => "lolminer",
+            Binaries::Graxil => "graxil",
+            Binaries::SrbMiner => "srbminer",
         }
     }
 
@@ -63,6 +67,8 @@ impl Binaries {
             "tor" => Binaries::Tor,
             "bridge" => Binaries::BridgeTapplet,
             "lolminer" => Binaries::LolMiner,
+            "graxil" => Binaries::Graxil,
+            "srbminer" => Binaries::SrbMiner,
             _ => panic!("Unknown binary name: {name}"),
         }
     }
@@ -107,6 +113,15 @@ impl Binaries {
                 let file_name = "lolMiner";
                 Self::append_exe_if_windows(&mut PathBuf::from(version).join(file_name))
             }
+            Binaries::Graxil => {
+                let file_name = "graxil";
+                Self::append_exe_if_windows(&mut PathBuf::from(file_name))
+            }
+            Binaries::SrbMiner => {
+                // Release archives unpack into SRBMiner-Multi-<major>-<minor>-<patch>
+                let folder_name = format!("SRBMiner-Multi-{}", version.replace('.', "-"));
+                Self::append_exe_if_windows(&mut PathBuf::from(folder_name).join("SRBMiner-MULTI"))
+            }
         }
     }
 
@@ -199,6 +214,24 @@ impl Binaries {
                 }
                 _ => "Not available for this platform".to_string(),
             },
+            Binaries::Graxil => match platform {
+                BinaryPlatformAssets::LinuxX64 => {
+                    format!("graxil-linux-x86_64-v{version}.zip")
+                }
+                BinaryPlatformAssets::WindowsX64 => {
+                    format!("graxil-windows-x64-v{version}.zip")
+                }
+                _ => "Not available for this platform".to_string(),
+            },
+            Binaries::SrbMiner => match platform {
+                BinaryPlatformAssets::LinuxX64 => {
+                    format!("SRBMiner-Multi-{}-Linux.tar.gz", version.replace('.', "-"))
+                }
+                BinaryPlatformAssets::WindowsX64 => {
+                    format!("SRBMiner-Multi-{}-win64.zip", version.replace('.', "-"))
+                }
+                _ => "Not available for this platform".to_string(),
+            },
         }
     }
 }
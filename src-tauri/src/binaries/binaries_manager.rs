@@ -132,7 +132,9 @@ impl BinaryManager {
             .adapter
             .get_base_fallback_download_url(&selected_version);
 
-        if self.binary_name.eq(Binaries::LolMiner.name()) {
+        if self.binary_name.eq(Binaries::LolMiner.name())
+            || self.binary_name.eq(Binaries::SrbMiner.name())
+        {
             // urls produces will be like:
             // https://github.com/Lolliedieb/lolMiner-releases/releases/download/v1.97/lolMiner_v1.97_Lin64.tar.gz
             // But real format for that specific binary is:
@@ -287,6 +289,8 @@ impl BinaryManager {
                 Binaries::MergeMiningProxy => &TasksTrackers::current().cpu_mining_phase,
                 Binaries::BridgeTapplet => &TasksTrackers::current().wallet_phase,
                 Binaries::LolMiner => &TasksTrackers::current().gpu_mining_phase,
+                Binaries::Graxil => &TasksTrackers::current().gpu_mining_phase,
+                Binaries::SrbMiner => &TasksTrackers::current().cpu_mining_phase,
             };
             let binary_name = self.binary_name.clone();
             let shutdown_signal = task_tacker.get_signal().await;
@@ -141,6 +141,32 @@ impl BinaryResolver {
             ),
         );
 
+        binary_manager.insert(
+            Binaries::Graxil,
+            BinaryManager::new(
+                Binaries::Graxil.name().to_string(),
+                None,
+                Box::new(GithubReleasesAdapter {
+                    repo: "graxil".to_string(),
+                    owner: "tari-project".to_string(),
+                }),
+                true,
+            ),
+        );
+
+        binary_manager.insert(
+            Binaries::SrbMiner,
+            BinaryManager::new(
+                Binaries::SrbMiner.name().to_string(),
+                None,
+                Box::new(GithubReleasesAdapter {
+                    repo: "SRBMiner-Multi".to_string(),
+                    owner: "doktor83".to_string(),
+                }),
+                false,
+            ),
+        );
+
         binary_manager.insert(
             Binaries::MergeMiningProxy,
             BinaryManager::new(
@@ -20,30 +20,41 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::address_book::{self, AddressBookContact, AddressBookFormat};
 use crate::airdrop::{get_der_encode_pub_key, get_websocket_key};
 use crate::app_in_memory_config::{AppInMemoryConfig, DEFAULT_EXCHANGE_ID, ExchangeMiner};
 use crate::auto_launcher::AutoLauncher;
 use crate::binaries::{Binaries, BinaryResolver};
 use crate::configs::config_core::{AirdropTokens, ConfigCore, ConfigCoreContent};
 use crate::configs::config_mining::{
-    ConfigMining, ConfigMiningContent, MiningModeType, PauseOnBatteryModeState,
+    ConfigMining, ConfigMiningContent, GpuDeviceOverrides, MiningModeType, PauseOnBatteryModeState,
 };
 use crate::configs::config_pools::{ConfigPools, ConfigPoolsContent};
 use crate::configs::config_ui::{ConfigUI, ConfigUIContent, DisplayMode};
 use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent, WalletId};
-use crate::configs::pools::BasePoolData;
+use crate::configs::pools::{BasePoolData, CustomPoolDefinition};
 use crate::configs::pools::{cpu_pools::CpuPool, gpu_pools::GpuPool};
 use crate::configs::trait_config::ConfigImpl;
 use crate::consts::DEFAULT_SYSTEM_LOCALE_FALLBACK;
-use crate::event_scheduler::{EventScheduler, SchedulerEventTiming, SchedulerEventType};
+use crate::electricity_tariff::{
+    TariffExpensiveAction, TariffScheduler, TariffTable, find_tariff_conflicts, plan_tariff_events,
+};
+use crate::event_scheduler::{
+    EventScheduler, SchedulerEventTiming, SchedulerEventType, SchedulerMissedRunPolicy,
+};
+use crate::event_scheduler_history::{SchedulerExecutionRecord, SchedulerHistory};
 use crate::events::ConnectionStatusPayload;
 use crate::events_emitter::EventsEmitter;
 use crate::events_manager::EventsManager;
 use crate::internal_wallet::{InternalWallet, PaperWalletConfig, mnemonic_to_tari_cipher_seed};
+use crate::metrics_exporter::MetricsExporter;
+use crate::mining::cpu::consts::CpuMinerType;
 use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::consts::{GpuMinerFeature, GpuMiningAlgorithm, MINERS_PRIORITY};
 use crate::mining::gpu::manager::GpuManager;
 use crate::mining::pools::PoolManagerInterfaceTrait;
 use crate::mining::pools::cpu_pool_manager::CpuPoolManager;
+use crate::mining::pools::earnings_ledger::{DailyPoolEarnings, PoolEarningsLedger, PoolPayout};
 use crate::mining::pools::gpu_pool_manager::GpuPoolManager;
 use crate::network_utils::NetworkExt;
 use crate::node::node_adapter::BaseNodeStatus;
@@ -72,6 +83,8 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::fs::{File, read_dir, remove_dir_all, remove_file};
+use std::net::IpAddr;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
 use std::thread::sleep;
@@ -90,6 +103,11 @@ use urlencoding::encode;
 
 const MAX_ACCEPTABLE_COMMAND_TIME: Duration = Duration::from_secs(1);
 const LOG_TARGET_WEB: &str = "tari::universe::web";
+const MIN_POOL_FAILOVER_THRESHOLD_SECS: u64 = 30;
+const DEFAULT_POOL_EARNINGS_DAYS: u32 = 30;
+const DEFAULT_SCHEDULER_HISTORY_LIMIT: usize = 200;
+const TEMPERATURE_CEILING_RANGE: std::ops::RangeInclusive<u32> = 40..=105;
+const MAX_THERMAL_THROTTLE_HYSTERESIS: u32 = 30;
 
 #[derive(Debug, Serialize)]
 pub struct ApplicationsInformation {
@@ -1012,6 +1030,37 @@ pub async fn set_cpu_mining_enabled(enabled: bool) -> Result<(), InvokeError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_cpu_miner_type(cpu_miner_type: CpuMinerType) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_cpu_miner_type] called with miner: {cpu_miner_type}");
+
+    if !cpu_miner_type.is_supported_on_current_platform() {
+        return Err(InvokeError::from(format!(
+            "{cpu_miner_type} is not supported on this platform"
+        )));
+    }
+
+    ConfigMining::update_field(ConfigMiningContent::set_cpu_miner_type, cpu_miner_type)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    // Restarting the phase downloads the selected miner binary if needed and loads it into CpuManager
+    tauri::async_runtime::spawn(async move {
+        SetupManager::get_instance()
+            .restart_phases(vec![SetupPhase::CpuMining])
+            .await;
+    });
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC,
+            "set_cpu_miner_type took too long: {:?}",
+            timer.elapsed()
+        );
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn sign_ws_data(data: String) -> Result<SignWsDataResponse, String> {
     let key: ring::signature::Ed25519KeyPair = get_websocket_key().map_err(|e| {
@@ -1078,6 +1127,324 @@ pub async fn toggle_device_exclusion(device_index: u32, excluded: bool) -> Resul
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_gpu_device_overrides(
+    device_index: u32,
+    overrides: GpuDeviceOverrides,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_gpu_device_overrides] called with device_index: {device_index}, overrides: {overrides:?}");
+
+    if ConfigMining::content()
+        .await
+        .gpu_devices_settings()
+        .get(device_index)
+        .is_none()
+    {
+        return Err(InvokeError::from(format!(
+            "GPU device {device_index} was not detected"
+        )));
+    }
+    if overrides
+        .max_temperature
+        .is_some_and(|temperature| !TEMPERATURE_CEILING_RANGE.contains(&temperature))
+    {
+        return Err(InvokeError::from(format!(
+            "Temperature cap must be between {} and {} degrees",
+            TEMPERATURE_CEILING_RANGE.start(),
+            TEMPERATURE_CEILING_RANGE.end()
+        )));
+    }
+    let pool_data = match &overrides.pool {
+        Some(pool) => match ConfigPools::content().await.gpu_pools().get(pool).cloned() {
+            Some(pool_data) => Some(pool_data),
+            None => {
+                return Err(InvokeError::from(format!("GPU pool {pool} does not exist")));
+            }
+        },
+        None => None,
+    };
+    // Miners the device can run with, given its algorithm and pool assignment
+    let device_miners = MINERS_PRIORITY
+        .iter()
+        .filter(|miner| {
+            overrides
+                .algorithm
+                .as_ref()
+                .is_none_or(|algorithm| miner.supported_algorithms().contains(algorithm))
+                && pool_data.as_ref().is_none_or(|pool_data| {
+                    miner.is_pool_supported(pool_data)
+                        && (!pool_data.pool_type.is_custom()
+                            || overrides.algorithm.is_none()
+                            || pool_data.algorithm == overrides.algorithm)
+                })
+        })
+        .collect::<Vec<_>>();
+    if let (Some(pool), Some(algorithm)) = (&overrides.pool, &overrides.algorithm)
+        && device_miners.is_empty()
+    {
+        return Err(InvokeError::from(format!(
+            "GPU pool {pool} does not support {algorithm:?} algorithm"
+        )));
+    }
+    if overrides.max_temperature.is_some()
+        && !device_miners
+            .iter()
+            .any(|miner| miner.has_feature(&GpuMinerFeature::TemperatureLimit))
+    {
+        return Err(InvokeError::from(
+            "Temperature cap is not supported by the GPU miners this device is assigned to"
+                .to_string(),
+        ));
+    }
+
+    ConfigMining::update_field(
+        ConfigMiningContent::set_gpu_device_overrides,
+        (device_index, overrides),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_update_gpu_devices_settings(
+        ConfigMining::content().await.gpu_devices_settings().clone(),
+    )
+    .await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_gpu_device_overrides took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_thermal_throttle_settings(
+    enabled: bool,
+    cpu_max_temperature: u32,
+    gpu_max_temperature: u32,
+    hysteresis: u32,
+    cooldown_secs: u64,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_thermal_throttle_settings] called with enabled: {enabled:?}, cpu_max_temperature: {cpu_max_temperature:?}, gpu_max_temperature: {gpu_max_temperature:?}, hysteresis: {hysteresis:?}, cooldown_secs: {cooldown_secs:?}");
+
+    if !TEMPERATURE_CEILING_RANGE.contains(&cpu_max_temperature)
+        || !TEMPERATURE_CEILING_RANGE.contains(&gpu_max_temperature)
+    {
+        return Err(InvokeError::from(format!(
+            "Temperature ceilings must be between {} and {} degrees",
+            TEMPERATURE_CEILING_RANGE.start(),
+            TEMPERATURE_CEILING_RANGE.end()
+        )));
+    }
+    if hysteresis == 0 || hysteresis > MAX_THERMAL_THROTTLE_HYSTERESIS {
+        return Err(InvokeError::from(format!(
+            "Hysteresis must be between 1 and {MAX_THERMAL_THROTTLE_HYSTERESIS} degrees"
+        )));
+    }
+
+    ConfigMining::update_field(ConfigMiningContent::set_thermal_throttle_enabled, enabled)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(
+        ConfigMiningContent::set_cpu_max_temperature,
+        cpu_max_temperature,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(
+        ConfigMiningContent::set_gpu_max_temperature,
+        gpu_max_temperature,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(
+        ConfigMiningContent::set_thermal_throttle_hysteresis,
+        hysteresis,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(
+        ConfigMiningContent::set_thermal_throttle_cooldown_secs,
+        cooldown_secs,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+
+    GpuManager::write()
+        .await
+        .reload_thermal_limit()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_thermal_throttle_settings took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_auto_mining_mode_settings(
+    electricity_price_per_kwh: f64,
+    xtm_price: f64,
+    cpu_watts: f64,
+    gpu_watts: f64,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_auto_mining_mode_settings] called with electricity_price_per_kwh: {electricity_price_per_kwh:?}, xtm_price: {xtm_price:?}, cpu_watts: {cpu_watts:?}, gpu_watts: {gpu_watts:?}");
+
+    if [electricity_price_per_kwh, xtm_price, cpu_watts, gpu_watts]
+        .iter()
+        .any(|value| !value.is_finite() || *value < 0.0)
+    {
+        return Err(InvokeError::from(
+            "Auto mining mode prices and wattages must be non-negative numbers".to_string(),
+        ));
+    }
+
+    ConfigMining::update_field(
+        ConfigMiningContent::set_auto_mode_electricity_price_per_kwh,
+        electricity_price_per_kwh,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_auto_mode_xtm_price, xtm_price)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_auto_mode_cpu_watts, cpu_watts)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_auto_mode_gpu_watts, gpu_watts)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_auto_mining_mode_settings took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_tariff_schedule_settings(
+    file_path: Option<String>,
+    cheap_price_per_kwh: f64,
+    expensive_price_per_kwh: f64,
+    expensive_action: TariffExpensiveAction,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_tariff_schedule_settings] called with file_path: {file_path:?}, cheap_price_per_kwh: {cheap_price_per_kwh:?}, expensive_price_per_kwh: {expensive_price_per_kwh:?}, expensive_action: {expensive_action:?}");
+
+    if [cheap_price_per_kwh, expensive_price_per_kwh]
+        .iter()
+        .any(|value| !value.is_finite() || *value < 0.0)
+        || cheap_price_per_kwh >= expensive_price_per_kwh
+    {
+        return Err(InvokeError::from(
+            "Tariff prices must be non-negative and the cheap price must be below the expensive price"
+                .to_string(),
+        ));
+    }
+
+    let file_path = file_path
+        .filter(|path| !path.trim().is_empty())
+        .map(PathBuf::from);
+    if let Some(path) = &file_path {
+        let table = TariffTable::load(path).map_err(InvokeError::from_anyhow)?;
+        let planned_events = plan_tariff_events(
+            &table,
+            cheap_price_per_kwh,
+            expensive_price_per_kwh,
+            expensive_action,
+        )
+        .map_err(InvokeError::from_anyhow)?;
+        let existing_events = EventScheduler::instance()
+            .list_events()
+            .await
+            .map_err(|e| InvokeError::from(e.to_string()))?;
+        let conflicts =
+            find_tariff_conflicts(&planned_events, &existing_events, chrono::Local::now());
+        if !conflicts.is_empty() {
+            return Err(InvokeError::from(format!(
+                "Conflicting tariff slots: {}",
+                conflicts.join("; ")
+            )));
+        }
+    }
+
+    ConfigCore::update_field(ConfigCoreContent::set_tariff_file_path, file_path)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigCore::update_field(
+        ConfigCoreContent::set_tariff_cheap_price_per_kwh,
+        cheap_price_per_kwh,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigCore::update_field(
+        ConfigCoreContent::set_tariff_expensive_price_per_kwh,
+        expensive_price_per_kwh,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigCore::update_field(
+        ConfigCoreContent::set_tariff_expensive_action,
+        expensive_action,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    TariffScheduler::apply()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_tariff_schedule_settings took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_metrics_exporter_settings(
+    enabled: bool,
+    bind_address: String,
+    port: u16,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_metrics_exporter_settings] called with enabled: {enabled:?}, bind_address: {bind_address:?}, port: {port:?}");
+
+    let bind_address = bind_address
+        .trim()
+        .parse::<IpAddr>()
+        .map_err(|e| InvokeError::from(format!("Invalid bind address {bind_address:?}: {e}")))?;
+    if port == 0 {
+        return Err(InvokeError::from(
+            "Metrics exporter port must be between 1 and 65535".to_string(),
+        ));
+    }
+
+    ConfigCore::update_field(ConfigCoreContent::set_metrics_exporter_enabled, enabled)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigCore::update_field(
+        ConfigCoreContent::set_metrics_exporter_bind_address,
+        bind_address,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigCore::update_field(ConfigCoreContent::set_metrics_exporter_port, port)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_core_config_loaded(&ConfigCore::content().await).await;
+
+    MetricsExporter::apply_config()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_metrics_exporter_settings took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_gpu_mining_enabled(enabled: bool) -> Result<(), InvokeError> {
     let timer = Instant::now();
@@ -1601,6 +1968,8 @@ pub async fn send_one_sided_to_stealth_address(
 ) -> Result<(), String> {
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[send_one_sided_to_stealth_address] called with args: (amount: {amount:?}, destination: {destination:?}, payment_id: {payment_id:?})");
+    let (destination, payment_id) =
+        address_book::resolve_destination(destination, payment_id).await;
     state
         .wallet_manager
         .send_one_sided_to_stealth_address(amount, destination, payment_id, &app_handle)
@@ -1618,6 +1987,45 @@ pub async fn send_one_sided_to_stealth_address(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn get_address_book_contacts() -> Result<Vec<AddressBookContact>, InvokeError> {
+    Ok(address_book::list_contacts().await)
+}
+
+#[tauri::command]
+pub async fn save_address_book_contact(
+    contact: AddressBookContact,
+    previous_name: Option<String>,
+) -> Result<(), InvokeError> {
+    address_book::save_contact(contact, previous_name)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn remove_address_book_contact(name: String) -> Result<(), InvokeError> {
+    address_book::remove_contact(name)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn import_address_book(
+    contents: String,
+    format: AddressBookFormat,
+) -> Result<usize, InvokeError> {
+    address_book::import_contacts(&contents, format)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn export_address_book(format: AddressBookFormat) -> Result<String, InvokeError> {
+    address_book::export_contacts(format)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
 #[tauri::command]
 pub fn verify_address_for_send(
     address: String,
@@ -1955,6 +2363,11 @@ pub async fn reset_gpu_pool_config(gpu_pool_type: String) -> Result<(), InvokeEr
     info!(target: LOG_TARGET_APP_LOGIC, "[reset_pool_gpu_pool_config] called with gpu_pool_name: {gpu_pool_type:?}");
 
     let gpu_pool = GpuPool::from_string(&gpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if gpu_pool.is_custom() {
+        return Err(InvokeError::from(
+            "Custom GPU pools have no default config to reset to".to_string(),
+        ));
+    }
 
     ConfigPools::update_field(
         ConfigPoolsContent::update_current_gpu_config,
@@ -1977,6 +2390,11 @@ pub async fn reset_cpu_pool_config(cpu_pool_type: String) -> Result<(), InvokeEr
     info!(target: LOG_TARGET_APP_LOGIC, "[reset_pool_cpu_pool_config] called with cpu_pool_name: {cpu_pool_type:?}");
 
     let cpu_pool = CpuPool::from_string(&cpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if cpu_pool.is_custom() {
+        return Err(InvokeError::from(
+            "Custom CPU pools have no default config to reset to".to_string(),
+        ));
+    }
 
     ConfigPools::update_field(
         ConfigPoolsContent::update_current_cpu_config,
@@ -1993,6 +2411,341 @@ pub async fn reset_cpu_pool_config(cpu_pool_type: String) -> Result<(), InvokeEr
     Ok(())
 }
 
+#[tauri::command]
+pub async fn add_custom_cpu_pool(pool: CustomPoolDefinition) -> Result<String, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[add_custom_cpu_pool] called with pool: {pool:?}");
+
+    pool.validate_cpu().map_err(InvokeError::from_anyhow)?;
+    let cpu_pool = CpuPool::new_custom();
+
+    ConfigPools::update_field(
+        ConfigPoolsContent::upsert_custom_cpu_pool,
+        pool.into_pool_data(cpu_pool.clone()),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "add_custom_cpu_pool took too long: {:?}", timer.elapsed());
+    }
+    Ok(cpu_pool.key_string())
+}
+
+#[tauri::command]
+pub async fn add_custom_gpu_pool(pool: CustomPoolDefinition) -> Result<String, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[add_custom_gpu_pool] called with pool: {pool:?}");
+
+    pool.validate_gpu().map_err(InvokeError::from_anyhow)?;
+    let gpu_pool = GpuPool::new_custom();
+
+    ConfigPools::update_field(
+        ConfigPoolsContent::upsert_custom_gpu_pool,
+        pool.into_pool_data(gpu_pool.clone()),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "add_custom_gpu_pool took too long: {:?}", timer.elapsed());
+    }
+    Ok(gpu_pool.key_string())
+}
+
+#[tauri::command]
+pub async fn edit_custom_cpu_pool(
+    cpu_pool_type: String,
+    pool: CustomPoolDefinition,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[edit_custom_cpu_pool] called with cpu_pool_type: {cpu_pool_type:?}, pool: {pool:?}");
+
+    let cpu_pool = CpuPool::from_string(&cpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if !cpu_pool.is_custom()
+        || !ConfigPools::content()
+            .await
+            .cpu_pools()
+            .contains_key(&cpu_pool)
+    {
+        return Err(InvokeError::from(format!(
+            "Custom CPU pool {cpu_pool_type} does not exist"
+        )));
+    }
+    pool.validate_cpu().map_err(InvokeError::from_anyhow)?;
+
+    ConfigPools::update_field(
+        ConfigPoolsContent::upsert_custom_cpu_pool,
+        pool.into_pool_data(cpu_pool.clone()),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    let current_cpu_pool = ConfigPools::content().await.current_cpu_pool();
+    if current_cpu_pool.pool_type == cpu_pool {
+        CpuPoolManager::handle_new_selected_pool(current_cpu_pool).await;
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "edit_custom_cpu_pool took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn edit_custom_gpu_pool(
+    gpu_pool_type: String,
+    pool: CustomPoolDefinition,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[edit_custom_gpu_pool] called with gpu_pool_type: {gpu_pool_type:?}, pool: {pool:?}");
+
+    let gpu_pool = GpuPool::from_string(&gpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if !gpu_pool.is_custom()
+        || !ConfigPools::content()
+            .await
+            .gpu_pools()
+            .contains_key(&gpu_pool)
+    {
+        return Err(InvokeError::from(format!(
+            "Custom GPU pool {gpu_pool_type} does not exist"
+        )));
+    }
+    pool.validate_gpu().map_err(InvokeError::from_anyhow)?;
+
+    ConfigPools::update_field(
+        ConfigPoolsContent::upsert_custom_gpu_pool,
+        pool.into_pool_data(gpu_pool.clone()),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    let current_gpu_pool = ConfigPools::content().await.current_gpu_pool();
+    if current_gpu_pool.pool_type == gpu_pool {
+        GpuPoolManager::handle_new_selected_pool(current_gpu_pool).await;
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "edit_custom_gpu_pool took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn delete_custom_cpu_pool(cpu_pool_type: String) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[delete_custom_cpu_pool] called with cpu_pool_type: {cpu_pool_type:?}");
+
+    let cpu_pool = CpuPool::from_string(&cpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if !cpu_pool.is_custom() {
+        return Err(InvokeError::from(
+            "Only custom CPU pools can be deleted".to_string(),
+        ));
+    }
+    let was_selected = ConfigPools::content().await.current_cpu_pool().pool_type == cpu_pool;
+
+    ConfigPools::update_field(ConfigPoolsContent::remove_custom_cpu_pool, cpu_pool)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    if was_selected {
+        CpuPoolManager::handle_new_selected_pool(ConfigPools::content().await.current_cpu_pool())
+            .await;
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "delete_custom_cpu_pool took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn delete_custom_gpu_pool(gpu_pool_type: String) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[delete_custom_gpu_pool] called with gpu_pool_type: {gpu_pool_type:?}");
+
+    let gpu_pool = GpuPool::from_string(&gpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if !gpu_pool.is_custom() {
+        return Err(InvokeError::from(
+            "Only custom GPU pools can be deleted".to_string(),
+        ));
+    }
+    let was_selected = ConfigPools::content().await.current_gpu_pool().pool_type == gpu_pool;
+
+    ConfigPools::update_field(ConfigPoolsContent::remove_custom_gpu_pool, gpu_pool)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    if was_selected {
+        GpuPoolManager::handle_new_selected_pool(ConfigPools::content().await.current_gpu_pool())
+            .await;
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "delete_custom_gpu_pool took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_pool_failover_settings(
+    enabled: bool,
+    disconnect_threshold_secs: u64,
+    missing_shares_threshold_secs: u64,
+    probe_latency_on_startup: bool,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_pool_failover_settings] called with enabled: {enabled:?}, disconnect_threshold_secs: {disconnect_threshold_secs:?}, missing_shares_threshold_secs: {missing_shares_threshold_secs:?}, probe_latency_on_startup: {probe_latency_on_startup:?}");
+
+    if disconnect_threshold_secs < MIN_POOL_FAILOVER_THRESHOLD_SECS
+        || missing_shares_threshold_secs < MIN_POOL_FAILOVER_THRESHOLD_SECS
+    {
+        return Err(InvokeError::from(format!(
+            "Failover thresholds have to be at least {MIN_POOL_FAILOVER_THRESHOLD_SECS} seconds"
+        )));
+    }
+
+    ConfigPools::update_field(ConfigPoolsContent::set_pool_failover_enabled, enabled)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigPools::update_field(
+        ConfigPoolsContent::set_pool_failover_disconnect_threshold_secs,
+        disconnect_threshold_secs,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigPools::update_field(
+        ConfigPoolsContent::set_pool_failover_missing_shares_threshold_secs,
+        missing_shares_threshold_secs,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigPools::update_field(
+        ConfigPoolsContent::set_probe_pool_latency_on_startup,
+        probe_latency_on_startup,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    let config = ConfigPools::content().await;
+    CpuPoolManager::load_failover_thresholds(&config).await;
+    GpuPoolManager::load_failover_thresholds(&config).await;
+    EventsEmitter::emit_pools_config_loaded(&config).await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_pool_failover_settings took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_cpu_failover_pools(cpu_pools: Vec<String>) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_cpu_failover_pools] called with cpu_pools: {cpu_pools:?}");
+
+    let available_pools = ConfigPools::content().await.cpu_pools().clone();
+    let mut failover_pools = Vec::with_capacity(cpu_pools.len());
+    for cpu_pool in &cpu_pools {
+        let cpu_pool = CpuPool::from_string(cpu_pool).map_err(InvokeError::from_anyhow)?;
+        if !available_pools.contains_key(&cpu_pool) {
+            return Err(InvokeError::from(format!(
+                "CPU pool {cpu_pool} does not exist"
+            )));
+        }
+        if !failover_pools.contains(&cpu_pool) {
+            failover_pools.push(cpu_pool);
+        }
+    }
+
+    ConfigPools::update_field(ConfigPoolsContent::set_cpu_failover_pools, failover_pools)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_cpu_failover_pools took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_gpu_failover_pools(
+    algorithm: GpuMiningAlgorithm,
+    gpu_pools: Vec<String>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_gpu_failover_pools] called with algorithm: {algorithm:?}, gpu_pools: {gpu_pools:?}");
+
+    let available_pools = ConfigPools::content().await.gpu_pools().clone();
+    let mut failover_pools = Vec::with_capacity(gpu_pools.len());
+    for gpu_pool in &gpu_pools {
+        let gpu_pool = GpuPool::from_string(gpu_pool).map_err(InvokeError::from_anyhow)?;
+        if !available_pools.contains_key(&gpu_pool) {
+            return Err(InvokeError::from(format!(
+                "GPU pool {gpu_pool} does not exist"
+            )));
+        }
+        if !failover_pools.contains(&gpu_pool) {
+            failover_pools.push(gpu_pool);
+        }
+    }
+
+    ConfigPools::update_field(
+        ConfigPoolsContent::set_gpu_failover_pools_for,
+        (algorithm, failover_pools),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await.clone()).await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_gpu_failover_pools took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_pool_daily_earnings(
+    pool: Option<String>,
+    days: Option<u32>,
+) -> Result<Vec<DailyPoolEarnings>, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[get_pool_daily_earnings] called with pool: {pool:?}, days: {days:?}");
+
+    let daily_earnings = PoolEarningsLedger::daily_earnings(
+        pool.as_deref(),
+        days.unwrap_or(DEFAULT_POOL_EARNINGS_DAYS),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_pool_daily_earnings took too long: {:?}", timer.elapsed());
+    }
+    Ok(daily_earnings)
+}
+
+#[tauri::command]
+pub async fn get_pool_payout_history(pool: Option<String>) -> Result<Vec<PoolPayout>, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[get_pool_payout_history] called with pool: {pool:?}");
+
+    let payouts = PoolEarningsLedger::payout_history(pool.as_deref())
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_pool_payout_history took too long: {:?}", timer.elapsed());
+    }
+    Ok(payouts)
+}
+
 #[tauri::command]
 pub async fn create_pin(app_handle: tauri::AppHandle) -> Result<(), String> {
     InternalWallet::create_pin(&app_handle)
@@ -2088,17 +2841,60 @@ pub async fn add_scheduler_event(
     event_id: String,
     event_time: SchedulerEventTiming,
     event_type: SchedulerEventType,
+    missed_run_policy: Option<SchedulerMissedRunPolicy>,
 ) -> Result<(), String> {
-    info!(target: LOG_TARGET_APP_LOGIC, "add_scheduler_event called with event_id: {event_id:?}, event_time: {event_time:?}, event_type: {event_type:?}");
+    info!(target: LOG_TARGET_APP_LOGIC, "add_scheduler_event called with event_id: {event_id:?}, event_time: {event_time:?}, event_type: {event_type:?}, missed_run_policy: {missed_run_policy:?}");
 
-    EventScheduler::instance()
+    let event_id = EventScheduler::instance()
         .schedule_event(event_type, event_id, event_time)
         .await
         .map_err(|e| e.to_string())?;
+    if let Some(missed_run_policy) = missed_run_policy {
+        EventScheduler::instance()
+            .set_missed_run_policy(event_id, missed_run_policy)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_scheduler_event_missed_run_policy(
+    event_id: String,
+    missed_run_policy: SchedulerMissedRunPolicy,
+) -> Result<(), String> {
+    info!(target: LOG_TARGET_APP_LOGIC, "set_scheduler_event_missed_run_policy called with event_id: {event_id:?}, missed_run_policy: {missed_run_policy:?}");
+
+    EventScheduler::instance()
+        .set_missed_run_policy(event_id, missed_run_policy)
+        .await
+        .map_err(|e| e.to_string())?;
 
     Ok(())
 }
 
+#[tauri::command]
+pub async fn get_scheduler_history(
+    event_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<SchedulerExecutionRecord>, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[get_scheduler_history] called with event_id: {event_id:?}, limit: {limit:?}");
+
+    let history = SchedulerHistory::history(
+        event_id.as_deref(),
+        limit.unwrap_or(DEFAULT_SCHEDULER_HISTORY_LIMIT),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_scheduler_history took too long: {:?}", timer.elapsed());
+    }
+    Ok(history)
+}
+
 #[tauri::command]
 pub async fn remove_scheduler_event(event_id: String) -> Result<(), String> {
     info!(target: LOG_TARGET_APP_LOGIC, "remove_scheduler_event called with event_id: {event_id:?}");
@@ -25,6 +25,7 @@ use log::error;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
 use std::path::PathBuf;
 use std::{sync::LazyLock, time::SystemTime};
 use tari_common::configuration::Network;
@@ -34,7 +35,9 @@ use tokio::sync::RwLock;
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::ab_test_selector::ABTestSelector;
 use crate::app_in_memory_config::{DEFAULT_EXCHANGE_ID, MinerType};
+use crate::electricity_tariff::TariffExpensiveAction;
 use crate::event_scheduler::ScheduledEventInfo;
+use crate::metrics_exporter::DEFAULT_METRICS_EXPORTER_PORT;
 use crate::network_utils::NetworkExt;
 use crate::node::node_manager::NodeType;
 use crate::shutdown_manager::ShutdownMode;
@@ -76,9 +79,19 @@ pub struct ConfigCoreContent {
     node_type: NodeType,
     exchange_id: String,
     scheduler_events: HashMap<String, ScheduledEventInfo>,
+    scheduler_last_active_at: Option<SystemTime>,
     shutdown_mode: ShutdownMode,
     show_window_on_startup: bool,
     node_data_directory: Option<PathBuf>,
+    tariff_file_path: Option<PathBuf>,
+    tariff_cheap_price_per_kwh: f64,
+    tariff_expensive_price_per_kwh: f64,
+    tariff_expensive_action: TariffExpensiveAction,
+    metrics_exporter_enabled: bool,
+    metrics_exporter_bind_address: IpAddr,
+    metrics_exporter_port: u16,
+    /// Serve the local control socket in desktop mode, the daemon always serves it
+    control_socket_enabled: bool,
 }
 
 fn default_monero_nodes() -> Vec<String> {
@@ -137,9 +150,18 @@ impl Default for ConfigCoreContent {
             },
             exchange_id: DEFAULT_EXCHANGE_ID.to_string(),
             scheduler_events: HashMap::new(),
+            scheduler_last_active_at: None,
             shutdown_mode: ShutdownMode::Tasktray,
             show_window_on_startup: true,
             node_data_directory: None,
+            tariff_file_path: None,
+            tariff_cheap_price_per_kwh: 0.10,
+            tariff_expensive_price_per_kwh: 0.25,
+            tariff_expensive_action: TariffExpensiveAction::default(),
+            metrics_exporter_enabled: false,
+            metrics_exporter_bind_address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            metrics_exporter_port: DEFAULT_METRICS_EXPORTER_PORT,
+            control_socket_enabled: false,
         }
     }
 }
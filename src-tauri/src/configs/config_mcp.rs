@@ -33,6 +33,8 @@ use tauri::AppHandle;
 use tokio::sync::RwLock;
 
 pub const MCP_CONFIG_VERSION: u32 = 0;
+/// Tools registered in the transaction tier of the MCP tool router
+pub const TRANSACTION_TIER_TOOLS: &[&str] = &["send_transaction"];
 pub(crate) const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
 static INSTANCE: LazyLock<RwLock<ConfigMcp>> = LazyLock::new(|| RwLock::new(ConfigMcp::new()));
 
@@ -131,6 +133,136 @@ pub(crate) mod token_cipher {
             None => Ok(None),
         }
     }
+
+    pub fn serialize_required_token<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&encrypt_deterministic(value))
+    }
+
+    pub fn deserialize_required_token<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        let encoded = String::deserialize(deserializer)?;
+        decrypt(&encoded)
+            .map_err(|e| serde::de::Error::custom(format!("failed to decrypt MCP token: {e}")))
+    }
+}
+
+/// Permission granted to a named MCP client token
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum McpScope {
+    /// Every tool of the read tier
+    Read,
+    /// Every tool of the control tier
+    Control,
+    /// Every tool of the transaction tier
+    Transact,
+    /// A single tool regardless of its tier, serialized as `tool:<name>`
+    Tool(String),
+}
+
+impl McpScope {
+    /// Whether the scope covers the tool, tiers are named as in the MCP tool router
+    pub fn covers(&self, tool_name: &str, tier: &str) -> bool {
+        match self {
+            McpScope::Read => tier == "read",
+            McpScope::Control => tier == "control",
+            McpScope::Transact => tier == "transaction",
+            McpScope::Tool(name) => name == tool_name,
+        }
+    }
+
+    /// Whether the scope lets a client call any tool of the transaction tier
+    pub fn grants_transactions(&self) -> bool {
+        TRANSACTION_TIER_TOOLS
+            .iter()
+            .any(|tool| self.covers(tool, "transaction"))
+    }
+}
+
+impl std::fmt::Display for McpScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            McpScope::Read => write!(f, "read"),
+            McpScope::Control => write!(f, "control"),
+            McpScope::Transact => write!(f, "transact"),
+            McpScope::Tool(name) => write!(f, "tool:{name}"),
+        }
+    }
+}
+
+impl TryFrom<String> for McpScope {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "read" => Ok(McpScope::Read),
+            "control" => Ok(McpScope::Control),
+            "transact" => Ok(McpScope::Transact),
+            _ => match value.strip_prefix("tool:") {
+                Some(name) if !name.is_empty() => Ok(McpScope::Tool(name.to_string())),
+                _ => Err(format!("Invalid MCP scope: {value}")),
+            },
+        }
+    }
+}
+
+impl From<McpScope> for String {
+    fn from(scope: McpScope) -> Self {
+        scope.to_string()
+    }
+}
+
+/// Named MCP client with its own token, permissions and limits
+#[derive(Serialize, Deserialize, Clone)]
+pub struct McpClientToken {
+    pub name: String,
+    #[serde(
+        serialize_with = "token_cipher::serialize_required_token",
+        deserialize_with = "token_cipher::deserialize_required_token"
+    )]
+    pub token: String,
+    pub scopes: Vec<McpScope>,
+    pub created_at: SystemTime,
+    /// `None` never expires
+    pub expires_at: Option<SystemTime>,
+    /// Applied on top of the global `max_transaction_amount`, in micro minotari
+    pub max_transaction_amount: Option<u64>,
+    /// Tool calls allowed per minute, `None` is unlimited
+    pub rate_limit_per_minute: Option<u32>,
+}
+
+impl McpClientToken {
+    pub fn new(
+        name: String,
+        scopes: Vec<McpScope>,
+        expiry_days: Option<u32>,
+        max_transaction_amount: Option<u64>,
+        rate_limit_per_minute: Option<u32>,
+    ) -> Self {
+        let now = SystemTime::now();
+        Self {
+            name,
+            token: ConfigMcpContent::generate_token(),
+            scopes,
+            created_at: now,
+            expires_at: expiry_days.map(|days| {
+                now + std::time::Duration::from_secs(u64::from(days) * SECONDS_PER_DAY)
+            }),
+            max_transaction_amount,
+            rate_limit_per_minute,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expiry| SystemTime::now() > expiry)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -157,6 +289,19 @@ pub struct ConfigMcpContent {
     read_tier_enabled: bool,
     control_tier_enabled: bool,
     rate_limit_transaction: u32,
+    client_tokens: Vec<McpClientToken>,
+    /// Writes the credential file used by the `mcp-stdio` proxy while the server runs
+    stdio_enabled: bool,
+    /// Named client whose scopes and limits apply to the `mcp-stdio` proxy
+    stdio_client: Option<String>,
+    // ======= Spending limits, amounts in micro minotari =======
+    daily_budget: Option<u64>,
+    weekly_budget: Option<u64>,
+    /// Allowlisted destinations below this amount are sent without the confirmation dialog,
+    /// the PIN is still asked as it decrypts the seed used for signing
+    allowlist_confirmation_threshold: Option<u64>,
+    allowlisted_addresses: Vec<String>,
+    denylisted_addresses: Vec<String>,
 }
 
 impl Default for ConfigMcpContent {
@@ -175,6 +320,14 @@ impl Default for ConfigMcpContent {
             read_tier_enabled: true,
             control_tier_enabled: true,
             rate_limit_transaction: 5,
+            client_tokens: Vec::new(),
+            stdio_enabled: false,
+            stdio_client: None,
+            daily_budget: None,
+            weekly_budget: None,
+            allowlist_confirmation_threshold: None,
+            allowlisted_addresses: Vec::new(),
+            denylisted_addresses: Vec::new(),
         }
     }
 }
@@ -226,6 +379,41 @@ impl ConfigMcpContent {
         }
     }
 
+    /// Insert a named client token or replace the one with the same name
+    pub fn upsert_client_token(&mut self, client: McpClientToken) -> &mut Self {
+        self.client_tokens
+            .retain(|existing| existing.name != client.name);
+        self.client_tokens.push(client);
+        self
+    }
+
+    pub fn remove_client_token(&mut self, name: String) -> &mut Self {
+        self.client_tokens.retain(|client| client.name != name);
+        // The stdio proxy must not keep the permissions of a revoked client
+        if self.stdio_client.as_ref() == Some(&name) {
+            self.stdio_client = None;
+            self.stdio_enabled = false;
+        }
+        self
+    }
+
+    /// Named client the stdio proxy is bound to, if it still exists
+    pub fn stdio_client_token(&self) -> Option<&McpClientToken> {
+        let name = self.stdio_client.as_ref()?;
+        self.client_tokens
+            .iter()
+            .find(|client| &client.name == name)
+    }
+
+    /// Finds the named client owning the token, compared via hashes to avoid timing side-channels
+    pub fn find_client_token(&self, token: &str) -> Option<&McpClientToken> {
+        let provided = ring::digest::digest(&ring::digest::SHA256, token.as_bytes());
+        self.client_tokens.iter().find(|client| {
+            ring::digest::digest(&ring::digest::SHA256, client.token.as_bytes()).as_ref()
+                == provided.as_ref()
+        })
+    }
+
     pub fn is_token_expired(&self) -> bool {
         match self.token_expires_at {
             Some(expiry) => SystemTime::now() > expiry,
@@ -256,6 +444,11 @@ impl ConfigMcpContent {
                 );
             }
             obj.remove("bearer_token");
+            if let Some(clients) = obj.get_mut("client_tokens").and_then(|v| v.as_array_mut()) {
+                for client in clients.iter_mut().filter_map(|c| c.as_object_mut()) {
+                    client.remove("token");
+                }
+            }
         }
         Ok(value)
     }
@@ -20,7 +20,7 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use super::config_mcp::ConfigMcpContent;
+use super::config_mcp::{ConfigMcpContent, McpClientToken, McpScope};
 
 fn ensure_test_identity() {
     super::config_mcp::token_cipher::set_anon_id("test-anon-id".to_string());
@@ -308,3 +308,115 @@ fn config_serialization_with_token() {
 
     assert_eq!(config.bearer_token(), deserialized.bearer_token());
 }
+
+// =============================================================================
+// Named client tokens
+// =============================================================================
+
+#[test]
+fn scopes_roundtrip_as_strings() {
+    for raw in ["read", "control", "transact", "tool:get_pool_status"] {
+        let scope = McpScope::try_from(raw.to_string()).unwrap();
+        assert_eq!(String::from(scope), raw);
+    }
+    assert!(McpScope::try_from("tool:".to_string()).is_err());
+    assert!(McpScope::try_from("admin".to_string()).is_err());
+}
+
+#[test]
+fn scopes_granting_transaction_tools_are_detected() {
+    assert!(McpScope::Transact.grants_transactions());
+    assert!(McpScope::Tool("send_transaction".to_string()).grants_transactions());
+    assert!(!McpScope::Tool("get_pool_status".to_string()).grants_transactions());
+    assert!(!McpScope::Read.grants_transactions());
+    assert!(!McpScope::Control.grants_transactions());
+}
+
+#[test]
+fn client_tokens_are_encrypted_and_roundtrip() {
+    ensure_test_identity();
+    let client = McpClientToken::new(
+        "agent".to_string(),
+        vec![McpScope::Read, McpScope::Tool("start_mining".to_string())],
+        Some(7),
+        None,
+        Some(30),
+    );
+    let token = client.token.clone();
+    let mut config = ConfigMcpContent::default();
+    config.upsert_client_token(client);
+
+    let serialized = serde_json::to_string(&config).unwrap();
+    assert!(!serialized.contains(&token));
+
+    let deserialized: ConfigMcpContent = serde_json::from_str(&serialized).unwrap();
+    let restored = deserialized.find_client_token(&token).unwrap();
+    assert_eq!(restored.name, "agent");
+    assert_eq!(restored.rate_limit_per_minute, Some(30));
+    assert!(!restored.is_expired());
+}
+
+#[test]
+fn upsert_replaces_client_with_same_name_and_remove_drops_it() {
+    ensure_test_identity();
+    let mut config = ConfigMcpContent::default();
+    config.upsert_client_token(McpClientToken::new(
+        "agent".to_string(),
+        vec![McpScope::Read],
+        None,
+        None,
+        None,
+    ));
+    config.upsert_client_token(McpClientToken::new(
+        "agent".to_string(),
+        vec![McpScope::Control],
+        None,
+        None,
+        None,
+    ));
+    assert_eq!(config.client_tokens().len(), 1);
+    assert_eq!(config.client_tokens()[0].scopes, vec![McpScope::Control]);
+
+    config.remove_client_token("agent".to_string());
+    assert!(config.client_tokens().is_empty());
+}
+
+#[test]
+fn redacted_value_omits_client_tokens() {
+    ensure_test_identity();
+    let client = McpClientToken::new("agent".to_string(), vec![McpScope::Read], None, None, None);
+    let token = client.token.clone();
+    let mut config = ConfigMcpContent::default();
+    config.upsert_client_token(client);
+
+    let value = config.to_redacted_value().unwrap();
+    assert!(!value.to_string().contains(&token));
+    assert_eq!(value["client_tokens"][0]["name"], "agent");
+    assert!(value["client_tokens"][0].get("token").is_none());
+}
+
+#[test]
+fn spending_limits_and_address_lists_roundtrip() {
+    ensure_test_identity();
+    let mut config = ConfigMcpContent::default();
+    assert!(config.daily_budget().is_none());
+    assert!(config.allowlisted_addresses().is_empty());
+
+    config.set_daily_budget(Some(1_000_000));
+    config.set_weekly_budget(Some(5_000_000));
+    config.set_allowlist_confirmation_threshold(Some(100_000));
+    config.set_denylisted_addresses(vec!["blocked".to_string()]);
+
+    let serialized = serde_json::to_string(&config).unwrap();
+    let deserialized: ConfigMcpContent = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(*deserialized.daily_budget(), Some(1_000_000));
+    assert_eq!(*deserialized.weekly_budget(), Some(5_000_000));
+    assert_eq!(
+        *deserialized.allowlist_confirmation_threshold(),
+        Some(100_000)
+    );
+    assert_eq!(
+        deserialized.denylisted_addresses(),
+        &vec!["blocked".to_string()]
+    );
+}
@@ -22,7 +22,10 @@
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
 use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::pools::gpu_pools::GpuPool;
 use crate::events_emitter::EventsEmitter;
+use crate::mining::cpu::consts::CpuMinerType;
+use crate::mining::gpu::consts::GpuMiningAlgorithm;
 use getset::{Getters, Setters};
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
@@ -31,7 +34,8 @@ use std::{collections::HashMap, fmt::Display, sync::LazyLock, time::SystemTime};
 use tauri::AppHandle;
 use tokio::sync::RwLock;
 
-pub const MINING_CONFIG_VERSION: u32 = 2;
+pub const MINING_CONFIG_VERSION: u32 = 3;
+pub const AUTO_MINING_MODE_NAME: &str = "Auto";
 static INSTANCE: LazyLock<RwLock<ConfigMining>> =
     LazyLock::new(|| RwLock::new(ConfigMining::new()));
 
@@ -43,6 +47,7 @@ pub enum MiningModeType {
     Ludicrous,
     Custom,
     User,
+    Auto,
 }
 
 impl Display for MiningModeType {
@@ -53,6 +58,7 @@ impl Display for MiningModeType {
             MiningModeType::Ludicrous => "Ludicrous",
             MiningModeType::Custom => "Custom",
             MiningModeType::User => "User",
+            MiningModeType::Auto => "Auto",
         };
         write!(f, "{mode_str}")
     }
@@ -66,6 +72,7 @@ impl From<&str> for MiningModeType {
             "ludicrous" => MiningModeType::Ludicrous,
             "custom" => MiningModeType::Custom,
             "user" => MiningModeType::User,
+            "auto" => MiningModeType::Auto,
             _ => {
                 warn!("Unknown mining mode type: {s}, defaulting to Eco");
                 MiningModeType::Eco
@@ -88,12 +95,60 @@ pub struct MiningMode {
     pub gpu_usage_percentage: u32,
 }
 
+/// Per-device overrides applied on top of the selected mining mode
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct GpuDeviceOverrides {
+    /// Device only mines while the selected GPU miner runs this algorithm
+    pub algorithm: Option<GpuMiningAlgorithm>,
+    /// Device only mines while this pool is selected, solo mining ignores the assignment
+    pub pool: Option<GpuPool>,
+    /// Device pauses once it reaches this temperature in Celsius.
+    /// Only applied by miners with the `TemperatureLimit` feature
+    pub max_temperature: Option<u32>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct GpuDeviceSettings {
     device_id: u32,
     is_excluded: bool,
+    #[serde(flatten)]
+    overrides: GpuDeviceOverrides,
 }
-#[derive(Serialize, Deserialize, Clone)]
+
+impl GpuDeviceSettings {
+    pub fn device_id(&self) -> u32 {
+        self.device_id
+    }
+
+    pub fn overrides(&self) -> &GpuDeviceOverrides {
+        &self.overrides
+    }
+
+    /// Whether the device should mine with the given algorithm and pool
+    /// ### Arguments
+    /// * `pool` - Selected GPU pool, `None` when mining directly to the node.
+    ///   Pool assigned devices keep mining then, as there is no pool to pick from
+    pub fn is_assigned_to(&self, algorithm: &GpuMiningAlgorithm, pool: Option<&GpuPool>) -> bool {
+        if self.is_excluded {
+            return false;
+        }
+        if self
+            .overrides
+            .algorithm
+            .as_ref()
+            .is_some_and(|assigned| assigned != algorithm)
+        {
+            return false;
+        }
+        match (&self.overrides.pool, pool) {
+            (Some(assigned), Some(pool)) => pool == assigned,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct GpuDevicesSettings(HashMap<u32, GpuDeviceSettings>);
 
 impl GpuDevicesSettings {
@@ -105,6 +160,7 @@ impl GpuDevicesSettings {
         self.0.entry(device_id).or_insert(GpuDeviceSettings {
             device_id,
             is_excluded: false,
+            overrides: GpuDeviceOverrides::default(),
         });
     }
     pub fn set_excluded(&mut self, device_id: u32, is_excluded: bool) {
@@ -112,6 +168,14 @@ impl GpuDevicesSettings {
             settings.is_excluded = is_excluded;
         }
     }
+    pub fn set_overrides(&mut self, device_id: u32, overrides: GpuDeviceOverrides) {
+        if let Some(settings) = self.0.get_mut(&device_id) {
+            settings.overrides = overrides;
+        }
+    }
+    pub fn get(&self, device_id: u32) -> Option<&GpuDeviceSettings> {
+        self.0.get(&device_id)
+    }
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum PauseOnBatteryModeState {
@@ -143,6 +207,7 @@ pub struct ConfigMiningContent {
     mine_on_app_start: bool,
     gpu_mining_enabled: bool,
     cpu_mining_enabled: bool,
+    cpu_miner_type: CpuMinerType,
     gpu_devices_settings: GpuDevicesSettings,
     squad_override: Option<String>,
     pause_on_battery_mode: PauseOnBatteryModeState,
@@ -151,6 +216,18 @@ pub struct ConfigMiningContent {
 
     eco_alert_needed: bool,
     mode_mining_times: HashMap<String, Duration>, // we only need Eco for now, but we can add to this if needed
+
+    thermal_throttle_enabled: bool,
+    cpu_max_temperature: u32,         // Celsius
+    gpu_max_temperature: u32,         // Celsius
+    thermal_throttle_hysteresis: u32, // Degrees below the ceiling before usage is restored
+    thermal_throttle_cooldown_secs: u64,
+
+    // Auto mining mode profitability inputs, prices share the same currency
+    auto_mode_electricity_price_per_kwh: f64,
+    auto_mode_xtm_price: f64,
+    auto_mode_cpu_watts: f64, // Power draw at full usage
+    auto_mode_gpu_watts: f64, // Power draw at full usage
 }
 
 impl Default for ConfigMiningContent {
@@ -188,6 +265,7 @@ impl Default for ConfigMiningContent {
                         gpu_usage_percentage: 95,
                     },
                 ),
+                (AUTO_MINING_MODE_NAME.to_string(), auto_mining_mode()),
                 (
                     "Custom".to_string(),
                     MiningMode {
@@ -200,6 +278,7 @@ impl Default for ConfigMiningContent {
             ]),
             gpu_mining_enabled: true,
             cpu_mining_enabled: true,
+            cpu_miner_type: CpuMinerType::default(),
             gpu_devices_settings: GpuDevicesSettings::new(),
             pause_on_battery_mode: PauseOnBatteryModeState::Enabled,
             squad_override: None,
@@ -207,10 +286,30 @@ impl Default for ConfigMiningContent {
             is_gpu_mining_recommended: true,
             eco_alert_needed: true,
             mode_mining_times: HashMap::from([("Eco".to_string(), Duration::new(0, 0))]),
+            thermal_throttle_enabled: false,
+            cpu_max_temperature: 85,
+            gpu_max_temperature: 83,
+            thermal_throttle_hysteresis: 8,
+            thermal_throttle_cooldown_secs: 120,
+            auto_mode_electricity_price_per_kwh: 0.0,
+            auto_mode_xtm_price: 0.0,
+            auto_mode_cpu_watts: 65.0,
+            auto_mode_gpu_watts: 200.0,
         }
     }
 }
 impl ConfigContentImpl for ConfigMiningContent {}
+
+/// Auto mode starts at Eco usage until the first profitability evaluation
+fn auto_mining_mode() -> MiningMode {
+    MiningMode {
+        mode_type: MiningModeType::Auto,
+        mode_name: AUTO_MINING_MODE_NAME.to_string(),
+        cpu_usage_percentage: 1,
+        gpu_usage_percentage: 1,
+    }
+}
+
 impl ConfigMiningContent {
     pub fn update_custom_mode_cpu_usage(&mut self, cpu_usage_percentage: u32) -> &mut Self {
         if let Some(custom_mode) = self.mining_modes.get_mut("Custom") {
@@ -226,6 +325,17 @@ impl ConfigMiningContent {
         self
     }
 
+    pub fn update_auto_mode_usage(
+        &mut self,
+        (cpu_usage_percentage, gpu_usage_percentage): (u32, u32),
+    ) -> &mut Self {
+        if let Some(auto_mode) = self.mining_modes.get_mut(AUTO_MINING_MODE_NAME) {
+            auto_mode.cpu_usage_percentage = cpu_usage_percentage;
+            auto_mode.gpu_usage_percentage = gpu_usage_percentage;
+        }
+        self
+    }
+
     /// Populate the GPU devices settings with the given device IDs.
     /// If a device ID already exists, it will not be added again.
     pub fn populate_gpu_devices_settings(&mut self, device_ids: Vec<u32>) -> &mut Self {
@@ -246,6 +356,15 @@ impl ConfigMiningContent {
         self
     }
 
+    pub fn set_gpu_device_overrides(
+        &mut self,
+        (device_id, overrides): (u32, GpuDeviceOverrides),
+    ) -> &mut Self {
+        self.gpu_devices_settings
+            .set_overrides(device_id, overrides);
+        self
+    }
+
     pub fn get_selected_cpu_usage_percentage(&self) -> u32 {
         match self.mining_modes.get(&self.selected_mining_mode) {
             Some(mode) => mode.cpu_usage_percentage,
@@ -256,17 +375,18 @@ impl ConfigMiningContent {
         }
     }
 
-    pub fn get_excluded_devices(&self) -> Vec<u32> {
+    /// Devices which should not mine with the given algorithm and pool,
+    /// either excluded by the user or assigned to a different algorithm or pool
+    pub fn get_unassigned_devices(
+        &self,
+        algorithm: &GpuMiningAlgorithm,
+        pool: Option<&GpuPool>,
+    ) -> Vec<u32> {
         self.gpu_devices_settings
             .0
             .iter()
-            .filter_map(|(&device_id, settings)| {
-                if settings.is_excluded {
-                    Some(device_id)
-                } else {
-                    None
-                }
-            })
+            .filter(|(_, settings)| !settings.is_assigned_to(algorithm, pool))
+            .map(|(&device_id, _)| device_id)
             .collect()
     }
 
@@ -367,6 +487,15 @@ impl ConfigMining {
         // since they're removed from the struct. GPU mining disable is handled at runtime
         // via is_supported_on_current_platform() check in phase_gpu_mining.rs
 
+        // v2 -> v3 migration (Auto mining mode)
+        if current_version < 3 {
+            let mut mining_modes = Self::content().await.mining_modes;
+            mining_modes
+                .entry(AUTO_MINING_MODE_NAME.to_string())
+                .or_insert_with(auto_mining_mode);
+            Self::update_field(ConfigMiningContent::set_mining_modes, mining_modes).await?;
+        }
+
         Ok(())
     }
 
@@ -420,3 +549,24 @@ impl ConfigImpl for ConfigMining {
         *self.app_handle.write().await = Some(app_handle);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_assigned_device_keeps_mining_solo() {
+        let settings = GpuDeviceSettings {
+            device_id: 0,
+            is_excluded: false,
+            overrides: GpuDeviceOverrides {
+                pool: Some(GpuPool::LuckyPoolC29),
+                ..Default::default()
+            },
+        };
+
+        assert!(settings.is_assigned_to(&GpuMiningAlgorithm::C29, Some(&GpuPool::LuckyPoolC29)));
+        assert!(!settings.is_assigned_to(&GpuMiningAlgorithm::C29, Some(&GpuPool::KryptexPoolC29)));
+        assert!(settings.is_assigned_to(&GpuMiningAlgorithm::C29, None));
+    }
+}
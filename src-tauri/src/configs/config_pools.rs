@@ -28,12 +28,22 @@ use tokio::sync::RwLock;
 
 use crate::{
     configs::pools::{BasePoolData, cpu_pools::CpuPool, gpu_pools::GpuPool},
-    mining::pools::{cpu_pool_manager::CpuPoolManager, gpu_pool_manager::GpuPoolManager},
+    mining::{
+        gpu::consts::GpuMiningAlgorithm,
+        pools::{
+            cpu_pool_manager::CpuPoolManager,
+            gpu_pool_manager::GpuPoolManager,
+            pools_manager::{
+                DEFAULT_FAILOVER_DISCONNECT_THRESHOLD_SECS,
+                DEFAULT_FAILOVER_MISSING_SHARES_THRESHOLD_SECS,
+            },
+        },
+    },
 };
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
 
-pub const POOLS_CONFIG_VERSION: u32 = 1;
+pub const POOLS_CONFIG_VERSION: u32 = 2;
 static INSTANCE: LazyLock<RwLock<ConfigPools>> = LazyLock::new(|| RwLock::new(ConfigPools::new()));
 
 #[allow(clippy::struct_excessive_bools)]
@@ -61,6 +71,19 @@ pub struct ConfigPoolsContent {
     current_cpu_pool: CpuPool,
     #[getset(get = "pub", set = "pub")]
     cpu_pools: HashMap<CpuPool, BasePoolData<CpuPool>>,
+    // ======= Pool failover =======
+    #[getset(get = "pub", set = "pub")]
+    pool_failover_enabled: bool,
+    #[getset(get = "pub", set = "pub")]
+    pool_failover_disconnect_threshold_secs: u64,
+    #[getset(get = "pub", set = "pub")]
+    pool_failover_missing_shares_threshold_secs: u64,
+    #[getset(get = "pub", set = "pub")]
+    probe_pool_latency_on_startup: bool,
+    #[getset(get = "pub", set = "pub")]
+    cpu_failover_pools: Vec<CpuPool>,
+    #[getset(get = "pub", set = "pub")]
+    gpu_failover_pools: HashMap<GpuMiningAlgorithm, Vec<GpuPool>>,
 }
 
 impl Default for ConfigPoolsContent {
@@ -77,6 +100,14 @@ impl Default for ConfigPoolsContent {
             cpu_pool_enabled: true,
             current_cpu_pool: CpuPool::default(),
             cpu_pools: CpuPool::load_default_pools_data(),
+            // ======= Pool failover =======
+            pool_failover_enabled: false,
+            pool_failover_disconnect_threshold_secs: DEFAULT_FAILOVER_DISCONNECT_THRESHOLD_SECS,
+            pool_failover_missing_shares_threshold_secs:
+                DEFAULT_FAILOVER_MISSING_SHARES_THRESHOLD_SECS,
+            probe_pool_latency_on_startup: false,
+            cpu_failover_pools: CpuPool::default_failover_pools(),
+            gpu_failover_pools: GpuPool::default_failover_pools(),
         }
     }
 }
@@ -89,6 +120,29 @@ impl ConfigPoolsContent {
             .unwrap_or_else(|| GpuPool::default().default_content())
     }
 
+    pub fn current_gpu_pool_type(&self) -> &GpuPool {
+        &self.current_gpu_pool
+    }
+
+    pub fn current_cpu_pool_type(&self) -> &CpuPool {
+        &self.current_cpu_pool
+    }
+
+    pub fn gpu_failover_pools_for(&self, algorithm: &GpuMiningAlgorithm) -> Vec<GpuPool> {
+        self.gpu_failover_pools
+            .get(algorithm)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn set_gpu_failover_pools_for(
+        &mut self,
+        (algorithm, pools): (GpuMiningAlgorithm, Vec<GpuPool>),
+    ) -> &mut Self {
+        self.gpu_failover_pools.insert(algorithm, pools);
+        self
+    }
+
     pub fn current_cpu_pool(&self) -> BasePoolData<CpuPool> {
         self.cpu_pools
             .get(&self.current_cpu_pool)
@@ -115,6 +169,70 @@ impl ConfigPoolsContent {
         }
         self
     }
+
+    /// Brings pools saved by an older app version up to date
+    pub fn migrate_from_version(&mut self, from_version: u32) -> &mut Self {
+        // v0 -> v1 migration (SHA3X pool removal)
+        if from_version < 1 {
+            // Migrate GPU pool selections to C29
+            self.current_gpu_pool = GpuPool::LuckyPoolC29;
+            // Reset the pools map to the defaults
+            self.gpu_pools = GpuPool::load_default_pools_data();
+        }
+
+        // v1 -> v2 migration (SHA3X pools for the Graxil miner)
+        if from_version < 2 {
+            // Keep user edits of existing pools, only add the missing defaults
+            for (pool, pool_data) in GpuPool::load_default_pools_data() {
+                self.gpu_pools.entry(pool).or_insert(pool_data);
+            }
+        }
+        self
+    }
+
+    /// Insert a custom cpu pool or replace the existing one with the same pool type
+    pub fn upsert_custom_cpu_pool(&mut self, pool_data: BasePoolData<CpuPool>) -> &mut Self {
+        if pool_data.pool_type.is_custom() {
+            self.cpu_pools
+                .insert(pool_data.pool_type.clone(), pool_data);
+        }
+        self
+    }
+
+    /// Insert a custom gpu pool or replace the existing one with the same pool type
+    pub fn upsert_custom_gpu_pool(&mut self, pool_data: BasePoolData<GpuPool>) -> &mut Self {
+        if pool_data.pool_type.is_custom() {
+            self.gpu_pools
+                .insert(pool_data.pool_type.clone(), pool_data);
+        }
+        self
+    }
+
+    /// Remove a custom cpu pool, falls back to the default pool if it was selected
+    pub fn remove_custom_cpu_pool(&mut self, pool_type: CpuPool) -> &mut Self {
+        if pool_type.is_custom() {
+            self.cpu_pools.remove(&pool_type);
+            self.cpu_failover_pools.retain(|pool| *pool != pool_type);
+            if self.current_cpu_pool == pool_type {
+                self.current_cpu_pool = CpuPool::default();
+            }
+        }
+        self
+    }
+
+    /// Remove a custom gpu pool, falls back to the default pool if it was selected
+    pub fn remove_custom_gpu_pool(&mut self, pool_type: GpuPool) -> &mut Self {
+        if pool_type.is_custom() {
+            self.gpu_pools.remove(&pool_type);
+            for pools in self.gpu_failover_pools.values_mut() {
+                pools.retain(|pool| *pool != pool_type);
+            }
+            if self.current_gpu_pool == pool_type {
+                self.current_gpu_pool = GpuPool::default();
+            }
+        }
+        self
+    }
 }
 pub struct ConfigPools {
     content: ConfigPoolsContent,
@@ -134,6 +252,14 @@ impl ConfigPools {
         // We want to initialize and fetch initial pool status only if pool mining is enabled
         CpuPoolManager::initialize_from_pool_config(&Self::content().await).await;
         GpuPoolManager::initialize_from_pool_config(&Self::content().await).await;
+
+        if *Self::content().await.probe_pool_latency_on_startup() {
+            // Probing takes a few seconds so it should not hold up the rest of the setup
+            tauri::async_runtime::spawn(async {
+                CpuPoolManager::probe_failover_pools_latency().await;
+                GpuPoolManager::probe_failover_pools_latency().await;
+            });
+        }
     }
 
     async fn _check_for_migration() -> Result<(), anyhow::Error> {
@@ -151,22 +277,7 @@ impl ConfigPools {
 
     async fn _migrate() -> Result<(), anyhow::Error> {
         let current_version = Self::content().await.version_counter;
-
-        // v0 -> v1 migration (SHA3X pool removal)
-        if current_version < 1 {
-            // Migrate GPU pool selections to C29
-            Self::update_field(
-                ConfigPoolsContent::set_current_gpu_pool,
-                GpuPool::LuckyPoolC29,
-            )
-            .await?;
-
-            // Update the pools map to only include C29 pools
-            let gpu_pools = GpuPool::load_default_pools_data();
-            Self::update_field(ConfigPoolsContent::set_gpu_pools, gpu_pools).await?;
-        }
-
-        Ok(())
+        Self::update_field(ConfigPoolsContent::migrate_from_version, current_version).await
     }
 }
 
@@ -204,3 +315,45 @@ impl ConfigImpl for ConfigPools {
         &mut self.content
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_legacy_sha3x_selection_to_c29() {
+        let mut content: ConfigPoolsContent = serde_json::from_str(
+            r#"{"version_counter": 0, "current_gpu_pool": "SupportXTMPoolSHA3X"}"#,
+        )
+        .unwrap();
+        assert_eq!(content.current_gpu_pool_type(), &GpuPool::LuckyPoolC29);
+
+        content.migrate_from_version(0);
+
+        assert_eq!(content.current_gpu_pool_type(), &GpuPool::LuckyPoolC29);
+        assert!(content.gpu_pools().contains_key(&GpuPool::LuckyPoolSHA3X));
+    }
+
+    #[test]
+    fn keeps_c29_pool_edits_when_adding_sha3x_pools() {
+        let mut content: ConfigPoolsContent = serde_json::from_str(
+            r#"{"version_counter": 1, "current_gpu_pool": "KryptexPoolC29", "gpu_pools": {
+                "KryptexPoolC29": {
+                    "pool_name": "Kryptex", "pool_url": "edited.example:7040", "stats_url": "",
+                    "pool_type": "KryptexPoolC29", "pool_origin": "Kryptex"
+                }
+            }}"#,
+        )
+        .unwrap();
+
+        content.migrate_from_version(1);
+
+        assert_eq!(content.current_gpu_pool_type(), &GpuPool::KryptexPoolC29);
+        assert_eq!(
+            content.gpu_pools()[&GpuPool::KryptexPoolC29].pool_url,
+            "edited.example:7040"
+        );
+        assert!(content.gpu_pools().contains_key(&GpuPool::LuckyPoolC29));
+        assert!(content.gpu_pools().contains_key(&GpuPool::KryptexPoolSHA3X));
+    }
+}
@@ -38,7 +38,7 @@ use crate::{
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
 
-static EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK: &str = "Exchanges";
+pub static EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK: &str = "Exchanges";
 
 static INSTANCE: LazyLock<RwLock<ConfigWallet>> =
     LazyLock::new(|| RwLock::new(ConfigWallet::new()));
@@ -49,6 +49,9 @@ pub const WALLET_VERSION: u32 = 2;
 pub struct ExternalTariAddressBookRecord {
     pub name: String,
     pub address: TariAddress,
+    /// Used when sending to this contact without an explicit payment ID
+    #[serde(default)]
+    pub payment_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -149,6 +152,7 @@ impl ConfigWalletContent {
             ExternalTariAddressBookRecord {
                 name: EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK.to_string(),
                 address,
+                payment_id: None,
             },
         );
         // Don't clear tari_wallet_details
@@ -25,7 +25,7 @@ use std::{collections::HashMap, fmt::Display, sync::LazyLock};
 use serde::{Deserialize, Serialize};
 use tari_common::configuration::Network;
 
-use crate::configs::pools::{BasePoolData, PoolOrigin};
+use crate::configs::pools::{BasePoolData, CUSTOM_POOL_KEY_PREFIX, PoolOrigin};
 
 fn global_tari_cpu_mining_pool_url() -> String {
     match Network::get_current_or_user_setting_or_default() {
@@ -56,6 +56,9 @@ static DEFAULT_CPU_SUPPORTXTM_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
         stats_url: global_tari_cpu_mining_pool_status_url(),
         pool_type: CpuPool::SupportXTMPoolRANDOMX,
         pool_origin: PoolOrigin::SupportXTM,
+        worker_name: None,
+        stats_mapping: None,
+        algorithm: None,
     });
 
 static DEFAULT_CPU_LUCKYPOOL_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
@@ -66,6 +69,9 @@ static DEFAULT_CPU_LUCKYPOOL_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
             .to_string(),
         pool_type: CpuPool::LuckyPoolRANDOMX,
         pool_origin: PoolOrigin::LuckyPool,
+        worker_name: None,
+        stats_mapping: None,
+        algorithm: None,
     });
 
 static DEFAULT_CPU_KRYPTEX_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
@@ -76,14 +82,22 @@ static DEFAULT_CPU_KRYPTEX_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
             .to_string(),
         pool_type: CpuPool::KryptexPoolRANDOMX,
         pool_origin: PoolOrigin::Kryptex,
+        worker_name: None,
+        stats_mapping: None,
+        algorithm: None,
     });
 
+// Serialized as its key string so custom pools can still be used as json map keys
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(try_from = "String", into = "String")]
 pub enum CpuPool {
     SupportXTMPoolRANDOMX,
     #[default]
     LuckyPoolRANDOMX,
     KryptexPoolRANDOMX,
+    Custom {
+        id: String,
+    },
 }
 
 impl Display for CpuPool {
@@ -92,6 +106,7 @@ impl Display for CpuPool {
             CpuPool::SupportXTMPoolRANDOMX => "SupportXTMPoolRANDOMX",
             CpuPool::LuckyPoolRANDOMX => "LuckyPoolRANDOMX",
             CpuPool::KryptexPoolRANDOMX => "KryptexPoolRANDOMX",
+            CpuPool::Custom { id } => return write!(f, "{CUSTOM_POOL_KEY_PREFIX}{id}"),
         };
         write!(f, "{name}")
     }
@@ -103,7 +118,10 @@ impl CpuPool {
             "SupportXTMPoolRANDOMX" => Ok(CpuPool::SupportXTMPoolRANDOMX),
             "LuckyPoolRANDOMX" => Ok(CpuPool::LuckyPoolRANDOMX),
             "KryptexPoolRANDOMX" => Ok(CpuPool::KryptexPoolRANDOMX),
-            _ => Err(anyhow::anyhow!("Invalid CPU pool name")),
+            _ => match pool_name.strip_prefix(CUSTOM_POOL_KEY_PREFIX) {
+                Some(id) if !id.is_empty() => Ok(CpuPool::Custom { id: id.to_string() }),
+                _ => Err(anyhow::anyhow!("Invalid CPU pool name")),
+            },
         }
     }
 
@@ -112,6 +130,7 @@ impl CpuPool {
             CpuPool::SupportXTMPoolRANDOMX => "SupportXTMPoolRANDOMX".to_string(),
             CpuPool::LuckyPoolRANDOMX => "LuckyPoolRANDOMX".to_string(),
             CpuPool::KryptexPoolRANDOMX => "KryptexPoolRANDOMX".to_string(),
+            CpuPool::Custom { id } => format!("{CUSTOM_POOL_KEY_PREFIX}{id}"),
         }
     }
 
@@ -120,9 +139,30 @@ impl CpuPool {
             CpuPool::SupportXTMPoolRANDOMX => DEFAULT_CPU_SUPPORTXTM_RANDOMX.clone(),
             CpuPool::LuckyPoolRANDOMX => DEFAULT_CPU_LUCKYPOOL_RANDOMX.clone(),
             CpuPool::KryptexPoolRANDOMX => DEFAULT_CPU_KRYPTEX_RANDOMX.clone(),
+            // Custom pools have no built-in defaults, the placeholder fails pool url validation
+            CpuPool::Custom { .. } => BasePoolData::empty_custom(self.clone()),
         }
     }
 
+    pub fn new_custom() -> Self {
+        CpuPool::Custom {
+            id: uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, CpuPool::Custom { .. })
+    }
+
+    /// Default order in which pools are tried when the current one becomes unhealthy
+    pub fn default_failover_pools() -> Vec<CpuPool> {
+        vec![
+            CpuPool::LuckyPoolRANDOMX,
+            CpuPool::KryptexPoolRANDOMX,
+            CpuPool::SupportXTMPoolRANDOMX,
+        ]
+    }
+
     pub fn load_default_pools_data() -> HashMap<Self, BasePoolData<CpuPool>> {
         use CpuPool::*;
         let mut cpu_pools = HashMap::new();
@@ -135,3 +175,17 @@ impl CpuPool {
         cpu_pools
     }
 }
+
+impl TryFrom<String> for CpuPool {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        CpuPool::from_string(&value)
+    }
+}
+
+impl From<CpuPool> for String {
+    fn from(pool: CpuPool) -> Self {
+        pool.key_string()
+    }
+}
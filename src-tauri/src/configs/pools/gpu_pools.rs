@@ -24,7 +24,10 @@ use std::{collections::HashMap, fmt::Display, sync::LazyLock};
 
 use serde::{Deserialize, Serialize};
 
-use crate::configs::pools::{BasePoolData, PoolOrigin};
+use crate::{
+    configs::pools::{BasePoolData, CUSTOM_POOL_KEY_PREFIX, PoolOrigin},
+    mining::gpu::consts::GpuMiningAlgorithm,
+};
 
 static DEFAULT_GPU_LUCKYPOOL_C29: LazyLock<BasePoolData<GpuPool>> =
     LazyLock::new(|| BasePoolData {
@@ -34,6 +37,9 @@ static DEFAULT_GPU_LUCKYPOOL_C29: LazyLock<BasePoolData<GpuPool>> =
             .to_string(),
         pool_type: GpuPool::LuckyPoolC29,
         pool_origin: PoolOrigin::LuckyPool,
+        worker_name: None,
+        stats_mapping: None,
+        algorithm: None,
     });
 
 static DEFAULT_GPU_KRYPTEX_C29: LazyLock<BasePoolData<GpuPool>> = LazyLock::new(|| BasePoolData {
@@ -42,13 +48,48 @@ static DEFAULT_GPU_KRYPTEX_C29: LazyLock<BasePoolData<GpuPool>> = LazyLock::new(
     stats_url: "https://pool.kryptex.com/xtm-c29/api/v1/miner/balance/%TARI_ADDRESS%".to_string(),
     pool_type: GpuPool::KryptexPoolC29,
     pool_origin: PoolOrigin::Kryptex,
+    worker_name: None,
+    stats_mapping: None,
+    algorithm: None,
 });
 
+static DEFAULT_GPU_LUCKYPOOL_SHA3X: LazyLock<BasePoolData<GpuPool>> =
+    LazyLock::new(|| BasePoolData {
+        pool_name: "LuckyPool [ SHA3X ]".to_string(),
+        pool_url: "tari.luckypool.io:3118".to_string(),
+        stats_url: "https://tari.luckypool.io/api/stats_address?address=%TARI_ADDRESS%".to_string(),
+        pool_type: GpuPool::LuckyPoolSHA3X,
+        pool_origin: PoolOrigin::LuckyPool,
+        worker_name: None,
+        stats_mapping: None,
+        algorithm: None,
+    });
+
+static DEFAULT_GPU_KRYPTEX_SHA3X: LazyLock<BasePoolData<GpuPool>> =
+    LazyLock::new(|| BasePoolData {
+        pool_name: "KryptexPool [ SHA3X ]".to_string(),
+        pool_url: "xtm-sha3x-tu.kryptex.network:7039".to_string(),
+        stats_url: "https://pool.kryptex.com/xtm-sha3x/api/v1/miner/balance/%TARI_ADDRESS%"
+            .to_string(),
+        pool_type: GpuPool::KryptexPoolSHA3X,
+        pool_origin: PoolOrigin::Kryptex,
+        worker_name: None,
+        stats_mapping: None,
+        algorithm: None,
+    });
+
+// Serialized as its key string so custom pools can still be used as json map keys
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(try_from = "String", into = "String")]
 pub enum GpuPool {
     #[default]
     LuckyPoolC29,
     KryptexPoolC29,
+    LuckyPoolSHA3X,
+    KryptexPoolSHA3X,
+    Custom {
+        id: String,
+    },
 }
 
 impl Display for GpuPool {
@@ -56,6 +97,9 @@ impl Display for GpuPool {
         let name = match self {
             GpuPool::LuckyPoolC29 => "LuckyPoolC29",
             GpuPool::KryptexPoolC29 => "KryptexPoolC29",
+            GpuPool::LuckyPoolSHA3X => "LuckyPoolSHA3X",
+            GpuPool::KryptexPoolSHA3X => "KryptexPoolSHA3X",
+            GpuPool::Custom { id } => return write!(f, "{CUSTOM_POOL_KEY_PREFIX}{id}"),
         };
         write!(f, "{name}")
     }
@@ -66,11 +110,14 @@ impl GpuPool {
         match pool_name {
             "LuckyPoolC29" => Ok(GpuPool::LuckyPoolC29),
             "KryptexPoolC29" => Ok(GpuPool::KryptexPoolC29),
-            // Backward compatibility: map old SHA3X pool names to C29 equivalents
-            "LuckyPoolSHA3X" => Ok(GpuPool::LuckyPoolC29),
-            "KryptexPoolSHA3X" => Ok(GpuPool::KryptexPoolC29),
+            "LuckyPoolSHA3X" => Ok(GpuPool::LuckyPoolSHA3X),
+            "KryptexPoolSHA3X" => Ok(GpuPool::KryptexPoolSHA3X),
+            // Backward compatibility: selections of the removed SupportXTM SHA3X pool were moved to C29
             "SupportXTMPoolSHA3X" => Ok(GpuPool::LuckyPoolC29),
-            _ => Err(anyhow::anyhow!("Invalid GPU pool name")),
+            _ => match pool_name.strip_prefix(CUSTOM_POOL_KEY_PREFIX) {
+                Some(id) if !id.is_empty() => Ok(GpuPool::Custom { id: id.to_string() }),
+                _ => Err(anyhow::anyhow!("Invalid GPU pool name")),
+            },
         }
     }
 
@@ -78,6 +125,9 @@ impl GpuPool {
         match self {
             GpuPool::LuckyPoolC29 => "LuckyPoolC29".to_string(),
             GpuPool::KryptexPoolC29 => "KryptexPoolC29".to_string(),
+            GpuPool::LuckyPoolSHA3X => "LuckyPoolSHA3X".to_string(),
+            GpuPool::KryptexPoolSHA3X => "KryptexPoolSHA3X".to_string(),
+            GpuPool::Custom { id } => format!("{CUSTOM_POOL_KEY_PREFIX}{id}"),
         }
     }
 
@@ -85,14 +135,58 @@ impl GpuPool {
         match self {
             GpuPool::LuckyPoolC29 => DEFAULT_GPU_LUCKYPOOL_C29.clone(),
             GpuPool::KryptexPoolC29 => DEFAULT_GPU_KRYPTEX_C29.clone(),
+            GpuPool::LuckyPoolSHA3X => DEFAULT_GPU_LUCKYPOOL_SHA3X.clone(),
+            GpuPool::KryptexPoolSHA3X => DEFAULT_GPU_KRYPTEX_SHA3X.clone(),
+            // Custom pools have no built-in defaults, the placeholder fails pool url validation
+            GpuPool::Custom { .. } => BasePoolData::empty_custom(self.clone()),
+        }
+    }
+
+    pub fn new_custom() -> Self {
+        GpuPool::Custom {
+            id: uuid::Uuid::new_v4().to_string(),
         }
     }
 
+    pub fn is_custom(&self) -> bool {
+        matches!(self, GpuPool::Custom { .. })
+    }
+
+    /// Default order in which pools are tried when the current one becomes unhealthy, per mining algorithm
+    pub fn default_failover_pools() -> HashMap<GpuMiningAlgorithm, Vec<GpuPool>> {
+        HashMap::from([
+            (
+                GpuMiningAlgorithm::C29,
+                vec![GpuPool::LuckyPoolC29, GpuPool::KryptexPoolC29],
+            ),
+            (
+                GpuMiningAlgorithm::SHA3X,
+                vec![GpuPool::LuckyPoolSHA3X, GpuPool::KryptexPoolSHA3X],
+            ),
+        ])
+    }
+
     pub fn load_default_pools_data() -> HashMap<Self, BasePoolData<GpuPool>> {
         use GpuPool::*;
         let mut gpu_pools = HashMap::new();
         gpu_pools.insert(LuckyPoolC29, DEFAULT_GPU_LUCKYPOOL_C29.clone());
         gpu_pools.insert(KryptexPoolC29, DEFAULT_GPU_KRYPTEX_C29.clone());
+        gpu_pools.insert(LuckyPoolSHA3X, DEFAULT_GPU_LUCKYPOOL_SHA3X.clone());
+        gpu_pools.insert(KryptexPoolSHA3X, DEFAULT_GPU_KRYPTEX_SHA3X.clone());
         gpu_pools
     }
 }
+
+impl TryFrom<String> for GpuPool {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        GpuPool::from_string(&value)
+    }
+}
+
+impl From<GpuPool> for String {
+    fn from(pool: GpuPool) -> Self {
+        pool.key_string()
+    }
+}
@@ -22,14 +22,20 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::mining::gpu::consts::GpuMiningAlgorithm;
+
 pub mod cpu_pools;
 pub mod gpu_pools;
 
+/// Prefix used to build the key string of user defined pools, e.g. `Custom:<id>`
+pub const CUSTOM_POOL_KEY_PREFIX: &str = "Custom:";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PoolOrigin {
     SupportXTM,
     LuckyPool,
     Kryptex,
+    Custom,
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BasePoolData<T> {
@@ -38,4 +44,335 @@ pub struct BasePoolData<T> {
     pub stats_url: String,
     pub pool_type: T,
     pub pool_origin: PoolOrigin,
+    /// Worker name appended to the mining address, only used by custom pools
+    #[serde(default)]
+    pub worker_name: Option<String>,
+    /// Mapping of the stats response fields, only used by custom pools
+    #[serde(default)]
+    pub stats_mapping: Option<PoolStatsMapping>,
+    /// Mining algorithm of the pool, only used by custom GPU pools
+    #[serde(default)]
+    pub algorithm: Option<GpuMiningAlgorithm>,
+}
+
+impl<T> BasePoolData<T> {
+    /// Placeholder for a custom pool without a configuration, its empty url never passes validation
+    pub fn empty_custom(pool_type: T) -> Self {
+        BasePoolData {
+            pool_name: String::new(),
+            pool_url: String::new(),
+            stats_url: String::new(),
+            pool_type,
+            pool_origin: PoolOrigin::Custom,
+            worker_name: None,
+            stats_mapping: None,
+            algorithm: None,
+        }
+    }
+
+    /// Worker name format depends on the pool
+    /// LuckyPool: .Tari-Universe
+    /// Kryptex: /Tari-Universe
+    /// SupportXTM: Not specified so we use None
+    /// Custom: Whatever the user has configured
+    pub fn worker_name(&self) -> Option<String> {
+        match self.pool_origin {
+            PoolOrigin::LuckyPool => Some(".Tari-universe".to_string()),
+            PoolOrigin::SupportXTM => None,
+            PoolOrigin::Kryptex => Some("/Tari-universe".to_string()),
+            PoolOrigin::Custom => self.worker_name.clone(),
+        }
+    }
+}
+
+/// Declarative description of a pool stats api response
+/// Each field points to JSON values ( RFC 6901 pointers ) in the response, fields without a mapping are reported as zero
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct PoolStatsMapping {
+    pub accepted_shares: Option<PoolStatsField>,
+    pub unpaid: Option<PoolStatsField>,
+    pub balance: Option<PoolStatsField>,
+    pub min_payout: Option<PoolStatsField>,
+    /// Responses containing this text are treated as an address without any mining history yet
+    pub empty_response_marker: Option<String>,
+}
+
+/// Values of all pointers are summed and multiplied by `scale` to get the value in the app units
+/// Can be configured as a single pointer string, e.g. `"/stats/paid"`,
+/// or as an object, e.g. `{ "pointers": ["/stats/locked", "/stats/unlocked"], "scale": 1000000 }`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "PoolStatsFieldConfig")]
+pub struct PoolStatsField {
+    pub pointers: Vec<String>,
+    pub scale: f64,
+}
+
+impl PoolStatsMapping {
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        let fields = [
+            ("accepted_shares", &self.accepted_shares),
+            ("unpaid", &self.unpaid),
+            ("balance", &self.balance),
+            ("min_payout", &self.min_payout),
+        ];
+        for (name, field) in fields {
+            let Some(field) = field else {
+                continue;
+            };
+            if field.pointers.is_empty() {
+                return Err(anyhow::anyhow!("Stats mapping for {name} has no pointers"));
+            }
+            // Empty pointer refers to the whole document, anything else has to start with a slash
+            if let Some(pointer) = field
+                .pointers
+                .iter()
+                .find(|pointer| !pointer.is_empty() && !pointer.starts_with('/'))
+            {
+                return Err(anyhow::anyhow!(
+                    "Stats mapping pointer {pointer} for {name} has to start with /"
+                ));
+            }
+            if !field.scale.is_finite() || field.scale <= 0.0 {
+                return Err(anyhow::anyhow!(
+                    "Stats mapping scale for {name} has to be a positive number"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PoolStatsFieldConfig {
+    Pointer(String),
+    Field {
+        pointers: Vec<String>,
+        #[serde(default = "default_stats_field_scale")]
+        scale: f64,
+    },
+}
+
+fn default_stats_field_scale() -> f64 {
+    1.0
+}
+
+impl From<PoolStatsFieldConfig> for PoolStatsField {
+    fn from(config: PoolStatsFieldConfig) -> Self {
+        match config {
+            PoolStatsFieldConfig::Pointer(pointer) => PoolStatsField {
+                pointers: vec![pointer],
+                scale: default_stats_field_scale(),
+            },
+            PoolStatsFieldConfig::Field { pointers, scale } => PoolStatsField { pointers, scale },
+        }
+    }
+}
+
+/// User input for adding or editing a custom pool
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomPoolDefinition {
+    pub pool_name: String,
+    pub pool_url: String,
+    #[serde(default)]
+    pub worker_name: Option<String>,
+    #[serde(default)]
+    pub stats_url: Option<String>,
+    #[serde(default)]
+    pub stats_mapping: Option<PoolStatsMapping>,
+    /// Required for GPU pools, CPU pools always mine RandomX
+    #[serde(default)]
+    pub algorithm: Option<GpuMiningAlgorithm>,
+}
+
+/// Strip the stratum scheme so the pool url can be used as a plain host:port address
+pub fn stratum_host_port(pool_url: &str) -> &str {
+    pool_url
+        .trim()
+        .trim_start_matches("stratum+tcp://")
+        .trim_start_matches("stratum+ssl://")
+}
+
+/// Checks that the pool url is a host:port address, optionally prefixed with a stratum scheme
+pub fn is_valid_stratum_url(pool_url: &str) -> bool {
+    matches!(
+        stratum_host_port(pool_url).rsplit_once(':'),
+        Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok()
+    )
+}
+
+impl CustomPoolDefinition {
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.pool_name.trim().is_empty() {
+            return Err(anyhow::anyhow!("Pool name can not be empty"));
+        }
+
+        if !is_valid_stratum_url(&self.pool_url) {
+            return Err(anyhow::anyhow!(
+                "Pool url has to be in the host:port format"
+            ));
+        }
+
+        if let Some(stats_url) = self.stats_url.as_ref().filter(|url| !url.is_empty())
+            && !stats_url.starts_with("http://")
+            && !stats_url.starts_with("https://")
+        {
+            return Err(anyhow::anyhow!("Stats url has to be a http(s) url"));
+        }
+
+        if let Some(stats_mapping) = &self.stats_mapping {
+            stats_mapping.validate()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_cpu(&self) -> Result<(), anyhow::Error> {
+        if self.algorithm.is_some() {
+            return Err(anyhow::anyhow!("CPU pools always mine RandomX"));
+        }
+        self.validate()
+    }
+
+    pub fn validate_gpu(&self) -> Result<(), anyhow::Error> {
+        if self.algorithm.is_none() {
+            return Err(anyhow::anyhow!(
+                "GPU pools have to specify a mining algorithm"
+            ));
+        }
+        self.validate()
+    }
+
+    pub fn into_pool_data<T>(self, pool_type: T) -> BasePoolData<T> {
+        BasePoolData {
+            pool_name: self.pool_name.trim().to_string(),
+            pool_url: self.pool_url.trim().to_string(),
+            stats_url: self.stats_url.unwrap_or_default(),
+            pool_type,
+            pool_origin: PoolOrigin::Custom,
+            worker_name: self.worker_name.filter(|name| !name.is_empty()),
+            stats_mapping: self.stats_mapping,
+            algorithm: self.algorithm,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{configs::pools::gpu_pools::GpuPool, mining::gpu::consts::GpuMinerType};
+
+    fn definition(pool_url: &str, stats_url: Option<&str>) -> CustomPoolDefinition {
+        CustomPoolDefinition {
+            pool_name: "Team pool".to_string(),
+            pool_url: pool_url.to_string(),
+            worker_name: Some(".rig1".to_string()),
+            stats_url: stats_url.map(str::to_string),
+            stats_mapping: None,
+            algorithm: None,
+        }
+    }
+
+    #[test]
+    fn accepts_host_and_port() {
+        assert!(
+            definition("stratum.example.com:3333", None)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            definition(
+                "stratum+tcp://10.0.0.2:4444",
+                Some("https://example.com/%TARI_ADDRESS%")
+            )
+            .validate()
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_url_without_port() {
+        assert!(definition("stratum.example.com", None).validate().is_err());
+        assert!(
+            definition("stratum.example.com:port", None)
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn requires_algorithm_only_for_gpu_pools() {
+        let mut pool = definition("stratum.example.com:3333", None);
+        assert!(pool.validate_cpu().is_ok());
+        assert!(pool.validate_gpu().is_err());
+
+        pool.algorithm = Some(GpuMiningAlgorithm::SHA3X);
+        assert!(pool.validate_cpu().is_err());
+        assert!(pool.validate_gpu().is_ok());
+    }
+
+    #[test]
+    fn custom_gpu_pool_needs_matching_algorithm_and_url() {
+        let mut pool = definition("stratum.example.com:3333", None);
+        pool.algorithm = Some(GpuMiningAlgorithm::SHA3X);
+        let pool = pool.into_pool_data(GpuPool::new_custom());
+        assert!(GpuMinerType::Graxil.is_pool_supported(&pool));
+        assert!(!GpuMinerType::LolMiner.is_pool_supported(&pool));
+
+        let placeholder = GpuPool::new_custom().default_content();
+        assert!(placeholder.pool_url.is_empty());
+        assert!(!GpuMinerType::Graxil.is_pool_supported(&placeholder));
+        assert!(!GpuMinerType::LolMiner.is_pool_supported(&placeholder));
+    }
+
+    #[test]
+    fn rejects_non_http_stats_url() {
+        assert!(
+            definition("stratum.example.com:3333", Some("ftp://example.com"))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn stats_field_accepts_pointer_or_object() {
+        let mapping: PoolStatsMapping = serde_json::from_str(
+            r#"{"accepted_shares":"/stats/shares","unpaid":{"pointers":["/locked","/unlocked"],"scale":1000000}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            mapping.accepted_shares,
+            Some(PoolStatsField {
+                pointers: vec!["/stats/shares".to_string()],
+                scale: 1.0,
+            })
+        );
+        assert_eq!(
+            mapping.unpaid,
+            Some(PoolStatsField {
+                pointers: vec!["/locked".to_string(), "/unlocked".to_string()],
+                scale: 1_000_000.0,
+            })
+        );
+        assert!(mapping.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_stats_mapping() {
+        let mut pool = definition("stratum.example.com:3333", Some("https://example.com"));
+        pool.stats_mapping = Some(serde_json::from_str(r#"{"unpaid":"stats.unpaid"}"#).unwrap());
+        assert!(pool.validate().is_err());
+
+        pool.stats_mapping =
+            Some(serde_json::from_str(r#"{"unpaid":{"pointers":["/unpaid"],"scale":0}}"#).unwrap());
+        assert!(pool.validate().is_err());
+    }
+
+    #[test]
+    fn custom_pool_uses_configured_worker_name() {
+        let pool_data = definition("stratum.example.com:3333", None).into_pool_data(());
+        assert_eq!(pool_data.worker_name(), Some(".rig1".to_string()));
+    }
 }
@@ -0,0 +1,296 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Command-line client for a running instance, invoked as `<binary> ctl <command>`.
+//!
+//! Arguments are translated into a [`ControlRequest`] and sent over the control socket
+//! together with the token from `daemon.token`. The result is printed as JSON. Like the
+//! control socket itself this is only available on Unix platforms.
+
+use std::path::Path;
+
+use super::control_socket::{
+    CONTROL_SOCKET_FILE_NAME, CONTROL_TOKEN_FILE_NAME, ControlEnvelope, ControlRequest,
+    ControlResponse,
+};
+
+pub const CLI_SUBCOMMAND: &str = "ctl";
+
+const USAGE: &str = "Usage: ctl <command>
+
+Commands:
+  status                                   Mining and chain status
+  mine start [--cpu] [--gpu]               Start mining (both when no flag is given)
+  mine stop [--cpu] [--gpu]                Stop mining (both when no flag is given)
+  mode set <mode>                          Select a mining mode, e.g. Eco or Ludicrous
+  pool set --cpu|--gpu <pool>              Select the CPU or GPU pool
+  wallet balance                           Wallet balance
+  wallet history [--limit <n>]             Recent transactions
+  node peers                               Peers connected to the local node
+  schedule list                            Scheduled events
+  schedule add <id> <mode> <start> <end>   Mine in <mode> daily between e.g. 9:30pm and 6am
+  schedule remove <id>                     Remove a scheduled event
+  logs tail [-n <lines>]                   Last lines of the application log
+  shutdown                                 Shut the running instance down";
+
+/// Runs the client when the first argument is [`CLI_SUBCOMMAND`] and returns the process
+/// exit code, or `None` when the app should start normally.
+pub fn run_if_requested(args: &[String], identifier: &str) -> Option<i32> {
+    let (subcommand, rest) = args.split_first()?;
+    if subcommand != CLI_SUBCOMMAND {
+        return None;
+    }
+
+    if rest.is_empty() || matches!(rest[0].as_str(), "help" | "--help" | "-h") {
+        println!("{USAGE}");
+        return Some(0);
+    }
+
+    let request = match parse_args(rest) {
+        Ok(request) => request,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return Some(2);
+        }
+    };
+
+    let Some(config_dir) = dirs::config_dir().map(|dir| dir.join(identifier)) else {
+        eprintln!("Could not resolve the app config directory");
+        return Some(1);
+    };
+
+    match send_request(&config_dir, request) {
+        Ok(response) if response.ok => {
+            let result = response.result.unwrap_or_default();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string())
+            );
+            Some(0)
+        }
+        Ok(response) => {
+            eprintln!(
+                "{}",
+                response
+                    .error
+                    .unwrap_or_else(|| "Unknown error".to_string())
+            );
+            Some(1)
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            Some(1)
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<ControlRequest, String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["status"] => Ok(ControlRequest::Status),
+        ["mine", action @ ("start" | "stop"), flags @ ..] => {
+            let (cpu, gpu) = parse_device_flags(flags)?;
+            if *action == "start" {
+                Ok(ControlRequest::StartMining { cpu, gpu })
+            } else {
+                Ok(ControlRequest::StopMining { cpu, gpu })
+            }
+        }
+        ["mode", "set", mode] => Ok(ControlRequest::SetMiningMode {
+            mode: (*mode).to_string(),
+        }),
+        ["pool", "set", "--cpu", pool] => Ok(ControlRequest::SetCpuPool {
+            pool: (*pool).to_string(),
+        }),
+        ["pool", "set", "--gpu", pool] => Ok(ControlRequest::SetGpuPool {
+            pool: (*pool).to_string(),
+        }),
+        ["wallet", "balance"] => Ok(ControlRequest::WalletBalance),
+        ["wallet", "history"] => Ok(ControlRequest::WalletHistory { limit: None }),
+        ["wallet", "history", "--limit", limit] => Ok(ControlRequest::WalletHistory {
+            limit: Some(parse_number(limit)?),
+        }),
+        ["node", "peers"] => Ok(ControlRequest::NodePeers),
+        ["schedule", "list"] => Ok(ControlRequest::ScheduleList),
+        ["schedule", "add", event_id, mining_mode, start, end] => {
+            let (start_hour, start_minute, start_period) = parse_clock_time(start)?;
+            let (end_hour, end_minute, end_period) = parse_clock_time(end)?;
+            Ok(ControlRequest::ScheduleAdd {
+                event_id: (*event_id).to_string(),
+                mining_mode: (*mining_mode).to_string(),
+                start_hour,
+                start_minute,
+                start_period,
+                end_hour,
+                end_minute,
+                end_period,
+            })
+        }
+        ["schedule", "remove", event_id] => Ok(ControlRequest::ScheduleRemove {
+            event_id: (*event_id).to_string(),
+        }),
+        ["logs", "tail"] => Ok(ControlRequest::LogsTail { lines: None }),
+        ["logs", "tail", "-n" | "--lines", lines] => Ok(ControlRequest::LogsTail {
+            lines: Some(parse_number(lines)?),
+        }),
+        ["shutdown"] => Ok(ControlRequest::Shutdown),
+        _ => Err(format!("Unknown command: {}", args.join(" "))),
+    }
+}
+
+/// No flag means both devices, matching the MCP tool defaults
+fn parse_device_flags(flags: &[&str]) -> Result<(Option<bool>, Option<bool>), String> {
+    let mut cpu = false;
+    let mut gpu = false;
+    for flag in flags {
+        match *flag {
+            "--cpu" => cpu = true,
+            "--gpu" => gpu = true,
+            other => return Err(format!("Unknown flag: {other}")),
+        }
+    }
+    if !cpu && !gpu {
+        return Ok((None, None));
+    }
+    Ok((Some(cpu), Some(gpu)))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid number: {value}"))
+}
+
+/// Parses `9pm`, `9:30pm` or `09:30 AM` into the 12-hour parts the scheduler expects
+fn parse_clock_time(value: &str) -> Result<(i64, i64, String), String> {
+    let normalized = value.trim().to_uppercase().replace(' ', "");
+    let (time, period) = if let Some(time) = normalized.strip_suffix("AM") {
+        (time, "AM")
+    } else if let Some(time) = normalized.strip_suffix("PM") {
+        (time, "PM")
+    } else {
+        return Err(format!("Invalid time: {value}. Use e.g. 9am or 9:30pm"));
+    };
+    let (hour, minute) = match time.split_once(':') {
+        Some((hour, minute)) => (parse_number(hour)?, parse_number(minute)?),
+        None => (parse_number(time)?, 0),
+    };
+    Ok((hour, minute, period.to_string()))
+}
+
+fn read_token(config_dir: &Path) -> Result<String, String> {
+    let token_path = config_dir.join(CONTROL_TOKEN_FILE_NAME);
+    std::fs::read_to_string(&token_path)
+        .map(|token| token.trim().to_string())
+        .map_err(|e| {
+            format!(
+                "Could not read {}: {e}. Is Tari Universe running?",
+                token_path.display()
+            )
+        })
+}
+
+fn send_request(config_dir: &Path, request: ControlRequest) -> Result<ControlResponse, String> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let socket_path = config_dir.join(CONTROL_SOCKET_FILE_NAME);
+    let envelope = ControlEnvelope {
+        token: read_token(config_dir)?,
+        request,
+    };
+    let mut payload = serde_json::to_vec(&envelope).map_err(|e| e.to_string())?;
+    payload.push(b'\n');
+
+    let mut stream = UnixStream::connect(&socket_path).map_err(|e| {
+        format!(
+            "Could not connect to {}: {e}. Is Tari Universe running?",
+            socket_path.display()
+        )
+    })?;
+    stream.write_all(&payload).map_err(|e| e.to_string())?;
+
+    let mut line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut line)
+        .map_err(|e| e.to_string())?;
+    serde_json::from_str(&line).map_err(|e| format!("Invalid response: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(command: &str) -> Result<ControlRequest, String> {
+        let args: Vec<String> = command.split_whitespace().map(String::from).collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn parses_mining_commands() {
+        assert_eq!(
+            parse("mine start"),
+            Ok(ControlRequest::StartMining {
+                cpu: None,
+                gpu: None
+            })
+        );
+        assert_eq!(
+            parse("mine stop --gpu"),
+            Ok(ControlRequest::StopMining {
+                cpu: Some(false),
+                gpu: Some(true)
+            })
+        );
+        assert!(parse("mine start --tpu").is_err());
+        assert_eq!(
+            parse("pool set --cpu LuckyPool"),
+            Ok(ControlRequest::SetCpuPool {
+                pool: "LuckyPool".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_schedule_add() {
+        assert_eq!(
+            parse("schedule add night Ludicrous 9:30pm 6am"),
+            Ok(ControlRequest::ScheduleAdd {
+                event_id: "night".to_string(),
+                mining_mode: "Ludicrous".to_string(),
+                start_hour: 9,
+                start_minute: 30,
+                start_period: "PM".to_string(),
+                end_hour: 6,
+                end_minute: 0,
+                end_period: "AM".to_string(),
+            })
+        );
+        assert!(parse("schedule add night Ludicrous 21:30 6am").is_err());
+    }
+
+    #[test]
+    fn ignores_regular_app_arguments() {
+        assert_eq!(run_if_requested(&["--headless".to_string()], "id"), None);
+        assert_eq!(run_if_requested(&[], "id"), None);
+    }
+}
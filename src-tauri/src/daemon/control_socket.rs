@@ -0,0 +1,595 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Local control socket, always served by the daemon and by the desktop app when
+//! `control_socket_enabled` is set in the core config.
+//!
+//! A Unix domain socket in the app config dir (`daemon.sock`, mode `0600`) that only
+//! accepts connections from the user running the app. Every start writes a fresh random
+//! token to `daemon.token` (mode `0600`) which has to accompany each request. The protocol
+//! is one JSON request per line, answered with one JSON response per line:
+//!
+//! ```text
+//! {"token":"tu_...","command":"start_mining","cpu":true,"gpu":false}
+//! {"ok":true,"result":{"cpu":"started"}}
+//! ```
+//!
+//! Requests are served by the same handlers as the MCP tools and Tauri commands so every
+//! control path behaves identically, and every request is recorded in the MCP audit log.
+//! The [`super::cli`] module is the client side.
+
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use tauri::ipc::InvokeError;
+use tauri::{AppHandle, Manager};
+
+use crate::UniverseAppState;
+use crate::commands;
+use crate::configs::config_mining::ConfigMining;
+use crate::configs::trait_config::ConfigImpl;
+use crate::events_emitter::EventsEmitter;
+use crate::mcp::audit::{AuditEntry, AuditLog, AuditStatus};
+use crate::mcp::tools::scheduler::MiningWindowParams;
+use crate::mcp::tools::{chain, mining, pools, scheduler, wallet};
+use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::manager::GpuManager;
+
+pub const CONTROL_SOCKET_FILE_NAME: &str = "daemon.sock";
+pub const CONTROL_TOKEN_FILE_NAME: &str = "daemon.token";
+const DEFAULT_LOG_TAIL_LINES: usize = 50;
+const MAX_LOG_TAIL_LINES: usize = 5_000;
+/// Client name of control socket requests in the audit log
+const AUDIT_CLIENT_NAME: &str = "control-socket";
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlRequest {
+    Status,
+    StartMining {
+        cpu: Option<bool>,
+        gpu: Option<bool>,
+    },
+    StopMining {
+        cpu: Option<bool>,
+        gpu: Option<bool>,
+    },
+    SetMiningMode {
+        mode: String,
+    },
+    SetCpuPool {
+        pool: String,
+    },
+    SetGpuPool {
+        pool: String,
+    },
+    WalletBalance,
+    WalletHistory {
+        limit: Option<u32>,
+    },
+    NodePeers,
+    ScheduleList,
+    ScheduleAdd {
+        event_id: String,
+        mining_mode: String,
+        start_hour: i64,
+        start_minute: i64,
+        start_period: String,
+        end_hour: i64,
+        end_minute: i64,
+        end_period: String,
+    },
+    ScheduleRemove {
+        event_id: String,
+    },
+    LogsTail {
+        lines: Option<usize>,
+    },
+    Shutdown,
+}
+
+impl ControlRequest {
+    /// MCP tier the request corresponds to, used for the audit log
+    fn tier(&self) -> &'static str {
+        match self {
+            Self::Status
+            | Self::WalletBalance
+            | Self::WalletHistory { .. }
+            | Self::NodePeers
+            | Self::ScheduleList
+            | Self::LogsTail { .. } => "read",
+            Self::StartMining { .. }
+            | Self::StopMining { .. }
+            | Self::SetMiningMode { .. }
+            | Self::SetCpuPool { .. }
+            | Self::SetGpuPool { .. }
+            | Self::ScheduleAdd { .. }
+            | Self::ScheduleRemove { .. }
+            | Self::Shutdown => "control",
+        }
+    }
+}
+
+/// A request as sent over the socket, carrying the control token next to the command
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ControlEnvelope {
+    pub token: String,
+    #[serde(flatten)]
+    pub request: ControlRequest,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ControlResponse {
+    fn success(result: Value) -> Self {
+        Self {
+            ok: true,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    fn failure(error: String) -> Self {
+        Self {
+            ok: false,
+            result: None,
+            error: Some(error),
+        }
+    }
+
+    /// MCP tool handlers return their result as a JSON string
+    fn from_tool_result(result: Result<String, String>) -> Self {
+        match result {
+            Ok(json) => Self::success(serde_json::from_str(&json).unwrap_or(Value::String(json))),
+            Err(e) => Self::failure(e),
+        }
+    }
+
+    fn from_command_result(result: Result<(), String>, success: Value) -> Self {
+        match result {
+            Ok(()) => Self::success(success),
+            Err(e) => Self::failure(e),
+        }
+    }
+}
+
+fn invoke_error_message(error: InvokeError) -> String {
+    match error {
+        InvokeError(Value::String(e)) => e,
+        InvokeError(e) => e.to_string(),
+    }
+}
+
+/// Per-device result of a start or stop command, in the same shape as the MCP mining tools
+fn device_result(result: Result<(), String>, success: &str) -> Value {
+    match result {
+        Ok(()) => json!(success),
+        Err(e) => json!(format!("error: {e}")),
+    }
+}
+
+/// Selects the mining mode through the shared command and restarts the running miners
+/// so they pick it up, like the frontend does
+async fn select_mining_mode(mode: String) -> Result<(), String> {
+    let cpu_was_running = CpuManager::read().await.is_running();
+    let gpu_was_running = GpuManager::read().await.is_running();
+    if cpu_was_running {
+        commands::stop_cpu_mining().await?;
+    }
+    if gpu_was_running {
+        commands::stop_gpu_mining().await?;
+    }
+
+    let result = commands::select_mining_mode(mode)
+        .await
+        .map_err(invoke_error_message);
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+
+    if cpu_was_running {
+        commands::start_cpu_mining().await?;
+    }
+    if gpu_was_running {
+        commands::start_gpu_mining().await?;
+    }
+    result
+}
+
+pub fn socket_path(app_handle: &AppHandle) -> Result<PathBuf, anyhow::Error> {
+    Ok(app_handle
+        .path()
+        .app_config_dir()?
+        .join(CONTROL_SOCKET_FILE_NAME))
+}
+
+/// Constant-time comparison via hashing, same as the MCP bearer token check
+fn is_valid_token(provided: &str, expected: &str) -> bool {
+    Sha256::digest(provided.as_bytes()) == Sha256::digest(expected.as_bytes())
+}
+
+/// Returns at most `count` trailing lines of the file
+fn tail_file(path: &Path, count: usize) -> Result<Vec<String>, std::io::Error> {
+    let contents = std::fs::read(path)?;
+    let contents = String::from_utf8_lossy(&contents);
+    let lines: Vec<&str> = contents.lines().collect();
+    let skip = lines.len().saturating_sub(count);
+    Ok(lines[skip..].iter().map(ToString::to_string).collect())
+}
+
+fn json_or_null(result: Result<String, String>) -> Result<Value, String> {
+    result.map(|json| serde_json::from_str(&json).unwrap_or(Value::Null))
+}
+
+#[allow(clippy::too_many_lines)]
+async fn handle_request(app_handle: &AppHandle, request: ControlRequest) -> ControlResponse {
+    let state = app_handle.state::<UniverseAppState>();
+    match request {
+        ControlRequest::Status => {
+            let mining_status = match json_or_null(mining::get_mining_status().await) {
+                Ok(status) => status,
+                Err(e) => return ControlResponse::failure(e),
+            };
+            let node_status = state.node_status_watch_rx.borrow().clone();
+            let chain_status = match json_or_null(chain::get_chain_status(&node_status)) {
+                Ok(status) => status,
+                Err(e) => return ControlResponse::failure(e),
+            };
+            ControlResponse::success(json!({
+                "mining": mining_status,
+                "chain": chain_status,
+            }))
+        }
+        ControlRequest::StartMining { cpu, gpu } => {
+            let mut results = json!({});
+            if cpu.unwrap_or(true) {
+                results["cpu"] = device_result(commands::start_cpu_mining().await, "started");
+            }
+            if gpu.unwrap_or(true) {
+                results["gpu"] = device_result(commands::start_gpu_mining().await, "started");
+            }
+            ControlResponse::success(results)
+        }
+        ControlRequest::StopMining { cpu, gpu } => {
+            let mut results = json!({});
+            if cpu.unwrap_or(true) {
+                results["cpu"] = device_result(commands::stop_cpu_mining().await, "stopped");
+            }
+            if gpu.unwrap_or(true) {
+                results["gpu"] = device_result(commands::stop_gpu_mining().await, "stopped");
+            }
+            ControlResponse::success(results)
+        }
+        ControlRequest::SetMiningMode { mode } => ControlResponse::from_command_result(
+            select_mining_mode(mode.clone()).await,
+            json!({"selected_mode": mode}),
+        ),
+        ControlRequest::SetCpuPool { pool } => {
+            ControlResponse::from_tool_result(pools::select_pool("cpu", pool).await)
+        }
+        ControlRequest::SetGpuPool { pool } => {
+            ControlResponse::from_tool_result(pools::select_pool("gpu", pool).await)
+        }
+        ControlRequest::WalletBalance => ControlResponse::from_tool_result(
+            wallet::get_wallet_balance(&state.wallet_manager).await,
+        ),
+        ControlRequest::WalletHistory { limit } => ControlResponse::from_tool_result(
+            wallet::get_transaction_history(&state.wallet_manager, limit).await,
+        ),
+        ControlRequest::NodePeers => match state.node_manager.list_connected_peers().await {
+            Ok(peers) => ControlResponse::success(json!(peers)),
+            Err(e) => ControlResponse::failure(e.to_string()),
+        },
+        ControlRequest::ScheduleList => {
+            ControlResponse::from_tool_result(scheduler::list_scheduled_events().await)
+        }
+        ControlRequest::ScheduleAdd {
+            event_id,
+            mining_mode,
+            start_hour,
+            start_minute,
+            start_period,
+            end_hour,
+            end_minute,
+            end_period,
+        } => ControlResponse::from_tool_result(
+            scheduler::schedule_mining_window(MiningWindowParams {
+                event_id,
+                mining_mode,
+                start_hour,
+                start_minute: Some(start_minute),
+                start_period,
+                end_hour,
+                end_minute: Some(end_minute),
+                end_period,
+            })
+            .await,
+        ),
+        ControlRequest::ScheduleRemove { event_id } => {
+            ControlResponse::from_tool_result(scheduler::cancel_scheduled_event(event_id).await)
+        }
+        ControlRequest::LogsTail { lines } => {
+            let count = lines
+                .unwrap_or(DEFAULT_LOG_TAIL_LINES)
+                .min(MAX_LOG_TAIL_LINES);
+            let log_file = match app_handle.path().app_log_dir() {
+                Ok(dir) => dir.join("universe").join("log").join("universe.log"),
+                Err(e) => return ControlResponse::failure(e.to_string()),
+            };
+            match tail_file(&log_file, count) {
+                Ok(lines) => ControlResponse::success(json!(lines)),
+                Err(e) => {
+                    ControlResponse::failure(format!("Could not read {}: {e}", log_file.display()))
+                }
+            }
+        }
+        ControlRequest::Shutdown => {
+            // Respond first, the shutdown sequence runs in its own task
+            tauri::async_runtime::spawn(super::request_shutdown());
+            ControlResponse::success(Value::String("shutting down".to_string()))
+        }
+    }
+}
+
+/// Audit log entry of a control socket request, `details` is the request without the token
+fn audit_entry(
+    request: &ControlRequest,
+    status: AuditStatus,
+    duration_ms: Option<u64>,
+) -> AuditEntry {
+    let details = serde_json::to_value(request).unwrap_or_default();
+    let command = details["command"].as_str().unwrap_or_default();
+    AuditEntry {
+        timestamp: SystemTime::now(),
+        tool_name: format!("control_socket.{command}"),
+        tier: request.tier().to_string(),
+        status,
+        duration_ms,
+        client_info: Some(AUDIT_CLIENT_NAME.to_string()),
+        details: Some(details.to_string()),
+        prev_hash: None,
+    }
+}
+
+async fn handle_line(app_handle: &AppHandle, expected_token: &str, line: &str) -> ControlResponse {
+    match serde_json::from_str::<ControlEnvelope>(line) {
+        Ok(envelope) if is_valid_token(&envelope.token, expected_token) => {
+            let started_at = Instant::now();
+            let request = envelope.request.clone();
+            let response = handle_request(app_handle, envelope.request).await;
+            let status = if response.ok {
+                AuditStatus::Success
+            } else {
+                AuditStatus::Error
+            };
+            let duration_ms = u64::try_from(started_at.elapsed().as_millis()).ok();
+            AuditLog::record(audit_entry(&request, status, duration_ms)).await;
+            response
+        }
+        Ok(envelope) => {
+            AuditLog::record(audit_entry(&envelope.request, AuditStatus::Denied, None)).await;
+            ControlResponse::failure("Invalid control token".to_string())
+        }
+        Err(e) => ControlResponse::failure(format!("Invalid request: {e}")),
+    }
+}
+
+#[cfg(unix)]
+pub async fn start(app_handle: AppHandle) -> Result<PathBuf, anyhow::Error> {
+    use log::{info, warn};
+    use std::io::Write;
+    use std::os::unix::fs::{MetadataExt, OpenOptionsExt, PermissionsExt};
+    use std::sync::Arc;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{UnixListener, UnixStream};
+
+    use crate::LOG_TARGET_APP_LOGIC;
+    use crate::configs::config_mcp::ConfigMcpContent;
+    use crate::tasks_tracker::TasksTrackers;
+
+    async fn serve_connection(
+        app_handle: AppHandle,
+        stream: UnixStream,
+        owner_uid: u32,
+        token: Arc<String>,
+    ) {
+        let is_same_user = stream.peer_cred().is_ok_and(|cred| cred.uid() == owner_uid);
+        let (reader, mut writer) = stream.into_split();
+        if !is_same_user {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Control socket: rejected connection from another user");
+            let _unused = writer
+                .write_all(b"{\"ok\":false,\"error\":\"Permission denied\"}\n")
+                .await;
+            return;
+        }
+
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = handle_line(&app_handle, &token, &line).await;
+            let Ok(mut payload) = serde_json::to_vec(&response) else {
+                break;
+            };
+            payload.push(b'\n');
+            if writer.write_all(&payload).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    let path = socket_path(&app_handle)?;
+    let Some(config_dir) = path.parent() else {
+        return Err(anyhow::anyhow!(
+            "Control socket path has no parent directory"
+        ));
+    };
+    std::fs::create_dir_all(config_dir)?;
+
+    let token = ConfigMcpContent::generate_token();
+    let token_path = config_dir.join(CONTROL_TOKEN_FILE_NAME);
+    let mut token_file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(&token_path)?;
+    token_file.write_all(token.as_bytes())?;
+    let token = Arc::new(token);
+
+    // A socket left behind by a crashed instance would make bind fail
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    let listener = UnixListener::bind(&path)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    // The socket is owned by the user running the app, only that user may connect
+    let owner_uid = std::fs::metadata(&path)?.uid();
+
+    let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+    let socket_path = path.clone();
+    TasksTrackers::current()
+        .common
+        .get_task_tracker()
+        .await
+        .spawn(async move {
+            loop {
+                tokio::select! {
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => {
+                            TasksTrackers::current()
+                                .common
+                                .get_task_tracker()
+                                .await
+                                .spawn(serve_connection(app_handle.clone(), stream, owner_uid, token.clone()));
+                        }
+                        Err(e) => {
+                            warn!(target: LOG_TARGET_APP_LOGIC, "Control socket: accept failed: {e:?}");
+                        }
+                    },
+                    _ = shutdown_signal.wait() => {
+                        info!(target: LOG_TARGET_APP_LOGIC, "Control socket: shutting down");
+                        break;
+                    }
+                }
+            }
+            let _unused = std::fs::remove_file(&socket_path);
+            let _unused = std::fs::remove_file(&token_path);
+        });
+
+    Ok(path)
+}
+
+#[cfg(not(unix))]
+pub async fn start(_app_handle: AppHandle) -> Result<PathBuf, anyhow::Error> {
+    Err(anyhow::anyhow!(
+        "The control socket is only available on Unix platforms, use the MCP server instead"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn parses_control_requests() {
+        let envelope: ControlEnvelope =
+            serde_json::from_str(r#"{"token":"tu_abc","command":"start_mining","cpu":true}"#)
+                .unwrap();
+        assert_eq!(envelope.token, "tu_abc");
+        assert_eq!(
+            envelope.request,
+            ControlRequest::StartMining {
+                cpu: Some(true),
+                gpu: None
+            }
+        );
+
+        let request: ControlRequest = serde_json::from_str(r#"{"command":"status"}"#).unwrap();
+        assert_eq!(request, ControlRequest::Status);
+
+        assert!(serde_json::from_str::<ControlRequest>(r#"{"command":"format_disk"}"#).is_err());
+        assert!(serde_json::from_str::<ControlEnvelope>(r#"{"command":"status"}"#).is_err());
+    }
+
+    #[test]
+    fn wraps_tool_results() {
+        let response = ControlResponse::from_tool_result(Ok(r#"{"cpu":"started"}"#.to_string()));
+        assert!(response.ok);
+        assert_eq!(response.result, Some(json!({"cpu": "started"})));
+
+        let response = ControlResponse::from_tool_result(Err("boom".to_string()));
+        assert!(!response.ok);
+        assert_eq!(response.error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn audit_entries_carry_the_request_without_the_token() {
+        let envelope: ControlEnvelope = serde_json::from_str(
+            r#"{"token":"tu_secret","command":"set_cpu_pool","pool":"LuckyPoolRANDOMX"}"#,
+        )
+        .unwrap();
+
+        let entry = audit_entry(&envelope.request, AuditStatus::Success, Some(5));
+
+        assert_eq!(entry.tool_name, "control_socket.set_cpu_pool");
+        assert_eq!(entry.tier, "control");
+        assert_eq!(entry.client_info.as_deref(), Some(AUDIT_CLIENT_NAME));
+        let details = entry.details.unwrap();
+        assert!(details.contains("LuckyPoolRANDOMX"));
+        assert!(!details.contains("tu_secret"));
+
+        let entry = audit_entry(&ControlRequest::Status, AuditStatus::Denied, None);
+        assert_eq!(entry.tool_name, "control_socket.status");
+        assert_eq!(entry.tier, "read");
+    }
+
+    #[test]
+    fn validates_tokens() {
+        assert!(is_valid_token("tu_abc", "tu_abc"));
+        assert!(!is_valid_token("tu_abd", "tu_abc"));
+        assert!(!is_valid_token("", "tu_abc"));
+    }
+
+    #[test]
+    fn tails_last_lines() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        for i in 0..10 {
+            writeln!(file, "line {i}").unwrap();
+        }
+        assert_eq!(
+            tail_file(file.path(), 3).unwrap(),
+            vec!["line 7", "line 8", "line 9"]
+        );
+        assert_eq!(tail_file(file.path(), 50).unwrap().len(), 10);
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Display for daemon mode on Linux servers.
+//!
+//! Tauri's event loop initializes GTK, which needs a display even though the daemon
+//! never creates a window or loads the webview. When neither `DISPLAY` nor
+//! `WAYLAND_DISPLAY` is set the daemon starts its own `Xvfb` server before the event
+//! loop is built. `-terminate` makes the server exit together with the daemon once
+//! its only client disconnects.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::OnceLock;
+
+const DISPLAY_VARS: [&str; 2] = ["DISPLAY", "WAYLAND_DISPLAY"];
+
+static VIRTUAL_DISPLAY: OnceLock<Child> = OnceLock::new();
+
+fn has_display() -> bool {
+    DISPLAY_VARS
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|value| !value.is_empty()))
+}
+
+/// Makes sure GTK can be initialized, starting a private `Xvfb` when there is no display.
+/// Must be called before the Tauri app is built and before any other thread is spawned.
+pub fn ensure_display() -> Result<(), String> {
+    if has_display() {
+        return Ok(());
+    }
+
+    // `-displayfd` picks a free display number and writes it to the given fd
+    let mut child = Command::new("Xvfb")
+        .args([
+            "-displayfd",
+            "1",
+            "-nolisten",
+            "tcp",
+            "-terminate",
+            "-screen",
+            "0",
+            "640x480x24",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| {
+            format!("No display is available and Xvfb could not be started ({e}), install Xvfb or set DISPLAY")
+        })?;
+
+    let mut display_number = String::new();
+    if let Some(stdout) = child.stdout.take() {
+        let _unused = BufReader::new(stdout).read_line(&mut display_number);
+    }
+    let display_number = display_number.trim();
+    if display_number.is_empty() || !display_number.chars().all(|c| c.is_ascii_digit()) {
+        let _unused = child.kill();
+        let _unused = child.wait();
+        return Err("Xvfb did not report a display number".to_string());
+    }
+
+    // Safe as long as no other thread exists yet, see the doc comment
+    unsafe {
+        std::env::set_var("DISPLAY", format!(":{display_number}"));
+    }
+    drop(VIRTUAL_DISPLAY.set(child));
+    Ok(())
+}
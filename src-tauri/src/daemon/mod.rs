@@ -0,0 +1,252 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Production daemon mode, started with `--daemon`.
+//!
+//! Runs the regular setup phases (core, node, wallet, CPU and GPU mining) without
+//! creating the main webview and without the test-mode remote-ui bridge. The daemon
+//! is controlled through the MCP server (when enabled in the MCP config) or through
+//! the local [`control_socket`], e.g. with the `ctl` command-line client.
+//!
+//! Signal handling is systemd friendly: `SIGTERM` and `SIGINT` go through the
+//! [`ShutdownManager`] so miners, node and wallet are stopped cleanly, `SIGHUP` is
+//! ignored, and `READY=1` / `STOPPING=1` are reported when `NOTIFY_SOCKET` is set so
+//! the app can run as a `Type=notify` unit.
+//!
+//! No window, webview or tray icon is created. Tauri still needs its event loop, so on
+//! Linux servers without a display the daemon hosts it on a private `Xvfb`, see [`display`].
+//!
+//! With `--file-credentials` the wallet secrets are kept in an encrypted file store instead
+//! of the OS keyring. The passphrase is read from the file named by
+//! `TARI_UNIVERSE_CREDENTIALS_PASSPHRASE_FILE` (e.g. a systemd `LoadCredential=`) or from
+//! `TARI_UNIVERSE_CREDENTIALS_PASSPHRASE`.
+
+#[cfg(unix)]
+pub mod cli;
+pub mod control_socket;
+#[cfg(target_os = "linux")]
+pub mod display;
+mod systemd;
+
+use log::{error, info, warn};
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_cli::CliExt;
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_mining::ConfigMining;
+use crate::configs::trait_config::ConfigImpl;
+use crate::events_emitter::EventsEmitter;
+use crate::file_credential_store;
+use crate::mcp::tools::mining;
+use crate::setup::setup_manager::{SetupManager, SetupPhase};
+use crate::shutdown_manager::ShutdownManager;
+use crate::tasks_tracker::TasksTrackers;
+use crate::utils::app_flow_utils::FrontendReadyChannel;
+
+const DAEMON_ARG: &str = "daemon";
+const FILE_CREDENTIALS_ARG: &str = "file-credentials";
+const PASSPHRASE_FILE_ENV: &str = "TARI_UNIVERSE_CREDENTIALS_PASSPHRASE_FILE";
+const PASSPHRASE_ENV: &str = "TARI_UNIVERSE_CREDENTIALS_PASSPHRASE";
+
+fn has_cli_flag<R: Runtime, M: Manager<R>>(manager: &M, name: &str) -> bool {
+    manager
+        .cli()
+        .matches()
+        .as_ref()
+        .is_ok_and(|m| m.args.get(name).is_some_and(|arg| arg.occurrences > 0))
+}
+
+pub fn is_daemon_requested<R: Runtime, M: Manager<R>>(manager: &M) -> bool {
+    has_cli_flag(manager, DAEMON_ARG)
+}
+
+/// Checks the raw process arguments, for use before the Tauri app is built
+pub fn is_daemon_arg(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == &format!("--{DAEMON_ARG}"))
+}
+
+/// Start the daemon backend: control socket, signal handlers and the setup pipeline.
+pub fn start_daemon(app_handle: AppHandle) {
+    info!(target: LOG_TARGET_APP_LOGIC, "Daemon mode: starting backend without webview");
+    if has_cli_flag(&app_handle, FILE_CREDENTIALS_ARG)
+        && let Err(e) = init_file_credential_store(&app_handle)
+    {
+        // Falling back to the keyring would look like an empty wallet, stop instead
+        error!(target: LOG_TARGET_APP_LOGIC, "Daemon: {e}");
+        app_handle.exit(1);
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        EventsEmitter::load_app_handle(app_handle.clone()).await;
+        // There is no frontend to wait for, setup can start straight away
+        FrontendReadyChannel::current().set_ready();
+
+        start_control_socket(app_handle.clone()).await;
+        spawn_signal_handler();
+
+        SetupManager::get_instance()
+            .start_setup(app_handle.clone())
+            .await;
+        SetupManager::spawn_sleep_mode_handler().await;
+        spawn_mine_on_app_start().await;
+
+        systemd::notify(systemd::READY);
+    });
+}
+
+/// Always started by the daemon, desktop mode starts it only when `control_socket_enabled`
+/// is set in the core config
+pub async fn start_control_socket(app_handle: AppHandle) {
+    match control_socket::start(app_handle).await {
+        Ok(path) => {
+            info!(target: LOG_TARGET_APP_LOGIC, "Control socket listening at {}", path.display());
+        }
+        Err(e) => {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to start control socket: {e:?}");
+        }
+    }
+}
+
+/// Shut the daemon down through the [`ShutdownManager`], skipping the dialogs that need a frontend.
+pub async fn request_shutdown() {
+    systemd::notify(systemd::STOPPING);
+    ShutdownManager::instance()
+        .initialize_direct_shutdown()
+        .await;
+}
+
+fn credential_store_passphrase() -> Result<Vec<u8>, String> {
+    if let Some(path) = std::env::var_os(PASSPHRASE_FILE_ENV) {
+        let content = std::fs::read(&path)
+            .map_err(|e| format!("could not read {PASSPHRASE_FILE_ENV} {path:?}: {e}"))?;
+        return Ok(content.trim_ascii_end().to_vec());
+    }
+    std::env::var(PASSPHRASE_ENV)
+        .map(String::into_bytes)
+        .map_err(|_| {
+            format!("--{FILE_CREDENTIALS_ARG} needs a passphrase in {PASSPHRASE_FILE_ENV} or {PASSPHRASE_ENV}")
+        })
+}
+
+fn init_file_credential_store(app_handle: &AppHandle) -> Result<(), String> {
+    let credential_dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("could not resolve app config dir for credentials: {e:?}"))?
+        .join("credentials");
+    let passphrase = credential_store_passphrase()?;
+    file_credential_store::init(credential_dir.clone(), &passphrase)
+        .map_err(|e| format!("could not open the credential store: {e}"))?;
+    keyring::set_default_credential_builder(file_credential_store::default_credential_builder());
+    info!(target: LOG_TARGET_APP_LOGIC, "Daemon: using encrypted file-backed credential store at {}", credential_dir.display());
+    Ok(())
+}
+
+/// Desktop builds start mining from the frontend once the mining modules unlock.
+/// The daemon has no frontend, so it waits for the mining phases itself.
+async fn spawn_mine_on_app_start() {
+    let config = ConfigMining::content().await;
+    if !*config.mine_on_app_start() {
+        return;
+    }
+
+    for (phase, is_enabled) in [
+        (SetupPhase::CpuMining, *config.cpu_mining_enabled()),
+        (SetupPhase::GpuMining, *config.gpu_mining_enabled()),
+    ] {
+        if !is_enabled {
+            continue;
+        }
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        let mut phase_status = SetupManager::get_instance().subscribe_to_phase_status(&phase);
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let is_phase_ready = tokio::select! {
+                    result = phase_status.wait_for(|status| status.is_success()) => result.is_ok(),
+                    _ = shutdown_signal.wait() => false,
+                };
+                if !is_phase_ready {
+                    return;
+                }
+                let is_cpu = phase == SetupPhase::CpuMining;
+                match mining::start_mining(Some(is_cpu), Some(!is_cpu)).await {
+                    Ok(result) => {
+                        info!(target: LOG_TARGET_APP_LOGIC, "Daemon: mine on app start for {phase}: {result}");
+                    }
+                    Err(e) => {
+                        warn!(target: LOG_TARGET_APP_LOGIC, "Daemon: mine on app start for {phase} failed: {e}");
+                    }
+                }
+            });
+    }
+}
+
+#[cfg(unix)]
+fn spawn_signal_handler() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    tauri::async_runtime::spawn(async move {
+        let (mut sigterm, mut sigint, mut sighup) = match (
+            signal(SignalKind::terminate()),
+            signal(SignalKind::interrupt()),
+            signal(SignalKind::hangup()),
+        ) {
+            (Ok(sigterm), Ok(sigint), Ok(sighup)) => (sigterm, sigint, sighup),
+            _ => {
+                error!(target: LOG_TARGET_APP_LOGIC, "Daemon: failed to install signal handlers");
+                return;
+            }
+        };
+        loop {
+            tokio::select! {
+                _ = sigterm.recv() => {
+                    info!(target: LOG_TARGET_APP_LOGIC, "Daemon: SIGTERM received, shutting down");
+                    break;
+                }
+                _ = sigint.recv() => {
+                    info!(target: LOG_TARGET_APP_LOGIC, "Daemon: SIGINT received, shutting down");
+                    break;
+                }
+                _ = sighup.recv() => {
+                    info!(target: LOG_TARGET_APP_LOGIC, "Daemon: SIGHUP received, ignoring");
+                }
+            }
+        }
+        request_shutdown().await;
+    });
+}
+
+#[cfg(not(unix))]
+fn spawn_signal_handler() {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!(target: LOG_TARGET_APP_LOGIC, "Daemon: failed to listen for ctrl-c: {e:?}");
+            return;
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Daemon: ctrl-c received, shutting down");
+        request_shutdown().await;
+    });
+}
@@ -0,0 +1,73 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Minimal `sd_notify` client so the daemon can run as a `Type=notify` systemd unit.
+//! Every call is a no-op when `NOTIFY_SOCKET` is unset, i.e. when not started by systemd.
+
+use log::{debug, warn};
+
+use crate::LOG_TARGET_APP_LOGIC;
+
+pub const READY: &str = "READY=1";
+pub const STOPPING: &str = "STOPPING=1";
+
+pub fn notify(state: &str) {
+    #[cfg(unix)]
+    {
+        let Some(socket_path) = std::env::var_os("NOTIFY_SOCKET") else {
+            return;
+        };
+        if let Err(e) = send(&socket_path, state) {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to notify systemd with {state}: {e}");
+        } else {
+            debug!(target: LOG_TARGET_APP_LOGIC, "Notified systemd: {state}");
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _unused = state;
+    }
+}
+
+#[cfg(unix)]
+fn send(socket_path: &std::ffi::OsStr, state: &str) -> std::io::Result<()> {
+    use std::os::unix::net::UnixDatagram;
+
+    let socket = UnixDatagram::unbound()?;
+
+    // Abstract namespace sockets are passed with a leading '@'
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::linux::net::SocketAddrExt;
+        use std::os::unix::ffi::OsStrExt;
+        use std::os::unix::net::SocketAddr;
+
+        if let Some(name) = socket_path.as_bytes().strip_prefix(b"@") {
+            let address = SocketAddr::from_abstract_name(name)?;
+            socket.send_to_addr(state.as_bytes(), &address)?;
+            return Ok(());
+        }
+    }
+
+    socket.send_to(state.as_bytes(), std::path::Path::new(socket_path))?;
+    Ok(())
+}
@@ -0,0 +1,477 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Generates scheduler events from a time-of-use electricity tariff.
+//! Cheap slots mine in Ludicrous, expensive slots mine in Eco or stop mining.
+//! The tariff file is watched and the generated events are recomputed when it changes.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{Context, anyhow};
+use chrono::{DateTime, Local};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::time::{MissedTickBehavior, interval};
+
+use crate::{
+    LOG_TARGET_APP_LOGIC,
+    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    event_scheduler::{
+        EventScheduler, ScheduledEventInfo, SchedulerEventTiming, SchedulerEventType,
+        WeeklyVariantPayload, check_event_conflicts,
+    },
+    events_emitter::EventsEmitter,
+    tasks_tracker::TasksTrackers,
+};
+
+/// Prefix of the IDs of generated events, they are replaced as a whole on every recompute
+pub const TARIFF_EVENT_ID_PREFIX: &str = "tariff_";
+const TARIFF_FILE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const CHEAP_MINING_MODE: &str = "Ludicrous";
+const EXPENSIVE_MINING_MODE: &str = "Eco";
+static IS_WATCHER_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// What to do while electricity is expensive
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TariffExpensiveAction {
+    #[default]
+    Eco,
+    Stop,
+}
+
+/// Price of a time slot, `start` and `end` are "HH:MM" in 24-hour time.
+/// A slot ending at or before its start ends on the following day.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TariffSlot {
+    pub start: String,
+    pub end: String,
+    pub price_per_kwh: f64,
+    /// "all" (default), "weekdays", "weekend" or day names separated by ';' e.g. "Mon;Wed"
+    #[serde(default)]
+    pub weekdays: Option<String>,
+}
+
+/// Tariff file contents
+/// JSON: `{"timezone": "Europe/Berlin", "slots": [{"start": "22:00", "end": "06:00", "price_per_kwh": 0.08}]}`
+/// CSV: `start,end,price_per_kwh[,weekdays]` with an optional header row, evaluated in local time
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TariffTable {
+    #[serde(default)]
+    pub timezone: Option<String>,
+    pub slots: Vec<TariffSlot>,
+}
+
+impl TariffTable {
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tariff file {}", path.display()))?;
+        let is_csv = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+        if is_csv {
+            Self::parse_csv(&contents)
+        } else {
+            serde_json::from_str(&contents).context("Invalid tariff JSON")
+        }
+    }
+
+    pub fn parse_csv(contents: &str) -> Result<Self, anyhow::Error> {
+        let mut slots = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let columns: Vec<&str> = line.split(',').map(str::trim).collect();
+            let (Some(start), Some(end), Some(price)) =
+                (columns.first(), columns.get(1), columns.get(2))
+            else {
+                return Err(anyhow!("Tariff CSV line {} has too few columns", index + 1));
+            };
+            let Ok(price_per_kwh) = price.parse::<f64>() else {
+                if slots.is_empty() {
+                    // Header row
+                    continue;
+                }
+                return Err(anyhow!(
+                    "Invalid price {price:?} on tariff CSV line {}",
+                    index + 1
+                ));
+            };
+            slots.push(TariffSlot {
+                start: (*start).to_string(),
+                end: (*end).to_string(),
+                price_per_kwh,
+                weekdays: columns
+                    .get(3)
+                    .filter(|weekdays| !weekdays.is_empty())
+                    .map(|weekdays| (*weekdays).to_string()),
+            });
+        }
+        Ok(Self {
+            timezone: None,
+            slots,
+        })
+    }
+}
+
+fn parse_time_of_day(value: &str) -> Result<(u32, u32), anyhow::Error> {
+    let (hour, minute) = value
+        .split_once(':')
+        .ok_or_else(|| anyhow!("Invalid time {value:?}, expected HH:MM"))?;
+    let hour = hour.trim().parse::<u32>()?;
+    let minute = minute.trim().parse::<u32>()?;
+    if hour > 23 || minute > 59 {
+        return Err(anyhow!("Invalid time {value:?}, expected HH:MM"));
+    }
+    Ok((hour, minute))
+}
+
+fn parse_weekdays(value: Option<&str>) -> Result<u8, anyhow::Error> {
+    let Some(value) = value else {
+        return Ok(WeeklyVariantPayload::WEEKDAYS | WeeklyVariantPayload::WEEKEND);
+    };
+    let mut mask = 0;
+    for day in value.split(';').map(str::trim) {
+        mask |= match day.to_lowercase().as_str() {
+            "all" => WeeklyVariantPayload::WEEKDAYS | WeeklyVariantPayload::WEEKEND,
+            "weekdays" => WeeklyVariantPayload::WEEKDAYS,
+            "weekend" => WeeklyVariantPayload::WEEKEND,
+            "mon" => WeeklyVariantPayload::MONDAY,
+            "tue" => WeeklyVariantPayload::TUESDAY,
+            "wed" => WeeklyVariantPayload::WEDNESDAY,
+            "thu" => WeeklyVariantPayload::THURSDAY,
+            "fri" => WeeklyVariantPayload::FRIDAY,
+            "sat" => WeeklyVariantPayload::SATURDAY,
+            "sun" => WeeklyVariantPayload::SUNDAY,
+            _ => return Err(anyhow!("Unknown weekday {day:?}")),
+        };
+    }
+    Ok(mask)
+}
+
+/// Scheduler event generated for a tariff slot
+#[derive(Debug, Clone)]
+pub struct TariffEvent {
+    pub event_id: String,
+    pub event_type: SchedulerEventType,
+    pub timing: SchedulerEventTiming,
+}
+
+/// Maps tariff slots to scheduler events.
+/// Slots priced at or below `cheap_price_per_kwh` mine in Ludicrous, slots at or above
+/// `expensive_price_per_kwh` run the expensive action, slots in between are left to other events.
+pub fn plan_tariff_events(
+    table: &TariffTable,
+    cheap_price_per_kwh: f64,
+    expensive_price_per_kwh: f64,
+    expensive_action: TariffExpensiveAction,
+) -> Result<Vec<TariffEvent>, anyhow::Error> {
+    let mut events = Vec::new();
+    for (index, slot) in table.slots.iter().enumerate() {
+        let (event_type, kind) = if slot.price_per_kwh <= cheap_price_per_kwh {
+            (
+                SchedulerEventType::Mine {
+                    mining_mode: CHEAP_MINING_MODE.to_string(),
+                },
+                "cheap",
+            )
+        } else if slot.price_per_kwh >= expensive_price_per_kwh {
+            let event_type = match expensive_action {
+                TariffExpensiveAction::Eco => SchedulerEventType::Mine {
+                    mining_mode: EXPENSIVE_MINING_MODE.to_string(),
+                },
+                TariffExpensiveAction::Stop => SchedulerEventType::StopMining,
+            };
+            (event_type, "expensive")
+        } else {
+            continue;
+        };
+
+        let (start_hour, start_minute) = parse_time_of_day(&slot.start)?;
+        let (end_hour, end_minute) = parse_time_of_day(&slot.end)?;
+        let timing = SchedulerEventTiming::parse_weekly_variant(
+            parse_weekdays(slot.weekdays.as_deref())?,
+            start_hour,
+            start_minute,
+            end_hour,
+            end_minute,
+            table.timezone.clone(),
+        )
+        .map_err(|e| anyhow!("Invalid tariff slot {}: {e}", index + 1))?;
+
+        events.push(TariffEvent {
+            event_id: format!("{TARIFF_EVENT_ID_PREFIX}{}_{kind}", index + 1),
+            event_type,
+            timing,
+        });
+    }
+    Ok(events)
+}
+
+/// Finds planned events that conflict with user events or with earlier planned events.
+/// Existing tariff events are ignored as they are replaced by the planned ones.
+///
+/// ### Returns
+/// * `Vec<String>` - One message per conflicting slot, empty when all slots can be scheduled
+pub fn find_tariff_conflicts(
+    planned_events: &[TariffEvent],
+    existing_events: &[ScheduledEventInfo],
+    from: DateTime<Local>,
+) -> Vec<String> {
+    let mut accepted: Vec<&TariffEvent> = Vec::new();
+    let mut conflicts = Vec::new();
+    for event in planned_events {
+        let others = existing_events
+            .iter()
+            .filter(|existing| !existing.id.starts_with(TARIFF_EVENT_ID_PREFIX))
+            .map(|existing| (existing.id.as_str(), &existing.event_type, &existing.timing))
+            .chain(accepted.iter().map(|accepted| {
+                (
+                    accepted.event_id.as_str(),
+                    &accepted.event_type,
+                    &accepted.timing,
+                )
+            }));
+        match check_event_conflicts(&event.event_type, &event.timing, others, from) {
+            Ok(()) => accepted.push(event),
+            Err(e) => conflicts.push(format!("{}: {e}", event.event_id)),
+        }
+    }
+    conflicts
+}
+
+pub struct TariffScheduler;
+
+impl TariffScheduler {
+    /// Replaces the generated events with the ones planned from the configured tariff file.
+    /// Removes them when no file is configured.
+    ///
+    /// ### Returns
+    /// * `Ok(usize)` - Number of events scheduled
+    /// * `Err(anyhow::Error)` - If the tariff file can't be loaded or some slots couldn't be scheduled,
+    ///   the remaining slots are still scheduled
+    pub async fn apply() -> Result<usize, anyhow::Error> {
+        let config = ConfigCore::content().await;
+        let planned_events = match config.tariff_file_path() {
+            Some(path) => plan_tariff_events(
+                &TariffTable::load(path)?,
+                *config.tariff_cheap_price_per_kwh(),
+                *config.tariff_expensive_price_per_kwh(),
+                *config.tariff_expensive_action(),
+            )?,
+            None => vec![],
+        };
+
+        let scheduler = EventScheduler::instance();
+        for event in scheduler.list_events().await? {
+            if event.id.starts_with(TARIFF_EVENT_ID_PREFIX) {
+                scheduler.remove_event(event.id).await?;
+            }
+        }
+
+        let mut scheduled = 0;
+        let mut rejected = Vec::new();
+        for event in planned_events {
+            match scheduler
+                .schedule_event(event.event_type, event.event_id.clone(), event.timing)
+                .await
+            {
+                Ok(_) => scheduled += 1,
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Skipping tariff event {}: {e}", event.event_id);
+                    rejected.push(format!("{}: {e}", event.event_id));
+                }
+            }
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Scheduled {scheduled} tariff events");
+        EventsEmitter::emit_core_config_loaded(&ConfigCore::content().await).await;
+
+        if !rejected.is_empty() {
+            return Err(anyhow!(
+                "Scheduled {scheduled} tariff events, rejected slots: {}",
+                rejected.join("; ")
+            ));
+        }
+        Ok(scheduled)
+    }
+
+    /// Recomputes the generated events on startup and whenever the tariff file or its path changes.
+    pub async fn start_watcher() {
+        if IS_WATCHER_STARTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut ticker = interval(TARIFF_FILE_CHECK_INTERVAL);
+                ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                let mut last_applied: Option<(Option<PathBuf>, Option<SystemTime>)> = None;
+                loop {
+                    tokio::select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET_APP_LOGIC, "Tariff watcher received shutdown signal");
+                            break;
+                        }
+                        _ = ticker.tick() => {
+                            let path = ConfigCore::content().await.tariff_file_path().clone();
+                            let modified_at = path
+                                .as_ref()
+                                .and_then(|path| std::fs::metadata(path).ok())
+                                .and_then(|metadata| metadata.modified().ok());
+                            let current = Some((path, modified_at));
+                            if current == last_applied {
+                                continue;
+                            }
+                            last_applied = current;
+                            if let Err(e) = Self::apply().await {
+                                error!(target: LOG_TARGET_APP_LOGIC, "Failed to apply tariff schedule: {e}");
+                            }
+                        }
+                    }
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_scheduler::SchedulerEventState;
+
+    #[test]
+    fn parses_csv_with_header_and_weekdays() {
+        let table = TariffTable::parse_csv(
+            "start,end,price_per_kwh,weekdays\n\
+             22:00,06:00,0.08\n\
+             06:00,17:00,0.20,weekdays\n\
+             # evening peak\n\
+             17:00,22:00,0.35,Mon;Tue;Wed;Thu;Fri\n",
+        )
+        .unwrap();
+
+        assert_eq!(table.slots.len(), 3);
+        assert_eq!(table.slots[0].weekdays, None);
+        assert_eq!(table.slots[2].price_per_kwh, 0.35);
+        assert!(TariffTable::parse_csv("22:00,06:00,0.08\n06:00,17:00,cheap\n").is_err());
+    }
+
+    #[test]
+    fn plans_events_by_price() {
+        let table: TariffTable = serde_json::from_str(
+            r#"{"timezone": "Europe/Berlin", "slots": [
+                {"start": "22:00", "end": "06:00", "price_per_kwh": 0.08},
+                {"start": "06:00", "end": "17:00", "price_per_kwh": 0.20},
+                {"start": "17:00", "end": "22:00", "price_per_kwh": 0.35, "weekdays": "weekdays"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let events = plan_tariff_events(&table, 0.10, 0.30, TariffExpensiveAction::Stop).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_id, "tariff_1_cheap");
+        assert_eq!(
+            events[0].event_type,
+            SchedulerEventType::Mine {
+                mining_mode: "Ludicrous".to_string()
+            }
+        );
+        assert_eq!(events[1].event_id, "tariff_3_expensive");
+        assert_eq!(events[1].event_type, SchedulerEventType::StopMining);
+        match &events[1].timing {
+            SchedulerEventTiming::Weekly(payload) => {
+                assert_eq!(payload.weekdays, WeeklyVariantPayload::WEEKDAYS);
+                assert_eq!((payload.start_hour, payload.end_hour), (17, 22));
+                assert_eq!(payload.timezone.as_deref(), Some("Europe/Berlin"));
+            }
+            timing => panic!("Expected weekly timing, got {timing:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_slots() {
+        for (start, weekdays) in [("25:00", None), ("22:00", Some("Funday"))] {
+            let table = TariffTable {
+                timezone: None,
+                slots: vec![TariffSlot {
+                    start: start.to_string(),
+                    end: "06:00".to_string(),
+                    price_per_kwh: 0.01,
+                    weekdays: weekdays.map(str::to_string),
+                }],
+            };
+            assert!(plan_tariff_events(&table, 0.10, 0.30, TariffExpensiveAction::Eco).is_err());
+        }
+    }
+
+    #[test]
+    fn reports_conflicting_slots() {
+        let table: TariffTable = serde_json::from_str(
+            r#"{"slots": [
+                {"start": "22:00", "end": "06:00", "price_per_kwh": 0.08},
+                {"start": "06:00", "end": "17:00", "price_per_kwh": 0.35},
+                {"start": "16:00", "end": "20:00", "price_per_kwh": 0.05}
+            ]}"#,
+        )
+        .unwrap();
+        let events = plan_tariff_events(&table, 0.10, 0.30, TariffExpensiveAction::Eco).unwrap();
+        let existing = vec![
+            ScheduledEventInfo {
+                id: "tariff_1_cheap".to_string(),
+                event_type: events[0].event_type.clone(),
+                timing: events[0].timing.clone(),
+                state: SchedulerEventState::Active,
+                missed_run_policy: Default::default(),
+            },
+            ScheduledEventInfo {
+                id: "user_stop".to_string(),
+                event_type: SchedulerEventType::StopMining,
+                timing: SchedulerEventTiming::parse_weekly_variant(
+                    WeeklyVariantPayload::WEEKDAYS | WeeklyVariantPayload::WEEKEND,
+                    23,
+                    0,
+                    23,
+                    30,
+                    None,
+                )
+                .unwrap(),
+                state: SchedulerEventState::Active,
+                missed_run_policy: Default::default(),
+            },
+        ];
+
+        let conflicts = find_tariff_conflicts(&events, &existing, Local::now());
+
+        // Adjacent slots don't conflict, overlapping ones and the user event do
+        assert_eq!(conflicts.len(), 2);
+        assert!(conflicts[0].starts_with("tariff_1_cheap"));
+        assert!(conflicts[1].starts_with("tariff_3_cheap"));
+    }
+}
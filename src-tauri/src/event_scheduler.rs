@@ -30,7 +30,11 @@
 //! The scheduler can handle two main types of events:
 //! - **One-time events**: Run something after a delay (like "start mining in 2 hours")
 //! - **Recurring events**: Run something during specific time windows using cron expressions
-//!   (like "mine every day from 10 PM to 6 AM")
+//!   (like "mine every day from 10 PM to 6 AM", "mine on weekends from 08:00 to 20:00 in Europe/Berlin"
+//!   or any raw cron pair)
+//! - **Date ranges**: Run something once between two dates (like "mine from Dec 24 to Dec 26")
+//! - **Idle events**: Run something once the system has been idle for a while and undo it
+//!   when the user comes back (like "mine in Ludicrous after 15 idle minutes")
 //!
 //! It also manages different mining modes and keeps your scheduled events even when you
 //! restart the application.
@@ -46,9 +50,14 @@
 //! ## Key parts
 //!
 //! - `CronSchedule`: Handles recurring time windows with cron expressions
-//! - `SchedulerEventTiming`: Defines when events should trigger (In/Between patterns)
-//! - `SchedulerEventType`: Defines what actions to perform (ResumeMining/Mine)
+//! - `SchedulerEventTiming`: Defines when events should trigger (In/Between/Idle patterns)
+//! - `IdleSource`: Pluggable source of the system idle time used by Idle timing
+//! - `SchedulerEventType`: Defines what actions to perform (ResumeMining/Mine/StopMining/SwitchPool/
+//!   SetMiningDevices/RestartNode)
 //! - Persistent storage: Your recurring events are saved and restored automatically
+//! - Conflict detection: Events whose windows overlap with conflicting actions are rejected
+//! - Execution history: Every run and every window missed while the app was closed is recorded
+//!   in `SchedulerHistory`, `SchedulerMissedRunPolicy` decides which missed windows are caught up at startup
 //!
 //! ## Basic usage
 //!
@@ -71,20 +80,24 @@
 
 #![allow(dead_code, unused_variables, unused_must_use)]
 
+use anyhow::anyhow;
 use chrono::{DateTime, Duration, Local};
+use chrono_tz::Tz;
 use croner::{self, Cron, parser::CronParser};
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
     fmt::Display,
+    path::{Path, PathBuf},
     sync::{
-        LazyLock,
+        Arc, LazyLock,
         atomic::{AtomicBool, AtomicU64},
     },
+    time::SystemTime,
 };
 use tokio::{
-    sync::{RwLock, mpsc},
+    sync::{RwLock, mpsc, watch},
     time::sleep,
 };
 
@@ -93,14 +106,37 @@ use crate::{
     configs::{
         config_core::{ConfigCore, ConfigCoreContent},
         config_mining::{ConfigMining, ConfigMiningContent},
+        config_pools::{ConfigPools, ConfigPoolsContent},
+        pools::{cpu_pools::CpuPool, gpu_pools::GpuPool},
         trait_config::ConfigImpl,
     },
+    event_scheduler_history::{
+        SchedulerExecutionOutcome, SchedulerExecutionPhase, SchedulerExecutionRecord,
+        SchedulerHistory, scheduler_data_dir,
+    },
     events_emitter::EventsEmitter,
-    mining::{cpu::manager::CpuManager, gpu::manager::GpuManager},
+    idle_source::{IdleSource, SystemIdleSource},
+    mining::{
+        cpu::manager::CpuManager,
+        gpu::manager::GpuManager,
+        pools::{
+            PoolManagerInterfaceTrait, cpu_pool_manager::CpuPoolManager,
+            gpu_pool_manager::GpuPoolManager,
+        },
+    },
+    setup::setup_manager::{SetupManager, SetupPhase},
+    systemtray_manager::{SystemTrayEvents, SystemTrayManager},
     tasks_tracker::TasksTrackers,
 };
 
 static ZERO_DURATION: std::time::Duration = std::time::Duration::from_secs(0);
+static IDLE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+const MAX_IDLE_MINUTES: i64 = 24 * 60;
+// Long enough to cover weekly and monthly cron patterns when checking for overlapping windows
+const CONFLICT_CHECK_HORIZON_DAYS: i64 = 35;
+const MAX_CONFLICT_CHECK_WINDOWS: usize = 2000;
+/// How far back to look for the window an entered event belongs to
+const WINDOW_END_LOOKBACK_DAYS: i64 = 8;
 
 static INSTANCE: LazyLock<EventScheduler> = LazyLock::new(EventScheduler::new);
 static EVENT_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -133,6 +169,11 @@ enum SchedulerMessage {
     TriggerExitCallback {
         event_id: String,
     },
+    SetMissedRunPolicy {
+        event_id: String,
+        missed_run_policy: SchedulerMissedRunPolicy,
+        response: tokio::sync::oneshot::Sender<Result<(), SchedulerError>>,
+    },
     ListEvents {
         response: tokio::sync::oneshot::Sender<Vec<ScheduledEventInfo>>,
     },
@@ -157,6 +198,10 @@ pub enum SchedulerError {
     InternalError(String),
     /// The timing format provided is invalid
     InvalidTimingFormat(String),
+    /// The event type payload is invalid
+    InvalidEventPayload(String),
+    /// The event overlaps an existing event with a conflicting action
+    EventConflict(String),
 }
 
 impl Display for SchedulerError {
@@ -169,6 +214,8 @@ impl Display for SchedulerError {
             Self::SchedulerNotRunning => write!(f, "Scheduler is not running"),
             Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
             Self::InvalidTimingFormat(format) => write!(f, "Invalid timing format: {}", format),
+            Self::InvalidEventPayload(msg) => write!(f, "Invalid event payload: {}", msg),
+            Self::EventConflict(msg) => write!(f, "Event conflict: {}", msg),
         }
     }
 }
@@ -181,6 +228,8 @@ impl std::error::Error for SchedulerError {}
 pub struct CronSchedule {
     pub start_time: Cron,
     pub end_time: Cron,
+    /// Timezone the cron expressions are evaluated in, the local timezone when not set
+    pub timezone: Option<Tz>,
     pub description: String,
 }
 
@@ -193,10 +242,16 @@ impl<'de> Deserialize<'de> for CronSchedule {
         struct CronScheduleHelper {
             start_time: String,
             end_time: String,
+            #[serde(default)]
+            timezone: Option<String>,
         }
 
         let helper = CronScheduleHelper::deserialize(deserializer)?;
-        CronSchedule::new(&helper.start_time, &helper.end_time).map_err(serde::de::Error::custom)
+        let timezone =
+            parse_timezone(helper.timezone.as_deref()).map_err(serde::de::Error::custom)?;
+        CronSchedule::new(&helper.start_time, &helper.end_time)
+            .map(|schedule| schedule.with_timezone(timezone))
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -209,11 +264,13 @@ impl Serialize for CronSchedule {
         struct CronScheduleHelper<'a> {
             start_time: &'a str,
             end_time: &'a str,
+            timezone: Option<&'a str>,
         }
 
         let helper = CronScheduleHelper {
             start_time: &self.start_time.to_string(),
             end_time: &self.end_time.to_string(),
+            timezone: self.timezone.as_ref().map(|tz| tz.name()),
         };
         helper.serialize(serializer)
     }
@@ -248,10 +305,61 @@ impl CronSchedule {
         Ok(Self {
             start_time: start_time_cron,
             end_time: end_time_cron,
+            timezone: None,
             description: format!("From {} to {}", start_time, end_time),
         })
     }
 
+    /// Evaluates the cron expressions in the given timezone instead of the local one.
+    pub fn with_timezone(mut self, timezone: Option<Tz>) -> Self {
+        if let Some(tz) = timezone {
+            self.description = format!("{} ({})", self.description, tz.name());
+        }
+        self.timezone = timezone;
+        self
+    }
+
+    /// Finds the next occurrence of a cron expression, evaluated in the schedule's timezone.
+    fn find_next_occurrence(&self, cron: &Cron, from: DateTime<Local>) -> Option<DateTime<Local>> {
+        match self.timezone {
+            Some(tz) => cron
+                .find_next_occurrence(&from.with_timezone(&tz), false)
+                .ok()
+                .map(|next| next.with_timezone(&Local)),
+            None => cron.find_next_occurrence(&from, false).ok(),
+        }
+    }
+
+    /// Lists the windows starting between `from` and `until`.
+    /// Each window ends at the first end time after its start, or at `until` if there is none.
+    ///
+    /// ### Parameters
+    /// * `from` - Time to search from
+    /// * `until` - Time to stop searching at
+    ///
+    /// ### Returns
+    /// * Start and end of each window, at most `MAX_CONFLICT_CHECK_WINDOWS`
+    pub fn windows_between(
+        &self,
+        from: DateTime<Local>,
+        until: DateTime<Local>,
+    ) -> Vec<(DateTime<Local>, DateTime<Local>)> {
+        let mut windows = Vec::new();
+        let mut cursor = from;
+        while windows.len() < MAX_CONFLICT_CHECK_WINDOWS {
+            let Some(start) = self.find_next_start_time(cursor) else {
+                break;
+            };
+            if start >= until {
+                break;
+            }
+            let end = self.find_next_end_time(start).unwrap_or(until);
+            windows.push((start, end));
+            cursor = start;
+        }
+        windows
+    }
+
     /// Finds when this schedule starts next.
     ///
     /// ### Parameters
@@ -261,7 +369,7 @@ impl CronSchedule {
     /// * `Some(DateTime<Local>)` - When it starts next
     /// * `None` - If it never starts again
     pub fn find_next_start_time(&self, from: DateTime<Local>) -> Option<DateTime<Local>> {
-        self.start_time.find_next_occurrence(&from, false).ok()
+        self.find_next_occurrence(&self.start_time, from)
     }
 
     /// How long to wait until this schedule starts next.
@@ -290,7 +398,7 @@ impl CronSchedule {
     /// * `Some(DateTime<Local>)` - The next end time occurrence
     /// * `None` - If no future occurrence can be found
     pub fn find_next_end_time(&self, from: DateTime<Local>) -> Option<DateTime<Local>> {
-        self.end_time.find_next_occurrence(&from, false).ok()
+        self.find_next_occurrence(&self.end_time, from)
     }
 
     /// Calculates how long to wait until the next end time from a given moment.
@@ -311,6 +419,22 @@ impl CronSchedule {
     }
 }
 
+/// Parses an IANA timezone name such as "Europe/Berlin".
+///
+/// ### Returns
+/// * `Ok(None)` - No timezone given, the local timezone is used
+/// * `Ok(Some(Tz))` - Timezone parsed successfully
+/// * `Err(SchedulerError::InvalidTimingFormat)` - Unknown timezone
+pub fn parse_timezone(timezone: Option<&str>) -> Result<Option<Tz>, SchedulerError> {
+    timezone
+        .map(|name| {
+            name.parse::<Tz>().map_err(|_| {
+                SchedulerError::InvalidTimingFormat(format!("Unknown timezone: {}", name))
+            })
+        })
+        .transpose()
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum TimeUnit {
     Hours,
@@ -339,6 +463,206 @@ pub struct InVariantPayload {
     pub time_unit: TimeUnit,
 }
 
+/// Raw cron pair, e.g. "0 22 * * 1-5" to "0 6 * * 2-6"
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CronVariantPayload {
+    pub start_cron: String,
+    pub end_cron: String,
+    /// IANA timezone name, the local timezone when not set
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+impl CronVariantPayload {
+    /// Converts the payload into a CronSchedule.
+    ///
+    /// ### Returns
+    /// * `Ok(CronSchedule)` - Schedule created successfully
+    /// * `Err(SchedulerError)` - Invalid cron expression or timezone
+    pub fn to_cron_schedule(&self) -> Result<CronSchedule, SchedulerError> {
+        let timezone = parse_timezone(self.timezone.as_deref())?;
+        Ok(CronSchedule::new(&self.start_cron, &self.end_cron)?.with_timezone(timezone))
+    }
+}
+
+/// Daily window on selected weekdays in 24-hour time.
+/// A window ending at or before its start time ends on the following day.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WeeklyVariantPayload {
+    /// Bit mask of weekdays, Monday is bit 0 and Sunday is bit 6
+    pub weekdays: u8,
+    pub start_hour: u32,
+    pub start_minute: u32,
+    pub end_hour: u32,
+    pub end_minute: u32,
+    /// IANA timezone name, the local timezone when not set
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+impl WeeklyVariantPayload {
+    pub const MONDAY: u8 = 1 << 0;
+    pub const TUESDAY: u8 = 1 << 1;
+    pub const WEDNESDAY: u8 = 1 << 2;
+    pub const THURSDAY: u8 = 1 << 3;
+    pub const FRIDAY: u8 = 1 << 4;
+    pub const SATURDAY: u8 = 1 << 5;
+    pub const SUNDAY: u8 = 1 << 6;
+    pub const WEEKDAYS: u8 =
+        Self::MONDAY | Self::TUESDAY | Self::WEDNESDAY | Self::THURSDAY | Self::FRIDAY;
+    pub const WEEKEND: u8 = Self::SATURDAY | Self::SUNDAY;
+    const ALL_DAYS: u8 = Self::WEEKDAYS | Self::WEEKEND;
+
+    /// Cron day-of-week list for the mask, shifted by `offset_days`.
+    /// Cron counts from Sunday = 0 while the mask starts at Monday.
+    fn cron_days(&self, offset_days: u32) -> String {
+        (0..7u32)
+            .filter(|bit| self.weekdays & (1 << bit) != 0)
+            .map(|bit| ((bit + 1 + offset_days) % 7).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Converts the payload into a CronSchedule.
+    ///
+    /// ### Returns
+    /// * `Ok(CronSchedule)` - Schedule created successfully
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - Empty mask, time out of range or unknown timezone
+    pub fn to_cron_schedule(&self) -> Result<CronSchedule, SchedulerError> {
+        if self.weekdays == 0 || self.weekdays & !Self::ALL_DAYS != 0 {
+            return Err(SchedulerError::InvalidTimingFormat(
+                "Weekday mask must select at least one day and use only the lowest 7 bits"
+                    .to_string(),
+            ));
+        }
+        if self.start_hour > 23 || self.end_hour > 23 {
+            return Err(SchedulerError::InvalidTimingFormat(
+                "Hour must be between 0 and 23".to_string(),
+            ));
+        }
+        if self.start_minute > 59 || self.end_minute > 59 {
+            return Err(SchedulerError::InvalidTimingFormat(
+                "Minute must be between 0 and 59".to_string(),
+            ));
+        }
+        let timezone = parse_timezone(self.timezone.as_deref())?;
+
+        let ends_next_day =
+            (self.end_hour, self.end_minute) <= (self.start_hour, self.start_minute);
+        let start_cron = format!(
+            "{} {} * * {}",
+            self.start_minute,
+            self.start_hour,
+            self.cron_days(0)
+        );
+        let end_cron = format!(
+            "{} {} * * {}",
+            self.end_minute,
+            self.end_hour,
+            self.cron_days(u32::from(ends_next_day))
+        );
+        Ok(CronSchedule::new(&start_cron, &end_cron)?.with_timezone(timezone))
+    }
+}
+
+/// One-off window between two RFC 3339 timestamps, e.g. "2026-12-24T18:00:00+01:00"
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DateRangeVariantPayload {
+    pub start: String,
+    pub end: String,
+}
+
+impl DateRangeVariantPayload {
+    /// Parses the start and end of the range.
+    ///
+    /// ### Returns
+    /// * `Ok((start, end))` - Range parsed successfully
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - Invalid timestamp or end not after start
+    pub fn to_range(&self) -> Result<(DateTime<Local>, DateTime<Local>), SchedulerError> {
+        let parse = |value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|date| date.with_timezone(&Local))
+                .map_err(|e| {
+                    SchedulerError::InvalidTimingFormat(format!(
+                        "Invalid RFC 3339 date {:?}: {}",
+                        value, e
+                    ))
+                })
+        };
+        let start = parse(&self.start)?;
+        let end = parse(&self.end)?;
+        if end <= start {
+            return Err(SchedulerError::InvalidTimingFormat(
+                "Date range must end after it starts".to_string(),
+            ));
+        }
+        Ok((start, end))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IdleVariantPayload {
+    pub idle_minutes: i64,
+}
+
+impl IdleVariantPayload {
+    /// Converts the payload into the idle threshold.
+    ///
+    /// ### Returns
+    /// * `Ok(Duration)` - Threshold created successfully
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - If value is out of range
+    pub fn to_threshold(&self) -> Result<std::time::Duration, SchedulerError> {
+        if self.idle_minutes <= 0 || self.idle_minutes > MAX_IDLE_MINUTES {
+            return Err(SchedulerError::InvalidTimingFormat(format!(
+                "Idle minutes value must be between 1 and {}",
+                MAX_IDLE_MINUTES
+            )));
+        }
+        Duration::minutes(self.idle_minutes)
+            .to_std()
+            .map_err(|e| SchedulerError::InvalidTimingFormat(e.to_string()))
+    }
+}
+
+/// Change in the user's presence reported by `IdleTracker`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleTransition {
+    /// Idle time reached the threshold
+    BecameIdle,
+    /// User activity after being idle
+    BecameActive,
+}
+
+/// Tracks whether the idle threshold has been crossed between polls of the idle source.
+#[derive(Debug, Clone)]
+pub struct IdleTracker {
+    threshold: std::time::Duration,
+    is_idle: bool,
+}
+
+impl IdleTracker {
+    pub fn new(threshold: std::time::Duration) -> Self {
+        Self {
+            threshold,
+            is_idle: false,
+        }
+    }
+
+    /// Feeds the latest idle time, returning a transition when the idle state changes.
+    pub fn update(&mut self, idle_time: std::time::Duration) -> Option<IdleTransition> {
+        let is_idle = idle_time >= self.threshold;
+        if is_idle == self.is_idle {
+            return None;
+        }
+        self.is_idle = is_idle;
+        Some(if is_idle {
+            IdleTransition::BecameIdle
+        } else {
+            IdleTransition::BecameActive
+        })
+    }
+}
+
 impl InVariantPayload {
     /// Converts the payload into a Duration.
     ///
@@ -381,6 +705,15 @@ pub enum SchedulerEventTiming {
     /// Run during recurring time windows (e.g., Between("0 22 * * *", "0 6 * * *") for 10PM to 6AM daily)
     /// The event keeps repeating according to the schedule.
     Between(BetweenTimeVariantPayload),
+    /// Run during recurring windows given as a raw cron pair, optionally in a specific timezone.
+    Cron(CronVariantPayload),
+    /// Run during a daily window on selected weekdays, optionally in a specific timezone.
+    Weekly(WeeklyVariantPayload),
+    /// Run once during a date range. The event gets removed after the range ends.
+    DateRange(DateRangeVariantPayload),
+    /// Run while the system has been idle for the given number of minutes.
+    /// The exit callback fires on user activity and the event keeps waiting for the next idle period.
+    Idle(IdleVariantPayload),
 }
 
 impl SchedulerEventTiming {
@@ -485,6 +818,219 @@ impl SchedulerEventTiming {
         Ok(SchedulerEventTiming::Between(payload))
     }
 
+    /// Create a recurring timing from a raw cron pair.
+    ///
+    /// ### Parameters
+    /// * `start_cron` - When the window starts (e.g., "0 22 * * 1-5")
+    /// * `end_cron` - When the window ends (e.g., "0 6 * * 2-6")
+    /// * `timezone` - IANA timezone name, local timezone when `None`
+    ///
+    /// ### Returns
+    /// * `Ok(SchedulerEventTiming::Cron)` - Timing created
+    /// * `Err(SchedulerError)` - Invalid cron expression or timezone
+    pub fn parse_cron_variant(
+        start_cron: String,
+        end_cron: String,
+        timezone: Option<String>,
+    ) -> Result<Self, SchedulerError> {
+        let payload = CronVariantPayload {
+            start_cron,
+            end_cron,
+            timezone,
+        };
+        payload.to_cron_schedule()?;
+        Ok(SchedulerEventTiming::Cron(payload))
+    }
+
+    /// Create a recurring timing on selected weekdays.
+    ///
+    /// ### Parameters
+    /// * `weekdays` - Weekday mask, see `WeeklyVariantPayload::WEEKDAYS` and friends
+    /// * `start_hour`, `start_minute` - When to start (24-hour time)
+    /// * `end_hour`, `end_minute` - When to end, the following day if not after the start
+    /// * `timezone` - IANA timezone name, local timezone when `None`
+    ///
+    /// ### Returns
+    /// * `Ok(SchedulerEventTiming::Weekly)` - Timing created
+    /// * `Err(SchedulerError)` - Invalid mask, time or timezone
+    ///
+    /// ### Example
+    /// ```
+    /// // Run on weekends from 8 AM to 8 PM Berlin time
+    /// let timing = SchedulerEventTiming::parse_weekly_variant(
+    ///     WeeklyVariantPayload::WEEKEND, 8, 0, 20, 0, Some("Europe/Berlin".to_string())
+    /// )?;
+    /// ```
+    pub fn parse_weekly_variant(
+        weekdays: u8,
+        start_hour: u32,
+        start_minute: u32,
+        end_hour: u32,
+        end_minute: u32,
+        timezone: Option<String>,
+    ) -> Result<Self, SchedulerError> {
+        let payload = WeeklyVariantPayload {
+            weekdays,
+            start_hour,
+            start_minute,
+            end_hour,
+            end_minute,
+            timezone,
+        };
+        payload.to_cron_schedule()?;
+        Ok(SchedulerEventTiming::Weekly(payload))
+    }
+
+    /// Create a one-off date range timing.
+    ///
+    /// ### Parameters
+    /// * `start` - RFC 3339 start of the range
+    /// * `end` - RFC 3339 end of the range, must be after the start
+    ///
+    /// ### Returns
+    /// * `Ok(SchedulerEventTiming::DateRange)` - Timing created
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - Invalid dates or range already ended
+    pub fn parse_date_range_variant(start: String, end: String) -> Result<Self, SchedulerError> {
+        let payload = DateRangeVariantPayload { start, end };
+        let (_, end) = payload.to_range()?;
+        if end <= Local::now() {
+            return Err(SchedulerError::InvalidTimingFormat(
+                "Date range has already ended".to_string(),
+            ));
+        }
+        Ok(SchedulerEventTiming::DateRange(payload))
+    }
+
+    /// Recurring schedule of window based timings.
+    ///
+    /// ### Returns
+    /// * `Ok(Some(CronSchedule))` - Between, Cron and Weekly timings
+    /// * `Ok(None)` - In, DateRange and Idle timings
+    /// * `Err(SchedulerError)` - If the schedule is invalid
+    pub fn to_cron_schedule(&self) -> Result<Option<CronSchedule>, SchedulerError> {
+        match self {
+            SchedulerEventTiming::Between(payload) => payload.to_cron_schedule().map(Some),
+            SchedulerEventTiming::Cron(payload) => payload.to_cron_schedule().map(Some),
+            SchedulerEventTiming::Weekly(payload) => payload.to_cron_schedule().map(Some),
+            SchedulerEventTiming::In(_)
+            | SchedulerEventTiming::DateRange(_)
+            | SchedulerEventTiming::Idle(_) => Ok(None),
+        }
+    }
+
+    /// Windows in which the timing is active, used to detect overlapping events.
+    /// In and Idle timings have no predictable window and return an empty list.
+    ///
+    /// ### Parameters
+    /// * `from` - Time to search from
+    /// * `until` - Time to stop searching at
+    pub fn windows_between(
+        &self,
+        from: DateTime<Local>,
+        until: DateTime<Local>,
+    ) -> Result<Vec<(DateTime<Local>, DateTime<Local>)>, SchedulerError> {
+        if let SchedulerEventTiming::DateRange(payload) = self {
+            let (start, end) = payload.to_range()?;
+            return Ok(if start < until && end > from {
+                vec![(start, end)]
+            } else {
+                vec![]
+            });
+        }
+        Ok(self
+            .to_cron_schedule()?
+            .map(|schedule| schedule.windows_between(from, until))
+            .unwrap_or_default())
+    }
+
+    /// Checks if the last window that started at or before `at` has already ended.
+    /// Windows end exclusively, a window ending at `at` is over.
+    ///
+    /// ### Returns
+    /// * `true` - The window ended at or before `at`
+    /// * `false` - The window is still active, or the timing has no predictable windows
+    pub fn has_window_ended_at(&self, at: DateTime<Local>) -> bool {
+        // Include a window starting exactly at `at`, it's the latest one and still active
+        let Ok(windows) = self.windows_between(
+            at - Duration::days(WINDOW_END_LOOKBACK_DAYS),
+            at + Duration::seconds(1),
+        ) else {
+            return false;
+        };
+        // A truncated list doesn't contain the latest windows
+        if windows.len() >= MAX_CONFLICT_CHECK_WINDOWS {
+            return false;
+        }
+        windows
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= at)
+            .is_some_and(|(_, end)| *end <= at)
+    }
+
+    /// Windows that started while the app was closed.
+    ///
+    /// ### Parameters
+    /// * `last_active_at` - Last time the app was known to run, nothing counts as missed when unknown
+    /// * `now` - Time the app started
+    ///
+    /// ### Returns
+    /// * `Ok(MissedWindows)` - Missed window starts, at most `MAX_CONFLICT_CHECK_WINDOWS`
+    /// * `Err(SchedulerError)` - If the timing is invalid
+    pub fn missed_windows(
+        &self,
+        last_active_at: Option<DateTime<Local>>,
+        now: DateTime<Local>,
+    ) -> Result<MissedWindows, SchedulerError> {
+        let starts = match (self, last_active_at) {
+            (_, None) => vec![],
+            // A date range is only missed once it passed completely, otherwise its task enters it
+            (SchedulerEventTiming::DateRange(payload), Some(last_active_at)) => {
+                let (start, end) = payload.to_range()?;
+                if start > last_active_at && end <= now {
+                    vec![start]
+                } else {
+                    vec![]
+                }
+            }
+            (_, Some(last_active_at)) => self
+                .windows_between(last_active_at, now)?
+                .into_iter()
+                .map(|(start, _)| start)
+                .collect(),
+        };
+
+        let is_window_open = self.to_cron_schedule()?.is_some_and(|schedule| {
+            match (
+                schedule.find_next_start_time(now),
+                schedule.find_next_end_time(now),
+            ) {
+                (Some(next_start), Some(next_end)) => next_end < next_start,
+                (None, Some(_)) => true,
+                (_, None) => false,
+            }
+        });
+
+        Ok(MissedWindows {
+            starts,
+            is_window_open,
+        })
+    }
+
+    /// Create an idle timing.
+    ///
+    /// ### Parameters
+    /// * `idle_minutes` - Minutes without user input before triggering (1-1440)
+    ///
+    /// ### Returns
+    /// * `Ok(SchedulerEventTiming::Idle)` - Timing created
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - Value out of range
+    pub fn parse_idle_variant(idle_minutes: i64) -> Result<Self, SchedulerError> {
+        let payload = IdleVariantPayload { idle_minutes };
+        payload.to_threshold()?;
+        Ok(SchedulerEventTiming::Idle(payload))
+    }
+
     /// Converts 12-hour format time to a cron expression.
     fn parse_cron(hour: i64, minute: i64, period: TimePeriod) -> Result<String, SchedulerError> {
         if !(1..=12).contains(&hour) {
@@ -522,10 +1068,10 @@ impl SchedulerEventTiming {
     /// Checks if this timing represents a recurring event.
     ///
     /// ### Returns
-    /// * `true` - Between timing (survives app restarts)
+    /// * `true` - Between, Cron, Weekly, DateRange and Idle timing (survives app restarts)
     /// * `false` - In timing (gets removed after running)
     pub fn is_persistent(&self) -> bool {
-        matches!(self, SchedulerEventTiming::Between { .. })
+        !matches!(self, SchedulerEventTiming::In { .. })
     }
 }
 
@@ -537,6 +1083,8 @@ pub struct ScheduledEventInfo {
     pub event_type: SchedulerEventType,
     pub timing: SchedulerEventTiming,
     pub state: SchedulerEventState,
+    #[serde(default)]
+    pub missed_run_policy: SchedulerMissedRunPolicy,
 }
 
 /// Defines the types of actions that can be scheduled.
@@ -553,6 +1101,23 @@ pub enum SchedulerEventType {
         /// The specific mining mode configuration to use
         mining_mode: String,
     },
+    /// Stop CPU and GPU mining.
+    /// Miners that were running are started again on exit.
+    StopMining,
+    /// Switch the CPU and/or GPU pool, given by pool key (e.g. "KryptexPoolRANDOMX").
+    /// The previous pools are restored on exit.
+    SwitchPool {
+        cpu_pool: Option<String>,
+        gpu_pool: Option<String>,
+    },
+    /// Enable or disable CPU and GPU mining independently (e.g. GPU only at night).
+    /// The previous settings and miner states are restored on exit.
+    SetMiningDevices {
+        cpu_enabled: bool,
+        gpu_enabled: bool,
+    },
+    /// Restart the base node. There is nothing to restore on exit.
+    RestartNode,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -563,6 +1128,49 @@ pub enum SchedulerEventState {
     Completed,
 }
 
+/// What to do at startup about windows of an event that started while the app was closed.
+/// Missed windows that are not caught up are recorded in the scheduler history.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SchedulerMissedRunPolicy {
+    /// Wait for the next window
+    #[default]
+    Skip,
+    /// Enter the event when the app starts inside one of its recurring windows.
+    /// Events without an exit action (ResumeMining, RestartNode) also run once for windows that already ended.
+    CatchUp,
+}
+
+impl SchedulerMissedRunPolicy {
+    /// Checks if the enter callback should run at startup.
+    ///
+    /// ### Parameters
+    /// * `event_type` - Action of the event
+    /// * `missed_windows` - Windows of the event missed while the app was closed
+    pub fn should_catch_up(
+        &self,
+        event_type: &SchedulerEventType,
+        missed_windows: &MissedWindows,
+    ) -> bool {
+        match self {
+            SchedulerMissedRunPolicy::Skip => false,
+            SchedulerMissedRunPolicy::CatchUp => {
+                missed_windows.is_window_open
+                    || (!missed_windows.starts.is_empty() && !event_type.has_exit_action())
+            }
+        }
+    }
+}
+
+/// Windows of an event that started while the app was closed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MissedWindows {
+    /// Starts of the windows between the last time the app was running and now, oldest first
+    pub starts: Vec<DateTime<Local>>,
+    /// The app starts inside a recurring window, whose scheduling task would only run the exit callback.
+    /// DateRange tasks enter by themselves when started inside the range.
+    pub is_window_open: bool,
+}
+
 impl SchedulerEventType {
     /// Checks if only one of this event type can exist at once.
     ///
@@ -572,6 +1180,162 @@ impl SchedulerEventType {
     pub fn is_unique(&self) -> bool {
         matches!(self, SchedulerEventType::ResumeMining)
     }
+
+    /// Checks if the exit callback restores the state from before the enter callback.
+    /// Mine and ResumeMining keep the legacy behaviour of stopping mining on exit, unless used with Idle timing.
+    ///
+    /// ### Returns
+    /// * `true` - StopMining, SwitchPool, SetMiningDevices and RestartNode events
+    /// * `false` - ResumeMining and Mine events
+    pub fn restores_state_on_exit(&self) -> bool {
+        !matches!(
+            self,
+            SchedulerEventType::ResumeMining | SchedulerEventType::Mine { .. }
+        )
+    }
+
+    /// Checks if the exit callback does anything, either the legacy stop of Mine events
+    /// or restoring the state captured on enter.
+    ///
+    /// ### Returns
+    /// * `true` - Mine, StopMining, SwitchPool and SetMiningDevices events
+    /// * `false` - ResumeMining and RestartNode events, whose effect outlasts the window
+    pub fn has_exit_action(&self) -> bool {
+        !matches!(
+            self,
+            SchedulerEventType::ResumeMining | SchedulerEventType::RestartNode
+        )
+    }
+
+    /// Validates the event payload before it is scheduled.
+    ///
+    /// ### Returns
+    /// * `Ok(())` - Payload is valid
+    /// * `Err(SchedulerError::InvalidEventPayload)` - Unknown pool or nothing to switch
+    pub fn validate(&self) -> Result<(), SchedulerError> {
+        if let SchedulerEventType::SwitchPool { cpu_pool, gpu_pool } = self {
+            if cpu_pool.is_none() && gpu_pool.is_none() {
+                return Err(SchedulerError::InvalidEventPayload(
+                    "SwitchPool requires a CPU or GPU pool".to_string(),
+                ));
+            }
+            if let Some(cpu_pool) = cpu_pool {
+                CpuPool::from_string(cpu_pool)
+                    .map_err(|e| SchedulerError::InvalidEventPayload(format!("{cpu_pool}: {e}")))?;
+            }
+            if let Some(gpu_pool) = gpu_pool {
+                GpuPool::from_string(gpu_pool)
+                    .map_err(|e| SchedulerError::InvalidEventPayload(format!("{gpu_pool}: {e}")))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks if two events cannot be active at the same time.
+    /// Node restarts and pool switches for different devices are independent of other actions,
+    /// everything else changes the mining state and conflicts.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SchedulerEventType::RestartNode, _) | (_, SchedulerEventType::RestartNode) => false,
+            (
+                SchedulerEventType::SwitchPool {
+                    cpu_pool: cpu_pool_a,
+                    gpu_pool: gpu_pool_a,
+                },
+                SchedulerEventType::SwitchPool {
+                    cpu_pool: cpu_pool_b,
+                    gpu_pool: gpu_pool_b,
+                },
+            ) => {
+                (cpu_pool_a.is_some() && cpu_pool_b.is_some())
+                    || (gpu_pool_a.is_some() && gpu_pool_b.is_some())
+            }
+            (SchedulerEventType::SwitchPool { .. }, _)
+            | (_, SchedulerEventType::SwitchPool { .. }) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Rejects Idle timing when the idle source can't read the idle time on this platform,
+/// such an event would never trigger.
+pub fn check_idle_detection(
+    timing: &SchedulerEventTiming,
+    idle_source: &dyn IdleSource,
+) -> Result<(), SchedulerError> {
+    if matches!(timing, SchedulerEventTiming::Idle(_)) && !idle_source.is_supported() {
+        return Err(SchedulerError::InvalidTimingFormat(
+            "Idle detection is not supported on this platform".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Finds the start of the first overlap between two lists of windows sorted by start time.
+fn find_first_overlap(
+    windows_a: &[(DateTime<Local>, DateTime<Local>)],
+    windows_b: &[(DateTime<Local>, DateTime<Local>)],
+) -> Option<DateTime<Local>> {
+    let (mut index_a, mut index_b) = (0, 0);
+    while let (Some((start_a, end_a)), Some((start_b, end_b))) =
+        (windows_a.get(index_a), windows_b.get(index_b))
+    {
+        if start_a < end_b && start_b < end_a {
+            return Some(*start_a.max(start_b));
+        }
+        if end_a <= end_b {
+            index_a += 1;
+        } else {
+            index_b += 1;
+        }
+    }
+    None
+}
+
+/// Checks a new event against existing events for overlapping windows with conflicting actions.
+///
+/// ### Parameters
+/// * `event_type` - Action of the new event
+/// * `timing` - Timing of the new event
+/// * `existing_events` - ID, action and timing of the events to check against
+/// * `from` - Time to start checking from, windows are compared over `CONFLICT_CHECK_HORIZON_DAYS`
+///
+/// ### Returns
+/// * `Ok(())` - No conflicts
+/// * `Err(SchedulerError::EventConflict)` - Describes the first conflicting event
+/// * `Err(SchedulerError)` - If the new timing is invalid
+pub fn check_event_conflicts<'a>(
+    event_type: &SchedulerEventType,
+    timing: &SchedulerEventTiming,
+    existing_events: impl IntoIterator<
+        Item = (&'a str, &'a SchedulerEventType, &'a SchedulerEventTiming),
+    >,
+    from: DateTime<Local>,
+) -> Result<(), SchedulerError> {
+    let until = from + Duration::days(CONFLICT_CHECK_HORIZON_DAYS);
+    let windows = timing.windows_between(from, until)?;
+    if windows.is_empty() {
+        return Ok(());
+    }
+
+    for (existing_id, existing_type, existing_timing) in existing_events {
+        if !event_type.conflicts_with(existing_type) {
+            continue;
+        }
+        let Ok(existing_windows) = existing_timing.windows_between(from, until) else {
+            continue;
+        };
+        if let Some(overlap_start) = find_first_overlap(&windows, &existing_windows) {
+            return Err(SchedulerError::EventConflict(format!(
+                "{} overlaps event {:?} ({}) starting {}",
+                event_type,
+                existing_id,
+                existing_type,
+                overlap_start.format("%Y-%m-%d %H:%M %:z")
+            )));
+        }
+    }
+    Ok(())
 }
 
 impl Display for SchedulerEventType {
@@ -581,19 +1345,161 @@ impl Display for SchedulerEventType {
             SchedulerEventType::Mine { mining_mode } => {
                 write!(f, "Mine ({})", mining_mode)
             }
+            SchedulerEventType::StopMining => write!(f, "Stop Mining"),
+            SchedulerEventType::SwitchPool { cpu_pool, gpu_pool } => {
+                write!(
+                    f,
+                    "Switch Pool (CPU: {}, GPU: {})",
+                    cpu_pool.as_deref().unwrap_or("unchanged"),
+                    gpu_pool.as_deref().unwrap_or("unchanged")
+                )
+            }
+            SchedulerEventType::SetMiningDevices {
+                cpu_enabled,
+                gpu_enabled,
+            } => {
+                write!(
+                    f,
+                    "Set Mining Devices (CPU: {}, GPU: {})",
+                    cpu_enabled, gpu_enabled
+                )
+            }
+            SchedulerEventType::RestartNode => write!(f, "Restart Node"),
         }
     }
 }
 
-/// Internal event data with runtime info.
-/// Contains everything needed to manage an event while it's running.
-#[derive(Debug)]
-struct ScheduledEvent {
-    id: String,
+/// Mining state captured by an enter callback so the exit callback can restore it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SchedulerRevertState {
+    mining_mode: String,
+    was_cpu_mining: bool,
+    was_gpu_mining: bool,
+    cpu_mining_enabled: bool,
+    gpu_mining_enabled: bool,
+    cpu_pool: CpuPool,
+    gpu_pool: GpuPool,
+}
+
+impl SchedulerRevertState {
+    async fn capture() -> Self {
+        let mining_config = ConfigMining::content().await;
+        let pools_config = ConfigPools::content().await;
+        Self {
+            mining_mode: mining_config.selected_mining_mode().clone(),
+            was_cpu_mining: CpuManager::read().await.is_running(),
+            was_gpu_mining: GpuManager::read().await.is_running(),
+            cpu_mining_enabled: *mining_config.cpu_mining_enabled(),
+            gpu_mining_enabled: *mining_config.gpu_mining_enabled(),
+            cpu_pool: pools_config.current_cpu_pool_type().clone(),
+            gpu_pool: pools_config.current_gpu_pool_type().clone(),
+        }
+    }
+}
+
+const REVERT_STATES_FILE_NAME: &str = "scheduler_revert_states.json";
+
+/// Revert state of an entered event, persisted so the event is still undone
+/// when the app is closed before its exit callback runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PersistedRevertState {
     event_type: SchedulerEventType,
-    timing: SchedulerEventTiming,
-    state: SchedulerEventState,
-    task_handle: Option<tokio::task::JoinHandle<()>>,
+    revert_state: SchedulerRevertState,
+}
+
+fn read_revert_states(path: &Path) -> HashMap<String, PersistedRevertState> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        warn!(target: LOG_TARGET_APP_LOGIC, "Ignoring malformed scheduler revert states: {e}");
+        HashMap::new()
+    })
+}
+
+fn write_revert_states(
+    path: &Path,
+    states: &HashMap<String, PersistedRevertState>,
+) -> Result<(), anyhow::Error> {
+    if states.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string(states)?)?;
+    Ok(())
+}
+
+/// Internal event data with runtime info.
+/// Contains everything needed to manage an event while it's running.
+#[derive(Debug)]
+struct ScheduledEvent {
+    id: String,
+    event_type: SchedulerEventType,
+    timing: SchedulerEventTiming,
+    state: SchedulerEventState,
+    task_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Set while an idle or state restoring event is entered
+    revert_state: Option<SchedulerRevertState>,
+    missed_run_policy: SchedulerMissedRunPolicy,
+    /// Set between the enter and the exit callback
+    is_entered: bool,
+    /// Exit already ran because another event entered at the end of the window,
+    /// the exit trigger of the window is skipped
+    exit_ran_early: bool,
+}
+
+/// Errors of the steps of an event action, a failing step doesn't stop the following ones.
+struct ActionErrors<'a> {
+    event_id: &'a str,
+    errors: Vec<String>,
+}
+
+impl<'a> ActionErrors<'a> {
+    fn new(event_id: &'a str) -> Self {
+        Self {
+            event_id,
+            errors: Vec::new(),
+        }
+    }
+
+    fn check(&mut self, result: Result<(), anyhow::Error>) {
+        if let Err(e) = result {
+            error!(target: LOG_TARGET_APP_LOGIC, "Event {:?}: {}", self.event_id, e);
+            self.errors.push(e.to_string());
+        }
+    }
+
+    /// Records the run in the scheduler history.
+    async fn record(
+        self,
+        phase: SchedulerExecutionPhase,
+        action: &SchedulerEventType,
+        caught_up_window: Option<DateTime<Local>>,
+    ) {
+        let (outcome, error) = if self.errors.is_empty() {
+            (SchedulerExecutionOutcome::Succeeded, None)
+        } else {
+            (
+                SchedulerExecutionOutcome::Failed,
+                Some(self.errors.join("; ")),
+            )
+        };
+        SchedulerHistory::record(SchedulerExecutionRecord {
+            event_id: self.event_id.to_string(),
+            fired_at: SystemTime::now(),
+            scheduled_at: caught_up_window.map(SystemTime::from),
+            phase,
+            action: action.clone(),
+            outcome,
+            error,
+        })
+        .await;
+    }
 }
 
 /// The main scheduler that handles all your scheduled events.
@@ -602,6 +1508,9 @@ pub struct EventScheduler {
     message_sender: mpsc::UnboundedSender<SchedulerMessage>,
     message_receiver: RwLock<mpsc::UnboundedReceiver<SchedulerMessage>>,
     is_running: AtomicBool,
+    idle_source: RwLock<Arc<dyn IdleSource>>,
+    /// Notified when events are added, removed or change state
+    events_changed: watch::Sender<()>,
 }
 
 impl EventScheduler {
@@ -614,15 +1523,31 @@ impl EventScheduler {
             message_sender,
             message_receiver: RwLock::new(message_receiver),
             is_running: AtomicBool::new(false),
+            idle_source: RwLock::new(Arc::new(SystemIdleSource)),
+            events_changed: watch::channel(()).0,
         }
     }
 
+    /// Subscribes to changes of the scheduled events list.
+    pub fn subscribe_to_changes(&self) -> watch::Receiver<()> {
+        self.events_changed.subscribe()
+    }
+
+    fn notify_events_changed() {
+        INSTANCE.events_changed.send_replace(());
+    }
+
     /// Gets the global scheduler instance.
     /// Returns the global EventScheduler singleton.
     pub fn instance() -> &'static Self {
         &INSTANCE
     }
 
+    /// Replaces the source polled by Idle timing events, e.g. with a stub in tests.
+    pub async fn set_idle_source(&self, idle_source: Arc<dyn IdleSource>) {
+        *self.idle_source.write().await = idle_source;
+    }
+
     /// Schedules a new event.
     ///
     /// This is the main way to add new scheduled events. The scheduler will
@@ -714,6 +1639,27 @@ impl EventScheduler {
             .map_err(|_| SchedulerError::InternalError("Response channel closed".to_string()))?
     }
 
+    /// Sets what to do about windows of an event missed while the app was closed.
+    pub async fn set_missed_run_policy(
+        &self,
+        event_id: String,
+        missed_run_policy: SchedulerMissedRunPolicy,
+    ) -> Result<(), SchedulerError> {
+        let (response_tx, response_rx) = tokio::sync::oneshot::channel();
+
+        self.message_sender
+            .send(SchedulerMessage::SetMissedRunPolicy {
+                event_id,
+                missed_run_policy,
+                response: response_tx,
+            })
+            .map_err(|_| SchedulerError::SchedulerNotRunning)?;
+
+        response_rx
+            .await
+            .map_err(|_| SchedulerError::InternalError("Response channel closed".to_string()))?
+    }
+
     /// Lists all events currently in the scheduler (both persistent and one-time).
     pub async fn list_events(&self) -> Result<Vec<ScheduledEventInfo>, SchedulerError> {
         let (response_tx, response_rx) = tokio::sync::oneshot::channel();
@@ -749,6 +1695,7 @@ impl EventScheduler {
                             event_type: event.event_type.clone(),
                             timing: event.timing.clone(),
                             state: event.state.clone(),
+                            missed_run_policy: event.missed_run_policy,
                         },
                     )
                 }),
@@ -759,6 +1706,68 @@ impl EventScheduler {
             .unwrap_or_else(|e| {
                 error!(target: LOG_TARGET_APP_LOGIC, "Failed to save persistent events to config: {}", e);
             });
+        ConfigCore::update_field(
+            ConfigCoreContent::set_scheduler_last_active_at,
+            Some(SystemTime::now()),
+        )
+        .await
+        .unwrap_or_else(|e| {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to save scheduler activity time to config: {}", e);
+        });
+    }
+
+    /// Last time the scheduler was known to run.
+    /// Saved to config on every change and on shutdown, the newest history record covers crashes.
+    async fn last_active_at() -> Option<DateTime<Local>> {
+        let saved_at = *ConfigCore::content().await.scheduler_last_active_at();
+        saved_at
+            .max(SchedulerHistory::last_recorded_at().await)
+            .map(DateTime::<Local>::from)
+    }
+
+    fn revert_states_path() -> PathBuf {
+        scheduler_data_dir().join(REVERT_STATES_FILE_NAME)
+    }
+
+    /// Persists the revert states of the currently entered events.
+    fn save_revert_states(events: &HashMap<String, ScheduledEvent>) {
+        let states = events
+            .values()
+            .filter_map(|event| {
+                event.revert_state.as_ref().map(|revert_state| {
+                    (
+                        event.id.clone(),
+                        PersistedRevertState {
+                            event_type: event.event_type.clone(),
+                            revert_state: revert_state.clone(),
+                        },
+                    )
+                })
+            })
+            .collect();
+        if let Err(e) = write_revert_states(&Self::revert_states_path(), &states) {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to persist scheduler revert states: {e}");
+        }
+    }
+
+    /// Undoes the events that were still entered when the app was last closed.
+    ///
+    /// Runs before the miners are started, so only the mining mode, devices and pools
+    /// are restored. Windows that are still active are entered again afterwards.
+    async fn restore_persisted_revert_states() {
+        let path = Self::revert_states_path();
+        for (event_id, persisted) in read_revert_states(&path) {
+            info!(target: LOG_TARGET_APP_LOGIC, "Restoring state of event {:?} interrupted by the last shutdown", event_id);
+            let mut errors = ActionErrors::new(&event_id);
+            Self::revert_event_config(&mut errors, &persisted.event_type, persisted.revert_state)
+                .await;
+            errors
+                .record(SchedulerExecutionPhase::Exit, &persisted.event_type, None)
+                .await;
+        }
+        if let Err(e) = write_revert_states(&path, &HashMap::new()) {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to clear scheduler revert states: {e}");
+        }
     }
 
     /// Loads persistent events from the application configuration.
@@ -781,6 +1790,10 @@ impl EventScheduler {
                         timing: info.timing,
                         state: SchedulerEventState::Active,
                         task_handle: None,
+                        revert_state: None,
+                        missed_run_policy: info.missed_run_policy,
+                        is_entered: false,
+                        exit_ran_early: false,
                     },
                 )
             }));
@@ -810,6 +1823,7 @@ impl EventScheduler {
         self.is_running
             .store(true, std::sync::atomic::Ordering::SeqCst);
 
+        let last_active_at = Self::last_active_at().await;
         let persistent_events_from_config = Self::load_persistent_events().await?;
 
         let task_tracker = TasksTrackers::current().common.get_task_tracker().await;
@@ -818,13 +1832,8 @@ impl EventScheduler {
         info!(target: LOG_TARGET_APP_LOGIC, "Starting Event Scheduler Listener");
 
         task_tracker.spawn(async move {
-            let mut internal_events: HashMap<String, ScheduledEvent> = HashMap::new();
-            for (id, event) in persistent_events_from_config {
-                Self::handle_add_event(&mut internal_events, event.event_type.clone(), id.clone(), event.timing.clone()).await.unwrap_or_else(|e| {
-                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to reschedule persistent event {:?}: {}", id, e);
-                    e.to_string()
-                });
-            }
+            let mut internal_events =
+                Self::restore_events(persistent_events_from_config, last_active_at).await;
 
             let message_receiver = &mut *INSTANCE.message_receiver.write().await;
 
@@ -842,56 +1851,11 @@ impl EventScheduler {
                         break;
                     },
                     message = message_receiver.recv() => {
-                        match message {
-                            Some(SchedulerMessage::AddEvent { event_type, timing, event_id,response }) => {
-                                let result = Self::handle_add_event(&mut internal_events, event_type, event_id, timing).await;
-                                let _unused = response.send(result);
-                            },
-                            Some(SchedulerMessage::RemoveEvent { event_id, response }) => {
-                                let result = Self::handle_remove_event(&mut internal_events, event_id);
-                                let should_persist = result.is_ok();
-                                let _unused = response.send(result);
-                                if should_persist {
-                                    Self::save_persistent_events_to_config(&internal_events).await;
-                                }
-                            },
-                            Some(SchedulerMessage::PauseEvent { event_id, response }) => {
-                                let result = Self::handle_pause_event(&mut internal_events, event_id);
-                                let _unused = response.send(result);
-                            },
-                            Some(SchedulerMessage::ResumeEvent { event_id, response }) => {
-                                let result = Self::handle_resume_event(&mut internal_events, event_id).await;
-                                let _unused = response.send(result);
-                            },
-                            Some(SchedulerMessage::TriggerEnterCallback { event_id }) => {
-                                Self::handle_enter_callback(&internal_events, event_id).await;
-
-                            },
-                            Some(SchedulerMessage::TriggerExitCallback { event_id }) => {
-                                Self::handle_exit_callback(&internal_events, event_id).await;
-
-                            }
-                            Some(SchedulerMessage::ListEvents { response }) => {
-                                let events: Vec<ScheduledEventInfo> = internal_events
-                                    .iter()
-                                    .map(|(id, event)| ScheduledEventInfo {
-                                        id: id.clone(),
-                                        event_type: event.event_type.clone(),
-                                        timing: event.timing.clone(),
-                                        state: event.state.clone(),
-                                    })
-                                    .collect();
-                                let _unused = response.send(events);
-                            },
-                            Some(SchedulerMessage::CleanupSchedule { event_id }) => {
-                                Self::handle_cleanup_schedule_events(&mut internal_events, event_id).await;
-                            }
-
-                            None => {
-                                warn!(target: LOG_TARGET_APP_LOGIC, "Message channel closed, stopping scheduler");
-                                break;
-                            }
-                        }
+                        let Some(message) = message else {
+                            warn!(target: LOG_TARGET_APP_LOGIC, "Message channel closed, stopping scheduler");
+                            break;
+                        };
+                        Self::handle_message(&mut internal_events, message).await;
                     }
                 }
             }
@@ -902,6 +1866,117 @@ impl EventScheduler {
         Ok(())
     }
 
+    /// Recreates the persisted events and applies their missed run policies
+    async fn restore_events(
+        persistent_events: HashMap<String, ScheduledEvent>,
+        last_active_at: Option<DateTime<Local>>,
+    ) -> HashMap<String, ScheduledEvent> {
+        let mut internal_events: HashMap<String, ScheduledEvent> = HashMap::new();
+        Self::restore_persisted_revert_states().await;
+        for (id, event) in persistent_events {
+            if let Err(e) = Self::insert_event(
+                &mut internal_events,
+                event.event_type.clone(),
+                id.clone(),
+                event.timing.clone(),
+                event.missed_run_policy,
+            )
+            .await
+            {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to reschedule persistent event {:?}: {}", id, e);
+            }
+        }
+        Self::handle_missed_runs(&mut internal_events, last_active_at).await;
+        internal_events
+    }
+
+    async fn handle_message(
+        internal_events: &mut HashMap<String, ScheduledEvent>,
+        message: SchedulerMessage,
+    ) {
+        match message {
+            SchedulerMessage::AddEvent {
+                event_type,
+                timing,
+                event_id,
+                response,
+            } => {
+                let result =
+                    Self::handle_add_event(internal_events, event_type, event_id, timing).await;
+                if result.is_ok() {
+                    Self::notify_events_changed();
+                }
+                let _unused = response.send(result);
+            }
+            SchedulerMessage::RemoveEvent { event_id, response } => {
+                let result = Self::handle_remove_event(internal_events, event_id);
+                let should_persist = result.is_ok();
+                let _unused = response.send(result);
+                if should_persist {
+                    Self::save_persistent_events_to_config(internal_events).await;
+                    Self::notify_events_changed();
+                }
+            }
+            SchedulerMessage::PauseEvent { event_id, response } => {
+                let result = Self::handle_pause_event(internal_events, event_id);
+                if result.is_ok() {
+                    Self::notify_events_changed();
+                }
+                let _unused = response.send(result);
+            }
+            SchedulerMessage::ResumeEvent { event_id, response } => {
+                let result = Self::handle_resume_event(internal_events, event_id).await;
+                if result.is_ok() {
+                    Self::notify_events_changed();
+                }
+                let _unused = response.send(result);
+            }
+            SchedulerMessage::TriggerEnterCallback { event_id } => {
+                Self::handle_enter_callback(internal_events, event_id, None).await;
+            }
+            SchedulerMessage::TriggerExitCallback { event_id } => {
+                Self::handle_exit_callback(internal_events, event_id).await;
+            }
+            SchedulerMessage::SetMissedRunPolicy {
+                event_id,
+                missed_run_policy,
+                response,
+            } => {
+                let result = Self::handle_set_missed_run_policy(
+                    internal_events,
+                    event_id,
+                    missed_run_policy,
+                );
+                let should_persist = result.is_ok();
+                let _unused = response.send(result);
+                if should_persist {
+                    Self::save_persistent_events_to_config(internal_events).await;
+                    Self::notify_events_changed();
+                }
+            }
+            SchedulerMessage::ListEvents { response } => {
+                let events = Self::list_events(internal_events);
+                let _unused = response.send(events);
+            }
+            SchedulerMessage::CleanupSchedule { event_id } => {
+                Self::handle_cleanup_schedule_events(internal_events, event_id).await;
+            }
+        }
+    }
+
+    fn list_events(events: &HashMap<String, ScheduledEvent>) -> Vec<ScheduledEventInfo> {
+        events
+            .iter()
+            .map(|(id, event)| ScheduledEventInfo {
+                id: id.clone(),
+                event_type: event.event_type.clone(),
+                timing: event.timing.clone(),
+                state: event.state.clone(),
+                missed_run_policy: event.missed_run_policy,
+            })
+            .collect()
+    }
+
     /// Internal handler for adding new events to the scheduler.
     ///
     /// Processes AddEvent messages from the public API, manages unique event types,
@@ -922,6 +1997,21 @@ impl EventScheduler {
         event_id: String,
         timing: SchedulerEventTiming,
     ) -> Result<String, SchedulerError> {
+        event_type.validate()?;
+        check_idle_detection(&timing, INSTANCE.idle_source.read().await.as_ref())?;
+        // Replaced events are not checked, they get removed below
+        check_event_conflicts(
+            &event_type,
+            &timing,
+            events
+                .iter()
+                .filter(|(id, event)| {
+                    **id != event_id && !(event_type.is_unique() && event.event_type == event_type)
+                })
+                .map(|(id, event)| (id.as_str(), &event.event_type, &event.timing)),
+            Local::now(),
+        )?;
+
         if event_type.is_unique() {
             info!(target: LOG_TARGET_APP_LOGIC, "Ensuring uniqueness for event type {:?}", event_type);
             let to_remove: Vec<String> = events
@@ -937,12 +2027,41 @@ impl EventScheduler {
             }
         }
 
+        Self::insert_event(
+            events,
+            event_type,
+            event_id,
+            timing,
+            SchedulerMissedRunPolicy::default(),
+        )
+        .await
+    }
+
+    /// Creates the execution task for an event and stores it.
+    /// Used directly when restoring persisted events, which were checked when first added.
+    async fn insert_event(
+        events: &mut HashMap<String, ScheduledEvent>,
+        event_type: SchedulerEventType,
+        event_id: String,
+        timing: SchedulerEventTiming,
+        missed_run_policy: SchedulerMissedRunPolicy,
+    ) -> Result<String, SchedulerError> {
+        if let Some(mut replaced_event) = events.remove(&event_id)
+            && let Some(handle) = replaced_event.task_handle.take()
+        {
+            handle.abort();
+        }
+
         let mut scheduled_event = ScheduledEvent {
             id: event_id.clone(),
             event_type: event_type.clone(),
             timing: timing.clone(),
             state: SchedulerEventState::Active,
             task_handle: None,
+            revert_state: None,
+            missed_run_policy,
+            is_entered: false,
+            exit_ran_early: false,
         };
 
         let task_handle =
@@ -976,6 +2095,9 @@ impl EventScheduler {
                 if let Some(handle) = event.task_handle.take() {
                     handle.abort();
                 }
+                if event.revert_state.is_some() {
+                    Self::save_revert_states(events);
+                }
                 info!(target: LOG_TARGET_APP_LOGIC, "Removed event with ID {:?}", event_id);
                 Ok(())
             }
@@ -1055,66 +2177,230 @@ impl EventScheduler {
         }
     }
 
+    /// Internal handler for setting the missed run policy of an event.
+    ///
+    /// ### Parameters
+    /// * `events` - Mutable reference to the events map
+    /// * `event_id` - ID of the event to update
+    /// * `missed_run_policy` - New policy
+    ///
+    /// ### Returns
+    /// * `Ok(())` - Policy updated
+    /// * `Err(SchedulerError::EventNotFound)` - No event with the given ID
+    fn handle_set_missed_run_policy(
+        events: &mut HashMap<String, ScheduledEvent>,
+        event_id: String,
+        missed_run_policy: SchedulerMissedRunPolicy,
+    ) -> Result<(), SchedulerError> {
+        let event = events
+            .get_mut(&event_id)
+            .ok_or_else(|| SchedulerError::EventNotFound(event_id.clone()))?;
+        event.missed_run_policy = missed_run_policy;
+        info!(target: LOG_TARGET_APP_LOGIC, "Set missed run policy of event {:?} to {:?}", event_id, missed_run_policy);
+        Ok(())
+    }
+
+    /// Applies the missed run policy of every event at startup.
+    /// Caught up events are entered straight away, other missed windows are recorded in the history.
+    ///
+    /// ### Parameters
+    /// * `events` - Mutable reference to the events map
+    /// * `last_active_at` - Last time the scheduler was known to run
+    async fn handle_missed_runs(
+        events: &mut HashMap<String, ScheduledEvent>,
+        last_active_at: Option<DateTime<Local>>,
+    ) {
+        let now = Local::now();
+        let event_ids: Vec<String> = events.keys().cloned().collect();
+        for event_id in event_ids {
+            let Some(event) = events.get(&event_id) else {
+                continue;
+            };
+            let missed_windows = match event.timing.missed_windows(last_active_at, now) {
+                Ok(missed_windows) => missed_windows,
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Failed to check missed windows of event {:?}: {}", event_id, e);
+                    continue;
+                }
+            };
+            let last_missed_start = missed_windows.starts.last().copied();
+
+            if event
+                .missed_run_policy
+                .should_catch_up(&event.event_type, &missed_windows)
+            {
+                info!(target: LOG_TARGET_APP_LOGIC, "Catching up event {:?} missed while the app was closed", event_id);
+                Self::handle_enter_callback(events, event_id, last_missed_start).await;
+            } else if let Some(last_missed_start) = last_missed_start {
+                info!(target: LOG_TARGET_APP_LOGIC, "Skipping {} window(s) of event {:?} missed while the app was closed", missed_windows.starts.len(), event_id);
+                SchedulerHistory::record(SchedulerExecutionRecord {
+                    event_id,
+                    fired_at: SystemTime::now(),
+                    scheduled_at: Some(last_missed_start.into()),
+                    phase: SchedulerExecutionPhase::Enter,
+                    action: event.event_type.clone(),
+                    outcome: SchedulerExecutionOutcome::Missed,
+                    error: Some(format!(
+                        "{} window(s) started while the app was closed",
+                        missed_windows.starts.len()
+                    )),
+                })
+                .await;
+            }
+        }
+    }
+
     /// Internal handler for event enter callbacks.
     ///
     /// Executes the actions associated with an event when it triggers.
-    /// This starts mining operations based on the event type.
+    /// Captures the current state first when the exit callback has to restore it.
+    /// The run is recorded in the scheduler history.
     ///
     /// ### Parameters
     /// * `events` - Reference to the events map
     /// * `event_id` - ID of the event that triggered
+    /// * `caught_up_window` - Start of the missed window when run at startup, `None` when triggered on time
     ///
     /// ### Returns
     /// * `Ok(())` - Callback executed successfully
     /// * `Err(SchedulerError)` - If execution fails
     async fn handle_enter_callback(
-        events: &HashMap<String, ScheduledEvent>,
+        events: &mut HashMap<String, ScheduledEvent>,
         event_id: String,
+        caught_up_window: Option<DateTime<Local>>,
     ) -> Result<(), SchedulerError> {
-        if let Some(event) = events.get(&event_id)
+        if events
+            .get(&event_id)
+            .is_some_and(|event| event.state == SchedulerEventState::Active)
+        {
+            Self::exit_ended_events(events, &event_id).await;
+        }
+
+        Self::capture_revert_state(events, &event_id).await;
+        if let Some(event) = events.get_mut(&event_id)
             && event.state == SchedulerEventState::Active
         {
-            match event.event_type.clone() {
-                SchedulerEventType::ResumeMining => {
-                    let config = ConfigMining::content().await;
-                    if *config.gpu_mining_enabled() {
-                        GpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start GPU mining during ResumeMining event {:?}: {}", event_id, e);
-                        });
-                    }
-                    if *config.cpu_mining_enabled() {
-                        CpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start CPU mining during ResumeMining event {:?}: {}", event_id, e);
-                        });
+            event.is_entered = true;
+            event.exit_ran_early = false;
+            let mut errors = ActionErrors::new(&event_id);
+            Self::run_enter_action(&mut errors, &event_id, event.event_type.clone()).await;
+            errors
+                .record(
+                    SchedulerExecutionPhase::Enter,
+                    &event.event_type,
+                    caught_up_window,
+                )
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Captures the state the exit callback restores, for Idle events and events that restore
+    /// the state on exit
+    async fn capture_revert_state(events: &mut HashMap<String, ScheduledEvent>, event_id: &str) {
+        let captures_state = events.get(event_id).is_some_and(|event| {
+            event.state == SchedulerEventState::Active
+                && (matches!(event.timing, SchedulerEventTiming::Idle(_))
+                    || event.event_type.restores_state_on_exit())
+        });
+        if captures_state {
+            let revert_state = SchedulerRevertState::capture().await;
+            if let Some(event) = events.get_mut(event_id) {
+                event.revert_state = Some(revert_state);
+            }
+            Self::save_revert_states(events);
+        }
+    }
+
+    async fn run_enter_action(
+        errors: &mut ActionErrors<'_>,
+        event_id: &str,
+        event_type: SchedulerEventType,
+    ) {
+        match event_type {
+            SchedulerEventType::ResumeMining => {
+                Self::start_enabled_miners(errors).await;
+            }
+            SchedulerEventType::Mine { mining_mode } => {
+                errors.check(
+                    ConfigMining::update_field(
+                        ConfigMiningContent::set_selected_mining_mode,
+                        mining_mode,
+                    )
+                    .await
+                    .map_err(|e| anyhow!("Failed to set mining mode: {e}")),
+                );
+                // TODO: Replace with emiting specific value only
+                EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+                Self::start_enabled_miners(errors).await;
+            }
+            SchedulerEventType::StopMining => {
+                errors.check(Self::set_gpu_miner_running(false).await);
+                errors.check(Self::set_cpu_miner_running(false).await);
+            }
+            SchedulerEventType::SwitchPool { cpu_pool, gpu_pool } => {
+                if let Some(cpu_pool) = cpu_pool {
+                    match CpuPool::from_string(&cpu_pool) {
+                        Ok(cpu_pool) => {
+                            errors.check(Self::switch_cpu_pool(event_id, cpu_pool).await)
+                        }
+                        Err(e) => errors.check(Err(anyhow!("Invalid CPU pool {cpu_pool:?}: {e}"))),
                     }
                 }
-                SchedulerEventType::Mine { mining_mode } => {
-                    ConfigMining::update_field(ConfigMiningContent::set_selected_mining_mode, mining_mode.clone()).await.unwrap_or_else(|e| {
-                        error!(target: LOG_TARGET_APP_LOGIC, "Failed to set mining mode during Mine event {:?}: {}", event_id, e);
-                    });
-                    // TODO: Replace with emiting specific value only
-                    let config = ConfigMining::content().await;
-                    EventsEmitter::emit_mining_config_loaded(&config).await;
-                    if *config.gpu_mining_enabled() {
-                        GpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start GPU mining during Mine event {:?}: {}", event_id, e);
-                        });
-                    }
-                    if *config.cpu_mining_enabled() {
-                        CpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start CPU mining during Mine event {:?}: {}", event_id, e);
-                        });
+                if let Some(gpu_pool) = gpu_pool {
+                    match GpuPool::from_string(&gpu_pool) {
+                        Ok(gpu_pool) => {
+                            errors.check(Self::switch_gpu_pool(event_id, gpu_pool).await)
+                        }
+                        Err(e) => errors.check(Err(anyhow!("Invalid GPU pool {gpu_pool:?}: {e}"))),
                     }
                 }
             }
+            SchedulerEventType::SetMiningDevices {
+                cpu_enabled,
+                gpu_enabled,
+            } => {
+                errors.check(Self::set_mining_devices(cpu_enabled, gpu_enabled).await);
+                errors.check(Self::set_gpu_miner_running(gpu_enabled).await);
+                errors.check(Self::set_cpu_miner_running(cpu_enabled).await);
+            }
+            SchedulerEventType::RestartNode => {
+                info!(target: LOG_TARGET_APP_LOGIC, "Restarting node for event {:?}", event_id);
+                SetupManager::get_instance()
+                    .restart_phases(vec![SetupPhase::Node])
+                    .await;
+            }
+        }
+    }
+
+    async fn start_enabled_miners(errors: &mut ActionErrors<'_>) {
+        let config = ConfigMining::content().await;
+        if *config.gpu_mining_enabled() {
+            errors.check(
+                GpuManager::write()
+                    .await
+                    .start_mining()
+                    .await
+                    .map_err(|e| anyhow!("Failed to start GPU mining: {e}")),
+            );
+        }
+        if *config.cpu_mining_enabled() {
+            errors.check(
+                CpuManager::write()
+                    .await
+                    .start_mining()
+                    .await
+                    .map_err(|e| anyhow!("Failed to start CPU mining: {e}")),
+            );
         }
-        Ok(())
     }
 
     /// Internal handler for event exit callbacks.
     ///
-    /// Executes cleanup actions when a time window ends (for Between events).
-    /// This typically stops mining operations.
+    /// Executes cleanup actions when a time window ends (for Between events)
+    /// or the user becomes active again (for Idle events).
+    /// Between events stop mining, Idle events restore the state captured on enter.
+    /// Runs that did anything are recorded in the scheduler history.
     ///
     /// ### Parameters
     /// * `events` - Reference to the events map
@@ -1124,29 +2410,349 @@ impl EventScheduler {
     /// * `Ok(())` - Callback executed successfully
     /// * `Err(SchedulerError)` - If execution fails
     async fn handle_exit_callback(
-        events: &HashMap<String, ScheduledEvent>,
+        events: &mut HashMap<String, ScheduledEvent>,
         event_id: String,
     ) -> Result<(), SchedulerError> {
-        if let Some(event) = events.get(&event_id) {
-            match event.event_type.clone() {
-                SchedulerEventType::ResumeMining => {}
-                SchedulerEventType::Mine { mining_mode } => {
-                    GpuManager::write().await.stop_mining().await.unwrap_or_else(|e| {
-                        error!(target: LOG_TARGET_APP_LOGIC, "Failed to stop mining during cleanup of event {:?}: {}", event_id, e);
-                    });
-                    CpuManager::write().await.stop_mining().await.unwrap_or_else(|e| {
-                        error!(target: LOG_TARGET_APP_LOGIC, "Failed to stop mining during cleanup of event {:?}: {}", event_id, e);
-                    });
+        let mut reverted = false;
+        if let Some(event) = events.get_mut(&event_id) {
+            if std::mem::take(&mut event.exit_ran_early) {
+                info!(target: LOG_TARGET_APP_LOGIC, "Exit of event {:?} already ran when the next event entered", event_id);
+                return Ok(());
+            }
+            event.is_entered = false;
+            let mut errors = ActionErrors::new(&event_id);
+            if let Some(revert_state) = event.revert_state.take() {
+                Self::revert_event(&mut errors, &event.event_type, revert_state).await;
+                reverted = true;
+            } else {
+                match event.event_type.clone() {
+                    SchedulerEventType::ResumeMining
+                    | SchedulerEventType::StopMining
+                    | SchedulerEventType::SwitchPool { .. }
+                    | SchedulerEventType::SetMiningDevices { .. }
+                    | SchedulerEventType::RestartNode => return Ok(()),
+                    SchedulerEventType::Mine { mining_mode } => {
+                        errors.check(
+                            GpuManager::write()
+                                .await
+                                .stop_mining()
+                                .await
+                                .map_err(|e| anyhow!("Failed to stop GPU mining: {e}")),
+                        );
+                        errors.check(
+                            CpuManager::write()
+                                .await
+                                .stop_mining()
+                                .await
+                                .map_err(|e| anyhow!("Failed to stop CPU mining: {e}")),
+                        );
+                    }
+                }
+            }
+            errors
+                .record(SchedulerExecutionPhase::Exit, &event.event_type, None)
+                .await;
+        }
+        if reverted {
+            Self::save_revert_states(events);
+        }
+        Ok(())
+    }
+
+    /// Runs the exit callbacks of entered events whose window has ended before another event enters.
+    ///
+    /// Adjacent windows end and start at the same moment and their triggers race, e.g. an Eco
+    /// window starting when a Ludicrous window ends. Running the exit first makes sure it
+    /// doesn't undo the action of the event that just entered.
+    ///
+    /// ### Parameters
+    /// * `events` - Mutable reference to the events map
+    /// * `entering_event_id` - ID of the event about to enter
+    async fn exit_ended_events(
+        events: &mut HashMap<String, ScheduledEvent>,
+        entering_event_id: &str,
+    ) {
+        let now = Local::now();
+        let ended_event_ids = events
+            .values()
+            .filter(|event| {
+                event.id != entering_event_id
+                    && event.is_entered
+                    && event.timing.has_window_ended_at(now)
+            })
+            .map(|event| event.id.clone())
+            .collect::<Vec<_>>();
+        for ended_event_id in ended_event_ids {
+            info!(target: LOG_TARGET_APP_LOGIC, "Running exit of event {:?} before entering event {:?}", ended_event_id, entering_event_id);
+            if let Err(e) = Self::handle_exit_callback(events, ended_event_id.clone()).await {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to exit event {:?}: {}", ended_event_id, e);
+            }
+            if let Some(event) = events.get_mut(&ended_event_id) {
+                event.exit_ran_early = true;
+            }
+        }
+    }
+
+    /// Undoes the action of an event using the state captured by its enter callback.
+    ///
+    /// ### Parameters
+    /// * `errors` - Collects the errors of the event being reverted
+    /// * `event_type` - Action to undo
+    /// * `revert_state` - State captured by the enter callback
+    async fn revert_event(
+        errors: &mut ActionErrors<'_>,
+        event_type: &SchedulerEventType,
+        revert_state: SchedulerRevertState,
+    ) {
+        info!(target: LOG_TARGET_APP_LOGIC, "Reverting {} for event {:?}", event_type, errors.event_id);
+        match event_type {
+            SchedulerEventType::ResumeMining | SchedulerEventType::Mine { .. } => {
+                Self::revert_mining_state(errors, revert_state).await;
+            }
+            SchedulerEventType::StopMining => {
+                if revert_state.was_gpu_mining {
+                    errors.check(Self::set_gpu_miner_running(true).await);
+                }
+                if revert_state.was_cpu_mining {
+                    errors.check(Self::set_cpu_miner_running(true).await);
+                }
+            }
+            SchedulerEventType::SwitchPool { cpu_pool, gpu_pool } => {
+                if cpu_pool.is_some() {
+                    errors
+                        .check(Self::switch_cpu_pool(errors.event_id, revert_state.cpu_pool).await);
+                }
+                if gpu_pool.is_some() {
+                    errors
+                        .check(Self::switch_gpu_pool(errors.event_id, revert_state.gpu_pool).await);
+                }
+            }
+            SchedulerEventType::SetMiningDevices { .. } => {
+                errors.check(
+                    Self::set_mining_devices(
+                        revert_state.cpu_mining_enabled,
+                        revert_state.gpu_mining_enabled,
+                    )
+                    .await,
+                );
+                errors.check(Self::set_gpu_miner_running(revert_state.was_gpu_mining).await);
+                errors.check(Self::set_cpu_miner_running(revert_state.was_cpu_mining).await);
+            }
+            SchedulerEventType::RestartNode => {}
+        }
+    }
+
+    /// Undoes the configuration changes of an event whose exit was missed because the app was closed.
+    /// The miners are not running yet, the setup starts them as usual.
+    ///
+    /// ### Parameters
+    /// * `errors` - Collects the errors of the event being reverted
+    /// * `event_type` - Action to undo
+    /// * `revert_state` - State captured by the enter callback before the shutdown
+    async fn revert_event_config(
+        errors: &mut ActionErrors<'_>,
+        event_type: &SchedulerEventType,
+        revert_state: SchedulerRevertState,
+    ) {
+        match event_type {
+            SchedulerEventType::ResumeMining | SchedulerEventType::Mine { .. } => {
+                errors.check(Self::restore_mining_mode(&revert_state.mining_mode).await);
+            }
+            SchedulerEventType::SwitchPool { cpu_pool, gpu_pool } => {
+                if cpu_pool.is_some() {
+                    errors
+                        .check(Self::switch_cpu_pool(errors.event_id, revert_state.cpu_pool).await);
+                }
+                if gpu_pool.is_some() {
+                    errors
+                        .check(Self::switch_gpu_pool(errors.event_id, revert_state.gpu_pool).await);
                 }
             }
+            SchedulerEventType::SetMiningDevices { .. } => {
+                errors.check(
+                    Self::set_mining_devices(
+                        revert_state.cpu_mining_enabled,
+                        revert_state.gpu_mining_enabled,
+                    )
+                    .await,
+                );
+            }
+            SchedulerEventType::StopMining | SchedulerEventType::RestartNode => {}
+        }
+    }
+
+    /// Starts or stops the CPU miner if it is not already in the requested state.
+    async fn set_cpu_miner_running(should_run: bool) -> Result<(), anyhow::Error> {
+        let mut cpu_manager = CpuManager::write().await;
+        if cpu_manager.is_running() == should_run {
+            return Ok(());
+        }
+        let result = if should_run {
+            cpu_manager.start_mining().await
+        } else {
+            cpu_manager.stop_mining().await
+        };
+        result.map_err(|e| {
+            anyhow!(
+                "Failed to {} CPU mining: {e}",
+                if should_run { "start" } else { "stop" }
+            )
+        })
+    }
+
+    /// Starts or stops the GPU miner if it is not already in the requested state.
+    async fn set_gpu_miner_running(should_run: bool) -> Result<(), anyhow::Error> {
+        let mut gpu_manager = GpuManager::write().await;
+        if gpu_manager.is_running() == should_run {
+            return Ok(());
+        }
+        let result = if should_run {
+            gpu_manager.start_mining().await
+        } else {
+            gpu_manager.stop_mining().await
+        };
+        result.map_err(|e| {
+            anyhow!(
+                "Failed to {} GPU mining: {e}",
+                if should_run { "start" } else { "stop" }
+            )
+        })
+    }
+
+    /// Persists the CPU and GPU mining toggles and updates the frontend and system tray.
+    async fn set_mining_devices(cpu_enabled: bool, gpu_enabled: bool) -> Result<(), anyhow::Error> {
+        ConfigMining::update_field(ConfigMiningContent::set_cpu_mining_enabled, cpu_enabled)
+            .await
+            .map_err(|e| anyhow!("Failed to set CPU mining enabled: {e}"))?;
+        ConfigMining::update_field(ConfigMiningContent::set_gpu_mining_enabled, gpu_enabled)
+            .await
+            .map_err(|e| anyhow!("Failed to set GPU mining enabled: {e}"))?;
+        EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+        SystemTrayManager::send_event(SystemTrayEvents::CpuMiningState(cpu_enabled)).await;
+        SystemTrayManager::send_event(SystemTrayEvents::GpuMiningState(gpu_enabled)).await;
+        Ok(())
+    }
+
+    /// Selects a CPU pool and restarts the CPU miner so it connects to it.
+    async fn switch_cpu_pool(event_id: &str, cpu_pool: CpuPool) -> Result<(), anyhow::Error> {
+        if !ConfigPools::content()
+            .await
+            .cpu_pools()
+            .contains_key(&cpu_pool)
+        {
+            return Err(anyhow!("CPU pool {} is not configured", cpu_pool));
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching CPU pool to {} for event {:?}", cpu_pool, event_id);
+        ConfigPools::update_field(ConfigPoolsContent::set_current_cpu_pool, cpu_pool)
+            .await
+            .map_err(|e| anyhow!("Failed to switch CPU pool: {e}"))?;
+        let config = ConfigPools::content().await;
+        CpuPoolManager::handle_new_selected_pool(config.current_cpu_pool()).await;
+        EventsEmitter::emit_pools_config_loaded(&config).await;
+
+        // Miner reads the pool url on start
+        if CpuManager::read().await.is_running() {
+            Self::set_cpu_miner_running(false).await?;
+            Self::set_cpu_miner_running(true).await?;
         }
         Ok(())
     }
 
+    /// Selects a GPU pool and restarts the GPU miner so it connects to it.
+    async fn switch_gpu_pool(event_id: &str, gpu_pool: GpuPool) -> Result<(), anyhow::Error> {
+        if !ConfigPools::content()
+            .await
+            .gpu_pools()
+            .contains_key(&gpu_pool)
+        {
+            return Err(anyhow!("GPU pool {} is not configured", gpu_pool));
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching GPU pool to {} for event {:?}", gpu_pool, event_id);
+        ConfigPools::update_field(ConfigPoolsContent::set_current_gpu_pool, gpu_pool)
+            .await
+            .map_err(|e| anyhow!("Failed to switch GPU pool: {e}"))?;
+        let config = ConfigPools::content().await;
+        GpuPoolManager::handle_new_selected_pool(config.current_gpu_pool()).await;
+        EventsEmitter::emit_pools_config_loaded(&config).await;
+
+        // Miner reads the pool url on start
+        if GpuManager::read().await.is_running() {
+            Self::set_gpu_miner_running(false).await?;
+            Self::set_gpu_miner_running(true).await?;
+        }
+        Ok(())
+    }
+
+    /// Selects the mining mode captured before an event was entered.
+    async fn restore_mining_mode(mining_mode: &str) -> Result<(), anyhow::Error> {
+        if ConfigMining::content().await.selected_mining_mode() == mining_mode {
+            return Ok(());
+        }
+        ConfigMining::update_field(
+            ConfigMiningContent::set_selected_mining_mode,
+            mining_mode.to_string(),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to restore mining mode: {e}"))?;
+        EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+        Ok(())
+    }
+
+    /// Restores the mining mode and miners captured before an event was entered.
+    ///
+    /// ### Parameters
+    /// * `errors` - Collects the errors of the event being reverted
+    /// * `revert_state` - State captured by the enter callback
+    async fn revert_mining_state(
+        errors: &mut ActionErrors<'_>,
+        revert_state: SchedulerRevertState,
+    ) {
+        info!(target: LOG_TARGET_APP_LOGIC, "Reverting event {:?} to mining mode {:?}", errors.event_id, revert_state.mining_mode);
+        errors.check(Self::restore_mining_mode(&revert_state.mining_mode).await);
+
+        let mut gpu_manager = GpuManager::write().await;
+        if gpu_manager.is_running() {
+            errors.check(
+                gpu_manager
+                    .stop_mining()
+                    .await
+                    .map_err(|e| anyhow!("Failed to stop GPU mining: {e}")),
+            );
+            // Restart to pick up the restored mining mode
+            if revert_state.was_gpu_mining {
+                errors.check(
+                    gpu_manager
+                        .start_mining()
+                        .await
+                        .map_err(|e| anyhow!("Failed to restart GPU mining: {e}")),
+                );
+            }
+        }
+        drop(gpu_manager);
+
+        let mut cpu_manager = CpuManager::write().await;
+        if cpu_manager.is_running() {
+            errors.check(
+                cpu_manager
+                    .stop_mining()
+                    .await
+                    .map_err(|e| anyhow!("Failed to stop CPU mining: {e}")),
+            );
+            if revert_state.was_cpu_mining {
+                errors.check(
+                    cpu_manager
+                        .start_mining()
+                        .await
+                        .map_err(|e| anyhow!("Failed to restart CPU mining: {e}")),
+                );
+            }
+        }
+    }
+
     /// Internal handler for cleaning up completed one-time events.
     ///
-    /// Removes "In" timing events after they execute, as they are not recurring.
-    /// "Between" timing events are kept for repeated execution.
+    /// Removes "In" timing events after they execute and "DateRange" events after they end,
+    /// as they are not recurring. Other timing events are kept for repeated execution.
     ///
     /// ### Parameters
     /// * `events` - Mutable reference to the events map
@@ -1156,11 +2762,20 @@ impl EventScheduler {
         event_id: String,
     ) {
         if let Some(event) = events.get(&event_id)
-            && let SchedulerEventTiming::In(_) = event.timing
+            && matches!(
+                event.timing,
+                SchedulerEventTiming::In(_) | SchedulerEventTiming::DateRange(_)
+            )
         {
             info!(target: LOG_TARGET_APP_LOGIC, "Cleaning up schedule for event ID {:?}", event_id);
+            let is_persistent = event.timing.is_persistent();
             if let Err(e) = Self::handle_remove_event(events, event_id.clone()) {
                 error!(target: LOG_TARGET_APP_LOGIC, "Failed to clean up scheduled event {:?}: {}", event_id, e);
+            } else {
+                if is_persistent {
+                    Self::save_persistent_events_to_config(events).await;
+                }
+                Self::notify_events_changed();
             }
         }
     }
@@ -1169,7 +2784,9 @@ impl EventScheduler {
     ///
     /// This spawns the appropriate timing task based on the event's timing type:
     /// - "In" timing: Simple delay then trigger
-    /// - "Between" timing: Complex cron-based recurring schedule
+    /// - "Between", "Cron" and "Weekly" timing: Complex cron-based recurring schedule
+    /// - "DateRange" timing: Waits for the start and end of the range, then cleans up
+    /// - "Idle" timing: Polls the idle source and triggers on idle/active transitions
     ///
     /// ### Parameters
     /// * `event_id` - Unique identifier for the event
@@ -1184,78 +2801,245 @@ impl EventScheduler {
         _event_type: SchedulerEventType, // Not used in scheduling logic, only for identification
         timing: SchedulerEventTiming,
     ) -> Result<tokio::task::JoinHandle<()>, SchedulerError> {
-        let handle = match timing {
-            SchedulerEventTiming::In(in_variant_payload) => tokio::spawn(async move {
-                let duration = in_variant_payload.to_duration();
-                if let Ok(duration) = duration {
-                    sleep(duration.to_std().unwrap_or_default()).await;
+        match timing {
+            SchedulerEventTiming::In(in_variant_payload) => {
+                Ok(Self::spawn_in_task(event_id, in_variant_payload))
+            }
+            SchedulerEventTiming::DateRange(date_range_variant_payload) => {
+                Self::spawn_date_range_task(event_id, &date_range_variant_payload)
+            }
+            recurring_timing @ (SchedulerEventTiming::Between(_)
+            | SchedulerEventTiming::Cron(_)
+            | SchedulerEventTiming::Weekly(_)) => {
+                Self::spawn_recurring_task(event_id, &recurring_timing)
+            }
+            SchedulerEventTiming::Idle(idle_variant_payload) => {
+                Self::spawn_idle_task(event_id, &idle_variant_payload)
+            }
+        }
+    }
+
+    /// Waits for the delay of an "In" event, then triggers it once
+    fn spawn_in_task(
+        event_id: String,
+        in_variant_payload: InVariantPayload,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let duration = in_variant_payload.to_duration();
+            if let Ok(duration) = duration {
+                sleep(duration.to_std().unwrap_or_default()).await;
+
+                let _unused = INSTANCE
+                    .message_sender
+                    .send(SchedulerMessage::TriggerEnterCallback { event_id });
+            } else {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to parse duration for 'In' event {:?}", event_id);
+            }
+        })
+    }
 
-                    let _unused = INSTANCE
+    /// Enters at the start and exits at the end of the range, then removes the event
+    fn spawn_date_range_task(
+        event_id: String,
+        date_range_variant_payload: &DateRangeVariantPayload,
+    ) -> Result<tokio::task::JoinHandle<()>, SchedulerError> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Creating scheduling task for 'DateRange' event ID {:?}", event_id);
+        let (start, end) = date_range_variant_payload.to_range()?;
+
+        Ok(tokio::spawn(async move {
+            if end > Local::now() {
+                sleep((start - Local::now()).to_std().unwrap_or_default()).await;
+                let _unused =
+                    INSTANCE
                         .message_sender
-                        .send(SchedulerMessage::TriggerEnterCallback { event_id });
+                        .send(SchedulerMessage::TriggerEnterCallback {
+                            event_id: event_id.clone(),
+                        });
+
+                sleep((end - Local::now()).to_std().unwrap_or_default()).await;
+                let _unused = INSTANCE
+                    .message_sender
+                    .send(SchedulerMessage::TriggerExitCallback {
+                        event_id: event_id.clone(),
+                    });
+            } else {
+                info!(target: LOG_TARGET_APP_LOGIC, "Date range of event {:?} has already ended", event_id);
+            }
+            let _unused = INSTANCE
+                .message_sender
+                .send(SchedulerMessage::CleanupSchedule { event_id });
+        }))
+    }
+
+    /// Enters at the start and exits at the end of every window of the cron schedule
+    fn spawn_recurring_task(
+        event_id: String,
+        recurring_timing: &SchedulerEventTiming,
+    ) -> Result<tokio::task::JoinHandle<()>, SchedulerError> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Creating scheduling task for recurring event ID {:?}", event_id);
+        let cron_schedule = recurring_timing
+            .to_cron_schedule()
+            .and_then(|cron_schedule| {
+                cron_schedule.ok_or_else(|| {
+                    SchedulerError::InternalError("Timing is not recurring".to_string())
+                })
+            })
+            .map_err(|e| {
+                SchedulerError::InvalidTimingFormat(format!(
+                    "Failed to create cron schedule for recurring timing: {}",
+                    e
+                ))
+            })?;
+
+        let copied_cron_schedule = cron_schedule.clone();
+        let copied_event_id = event_id.clone();
+
+        tokio::spawn(async move {
+            let cron_schedule = copied_cron_schedule.clone();
+            let event_id = copied_event_id.clone();
+            loop {
+                let local_now = Local::now();
+
+                if let Some(next_start_wait_time) =
+                    cron_schedule.find_next_start_wait_time(local_now)
+                {
+                    sleep(next_start_wait_time).await;
                 } else {
-                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to parse duration for 'In' event {:?}", event_id);
+                    warn!(target: LOG_TARGET_APP_LOGIC, "No next start time found for event with ID {:?}", event_id);
+                    break;
                 }
-            }),
-
-            SchedulerEventTiming::Between(between_time_variant_payload) => {
-                info!(target: LOG_TARGET_APP_LOGIC, "Creating scheduling task for 'Between' event ID {:?}", event_id);
-                let cron_schedule =
-                    between_time_variant_payload
-                        .to_cron_schedule()
-                        .map_err(|e| {
-                            SchedulerError::InvalidTimingFormat(format!(
-                                "Failed to create cron schedule for Between timing: {}",
-                                e
-                            ))
-                        })?;
-
-                let copied_cron_schedule = cron_schedule.clone();
-                let copied_event_id = event_id.clone();
-
-                tokio::spawn(async move {
-                    let cron_schedule = copied_cron_schedule.clone();
-                    let event_id = copied_event_id.clone();
-                    loop {
-                        let local_now = Local::now();
-
-                        if let Some(next_start_wait_time) =
-                            cron_schedule.find_next_start_wait_time(local_now)
-                        {
-                            sleep(next_start_wait_time).await;
-                        } else {
-                            warn!(target: LOG_TARGET_APP_LOGIC, "No next start time found for event with ID {:?}", event_id);
-                            break;
-                        }
 
-                        let _unused =
-                            INSTANCE
-                                .message_sender
-                                .send(SchedulerMessage::TriggerEnterCallback {
-                                    event_id: event_id.clone(),
-                                });
-                    }
-                });
-                tokio::spawn(async move {
-                    let cron_schedule = cron_schedule.clone();
-                    loop {
-                        let local_now = Local::now();
-                        // Now wait until end time, eg. currently is 10AM and the range is 9AM - 11AM, then we wait until 11AM
-                        if let Some(next_end_wait_time) =
-                            cron_schedule.find_next_end_wait_time(local_now)
-                        {
-                            sleep(next_end_wait_time).await;
-                            let _unused = INSTANCE.message_sender.send(
-                                SchedulerMessage::TriggerExitCallback {
-                                    event_id: event_id.clone(),
-                                },
-                            );
-                        }
+                let _unused =
+                    INSTANCE
+                        .message_sender
+                        .send(SchedulerMessage::TriggerEnterCallback {
+                            event_id: event_id.clone(),
+                        });
+            }
+        });
+        Ok(tokio::spawn(async move {
+            let cron_schedule = cron_schedule.clone();
+            loop {
+                let local_now = Local::now();
+                // Now wait until end time, eg. currently is 10AM and the range is 9AM - 11AM, then we wait until 11AM
+                if let Some(next_end_wait_time) = cron_schedule.find_next_end_wait_time(local_now) {
+                    sleep(next_end_wait_time).await;
+                    let _unused =
+                        INSTANCE
+                            .message_sender
+                            .send(SchedulerMessage::TriggerExitCallback {
+                                event_id: event_id.clone(),
+                            });
+                }
+            }
+        }))
+    }
+
+    /// Polls the idle source, entering when the user becomes idle and exiting when they are back
+    fn spawn_idle_task(
+        event_id: String,
+        idle_variant_payload: &IdleVariantPayload,
+    ) -> Result<tokio::task::JoinHandle<()>, SchedulerError> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Creating scheduling task for 'Idle' event ID {:?}", event_id);
+        let threshold = idle_variant_payload.to_threshold()?;
+
+        Ok(tokio::spawn(async move {
+            let mut idle_tracker = IdleTracker::new(threshold);
+            loop {
+                sleep(IDLE_POLL_INTERVAL).await;
+
+                let idle_source = INSTANCE.idle_source.read().await.clone();
+                let idle_time = match idle_source.idle_time().await {
+                    Ok(idle_time) => idle_time,
+                    Err(e) => {
+                        warn!(target: LOG_TARGET_APP_LOGIC, "Failed to read idle time for event {:?}: {}", event_id, e);
+                        continue;
                     }
-                })
+                };
+
+                let message = match idle_tracker.update(idle_time) {
+                    Some(IdleTransition::BecameIdle) => SchedulerMessage::TriggerEnterCallback {
+                        event_id: event_id.clone(),
+                    },
+                    Some(IdleTransition::BecameActive) => SchedulerMessage::TriggerExitCallback {
+                        event_id: event_id.clone(),
+                    },
+                    None => continue,
+                };
+                let _unused = INSTANCE.message_sender.send(message);
             }
-        };
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn persisted_state(mining_mode: &str) -> PersistedRevertState {
+        PersistedRevertState {
+            event_type: SchedulerEventType::SetMiningDevices {
+                cpu_enabled: false,
+                gpu_enabled: true,
+            },
+            revert_state: SchedulerRevertState {
+                mining_mode: mining_mode.to_string(),
+                was_cpu_mining: true,
+                was_gpu_mining: false,
+                cpu_mining_enabled: true,
+                gpu_mining_enabled: true,
+                cpu_pool: CpuPool::LuckyPoolRANDOMX,
+                gpu_pool: GpuPool::KryptexPoolSHA3X,
+            },
+        }
+    }
+
+    #[test]
+    fn revert_states_survive_a_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("network").join(REVERT_STATES_FILE_NAME);
+        let states = HashMap::from([
+            ("night".to_string(), persisted_state("Eco")),
+            ("weekend".to_string(), persisted_state("Ludicrous")),
+        ]);
+
+        write_revert_states(&path, &states).unwrap();
+
+        assert_eq!(read_revert_states(&path), states);
+    }
+
+    #[test]
+    fn clearing_revert_states_removes_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(REVERT_STATES_FILE_NAME);
+        write_revert_states(
+            &path,
+            &HashMap::from([("night".to_string(), persisted_state("Eco"))]),
+        )
+        .unwrap();
+
+        write_revert_states(&path, &HashMap::new()).unwrap();
+
+        assert!(!path.exists());
+        assert!(read_revert_states(&path).is_empty());
+    }
+
+    #[test]
+    fn event_changes_notify_subscribers() {
+        let mut changes = EventScheduler::instance().subscribe_to_changes();
+        assert!(!changes.has_changed().unwrap());
+
+        EventScheduler::notify_events_changed();
+
+        assert!(changes.has_changed().unwrap());
+    }
+
+    #[test]
+    fn malformed_revert_states_are_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(REVERT_STATES_FILE_NAME);
+        std::fs::write(&path, "{not json").unwrap();
 
-        Ok(handle)
+        assert!(read_revert_states(&path).is_empty());
     }
 }
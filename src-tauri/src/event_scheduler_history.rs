@@ -0,0 +1,253 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Persisted execution history of scheduler events.
+//! Every run of an event action and every window missed while the app was closed
+//! is appended to a jsonl file next to the app configs.
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    time::SystemTime,
+};
+
+use dirs::config_dir;
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tokio::{io::AsyncWriteExt, sync::RwLock};
+
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC, event_scheduler::SchedulerEventType};
+
+const HISTORY_FILE_NAME: &str = "scheduler_history.jsonl";
+// A few firings a day per event, this keeps months of history
+const MAX_STORED_EXECUTIONS: usize = 10_000;
+
+static INSTANCE: LazyLock<RwLock<SchedulerHistory>> =
+    LazyLock::new(|| RwLock::new(SchedulerHistory::new()));
+
+/// Which callback of an event ran
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SchedulerExecutionPhase {
+    Enter,
+    Exit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SchedulerExecutionOutcome {
+    Succeeded,
+    /// At least one step of the action failed, see `error`
+    Failed,
+    /// The window started while the app was closed and was not caught up
+    Missed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchedulerExecutionRecord {
+    pub event_id: String,
+    pub fired_at: SystemTime,
+    /// Start of the window the run belongs to, set for catch-up runs and missed windows
+    pub scheduled_at: Option<SystemTime>,
+    pub phase: SchedulerExecutionPhase,
+    pub action: SchedulerEventType,
+    pub outcome: SchedulerExecutionOutcome,
+    pub error: Option<String>,
+}
+
+/// Append-only log of scheduler executions
+pub struct SchedulerHistory {
+    path: PathBuf,
+    last_recorded_at: Option<SystemTime>,
+}
+
+impl SchedulerHistory {
+    fn new() -> Self {
+        Self::with_path(scheduler_data_dir().join(HISTORY_FILE_NAME))
+    }
+
+    fn with_path(path: PathBuf) -> Self {
+        let mut records = read_records(&path);
+        if records.len() > MAX_STORED_EXECUTIONS {
+            records.drain(..records.len() - MAX_STORED_EXECUTIONS);
+            if let Err(e) = write_records(&path, &records) {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to compact scheduler history: {e}");
+            }
+        }
+
+        Self {
+            last_recorded_at: records.iter().map(|record| record.fired_at).max(),
+            path,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    pub async fn record(record: SchedulerExecutionRecord) {
+        Self::current().write().await.append(record).await;
+    }
+
+    async fn append(&mut self, record: SchedulerExecutionRecord) {
+        self.last_recorded_at = self.last_recorded_at.max(Some(record.fired_at));
+        let Ok(serialized) = serde_json::to_string(&record) else {
+            return;
+        };
+        if let Some(parent) = self.path.parent() {
+            let _unused = tokio::fs::create_dir_all(parent).await;
+        }
+        match tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+        {
+            Ok(mut file) => {
+                if let Err(e) = file.write_all(format!("{serialized}\n").as_bytes()).await {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to write scheduler history {:?}: {e:?}", self.path);
+                }
+            }
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to open scheduler history {:?}: {e:?}", self.path);
+            }
+        }
+    }
+
+    /// Time of the most recent record, used as a lower bound of when the app was last running
+    pub async fn last_recorded_at() -> Option<SystemTime> {
+        Self::current().read().await.last_recorded_at
+    }
+
+    /// Executions, newest first
+    /// ### Arguments
+    /// * `event_id` - Only include this event when set
+    /// * `limit` - Maximum number of records to return
+    pub async fn history(
+        event_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SchedulerExecutionRecord>, anyhow::Error> {
+        let path = Self::current().read().await.path.clone();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = tokio::fs::read_to_string(&path).await?;
+        Ok(content
+            .lines()
+            .rev()
+            .filter_map(|line| match serde_json::from_str::<SchedulerExecutionRecord>(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Skipping malformed scheduler history entry: {e}");
+                    None
+                }
+            })
+            .filter(|record| event_id.is_none_or(|event_id| record.event_id == event_id))
+            .take(limit)
+            .collect())
+    }
+}
+
+/// Directory of the persisted scheduler state of the current network
+pub fn scheduler_data_dir() -> PathBuf {
+    config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(APPLICATION_FOLDER_ID)
+        .join("app_configs")
+        .join(Network::get_current_or_user_setting_or_default().as_key_str())
+}
+
+fn read_records(path: &Path) -> Vec<SchedulerExecutionRecord> {
+    let Ok(file) = File::open(path) else {
+        return Vec::new();
+    };
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+fn write_records(path: &Path, records: &[SchedulerExecutionRecord]) -> Result<(), anyhow::Error> {
+    let mut file = File::create(path)?;
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn record(event_id: &str, seconds: u64) -> SchedulerExecutionRecord {
+        SchedulerExecutionRecord {
+            event_id: event_id.to_string(),
+            fired_at: SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+            scheduled_at: None,
+            phase: SchedulerExecutionPhase::Enter,
+            action: SchedulerEventType::RestartNode,
+            outcome: SchedulerExecutionOutcome::Succeeded,
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn appends_and_reloads_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(HISTORY_FILE_NAME);
+
+        let mut history = SchedulerHistory::with_path(path.clone());
+        assert_eq!(history.last_recorded_at, None);
+        history.append(record("night", 60)).await;
+        history.append(record("weekend", 120)).await;
+
+        let reloaded = SchedulerHistory::with_path(path.clone());
+        assert_eq!(
+            reloaded.last_recorded_at,
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(120))
+        );
+        assert_eq!(
+            read_records(&path),
+            vec![record("night", 60), record("weekend", 120)]
+        );
+    }
+
+    #[test]
+    fn compacts_to_the_newest_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(HISTORY_FILE_NAME);
+        let records = (0..MAX_STORED_EXECUTIONS as u64 + 5)
+            .map(|seconds| record("night", seconds))
+            .collect::<Vec<_>>();
+        write_records(&path, &records).unwrap();
+
+        SchedulerHistory::with_path(path.clone());
+
+        let compacted = read_records(&path);
+        assert_eq!(compacted.len(), MAX_STORED_EXECUTIONS);
+        assert_eq!(compacted[0], record("night", 5));
+    }
+}
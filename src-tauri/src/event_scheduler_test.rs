@@ -20,12 +20,18 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use chrono::Local;
+use chrono::{Local, TimeZone, Timelike};
 use test_case::test_case;
 
+use std::time::Duration;
+
 use crate::event_scheduler::{
-    BetweenTimeVariantPayload, CronSchedule, InVariantPayload, SchedulerError, TimePeriod, TimeUnit,
+    BetweenTimeVariantPayload, CronSchedule, IdleTracker, IdleTransition, InVariantPayload,
+    MissedWindows, SchedulerError, SchedulerEventTiming, SchedulerEventType,
+    SchedulerMissedRunPolicy, TimePeriod, TimeUnit, WeeklyVariantPayload, check_event_conflicts,
+    check_idle_detection,
 };
+use crate::idle_source::{IdleSource, StubIdleSource};
 
 // =============================================================================
 // CronSchedule::new tests
@@ -488,3 +494,494 @@ fn between_payload_invalid_minute_returns_error() {
         result
     );
 }
+
+// =============================================================================
+// Idle timing tests
+// =============================================================================
+
+#[test_case(1 ; "minimum")]
+#[test_case(15 ; "quarter hour")]
+#[test_case(1440 ; "maximum")]
+fn idle_variant_valid_range(minutes: i64) {
+    let result = SchedulerEventTiming::parse_idle_variant(minutes);
+
+    assert!(
+        matches!(result, Ok(SchedulerEventTiming::Idle(_))),
+        "Expected {} idle minutes to be valid, but got: {:?}",
+        minutes,
+        result
+    );
+    assert!(result.unwrap().is_persistent());
+}
+
+#[test_case(0 ; "zero")]
+#[test_case(-5 ; "negative")]
+#[test_case(1441 ; "over a day")]
+fn idle_variant_invalid_range(minutes: i64) {
+    let result = SchedulerEventTiming::parse_idle_variant(minutes);
+
+    assert!(
+        matches!(result, Err(SchedulerError::InvalidTimingFormat(_))),
+        "Expected InvalidTimingFormat error for {} idle minutes, but got: {:?}",
+        minutes,
+        result
+    );
+}
+
+#[test]
+fn idle_tracker_reports_only_transitions() {
+    let mut tracker = IdleTracker::new(Duration::from_secs(600));
+
+    assert_eq!(tracker.update(Duration::from_secs(30)), None);
+    assert_eq!(
+        tracker.update(Duration::from_secs(600)),
+        Some(IdleTransition::BecameIdle)
+    );
+    assert_eq!(tracker.update(Duration::from_secs(900)), None);
+    assert_eq!(
+        tracker.update(Duration::from_secs(2)),
+        Some(IdleTransition::BecameActive)
+    );
+    assert_eq!(tracker.update(Duration::from_secs(5)), None);
+}
+
+#[tokio::test]
+async fn idle_tracker_follows_stub_idle_source() {
+    let idle_source = StubIdleSource::default();
+    let mut tracker = IdleTracker::new(Duration::from_secs(60));
+    let mut transitions = Vec::new();
+
+    for idle_secs in [10, 70, 130, 0, 65] {
+        idle_source.set_idle_time(Duration::from_secs(idle_secs));
+        let idle_time = idle_source.idle_time().await.unwrap();
+        transitions.extend(tracker.update(idle_time));
+    }
+
+    assert_eq!(
+        transitions,
+        vec![
+            IdleTransition::BecameIdle,
+            IdleTransition::BecameActive,
+            IdleTransition::BecameIdle
+        ]
+    );
+}
+
+#[test]
+fn idle_timing_needs_idle_detection() {
+    let idle_source = StubIdleSource::default();
+    let idle_timing = SchedulerEventTiming::parse_idle_variant(15).unwrap();
+    let in_timing = SchedulerEventTiming::parse_in_variant(10, TimeUnit::Minutes).unwrap();
+
+    assert!(check_idle_detection(&idle_timing, &idle_source).is_ok());
+
+    idle_source.set_supported(false);
+    assert!(matches!(
+        check_idle_detection(&idle_timing, &idle_source),
+        Err(SchedulerError::InvalidTimingFormat(_))
+    ));
+    assert!(check_idle_detection(&in_timing, &idle_source).is_ok());
+}
+
+// =============================================================================
+// SchedulerEventType action tests
+// =============================================================================
+
+#[test]
+fn action_event_types_survive_persistence() {
+    let event_types = [
+        SchedulerEventType::StopMining,
+        SchedulerEventType::SwitchPool {
+            cpu_pool: Some("KryptexPoolRANDOMX".to_string()),
+            gpu_pool: None,
+        },
+        SchedulerEventType::SetMiningDevices {
+            cpu_enabled: false,
+            gpu_enabled: true,
+        },
+        SchedulerEventType::RestartNode,
+    ];
+
+    for event_type in event_types {
+        let json = serde_json::to_string(&event_type).unwrap();
+        let restored: SchedulerEventType = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, event_type, "Round trip failed for {}", json);
+        assert!(event_type.restores_state_on_exit());
+    }
+}
+
+#[test]
+fn mining_event_types_keep_legacy_exit_behaviour() {
+    assert!(!SchedulerEventType::ResumeMining.restores_state_on_exit());
+    assert!(
+        !SchedulerEventType::Mine {
+            mining_mode: "Eco".to_string()
+        }
+        .restores_state_on_exit()
+    );
+}
+
+#[test]
+fn switch_pool_validates_pool_keys() {
+    let valid = SchedulerEventType::SwitchPool {
+        cpu_pool: Some("LuckyPoolRANDOMX".to_string()),
+        gpu_pool: Some("Custom:my-pool".to_string()),
+    };
+    assert!(valid.validate().is_ok());
+
+    let invalid = [
+        SchedulerEventType::SwitchPool {
+            cpu_pool: None,
+            gpu_pool: None,
+        },
+        SchedulerEventType::SwitchPool {
+            cpu_pool: Some("UnknownPool".to_string()),
+            gpu_pool: None,
+        },
+        SchedulerEventType::SwitchPool {
+            cpu_pool: None,
+            gpu_pool: Some("Custom:".to_string()),
+        },
+    ];
+    for event_type in invalid {
+        let result = event_type.validate();
+        assert!(
+            matches!(result, Err(SchedulerError::InvalidEventPayload(_))),
+            "Expected InvalidEventPayload error for {:?}, but got: {:?}",
+            event_type,
+            result
+        );
+    }
+}
+
+// =============================================================================
+// Cron, Weekly and DateRange timing tests
+// =============================================================================
+
+fn weekly_payload(weekdays: u8, start_hour: u32, end_hour: u32) -> WeeklyVariantPayload {
+    WeeklyVariantPayload {
+        weekdays,
+        start_hour,
+        start_minute: 0,
+        end_hour,
+        end_minute: 0,
+        timezone: None,
+    }
+}
+
+#[test]
+fn weekly_payload_builds_weekday_crons() {
+    let schedule = weekly_payload(WeeklyVariantPayload::WEEKDAYS, 9, 17)
+        .to_cron_schedule()
+        .unwrap();
+
+    assert_eq!(schedule.start_time.pattern.to_string(), "0 9 * * 1,2,3,4,5");
+    assert_eq!(schedule.end_time.pattern.to_string(), "0 17 * * 1,2,3,4,5");
+}
+
+#[test]
+fn weekly_payload_overnight_window_ends_next_day() {
+    let schedule = weekly_payload(
+        WeeklyVariantPayload::FRIDAY | WeeklyVariantPayload::SUNDAY,
+        22,
+        6,
+    )
+    .to_cron_schedule()
+    .unwrap();
+
+    assert_eq!(schedule.start_time.pattern.to_string(), "0 22 * * 5,0");
+    assert_eq!(schedule.end_time.pattern.to_string(), "0 6 * * 6,1");
+}
+
+#[test_case(0, 9, 17 ; "empty mask")]
+#[test_case(0x80, 9, 17 ; "unknown day bit")]
+#[test_case(WeeklyVariantPayload::MONDAY, 24, 17 ; "start hour 24")]
+#[test_case(WeeklyVariantPayload::MONDAY, 9, 25 ; "end hour 25")]
+fn weekly_payload_invalid_returns_error(weekdays: u8, start_hour: u32, end_hour: u32) {
+    let result = weekly_payload(weekdays, start_hour, end_hour).to_cron_schedule();
+
+    assert!(
+        matches!(result, Err(SchedulerError::InvalidTimingFormat(_))),
+        "Expected InvalidTimingFormat error, but got: {:?}",
+        result
+    );
+}
+
+#[test]
+fn timezone_windows_use_the_given_timezone() {
+    let timing = SchedulerEventTiming::parse_weekly_variant(
+        WeeklyVariantPayload::WEEKDAYS | WeeklyVariantPayload::WEEKEND,
+        3,
+        0,
+        4,
+        0,
+        Some("Asia/Tokyo".to_string()),
+    )
+    .unwrap();
+    let schedule = timing.to_cron_schedule().unwrap().unwrap();
+
+    let next_start = schedule.find_next_start_time(Local::now()).unwrap();
+
+    assert_eq!(
+        next_start.with_timezone(&chrono_tz::Asia::Tokyo).hour(),
+        3,
+        "Start should be 3 AM in Tokyo"
+    );
+}
+
+#[test]
+fn unknown_timezone_returns_error() {
+    let result = SchedulerEventTiming::parse_cron_variant(
+        "0 22 * * *".to_string(),
+        "0 6 * * *".to_string(),
+        Some("Mars/Olympus_Mons".to_string()),
+    );
+
+    assert!(
+        matches!(result, Err(SchedulerError::InvalidTimingFormat(_))),
+        "Expected InvalidTimingFormat error, but got: {:?}",
+        result
+    );
+}
+
+#[test]
+fn invalid_raw_cron_returns_error() {
+    let result = SchedulerEventTiming::parse_cron_variant(
+        "0 22 * * *".to_string(),
+        "not a cron".to_string(),
+        None,
+    );
+
+    assert!(
+        matches!(result, Err(SchedulerError::InvalidCronPattern(_))),
+        "Expected InvalidCronPattern error, but got: {:?}",
+        result
+    );
+}
+
+#[test]
+fn date_range_must_end_after_start() {
+    let result = SchedulerEventTiming::parse_date_range_variant(
+        "2099-12-26T00:00:00Z".to_string(),
+        "2099-12-24T00:00:00Z".to_string(),
+    );
+    assert!(matches!(
+        result,
+        Err(SchedulerError::InvalidTimingFormat(_))
+    ));
+
+    let result = SchedulerEventTiming::parse_date_range_variant(
+        "2000-12-24T00:00:00Z".to_string(),
+        "2000-12-26T00:00:00Z".to_string(),
+    );
+    assert!(matches!(
+        result,
+        Err(SchedulerError::InvalidTimingFormat(_))
+    ));
+
+    let result = SchedulerEventTiming::parse_date_range_variant(
+        "2099-12-24T00:00:00+01:00".to_string(),
+        "2099-12-26T00:00:00+01:00".to_string(),
+    );
+    assert!(result.is_ok());
+}
+
+// =============================================================================
+// Conflict detection tests
+// =============================================================================
+
+fn mine(mining_mode: &str) -> SchedulerEventType {
+    SchedulerEventType::Mine {
+        mining_mode: mining_mode.to_string(),
+    }
+}
+
+fn weekly(weekdays: u8, start_hour: u32, end_hour: u32) -> SchedulerEventTiming {
+    SchedulerEventTiming::Weekly(weekly_payload(weekdays, start_hour, end_hour))
+}
+
+#[test]
+fn overlapping_mining_windows_conflict() {
+    let existing_type = mine("Eco");
+    let existing_timing = weekly(WeeklyVariantPayload::WEEKDAYS, 9, 17);
+
+    let result = check_event_conflicts(
+        &mine("Ludicrous"),
+        &weekly(WeeklyVariantPayload::FRIDAY, 16, 20),
+        [("office_hours", &existing_type, &existing_timing)],
+        Local::now(),
+    );
+
+    match result {
+        Err(SchedulerError::EventConflict(message)) => {
+            assert!(
+                message.contains("office_hours"),
+                "Unexpected message: {message}"
+            );
+        }
+        other => panic!("Expected EventConflict error, but got: {:?}", other),
+    }
+}
+
+#[test]
+fn adjacent_or_independent_windows_do_not_conflict() {
+    let existing_type = mine("Eco");
+    let existing_timing = weekly(WeeklyVariantPayload::WEEKDAYS, 9, 17);
+
+    let cases = [
+        (
+            mine("Ludicrous"),
+            weekly(WeeklyVariantPayload::WEEKEND, 9, 17),
+        ),
+        (
+            SchedulerEventType::StopMining,
+            weekly(WeeklyVariantPayload::WEEKDAYS, 17, 9),
+        ),
+        (
+            SchedulerEventType::RestartNode,
+            weekly(WeeklyVariantPayload::MONDAY, 10, 11),
+        ),
+        (
+            SchedulerEventType::SwitchPool {
+                cpu_pool: Some("KryptexPoolRANDOMX".to_string()),
+                gpu_pool: None,
+            },
+            weekly(WeeklyVariantPayload::MONDAY, 10, 11),
+        ),
+    ];
+
+    for (event_type, timing) in cases {
+        let result = check_event_conflicts(
+            &event_type,
+            &timing,
+            [("office_hours", &existing_type, &existing_timing)],
+            Local::now(),
+        );
+        assert!(
+            result.is_ok(),
+            "Expected no conflict for {}, but got: {:?}",
+            event_type,
+            result
+        );
+    }
+}
+
+#[test]
+fn date_range_overlapping_daily_window_conflicts() {
+    let existing_type = SchedulerEventType::StopMining;
+    let existing_timing = SchedulerEventTiming::parse_cron_variant(
+        "0 9 * * *".to_string(),
+        "0 17 * * *".to_string(),
+        None,
+    )
+    .unwrap();
+    let start = Local::now() + chrono::Duration::days(2);
+    let date_range = SchedulerEventTiming::parse_date_range_variant(
+        start.to_rfc3339(),
+        (start + chrono::Duration::days(1)).to_rfc3339(),
+    )
+    .unwrap();
+
+    let result = check_event_conflicts(
+        &mine("Ludicrous"),
+        &date_range,
+        [("daytime_pause", &existing_type, &existing_timing)],
+        Local::now(),
+    );
+
+    assert!(
+        matches!(result, Err(SchedulerError::EventConflict(_))),
+        "Expected EventConflict error, but got: {:?}",
+        result
+    );
+}
+
+// =============================================================================
+// Missed run tests
+// =============================================================================
+
+fn local_time(day: u32, hour: u32) -> chrono::DateTime<Local> {
+    // January 2026 starts on a Thursday, the 5th is a Monday
+    Local.with_ymd_and_hms(2026, 1, day, hour, 0, 0).unwrap()
+}
+
+#[test]
+fn missed_windows_are_counted_since_last_active() {
+    let timing = weekly(
+        WeeklyVariantPayload::WEEKDAYS | WeeklyVariantPayload::WEEKEND,
+        22,
+        6,
+    );
+
+    let missed = timing
+        .missed_windows(Some(local_time(5, 12)), local_time(8, 12))
+        .unwrap();
+    assert_eq!(
+        missed.starts,
+        vec![local_time(5, 22), local_time(6, 22), local_time(7, 22)]
+    );
+    assert!(!missed.is_window_open);
+
+    let missed = timing
+        .missed_windows(Some(local_time(5, 12)), local_time(8, 23))
+        .unwrap();
+    assert_eq!(missed.starts.len(), 4);
+    assert!(missed.is_window_open);
+
+    let missed = timing.missed_windows(None, local_time(8, 23)).unwrap();
+    assert!(missed.starts.is_empty());
+    assert!(missed.is_window_open);
+}
+
+#[test]
+fn window_end_is_exclusive() {
+    let timing = weekly(
+        WeeklyVariantPayload::WEEKDAYS | WeeklyVariantPayload::WEEKEND,
+        22,
+        6,
+    );
+
+    assert!(!timing.has_window_ended_at(local_time(6, 5)));
+    assert!(timing.has_window_ended_at(local_time(6, 6)));
+    assert!(timing.has_window_ended_at(local_time(6, 12)));
+    assert!(!timing.has_window_ended_at(local_time(6, 22)));
+    assert!(!timing.has_window_ended_at(local_time(6, 23)));
+    assert!(
+        !SchedulerEventTiming::parse_idle_variant(15)
+            .unwrap()
+            .has_window_ended_at(local_time(6, 6))
+    );
+}
+
+#[test]
+fn idle_timing_never_misses_windows() {
+    let timing = SchedulerEventTiming::parse_idle_variant(15).unwrap();
+
+    let missed = timing
+        .missed_windows(Some(local_time(5, 12)), local_time(8, 12))
+        .unwrap();
+
+    assert_eq!(missed, MissedWindows::default());
+}
+
+#[test_case(SchedulerMissedRunPolicy::Skip, mine("Eco"), 3, true, false ; "skip ignores open window")]
+#[test_case(SchedulerMissedRunPolicy::CatchUp, mine("Eco"), 3, true, true ; "catch up enters open window")]
+#[test_case(SchedulerMissedRunPolicy::CatchUp, mine("Eco"), 3, false, false ; "catch up skips ended mining window")]
+#[test_case(SchedulerMissedRunPolicy::CatchUp, SchedulerEventType::RestartNode, 1, false, true ; "catch up runs ended node restart")]
+#[test_case(SchedulerMissedRunPolicy::CatchUp, SchedulerEventType::ResumeMining, 0, false, false ; "catch up without missed windows")]
+fn missed_run_policy_decides_catch_up(
+    policy: SchedulerMissedRunPolicy,
+    event_type: SchedulerEventType,
+    missed_count: u32,
+    is_window_open: bool,
+    expected: bool,
+) {
+    let missed = MissedWindows {
+        starts: (0..missed_count)
+            .map(|day| local_time(5 + day, 22))
+            .collect(),
+        is_window_open,
+    };
+
+    assert_eq!(policy.should_catch_up(&event_type, &missed), expected);
+}
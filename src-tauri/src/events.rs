@@ -28,7 +28,10 @@ use std::{
 
 use crate::{
     internal_wallet::TariAddressType,
-    mining::gpu::miners::GpuCommonInformation,
+    mining::{
+        gpu::miners::GpuCommonInformation,
+        pools::failover::{PoolFailoverKind, PoolFailoverReason},
+    },
     node::{node_adapter::NodeIdentity, node_manager::NodeType},
     setup::{listeners::AppModule, setup_manager::SetupPhase},
     wallet::wallet_types::TransactionInfo,
@@ -41,6 +44,8 @@ pub enum EventType {
     GpuDevicesUpdate,
     CpuPoolsStatsUpdate,
     GpuPoolsStatsUpdate,
+    PoolFailover,
+    ThermalThrottle,
     CpuMiningUpdate,
     GpuMiningUpdate,
     NewBlockHeight,
@@ -83,6 +88,7 @@ pub enum EventType {
     UpdateTorEntryGuards,
     UpdateAppModuleStatus,
     UpdateSelectedMiner,
+    UpdateSelectedCpuMiner,
     AvailableMiners,
     WalletStatusUpdate,
     UpdateCpuMinerControlsState,
@@ -162,6 +168,14 @@ pub struct CriticalProblemPayload {
     pub error_message: Option<String>,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct PoolFailoverPayload {
+    pub kind: PoolFailoverKind,
+    pub from_pool: String,
+    pub to_pool: String,
+    pub reason: PoolFailoverReason,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct NodeTypeUpdatePayload {
     pub node_type: Option<NodeType>,
@@ -25,11 +25,13 @@ use crate::configs::config_pools::ConfigPoolsContent;
 use crate::configs::config_ui::WalletUIMode;
 use crate::events::{
     ConnectionStatusPayload, CriticalProblemPayload, DisabledPhasesPayload,
-    InitWalletScanningProgressPayload, UpdateAppModuleStatusPayload, WalletStatusUpdatePayload,
+    InitWalletScanningProgressPayload, PoolFailoverPayload, UpdateAppModuleStatusPayload,
+    WalletStatusUpdatePayload,
 };
 use crate::internal_wallet::TariAddressType;
 use crate::mining::MinerControlsState;
 use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::cpu::consts::CpuMinerType;
 use crate::mining::gpu::consts::{GpuMiner, GpuMinerStatus, GpuMinerType};
 use crate::mining::gpu::miners::GpuCommonInformation;
 use crate::mining::pools::PoolStatus;
@@ -48,7 +50,9 @@ use crate::{
         NodeTypeUpdatePayload, ProgressTrackerUpdatePayload, ShowReleaseNotesPayload,
         TariAddressUpdatePayload,
     },
-    hardware::hardware_status_monitor::PublicDeviceGpuProperties,
+    hardware::{
+        hardware_status_monitor::PublicDeviceGpuProperties, thermal_throttle::ThermalThrottleEvent,
+    },
     setup::setup_manager::SetupPhase,
     utils::app_flow_utils::FrontendReadyChannel,
 };
@@ -219,6 +223,20 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_update_selected_cpu_miner(payload: CpuMinerType) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::UpdateSelectedCpuMiner,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit UpdateSelectedCpuMiner event: {e:?}");
+        }
+    }
+
     pub async fn emit_available_gpu_miners(payload: HashMap<GpuMinerType, GpuMiner>) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
@@ -476,6 +494,34 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_pool_failover(payload: PoolFailoverPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::PoolFailover,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit PoolFailover event: {e:?}");
+        }
+    }
+
+    pub async fn emit_thermal_throttle(payload: ThermalThrottleEvent) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::ThermalThrottle,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit ThermalThrottle event: {e:?}");
+        }
+    }
+
     pub async fn emit_gpu_pools_status_update(pool_status: HashMap<String, PoolStatus>) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
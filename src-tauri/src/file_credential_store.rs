@@ -20,40 +20,180 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-// File-backed credential store for test-mode only.
-// Stores credentials as files on disk so wallet state persists across
-// binary restarts during Playwright tests. NOT for production use.
-
-#![cfg(feature = "test-mode")]
+// File-backed credential store.
+// Stores credentials as encrypted 0600 files on disk so wallet state persists
+// across binary restarts during Playwright tests, and on servers without a
+// keyring when the daemon is started with `--file-credentials`. Desktop builds
+// keep using the OS keyring.
+//
+// Secrets are sealed with ChaCha20-Poly1305 under a key derived from a
+// passphrase with PBKDF2, the salt and a passphrase check are kept in the
+// store directory. Each file is `nonce || ciphertext || tag`, the file name is
+// the associated data so files can't be swapped between entries.
 
 use keyring::Error;
 use keyring::credential::{
     Credential, CredentialApi, CredentialBuilder, CredentialBuilderApi, CredentialPersistence,
 };
 use keyring::error::Result;
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
 use std::fs;
 use std::io::Write;
-use std::path::PathBuf;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
-static STORE_DIR: OnceLock<PathBuf> = OnceLock::new();
+const SALT_FILE_NAME: &str = "salt";
+const CHECK_FILE_NAME: &str = "check";
+const CHECK_PLAINTEXT: &[u8] = b"tari-universe-file-credentials";
+const SALT_LEN: usize = 16;
+#[cfg(not(test))]
+const PBKDF2_ITERATIONS: u32 = 600_000;
+#[cfg(test)]
+const PBKDF2_ITERATIONS: u32 = 1_000;
+
+static STORE: OnceLock<FileCredentialStore> = OnceLock::new();
+
+/// Directory and key of the encrypted credential files
+struct FileCredentialStore {
+    dir: PathBuf,
+    key: LessSafeKey,
+}
+
+impl FileCredentialStore {
+    /// Opens the store in `dir`, creating the salt on first use.
+    /// Fails when the passphrase doesn't match the one the store was created with.
+    fn open(dir: PathBuf, passphrase: &[u8]) -> std::result::Result<Self, anyhow::Error> {
+        if passphrase.is_empty() {
+            return Err(anyhow::anyhow!("Credential store passphrase is empty"));
+        }
+        create_private_dir(&dir)?;
+
+        let salt_path = dir.join(SALT_FILE_NAME);
+        let is_new = !salt_path.exists();
+        let salt = if is_new {
+            let mut salt = [0u8; SALT_LEN];
+            SystemRandom::new()
+                .fill(&mut salt)
+                .map_err(|_| anyhow::anyhow!("Failed to generate credential store salt"))?;
+            write_private_file(&salt_path, &salt)?;
+            salt.to_vec()
+        } else {
+            fs::read(&salt_path)?
+        };
+
+        let mut key_bytes = [0u8; 32];
+        pbkdf2::derive(
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            NonZeroU32::new(PBKDF2_ITERATIONS).expect("iterations are non-zero"),
+            &salt,
+            passphrase,
+            &mut key_bytes,
+        );
+        let key = LessSafeKey::new(
+            UnboundKey::new(&CHACHA20_POLY1305, &key_bytes)
+                .map_err(|_| anyhow::anyhow!("Failed to create credential store key"))?,
+        );
+        let store = Self { dir, key };
+
+        let check_path = store.dir.join(CHECK_FILE_NAME);
+        if is_new || !check_path.exists() {
+            write_private_file(&check_path, &store.seal(CHECK_FILE_NAME, CHECK_PLAINTEXT)?)?;
+            return Ok(store);
+        }
+        if store.unseal(CHECK_FILE_NAME, &fs::read(&check_path)?)? != CHECK_PLAINTEXT {
+            return Err(anyhow::anyhow!("Credential store check failed"));
+        }
+        Ok(store)
+    }
+
+    fn seal(&self, name: &str, secret: &[u8]) -> std::result::Result<Vec<u8>, anyhow::Error> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| anyhow::anyhow!("Failed to generate nonce"))?;
+        let mut sealed = secret.to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(name.as_bytes()),
+                &mut sealed,
+            )
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt credential"))?;
+        Ok([nonce.as_slice(), &sealed].concat())
+    }
 
-/// Set the directory where credential files are stored.
+    fn unseal(&self, name: &str, data: &[u8]) -> std::result::Result<Vec<u8>, anyhow::Error> {
+        if data.len() < NONCE_LEN {
+            return Err(anyhow::anyhow!("Credential file {name} is truncated"));
+        }
+        let (nonce, sealed) = data.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| anyhow::anyhow!("Invalid nonce in credential file {name}"))?;
+        let mut sealed = sealed.to_vec();
+        let secret = self
+            .key
+            .open_in_place(nonce, Aad::from(name.as_bytes()), &mut sealed)
+            .map_err(|_| {
+                anyhow::anyhow!("Credential file {name} can't be decrypted, wrong passphrase?")
+            })?;
+        Ok(secret.to_vec())
+    }
+}
+
+/// Opens the encrypted store in `dir` with the given passphrase.
 /// Must be called before any keyring operations.
-pub fn set_store_dir(dir: PathBuf) {
-    drop(STORE_DIR.set(dir));
+pub fn init(dir: PathBuf, passphrase: &[u8]) -> std::result::Result<(), anyhow::Error> {
+    let store = FileCredentialStore::open(dir, passphrase)?;
+    drop(STORE.set(store));
+    Ok(())
 }
 
-fn store_dir() -> &'static PathBuf {
-    STORE_DIR
+fn store() -> &'static FileCredentialStore {
+    STORE
         .get()
-        .expect("file_credential_store::set_store_dir must be called before use")
+        .expect("file_credential_store::init must be called before use")
+}
+
+fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(dir, fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(())
 }
 
-/// A credential backed by a file on disk.
+fn write_private_file(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        file.write_all(content)
+    }
+    #[cfg(not(unix))]
+    {
+        fs::write(path, content)
+    }
+}
+
+fn platform_failure(e: impl Into<anyhow::Error>) -> Error {
+    let e: anyhow::Error = e.into();
+    Error::PlatformFailure(e.into())
+}
+
+/// A credential backed by an encrypted file on disk.
 #[derive(Debug)]
 struct FileCredential {
-    path: PathBuf,
+    name: String,
 }
 
 impl FileCredential {
@@ -63,11 +203,14 @@ impl FileCredential {
         hasher.update(format!("{service}_{user}").as_bytes());
         let hash = hasher.finalize();
         // Use first 16 bytes (32 hex chars) for a short but unique filename
-        let safe_filename = format!("{}.bin", hex::encode(&hash[..16]));
         Ok(Self {
-            path: store_dir().join(safe_filename),
+            name: format!("{}.bin", hex::encode(&hash[..16])),
         })
     }
+
+    fn path(&self) -> PathBuf {
+        store().dir.join(&self.name)
+    }
 }
 
 impl CredentialApi for FileCredential {
@@ -76,25 +219,10 @@ impl CredentialApi for FileCredential {
     }
 
     fn set_secret(&self, secret: &[u8]) -> Result<()> {
-        fs::create_dir_all(store_dir()).map_err(|e| Error::PlatformFailure(Box::new(e)))?;
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::OpenOptionsExt;
-            let mut file = fs::OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .mode(0o600)
-                .open(&self.path)
-                .map_err(|e| Error::PlatformFailure(Box::new(e)))?;
-            file.write_all(secret)
-                .map_err(|e| Error::PlatformFailure(Box::new(e)))?;
-        }
-        #[cfg(not(unix))]
-        {
-            fs::write(&self.path, secret).map_err(|e| Error::PlatformFailure(Box::new(e)))?;
-        }
-        Ok(())
+        let store = store();
+        create_private_dir(&store.dir).map_err(platform_failure)?;
+        let sealed = store.seal(&self.name, secret).map_err(platform_failure)?;
+        write_private_file(&self.path(), &sealed).map_err(platform_failure)
     }
 
     fn get_password(&self) -> Result<String> {
@@ -103,15 +231,15 @@ impl CredentialApi for FileCredential {
     }
 
     fn get_secret(&self) -> Result<Vec<u8>> {
-        match fs::read(&self.path) {
-            Ok(v) => Ok(v),
+        match fs::read(self.path()) {
+            Ok(data) => store().unseal(&self.name, &data).map_err(platform_failure),
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(Error::NoEntry),
             Err(e) => Err(Error::PlatformFailure(Box::new(e))),
         }
     }
 
     fn delete_credential(&self) -> Result<()> {
-        match fs::remove_file(&self.path) {
+        match fs::remove_file(self.path()) {
             Ok(()) => Ok(()),
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(Error::NoEntry),
             Err(e) => Err(Error::PlatformFailure(Box::new(e))),
@@ -123,7 +251,7 @@ impl CredentialApi for FileCredential {
     }
 
     fn debug_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "FileCredential({:?})", self.path)
+        write!(f, "FileCredential({:?})", self.name)
     }
 }
 
@@ -144,7 +272,44 @@ impl CredentialBuilderApi for FileCredentialBuilder {
     }
 }
 
-/// Returns a file-backed credential builder for use in test-mode.
+/// Returns the encrypted file-backed credential builder, see [`init`].
 pub fn default_credential_builder() -> Box<CredentialBuilder> {
     Box::new(FileCredentialBuilder)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_secrets_round_trip_and_are_not_plaintext() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileCredentialStore::open(dir.path().to_path_buf(), b"passphrase").unwrap();
+
+        let sealed = store.seal("wallet.bin", b"seed words").unwrap();
+
+        assert!(!sealed.windows(10).any(|window| window == b"seed words"));
+        assert_eq!(store.unseal("wallet.bin", &sealed).unwrap(), b"seed words");
+        assert!(store.unseal("other.bin", &sealed).is_err());
+    }
+
+    #[test]
+    fn reopening_requires_the_same_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileCredentialStore::open(dir.path().to_path_buf(), b"passphrase").unwrap();
+        let sealed = store.seal("wallet.bin", b"seed words").unwrap();
+
+        assert!(FileCredentialStore::open(dir.path().to_path_buf(), b"wrong").is_err());
+        let reopened = FileCredentialStore::open(dir.path().to_path_buf(), b"passphrase").unwrap();
+        assert_eq!(
+            reopened.unseal("wallet.bin", &sealed).unwrap(),
+            b"seed words"
+        );
+    }
+
+    #[test]
+    fn empty_passphrase_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(FileCredentialStore::open(dir.path().to_path_buf(), b"").is_err());
+    }
+}
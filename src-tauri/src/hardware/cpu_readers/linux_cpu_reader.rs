@@ -0,0 +1,162 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Error, anyhow};
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::{
+    hardware::{
+        hardware_status_monitor::DeviceParameters,
+        linux_sysfs::{CpuTimes, LinuxSysfs},
+    },
+    utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
+};
+
+use super::CpuParametersReader;
+
+// Usage needs two samples of /proc/stat, this is the gap when there is no earlier sample.
+// Reads closer together than this belong to the same poll cycle and share its sample.
+const CPU_USAGE_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Clone, Copy)]
+struct UsageSample {
+    cpu_times: CpuTimes,
+    taken_at: Instant,
+    usage: f32,
+}
+
+/// Reads CPU temperature from hwmon and usage from /proc/stat
+/// One reader is shared by all cores, which all report the overall usage
+#[derive(Clone)]
+pub struct LinuxCpuParametersReader {
+    sysfs: LinuxSysfs,
+    last_sample: Arc<Mutex<Option<UsageSample>>>,
+}
+
+impl LinuxCpuParametersReader {
+    pub fn new() -> Self {
+        Self::with_sysfs(LinuxSysfs::new())
+    }
+
+    pub fn with_sysfs(sysfs: LinuxSysfs) -> Self {
+        Self {
+            sysfs,
+            last_sample: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn read_usage(&self) -> Result<f32, Error> {
+        let mut last_sample = self.last_sample.lock().await;
+        let previous = match *last_sample {
+            // Another core already sampled in this poll cycle
+            Some(sample) if sample.taken_at.elapsed() < CPU_USAGE_SAMPLE_INTERVAL => {
+                return Ok(sample.usage);
+            }
+            Some(sample) => sample.cpu_times,
+            None => {
+                let first = self
+                    .sysfs
+                    .cpu_times()
+                    .ok_or_else(|| anyhow!("Failed to read /proc/stat"))?;
+                tokio::time::sleep(CPU_USAGE_SAMPLE_INTERVAL).await;
+                first
+            }
+        };
+        let current = self
+            .sysfs
+            .cpu_times()
+            .ok_or_else(|| anyhow!("Failed to read /proc/stat"))?;
+        let usage = current.usage_since(&previous);
+        *last_sample = Some(UsageSample {
+            cpu_times: current,
+            taken_at: Instant::now(),
+            usage,
+        });
+        Ok(usage)
+    }
+}
+
+#[async_trait]
+impl CpuParametersReader for LinuxCpuParametersReader {
+    fn get_is_reader_implemented(&self) -> bool {
+        matches!(
+            PlatformUtils::detect_current_os(),
+            CurrentOperatingSystem::Linux
+        )
+    }
+
+    async fn get_device_parameters(
+        &self,
+        old_device_parameters: Option<DeviceParameters>,
+    ) -> Result<DeviceParameters, Error> {
+        let usage_percentage = self.read_usage().await?;
+        let current_temperature = self.sysfs.cpu_temperature().unwrap_or_default();
+
+        Ok(DeviceParameters {
+            usage_percentage,
+            current_temperature,
+            max_temperature: old_device_parameters.map_or(current_temperature, |old| {
+                old.max_temperature.max(current_temperature)
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_stat(root: &std::path::Path, user: u64, idle: u64) {
+        std::fs::create_dir_all(root.join("proc")).unwrap();
+        std::fs::write(
+            root.join("proc/stat"),
+            format!("cpu  {user} 0 0 {idle} 0 0 0 0 0 0\ncpu0 {user} 0 0 {idle} 0 0 0 0 0 0\n"),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn every_core_of_a_poll_cycle_gets_the_same_usage() {
+        let dir = tempfile::tempdir().unwrap();
+        write_stat(dir.path(), 100, 100);
+        let reader =
+            LinuxCpuParametersReader::with_sysfs(LinuxSysfs::with_root(dir.path().to_path_buf()));
+        // Devices share the reader like the hardware monitor does
+        let devices: Vec<LinuxCpuParametersReader> = (0..4).map(|_| reader.clone()).collect();
+        reader.read_usage().await.unwrap();
+
+        for (user, idle, expected) in [(150, 150, 50.0), (225, 175, 75.0)] {
+            write_stat(dir.path(), user, idle);
+            tokio::time::sleep(CPU_USAGE_SAMPLE_INTERVAL).await;
+            for device in &devices {
+                let usage = device.read_usage().await.unwrap();
+                assert!((usage - expected).abs() < f32::EPSILON);
+            }
+        }
+    }
+}
@@ -23,6 +23,7 @@
 pub mod amd_cpu_reader;
 pub mod apple_cpu_reader;
 pub mod intel_cpu_reader;
+pub mod linux_cpu_reader;
 
 use super::hardware_status_monitor::DeviceParameters;
 use anyhow::Error;
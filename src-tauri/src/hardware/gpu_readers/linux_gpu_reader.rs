@@ -0,0 +1,152 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use anyhow::{Error, anyhow};
+use async_trait::async_trait;
+
+use crate::{
+    hardware::{hardware_status_monitor::DeviceParameters, linux_sysfs::LinuxSysfs},
+    utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
+};
+
+use super::GpuParametersReader;
+
+/// Reads GPU usage and temperature from /sys/class/drm, used for non NVIDIA cards
+#[derive(Clone)]
+pub struct LinuxGpuReader {
+    sysfs: LinuxSysfs,
+    vendor_id: &'static str,
+    // Position of the card among the cards of the same vendor
+    vendor_device_index: usize,
+}
+
+impl LinuxGpuReader {
+    pub fn new(vendor_id: &'static str, vendor_device_index: usize) -> Self {
+        Self::with_sysfs(LinuxSysfs::new(), vendor_id, vendor_device_index)
+    }
+
+    pub fn with_sysfs(
+        sysfs: LinuxSysfs,
+        vendor_id: &'static str,
+        vendor_device_index: usize,
+    ) -> Self {
+        Self {
+            sysfs,
+            vendor_id,
+            vendor_device_index,
+        }
+    }
+}
+
+#[async_trait]
+impl GpuParametersReader for LinuxGpuReader {
+    fn get_is_reader_implemented(&self) -> bool {
+        matches!(
+            PlatformUtils::detect_current_os(),
+            CurrentOperatingSystem::Linux
+        )
+    }
+
+    async fn get_device_parameters(
+        &self,
+        old_device_parameters: Option<DeviceParameters>,
+    ) -> Result<DeviceParameters, Error> {
+        let device = self
+            .sysfs
+            .gpu_devices(self.vendor_id)
+            .into_iter()
+            .nth(self.vendor_device_index)
+            .ok_or_else(|| {
+                anyhow!(
+                    "GPU {} of vendor {} not found in sysfs",
+                    self.vendor_device_index,
+                    self.vendor_id
+                )
+            })?;
+        let current_temperature = self.sysfs.gpu_temperature(&device).unwrap_or_default();
+
+        Ok(DeviceParameters {
+            usage_percentage: self.sysfs.gpu_usage(&device).unwrap_or_default(),
+            current_temperature,
+            max_temperature: old_device_parameters.map_or(current_temperature, |old| {
+                old.max_temperature.max(current_temperature)
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+    use crate::hardware::linux_sysfs::AMD_PCI_VENDOR_ID;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[tokio::test]
+    async fn reads_parameters_of_nth_vendor_card() {
+        let root = tempfile::tempdir().unwrap();
+        for (card, busy, temperature) in [("card0", "10", "40000"), ("card2", "95", "71000")] {
+            write(
+                root.path(),
+                &format!("sys/class/drm/{card}/device/vendor"),
+                "0x1002\n",
+            );
+            write(
+                root.path(),
+                &format!("sys/class/drm/{card}/device/gpu_busy_percent"),
+                busy,
+            );
+            write(
+                root.path(),
+                &format!("sys/class/drm/{card}/device/hwmon/hwmon0/temp1_input"),
+                temperature,
+            );
+        }
+        let sysfs = LinuxSysfs::with_root(root.path().to_path_buf());
+        let reader = LinuxGpuReader::with_sysfs(sysfs.clone(), AMD_PCI_VENDOR_ID, 1);
+
+        let parameters = reader
+            .get_device_parameters(Some(DeviceParameters {
+                usage_percentage: 0.0,
+                current_temperature: 0.0,
+                max_temperature: 80.0,
+            }))
+            .await
+            .unwrap();
+
+        assert!((parameters.usage_percentage - 95.0).abs() < f32::EPSILON);
+        assert!((parameters.current_temperature - 71.0).abs() < f32::EPSILON);
+        assert!((parameters.max_temperature - 80.0).abs() < f32::EPSILON);
+        assert!(
+            LinuxGpuReader::with_sysfs(sysfs, AMD_PCI_VENDOR_ID, 2)
+                .get_device_parameters(None)
+                .await
+                .is_err()
+        );
+    }
+}
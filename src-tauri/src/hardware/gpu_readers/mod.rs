@@ -23,6 +23,7 @@
 pub mod amd_gpu_reader;
 pub mod apple_gpu_reader;
 pub mod intel_gpu_reader;
+pub mod linux_gpu_reader;
 pub mod nvidia_gpu_reader;
 
 use anyhow::Error;
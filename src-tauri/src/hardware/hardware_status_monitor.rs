@@ -20,7 +20,14 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{path::PathBuf, sync::LazyLock};
+use std::{
+    path::PathBuf,
+    sync::{
+        LazyLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use crate::{
     APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC,
@@ -29,26 +36,43 @@ use crate::{
         trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
-    hardware::{cpu_readers::DefaultCpuParametersReader, gpu_readers::DefaultGpuParametersReader},
+    hardware::{
+        cpu_readers::DefaultCpuParametersReader,
+        gpu_readers::DefaultGpuParametersReader,
+        thermal_throttle::{
+            ThermalThrottleController, ThermalThrottleSettings, ThrottledDeviceKind,
+        },
+    },
+    mining::{cpu::manager::CpuManager, gpu::manager::GpuManager},
+    tasks_tracker::TasksTrackers,
+    utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
 use super::{
     cpu_readers::{
         CpuParametersReader, amd_cpu_reader::AmdCpuParametersReader,
         apple_cpu_reader::AppleCpuParametersReader, intel_cpu_reader::IntelCpuParametersReader,
+        linux_cpu_reader::LinuxCpuParametersReader,
     },
     gpu_readers::{
         GpuParametersReader, amd_gpu_reader::AmdGpuReader, apple_gpu_reader::AppleGpuReader,
-        intel_gpu_reader::IntelGpuReader, nvidia_gpu_reader::NvidiaGpuReader,
+        intel_gpu_reader::IntelGpuReader, linux_gpu_reader::LinuxGpuReader,
+        nvidia_gpu_reader::NvidiaGpuReader,
     },
+    linux_sysfs::{AMD_PCI_VENDOR_ID, INTEL_PCI_VENDOR_ID},
 };
 use anyhow::Error;
 use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use sysinfo::{CpuRefreshKind, RefreshKind, System};
-use tokio::sync::RwLock;
+use tokio::{
+    sync::RwLock,
+    time::{Instant, MissedTickBehavior, interval},
+};
 
 static INSTANCE: LazyLock<HardwareStatusMonitor> = LazyLock::new(HardwareStatusMonitor::new);
+static IS_THERMAL_THROTTLE_LOOP_STARTED: AtomicBool = AtomicBool::new(false);
+const THERMAL_THROTTLE_INTERVAL: Duration = Duration::from_secs(15);
 
 #[derive(Debug, Serialize, Clone, Default, PartialEq)]
 pub enum HardwareVendor {
@@ -190,11 +214,25 @@ impl HardwareStatusMonitor {
         }
     }
 
+    /// ### Arguments
+    /// * `vendor_device_index` - Position of the device among the devices of the same vendor
     async fn select_reader_for_gpu_device(
         &self,
         vendor: HardwareVendor,
+        vendor_device_index: usize,
     ) -> Box<dyn GpuParametersReader> {
+        let is_linux = matches!(
+            PlatformUtils::detect_current_os(),
+            CurrentOperatingSystem::Linux
+        );
         match vendor {
+            HardwareVendor::Amd if is_linux => {
+                Box::new(LinuxGpuReader::new(AMD_PCI_VENDOR_ID, vendor_device_index))
+            }
+            HardwareVendor::Intel if is_linux => Box::new(LinuxGpuReader::new(
+                INTEL_PCI_VENDOR_ID,
+                vendor_device_index,
+            )),
             HardwareVendor::Nvidia => Box::new(NvidiaGpuReader::new()),
             HardwareVendor::Amd => Box::new(AmdGpuReader::new()),
             HardwareVendor::Intel => Box::new(IntelGpuReader::new()),
@@ -211,12 +249,18 @@ impl HardwareStatusMonitor {
             .expect("Could not get config dir")
             .join(APPLICATION_FOLDER_ID);
         let gpu_status_file_content = self.load_gpu_devices_from_status_file(config_dir).await?;
-        let mut platform_devices = Vec::new();
+        let mut platform_devices: Vec<GpuDeviceProperties> = Vec::new();
 
         for gpu_device in &gpu_status_file_content.devices {
             debug!(target: LOG_TARGET_APP_LOGIC, "GPU device name: {:?}", gpu_device.name);
             let vendor = HardwareVendor::from_string(&gpu_device.name);
-            let device_reader = self.select_reader_for_gpu_device(vendor.clone()).await;
+            let vendor_device_index = platform_devices
+                .iter()
+                .filter(|device| device.public_properties.vendor == vendor)
+                .count();
+            let device_reader = self
+                .select_reader_for_gpu_device(vendor.clone(), vendor_device_index)
+                .await;
             let platform_device = GpuDeviceProperties {
                 private_properties: PrivateGpuDeviceProperties {
                     device_reader: device_reader.clone(),
@@ -251,7 +295,14 @@ impl HardwareStatusMonitor {
         &self,
         vendor: HardwareVendor,
     ) -> Box<dyn CpuParametersReader> {
+        let is_linux = matches!(
+            PlatformUtils::detect_current_os(),
+            CurrentOperatingSystem::Linux
+        );
         match vendor {
+            HardwareVendor::Amd | HardwareVendor::Intel if is_linux => {
+                Box::new(LinuxCpuParametersReader::new())
+            }
             HardwareVendor::Amd => Box::new(AmdCpuParametersReader::new()),
             HardwareVendor::Intel => Box::new(IntelCpuParametersReader::new()),
             HardwareVendor::Apple => Box::new(AppleCpuParametersReader::new()),
@@ -268,13 +319,15 @@ impl HardwareStatusMonitor {
 
         let mut cpu_devices = vec![];
 
+        let vendor = HardwareVendor::Intel;
+        // Shared by all cores, so readers which keep samples between reads keep a single history
+        let device_reader = self.select_reader_for_cpu_device(vendor.clone()).await;
+
         for cpu_device in system.cpus() {
             debug!(target: LOG_TARGET_APP_LOGIC, "CPU brand: {:?}", cpu_device.brand());
             debug!(target: LOG_TARGET_APP_LOGIC, "CPU vendor: {:?}", cpu_device.vendor_id());
             debug!(target: LOG_TARGET_APP_LOGIC, "CPU model: {:?}", cpu_device.name());
 
-            let vendor = HardwareVendor::Intel;
-            let device_reader = self.select_reader_for_cpu_device(vendor.clone()).await;
             let platform_device = CpuDeviceProperties {
                 private_properties: PrivateCpuDeviceProperties {
                     device_reader: device_reader.clone(),
@@ -395,6 +448,132 @@ impl HardwareStatusMonitor {
         Ok(())
     }
 
+    /// Start the loop which lowers CPU threads or GPU intensity when devices cross
+    /// the temperature ceilings from the mining config, it is started only once per app run
+    pub async fn start_thermal_throttle_loop(&'static self) {
+        if IS_THERMAL_THROTTLE_LOOP_STARTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut controller = ThermalThrottleController::default();
+                let mut ticker = interval(THERMAL_THROTTLE_INTERVAL);
+                ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+                loop {
+                    tokio::select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET_APP_LOGIC, "Thermal throttle loop received shutdown signal");
+                            break;
+                        }
+                        _ = ticker.tick() => {
+                            self.thermal_throttle_tick(&mut controller).await;
+                        }
+                    }
+                }
+            });
+    }
+
+    async fn thermal_throttle_tick(&self, controller: &mut ThermalThrottleController) {
+        let config = ConfigMining::content().await;
+        if !*config.thermal_throttle_enabled() {
+            // Throttling got disabled by the user while a device was throttled
+            for device_kind in [ThrottledDeviceKind::Cpu, ThrottledDeviceKind::Gpu] {
+                if controller
+                    .state(device_kind)
+                    .throttled_percentage()
+                    .is_some()
+                {
+                    Self::apply_thermal_throttle(device_kind, None).await;
+                }
+            }
+            *controller = ThermalThrottleController::default();
+            return;
+        }
+
+        let cpu_readers = self
+            .cpu_devices
+            .read()
+            .await
+            .iter()
+            .map(|device| device.private_properties.device_reader.clone())
+            .collect::<Vec<_>>();
+        // Miners without intensity control pause hot devices themselves at the GPU ceiling
+        let gpu_readers = if GpuManager::read().await.supports_intensity_control() {
+            self.gpu_devices
+                .read()
+                .await
+                .iter()
+                .map(|device| device.private_properties.device_reader.clone())
+                .collect::<Vec<_>>()
+        } else {
+            if controller
+                .state(ThrottledDeviceKind::Gpu)
+                .throttled_percentage()
+                .is_some()
+            {
+                Self::apply_thermal_throttle(ThrottledDeviceKind::Gpu, None).await;
+                controller.reset(ThrottledDeviceKind::Gpu);
+            }
+            Vec::new()
+        };
+        let settings_for = |max_temperature: u32| ThermalThrottleSettings {
+            max_temperature,
+            hysteresis: *config.thermal_throttle_hysteresis(),
+            cooldown: Duration::from_secs(*config.thermal_throttle_cooldown_secs()),
+        };
+
+        let events = controller
+            .tick(
+                &cpu_readers,
+                &gpu_readers,
+                &settings_for(*config.cpu_max_temperature()),
+                &settings_for(*config.gpu_max_temperature()),
+                config.get_selected_cpu_usage_percentage(),
+                config.get_selected_gpu_usage_percentage(),
+                Instant::now(),
+            )
+            .await;
+
+        for event in events {
+            info!(target: LOG_TARGET_APP_LOGIC, "Thermal throttle {:?}: throttled: {}, temperature: {}, ceiling: {}, usage: {}%", event.device_kind, event.is_throttled, event.temperature, event.max_temperature, event.usage_percentage);
+            Self::apply_thermal_throttle(
+                event.device_kind,
+                event.is_throttled.then_some(event.usage_percentage),
+            )
+            .await;
+            EventsEmitter::emit_thermal_throttle(event).await;
+        }
+    }
+
+    async fn apply_thermal_throttle(
+        device_kind: ThrottledDeviceKind,
+        usage_percentage: Option<u32>,
+    ) {
+        let result = match device_kind {
+            ThrottledDeviceKind::Cpu => {
+                CpuManager::write()
+                    .await
+                    .set_thermal_throttle(usage_percentage)
+                    .await
+            }
+            ThrottledDeviceKind::Gpu => {
+                GpuManager::write()
+                    .await
+                    .set_thermal_throttle(usage_percentage)
+                    .await
+            }
+        };
+        if let Err(e) = result {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to apply thermal throttle to {device_kind:?}: {e}");
+        }
+    }
+
     pub fn current() -> &'static HardwareStatusMonitor {
         &INSTANCE
     }
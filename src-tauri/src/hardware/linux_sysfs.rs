@@ -0,0 +1,319 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+
+// Hwmon drivers exposing the CPU package temperature
+const CPU_HWMON_NAMES: &[&str] = &["k10temp", "zenpower", "coretemp", "cpu_thermal"];
+// Preferred sensor labels, the first one found wins over the hottest sensor of the driver
+const CPU_TEMPERATURE_LABELS: &[&str] = &["Tctl", "Tdie", "Package id 0"];
+
+pub const AMD_PCI_VENDOR_ID: &str = "0x1002";
+pub const INTEL_PCI_VENDOR_ID: &str = "0x8086";
+
+/// Aggregated CPU times from the first line of `/proc/stat`, in clock ticks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpuTimes {
+    pub idle: u64,
+    pub total: u64,
+}
+
+impl CpuTimes {
+    /// Busy percentage between an earlier sample and this one
+    pub fn usage_since(&self, previous: &CpuTimes) -> f32 {
+        let total = self.total.saturating_sub(previous.total);
+        if total == 0 {
+            return 0.0;
+        }
+        let idle = self.idle.saturating_sub(previous.idle);
+        total.saturating_sub(idle) as f32 / total as f32 * 100.0
+    }
+}
+
+/// Reads hardware parameters from Linux sysfs and procfs
+/// The root is configurable so the readers can be exercised against a fake tree
+#[derive(Debug, Clone)]
+pub struct LinuxSysfs {
+    root: PathBuf,
+}
+
+impl LinuxSysfs {
+    pub fn new() -> Self {
+        Self::with_root(PathBuf::from("/"))
+    }
+
+    pub fn with_root(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// CPU package temperature in Celsius from the first known CPU hwmon driver
+    pub fn cpu_temperature(&self) -> Option<f32> {
+        let hwmon = list_dir(&self.root.join("sys/class/hwmon"))
+            .into_iter()
+            .find(|hwmon| {
+                read_trimmed(&hwmon.join("name"))
+                    .is_some_and(|name| CPU_HWMON_NAMES.contains(&name.as_str()))
+            })?;
+
+        let sensors = temperature_sensors(&hwmon);
+        CPU_TEMPERATURE_LABELS
+            .iter()
+            .find_map(|preferred| {
+                sensors
+                    .iter()
+                    .find(|(label, _)| label.as_deref() == Some(*preferred))
+                    .map(|(_, temperature)| *temperature)
+            })
+            .or_else(|| {
+                sensors
+                    .iter()
+                    .map(|(_, temperature)| *temperature)
+                    .reduce(f32::max)
+            })
+    }
+
+    pub fn cpu_times(&self) -> Option<CpuTimes> {
+        let stat = std::fs::read_to_string(self.root.join("proc/stat")).ok()?;
+        let values = stat
+            .lines()
+            .find(|line| line.starts_with("cpu "))?
+            .split_whitespace()
+            .skip(1)
+            .map(|value| value.parse::<u64>().ok())
+            .collect::<Option<Vec<u64>>>()?;
+        // user nice system idle iowait irq softirq steal, guest times are already part of user and nice
+        let idle = values.get(3)? + values.get(4).unwrap_or(&0);
+        let total = values.iter().take(8).sum();
+        Some(CpuTimes { idle, total })
+    }
+
+    /// `/sys/class/drm/cardN/device` directories of GPUs from the given PCI vendor, ordered by card number
+    pub fn gpu_devices(&self, vendor_id: &str) -> Vec<PathBuf> {
+        let mut cards: Vec<(u32, PathBuf)> = list_dir(&self.root.join("sys/class/drm"))
+            .into_iter()
+            .filter_map(|card| {
+                // Connectors like card0-DP-1 share the prefix but are not devices
+                let number = card
+                    .file_name()?
+                    .to_str()?
+                    .strip_prefix("card")?
+                    .parse::<u32>()
+                    .ok()?;
+                Some((number, card.join("device")))
+            })
+            .filter(|(_, device)| {
+                read_trimmed(&device.join("vendor"))
+                    .is_some_and(|vendor| vendor.eq_ignore_ascii_case(vendor_id))
+            })
+            .collect();
+        cards.sort_by_key(|(number, _)| *number);
+        cards.into_iter().map(|(_, device)| device).collect()
+    }
+
+    /// Busy percentage reported by amdgpu, other drivers don't expose it
+    pub fn gpu_usage(&self, device: &Path) -> Option<f32> {
+        read_trimmed(&device.join("gpu_busy_percent"))?.parse().ok()
+    }
+
+    /// Edge temperature in Celsius from the hwmon of the GPU device
+    pub fn gpu_temperature(&self, device: &Path) -> Option<f32> {
+        let sensors = list_dir(&device.join("hwmon"))
+            .iter()
+            .flat_map(|hwmon| temperature_sensors(hwmon))
+            .collect::<Vec<_>>();
+        sensors
+            .iter()
+            .find(|(label, _)| label.as_deref() == Some("edge"))
+            .or_else(|| sensors.first())
+            .map(|(_, temperature)| *temperature)
+    }
+}
+
+impl Default for LinuxSysfs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Labels and temperatures in Celsius of all `tempN_input` files, ordered by sensor number
+fn temperature_sensors(hwmon: &Path) -> Vec<(Option<String>, f32)> {
+    let mut sensors: Vec<(u32, Option<String>, f32)> = list_dir(hwmon)
+        .into_iter()
+        .filter_map(|path| {
+            let file_name = path.file_name()?.to_str()?;
+            let number = file_name
+                .strip_prefix("temp")?
+                .strip_suffix("_input")?
+                .parse::<u32>()
+                .ok()?;
+            let millidegrees = read_trimmed(&path)?.parse::<f32>().ok()?;
+            let label = read_trimmed(&hwmon.join(format!("temp{number}_label")));
+            Some((number, label, millidegrees / 1000.0))
+        })
+        .collect();
+    sensors.sort_by_key(|(number, _, _)| *number);
+    sensors
+        .into_iter()
+        .map(|(_, label, temperature)| (label, temperature))
+        .collect()
+}
+
+fn list_dir(path: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+    paths.sort();
+    paths
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|content| content.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn reads_preferred_cpu_temperature_label() {
+        let root = tempfile::tempdir().unwrap();
+        write(root.path(), "sys/class/hwmon/hwmon0/name", "nvme\n");
+        write(root.path(), "sys/class/hwmon/hwmon0/temp1_input", "90000\n");
+        write(root.path(), "sys/class/hwmon/hwmon1/name", "k10temp\n");
+        write(root.path(), "sys/class/hwmon/hwmon1/temp1_input", "61250\n");
+        write(root.path(), "sys/class/hwmon/hwmon1/temp1_label", "Tctl\n");
+        write(root.path(), "sys/class/hwmon/hwmon1/temp3_input", "70000\n");
+        write(root.path(), "sys/class/hwmon/hwmon1/temp3_label", "Tccd1\n");
+
+        let sysfs = LinuxSysfs::with_root(root.path().to_path_buf());
+
+        assert_eq!(sysfs.cpu_temperature(), Some(61.25));
+    }
+
+    #[test]
+    fn falls_back_to_hottest_cpu_sensor() {
+        let root = tempfile::tempdir().unwrap();
+        write(root.path(), "sys/class/hwmon/hwmon2/name", "coretemp\n");
+        write(root.path(), "sys/class/hwmon/hwmon2/temp2_input", "55000\n");
+        write(
+            root.path(),
+            "sys/class/hwmon/hwmon2/temp2_label",
+            "Core 0\n",
+        );
+        write(root.path(), "sys/class/hwmon/hwmon2/temp3_input", "58000\n");
+        write(
+            root.path(),
+            "sys/class/hwmon/hwmon2/temp3_label",
+            "Core 1\n",
+        );
+
+        let sysfs = LinuxSysfs::with_root(root.path().to_path_buf());
+
+        assert_eq!(sysfs.cpu_temperature(), Some(58.0));
+    }
+
+    #[test]
+    fn computes_cpu_usage_from_proc_stat() {
+        let root = tempfile::tempdir().unwrap();
+        let sysfs = LinuxSysfs::with_root(root.path().to_path_buf());
+        write(
+            root.path(),
+            "proc/stat",
+            "cpu  100 0 100 700 100 0 0 0 0 0\ncpu0 50 0 50 350 50 0 0 0 0 0\n",
+        );
+        let previous = sysfs.cpu_times().unwrap();
+        write(
+            root.path(),
+            "proc/stat",
+            "cpu  250 0 150 850 150 0 0 0 0 0\n",
+        );
+        let current = sysfs.cpu_times().unwrap();
+
+        assert_eq!(
+            previous,
+            CpuTimes {
+                idle: 800,
+                total: 1000
+            }
+        );
+        assert!((current.usage_since(&previous) - 50.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn reads_amdgpu_cards_of_vendor() {
+        let root = tempfile::tempdir().unwrap();
+        write(root.path(), "sys/class/drm/card1/device/vendor", "0x1002\n");
+        write(
+            root.path(),
+            "sys/class/drm/card1/device/gpu_busy_percent",
+            "87\n",
+        );
+        write(
+            root.path(),
+            "sys/class/drm/card1/device/hwmon/hwmon5/temp1_input",
+            "64000\n",
+        );
+        write(
+            root.path(),
+            "sys/class/drm/card1/device/hwmon/hwmon5/temp1_label",
+            "edge\n",
+        );
+        write(
+            root.path(),
+            "sys/class/drm/card1/device/hwmon/hwmon5/temp2_input",
+            "80000\n",
+        );
+        write(
+            root.path(),
+            "sys/class/drm/card1/device/hwmon/hwmon5/temp2_label",
+            "junction\n",
+        );
+        write(
+            root.path(),
+            "sys/class/drm/card1-DP-1/status",
+            "connected\n",
+        );
+        write(root.path(), "sys/class/drm/card0/device/vendor", "0x8086\n");
+
+        let sysfs = LinuxSysfs::with_root(root.path().to_path_buf());
+        let amd_devices = sysfs.gpu_devices(AMD_PCI_VENDOR_ID);
+
+        assert_eq!(
+            amd_devices,
+            vec![root.path().join("sys/class/drm/card1/device")]
+        );
+        assert_eq!(sysfs.gpu_usage(&amd_devices[0]), Some(87.0));
+        assert_eq!(sysfs.gpu_temperature(&amd_devices[0]), Some(64.0));
+        assert_eq!(sysfs.gpu_devices(INTEL_PCI_VENDOR_ID).len(), 1);
+    }
+}
@@ -0,0 +1,478 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::time::Instant;
+
+use super::{cpu_readers::CpuParametersReader, gpu_readers::GpuParametersReader};
+
+// Usage is lowered by this many percent of the current value on every throttle step
+const THROTTLE_STEP_PERCENTAGE: u32 = 25;
+// Usage never drops below this while throttled, stopping the miner is left to the user
+const MIN_THROTTLED_USAGE_PERCENTAGE: u32 = 10;
+// Miners restart on every usage change, so give the device time to react before the next step
+const MIN_THROTTLE_STEP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ThrottledDeviceKind {
+    Cpu,
+    Gpu,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalThrottleSettings {
+    /// Temperature ceiling in Celsius
+    pub max_temperature: u32,
+    /// Device has to cool down this many degrees below the ceiling before usage is restored
+    pub hysteresis: u32,
+    /// How long the device has to stay cooled down before usage is restored
+    pub cooldown: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThermalThrottleDecision {
+    /// Lower the usage to the given percentage
+    Throttle(u32),
+    /// Go back to the usage of the selected mining mode
+    Restore,
+}
+
+/// Throttle state of a single device kind
+#[derive(Debug, Default)]
+pub struct ThermalThrottleState {
+    throttled_percentage: Option<u32>,
+    last_step_at: Option<Instant>,
+    cooling_since: Option<Instant>,
+}
+
+impl ThermalThrottleState {
+    pub fn throttled_percentage(&self) -> Option<u32> {
+        self.throttled_percentage
+    }
+
+    /// Decide whether usage should change based on the hottest device temperature
+    /// ### Arguments
+    /// * `temperature` - Hottest temperature of the device kind
+    /// * `usage_percentage` - Usage of the selected mining mode
+    pub fn evaluate(
+        &mut self,
+        temperature: f32,
+        usage_percentage: u32,
+        settings: &ThermalThrottleSettings,
+        now: Instant,
+    ) -> Option<ThermalThrottleDecision> {
+        let max_temperature = settings.max_temperature as f32;
+        let resume_temperature =
+            settings.max_temperature.saturating_sub(settings.hysteresis) as f32;
+
+        if temperature >= max_temperature {
+            self.cooling_since = None;
+            if self.last_step_at.is_some_and(|last_step_at| {
+                now.duration_since(last_step_at) < MIN_THROTTLE_STEP_INTERVAL
+            }) {
+                return None;
+            }
+
+            let current_percentage = self.throttled_percentage.unwrap_or(usage_percentage);
+            let next_percentage = current_percentage
+                .saturating_mul(100 - THROTTLE_STEP_PERCENTAGE)
+                .saturating_div(100)
+                .max(MIN_THROTTLED_USAGE_PERCENTAGE);
+            if next_percentage >= current_percentage {
+                return None;
+            }
+            self.throttled_percentage = Some(next_percentage);
+            self.last_step_at = Some(now);
+            return Some(ThermalThrottleDecision::Throttle(next_percentage));
+        }
+
+        self.throttled_percentage?;
+        if temperature > resume_temperature {
+            // Still inside the hysteresis band, keep the current throttle
+            self.cooling_since = None;
+            return None;
+        }
+
+        let cooling_since = *self.cooling_since.get_or_insert(now);
+        if now.duration_since(cooling_since) < settings.cooldown {
+            return None;
+        }
+
+        *self = Self::default();
+        Some(ThermalThrottleDecision::Restore)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThermalThrottleEvent {
+    pub device_kind: ThrottledDeviceKind,
+    pub is_throttled: bool,
+    pub temperature: f32,
+    pub max_temperature: u32,
+    /// Usage the miner should run with after this event
+    pub usage_percentage: u32,
+}
+
+/// Control loop state which lowers CPU threads and GPU intensity when devices cross their temperature ceiling
+#[derive(Debug, Default)]
+pub struct ThermalThrottleController {
+    cpu: ThermalThrottleState,
+    gpu: ThermalThrottleState,
+}
+
+impl ThermalThrottleController {
+    pub fn state(&self, device_kind: ThrottledDeviceKind) -> &ThermalThrottleState {
+        match device_kind {
+            ThrottledDeviceKind::Cpu => &self.cpu,
+            ThrottledDeviceKind::Gpu => &self.gpu,
+        }
+    }
+
+    pub fn reset(&mut self, device_kind: ThrottledDeviceKind) {
+        match device_kind {
+            ThrottledDeviceKind::Cpu => self.cpu = ThermalThrottleState::default(),
+            ThrottledDeviceKind::Gpu => self.gpu = ThermalThrottleState::default(),
+        }
+    }
+
+    /// Read temperatures of all devices and decide on throttling for each device kind
+    /// ### Arguments
+    /// * `cpu_usage_percentage` / `gpu_usage_percentage` - Usage of the selected mining mode
+    pub async fn tick(
+        &mut self,
+        cpu_readers: &[Box<dyn CpuParametersReader>],
+        gpu_readers: &[Box<dyn GpuParametersReader>],
+        cpu_settings: &ThermalThrottleSettings,
+        gpu_settings: &ThermalThrottleSettings,
+        cpu_usage_percentage: u32,
+        gpu_usage_percentage: u32,
+        now: Instant,
+    ) -> Vec<ThermalThrottleEvent> {
+        let mut events = Vec::new();
+
+        let mut cpu_temperatures = Vec::with_capacity(cpu_readers.len());
+        for reader in cpu_readers.iter().filter(|r| r.get_is_reader_implemented()) {
+            if let Ok(parameters) = reader.get_device_parameters(None).await {
+                cpu_temperatures.push(parameters.current_temperature);
+            }
+        }
+        if let Some(event) = Self::evaluate_kind(
+            &mut self.cpu,
+            ThrottledDeviceKind::Cpu,
+            hottest(&cpu_temperatures),
+            cpu_usage_percentage,
+            cpu_settings,
+            now,
+        ) {
+            events.push(event);
+        }
+
+        let mut gpu_temperatures = Vec::with_capacity(gpu_readers.len());
+        for reader in gpu_readers.iter().filter(|r| r.get_is_reader_implemented()) {
+            if let Ok(parameters) = reader.get_device_parameters(None).await {
+                gpu_temperatures.push(parameters.current_temperature);
+            }
+        }
+        if let Some(event) = Self::evaluate_kind(
+            &mut self.gpu,
+            ThrottledDeviceKind::Gpu,
+            hottest(&gpu_temperatures),
+            gpu_usage_percentage,
+            gpu_settings,
+            now,
+        ) {
+            events.push(event);
+        }
+
+        events
+    }
+
+    fn evaluate_kind(
+        state: &mut ThermalThrottleState,
+        device_kind: ThrottledDeviceKind,
+        temperature: Option<f32>,
+        usage_percentage: u32,
+        settings: &ThermalThrottleSettings,
+        now: Instant,
+    ) -> Option<ThermalThrottleEvent> {
+        let temperature = temperature?;
+        let decision = state.evaluate(temperature, usage_percentage, settings, now)?;
+        Some(ThermalThrottleEvent {
+            device_kind,
+            is_throttled: matches!(decision, ThermalThrottleDecision::Throttle(_)),
+            temperature,
+            max_temperature: settings.max_temperature,
+            usage_percentage: match decision {
+                ThermalThrottleDecision::Throttle(percentage) => percentage,
+                ThermalThrottleDecision::Restore => usage_percentage,
+            },
+        })
+    }
+}
+
+// Readers report 0 when the sensor is not available
+fn hottest(temperatures: &[f32]) -> Option<f32> {
+    temperatures
+        .iter()
+        .copied()
+        .filter(|temperature| *temperature > 0.0)
+        .reduce(f32::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use anyhow::Error;
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::hardware::hardware_status_monitor::DeviceParameters;
+
+    #[derive(Clone)]
+    struct MockReader {
+        temperature: Arc<Mutex<f32>>,
+    }
+
+    impl MockReader {
+        fn new(temperature: f32) -> Self {
+            Self {
+                temperature: Arc::new(Mutex::new(temperature)),
+            }
+        }
+
+        fn set_temperature(&self, temperature: f32) {
+            *self.temperature.lock().unwrap() = temperature;
+        }
+
+        fn parameters(&self) -> DeviceParameters {
+            DeviceParameters {
+                usage_percentage: 100.0,
+                current_temperature: *self.temperature.lock().unwrap(),
+                max_temperature: 100.0,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl CpuParametersReader for MockReader {
+        async fn get_device_parameters(
+            &self,
+            _old_device_parameters: Option<DeviceParameters>,
+        ) -> Result<DeviceParameters, Error> {
+            Ok(self.parameters())
+        }
+        fn get_is_reader_implemented(&self) -> bool {
+            true
+        }
+    }
+
+    #[async_trait]
+    impl GpuParametersReader for MockReader {
+        async fn get_device_parameters(
+            &self,
+            _old_device_parameters: Option<DeviceParameters>,
+        ) -> Result<DeviceParameters, Error> {
+            Ok(self.parameters())
+        }
+        fn get_is_reader_implemented(&self) -> bool {
+            true
+        }
+    }
+
+    fn settings() -> ThermalThrottleSettings {
+        ThermalThrottleSettings {
+            max_temperature: 80,
+            hysteresis: 10,
+            cooldown: Duration::from_secs(120),
+        }
+    }
+
+    #[test]
+    fn throttles_in_steps_down_to_minimum() {
+        let mut state = ThermalThrottleState::default();
+        let start = Instant::now();
+
+        assert_eq!(
+            state.evaluate(85.0, 40, &settings(), start),
+            Some(ThermalThrottleDecision::Throttle(30))
+        );
+        // Too early for another step
+        assert_eq!(
+            state.evaluate(85.0, 40, &settings(), start + Duration::from_secs(10)),
+            None
+        );
+        assert_eq!(
+            state.evaluate(85.0, 40, &settings(), start + MIN_THROTTLE_STEP_INTERVAL),
+            Some(ThermalThrottleDecision::Throttle(22))
+        );
+        assert_eq!(
+            state.evaluate(
+                85.0,
+                12,
+                &settings(),
+                start + MIN_THROTTLE_STEP_INTERVAL * 2
+            ),
+            Some(ThermalThrottleDecision::Throttle(16))
+        );
+        assert_eq!(
+            state.evaluate(
+                85.0,
+                12,
+                &settings(),
+                start + MIN_THROTTLE_STEP_INTERVAL * 3
+            ),
+            Some(ThermalThrottleDecision::Throttle(12))
+        );
+        assert_eq!(
+            state.evaluate(
+                85.0,
+                12,
+                &settings(),
+                start + MIN_THROTTLE_STEP_INTERVAL * 4
+            ),
+            Some(ThermalThrottleDecision::Throttle(
+                MIN_THROTTLED_USAGE_PERCENTAGE
+            ))
+        );
+        assert_eq!(
+            state.evaluate(
+                85.0,
+                12,
+                &settings(),
+                start + MIN_THROTTLE_STEP_INTERVAL * 5
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn restores_only_after_cooldown_below_hysteresis() {
+        let mut state = ThermalThrottleState::default();
+        let start = Instant::now();
+        state.evaluate(85.0, 80, &settings(), start);
+
+        // Inside the hysteresis band
+        assert_eq!(
+            state.evaluate(75.0, 80, &settings(), start + Duration::from_secs(300)),
+            None
+        );
+        assert_eq!(
+            state.evaluate(65.0, 80, &settings(), start + Duration::from_secs(310)),
+            None
+        );
+        // Heating up again resets the cooldown
+        assert_eq!(
+            state.evaluate(72.0, 80, &settings(), start + Duration::from_secs(320)),
+            None
+        );
+        assert_eq!(
+            state.evaluate(65.0, 80, &settings(), start + Duration::from_secs(330)),
+            None
+        );
+        assert_eq!(
+            state.evaluate(65.0, 80, &settings(), start + Duration::from_secs(440)),
+            None
+        );
+        assert_eq!(
+            state.evaluate(65.0, 80, &settings(), start + Duration::from_secs(450)),
+            Some(ThermalThrottleDecision::Restore)
+        );
+        assert_eq!(state.throttled_percentage(), None);
+    }
+
+    #[test]
+    fn does_nothing_below_ceiling_when_not_throttled() {
+        let mut state = ThermalThrottleState::default();
+
+        assert_eq!(state.evaluate(50.0, 80, &settings(), Instant::now()), None);
+    }
+
+    #[tokio::test]
+    async fn tick_uses_hottest_device_of_each_kind() {
+        let cpu = MockReader::new(60.0);
+        let gpu_cool = MockReader::new(50.0);
+        let gpu_hot = MockReader::new(90.0);
+        let cpu_readers: Vec<Box<dyn CpuParametersReader>> = vec![Box::new(cpu.clone())];
+        let gpu_readers: Vec<Box<dyn GpuParametersReader>> =
+            vec![Box::new(gpu_cool), Box::new(gpu_hot.clone())];
+        let mut controller = ThermalThrottleController::default();
+        let start = Instant::now();
+
+        let events = controller
+            .tick(
+                &cpu_readers,
+                &gpu_readers,
+                &settings(),
+                &settings(),
+                50,
+                100,
+                start,
+            )
+            .await;
+        assert_eq!(
+            events,
+            vec![ThermalThrottleEvent {
+                device_kind: ThrottledDeviceKind::Gpu,
+                is_throttled: true,
+                temperature: 90.0,
+                max_temperature: 80,
+                usage_percentage: 75,
+            }]
+        );
+
+        gpu_hot.set_temperature(60.0);
+        controller
+            .tick(
+                &cpu_readers,
+                &gpu_readers,
+                &settings(),
+                &settings(),
+                50,
+                100,
+                start + Duration::from_secs(10),
+            )
+            .await;
+        let events = controller
+            .tick(
+                &cpu_readers,
+                &gpu_readers,
+                &settings(),
+                &settings(),
+                50,
+                100,
+                start + Duration::from_secs(200),
+            )
+            .await;
+        assert_eq!(events.len(), 1);
+        assert!(!events[0].is_throttled);
+        assert_eq!(events[0].usage_percentage, 100);
+        assert_eq!(
+            controller
+                .state(ThrottledDeviceKind::Gpu)
+                .throttled_percentage(),
+            None
+        );
+    }
+}
@@ -165,6 +165,9 @@ pub async fn replay_state_snapshot(app_handle: &AppHandle) {
     info!(target: LOG_TARGET_APP_LOGIC, "Headless: replayed {count} cached state events");
 }
 
+/// Passphrase of the test-mode credential store, test builds never hold real funds
+const CREDENTIAL_STORE_PASSPHRASE: &[u8] = b"tari-universe-test-mode";
+
 /// Initialize the file-backed credential store for headless mode.
 pub fn init_credential_store(app_handle: &AppHandle) {
     let credential_dir = app_handle
@@ -172,7 +175,8 @@ pub fn init_credential_store(app_handle: &AppHandle) {
         .app_config_dir()
         .expect("Could not get app config dir")
         .join("credentials");
-    file_credential_store::set_store_dir(credential_dir.clone());
+    file_credential_store::init(credential_dir.clone(), CREDENTIAL_STORE_PASSPHRASE)
+        .expect("Could not open the file-backed credential store");
     keyring::set_default_credential_builder(file_credential_store::default_credential_builder());
     info!(target: LOG_TARGET_APP_LOGIC, "Headless: using file-backed credential store at {:?}", credential_dir);
 }
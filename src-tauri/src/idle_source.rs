@@ -0,0 +1,210 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Sources reporting how long the user has been inactive, used by idle-triggered scheduler events.
+//! The scheduler polls the configured source, which can be swapped (e.g. for a stub in tests).
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IdleSource: Send + Sync {
+    /// Time since the last keyboard or mouse input
+    async fn idle_time(&self) -> Result<Duration, anyhow::Error>;
+
+    /// Whether idle time can be read on this platform at all
+    fn is_supported(&self) -> bool {
+        true
+    }
+}
+
+/// Reads idle time from the desktop session
+/// Linux: Mutter idle monitor on Wayland, `xprintidle` on X11
+/// macOS: `HIDIdleTime` from IOKit
+pub struct SystemIdleSource;
+
+#[async_trait]
+impl IdleSource for SystemIdleSource {
+    async fn idle_time(&self) -> Result<Duration, anyhow::Error> {
+        #[cfg(target_os = "linux")]
+        {
+            if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+                let output = run_command(
+                    "gdbus",
+                    &[
+                        "call",
+                        "--session",
+                        "--dest",
+                        "org.gnome.Mutter.IdleMonitor",
+                        "--object-path",
+                        "/org/gnome/Mutter/IdleMonitor/Core",
+                        "--method",
+                        "org.gnome.Mutter.IdleMonitor.GetIdletime",
+                    ],
+                )
+                .await?;
+                return parse_mutter_idle_time(&output);
+            }
+            let output = run_command("xprintidle", &[]).await?;
+            parse_xprintidle(&output)
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let output = run_command("ioreg", &["-c", "IOHIDSystem", "-d", "4"]).await?;
+            parse_ioreg_idle_time(&output)
+        }
+        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+        {
+            Err(anyhow!("Idle detection is not supported on this platform"))
+        }
+    }
+
+    fn is_supported(&self) -> bool {
+        cfg!(any(target_os = "linux", target_os = "macos"))
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+async fn run_command(program: &str, args: &[&str]) -> Result<String, anyhow::Error> {
+    let output = tokio::process::Command::new(program)
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| anyhow!("Failed to run {program}: {e}"))?;
+    if !output.status.success() {
+        return Err(anyhow!("{program} exited with {}", output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(any(target_os = "linux", test))]
+/// `xprintidle` prints idle milliseconds
+fn parse_xprintidle(output: &str) -> Result<Duration, anyhow::Error> {
+    let millis = output
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| anyhow!("Invalid xprintidle output {output:?}: {e}"))?;
+    Ok(Duration::from_millis(millis))
+}
+
+#[cfg(any(target_os = "linux", test))]
+/// `GetIdletime` replies with `(uint64 <milliseconds>,)`
+fn parse_mutter_idle_time(output: &str) -> Result<Duration, anyhow::Error> {
+    let millis = output
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .trim_end_matches(',')
+        .trim_start_matches("uint64")
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| anyhow!("Invalid idle monitor output {output:?}: {e}"))?;
+    Ok(Duration::from_millis(millis))
+}
+
+#[cfg(any(target_os = "macos", test))]
+/// `ioreg` reports `"HIDIdleTime" = <nanoseconds>` for the HID system
+fn parse_ioreg_idle_time(output: &str) -> Result<Duration, anyhow::Error> {
+    output
+        .lines()
+        .find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            if !key.contains("\"HIDIdleTime\"") {
+                return None;
+            }
+            value.trim().parse::<u64>().ok()
+        })
+        .map(Duration::from_nanos)
+        .ok_or_else(|| anyhow!("HIDIdleTime not found in ioreg output"))
+}
+
+/// Idle source returning a fixed idle time which tests can change
+#[cfg(test)]
+#[derive(Default)]
+pub struct StubIdleSource {
+    idle_time: std::sync::Mutex<Duration>,
+    is_unsupported: std::sync::atomic::AtomicBool,
+}
+
+#[cfg(test)]
+impl StubIdleSource {
+    pub fn set_idle_time(&self, idle_time: Duration) {
+        *self.idle_time.lock().expect("Stub idle time lock poisoned") = idle_time;
+    }
+
+    pub fn set_supported(&self, is_supported: bool) {
+        self.is_unsupported
+            .store(!is_supported, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl IdleSource for StubIdleSource {
+    async fn idle_time(&self) -> Result<Duration, anyhow::Error> {
+        Ok(*self.idle_time.lock().expect("Stub idle time lock poisoned"))
+    }
+
+    fn is_supported(&self) -> bool {
+        !self
+            .is_unsupported
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_xprintidle_output() {
+        assert_eq!(
+            parse_xprintidle("12345\n").unwrap(),
+            Duration::from_millis(12_345)
+        );
+        assert!(parse_xprintidle("").is_err());
+    }
+
+    #[test]
+    fn parses_mutter_idle_time_output() {
+        assert_eq!(
+            parse_mutter_idle_time("(uint64 60000,)\n").unwrap(),
+            Duration::from_secs(60)
+        );
+        assert!(parse_mutter_idle_time("Error: GDBus.Error").is_err());
+    }
+
+    #[test]
+    fn parses_ioreg_idle_time_output() {
+        let output = r#"
+    | |   "HIDIdleTimeDelta" = 0
+    | |   "HIDIdleTime" = 5000000000
+"#;
+        assert_eq!(
+            parse_ioreg_idle_time(output).unwrap(),
+            Duration::from_secs(5)
+        );
+        assert!(parse_ioreg_idle_time("").is_err());
+    }
+}
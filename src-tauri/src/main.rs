@@ -32,6 +32,7 @@ compile_error!("test-mode feature must not be enabled in release builds");
 use app_in_memory_config::AppInMemoryConfig;
 use events_emitter::EventsEmitter;
 use log::{error, info, warn};
+use mining::auto_mode::AutoMiningModeManager;
 use mining_status_manager::MiningStatusManager;
 use node::local_node_adapter::LocalNodeAdapter;
 use node::node_adapter::BaseNodeStatus;
@@ -66,6 +67,7 @@ use app_in_memory_config::EXCHANGE_ID;
 use telemetry_manager::TelemetryManager;
 
 use crate::feedback::Feedback;
+use crate::metrics_exporter::{MetricsExporter, MetricsSources};
 use crate::mining::cpu::CpuMinerStatus;
 use crate::mining::cpu::manager::CpuManager;
 use crate::mining::gpu::consts::GpuMinerStatus;
@@ -79,6 +81,7 @@ use crate::wallet::wallet_manager::WalletManager;
 use crate::wallet::wallet_types::WalletState;
 
 mod ab_test_selector;
+mod address_book;
 mod airdrop;
 mod airdrop_claim;
 mod app_in_memory_config;
@@ -88,23 +91,27 @@ mod commands;
 mod configs;
 mod consts;
 mod credential_manager;
+mod daemon;
 mod download_utils;
+mod electricity_tariff;
 mod event_scheduler;
+mod event_scheduler_history;
 #[cfg(test)]
 mod event_scheduler_test;
 mod events;
 mod events_emitter;
 mod events_manager;
 mod feedback;
-#[cfg(feature = "test-mode")]
 mod file_credential_store;
 mod hardware;
 #[cfg(feature = "test-mode")]
 mod headless;
+mod idle_source;
 mod internal_wallet;
 #[cfg(test)]
 mod internal_wallet_test;
 mod mcp;
+mod metrics_exporter;
 mod mining;
 mod mining_status_manager;
 mod mm_proxy_adapter;
@@ -206,6 +213,7 @@ struct UniverseAppState {
     tor_manager: TorManager,
     updates_manager: UpdatesManager,
     mining_status_manager: Arc<RwLock<MiningStatusManager>>,
+    auto_mining_mode_manager: AutoMiningModeManager,
     websocket_message_tx: Arc<tokio::sync::mpsc::Sender<WebsocketMessage>>,
     websocket_manager_status_rx: Arc<watch::Receiver<WebsocketManagerStatusMessage>>,
     websocket_manager: Arc<RwLock<WebsocketManager>>,
@@ -225,6 +233,27 @@ fn main() {
             }
         }
     }
+
+    let context = tauri::generate_context!();
+    {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        if let Some(exit_code) = mcp::stdio::run_if_requested(&args) {
+            std::process::exit(exit_code);
+        }
+        #[cfg(unix)]
+        if let Some(exit_code) = daemon::cli::run_if_requested(&args, &context.config().identifier)
+        {
+            std::process::exit(exit_code);
+        }
+        #[cfg(target_os = "linux")]
+        if daemon::is_daemon_arg(&args)
+            && let Err(e) = daemon::display::ensure_display()
+        {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+
     let _unused = fix_path_env::fix();
     // TODO: Integrate sentry into logs. Because we are using Tari's logging infrastructure, log4rs
     // sets the logger and does not expose a way to add sentry into it.
@@ -314,6 +343,15 @@ fn main() {
     let tor_manager = TorManager::new(tor_watch_tx, &mut stats_collector);
     let mm_proxy_manager = MmProxyManager::new(&mut stats_collector);
 
+    let process_stats_collector = stats_collector.build();
+    block_on(MetricsExporter::initialize(MetricsSources {
+        cpu_miner_status_rx: cpu_miner_status_watch_rx.clone(),
+        gpu_miner_status_rx: gpu_status_rx.clone(),
+        node_status_rx: base_node_watch_rx.clone(),
+        wallet_state_rx: wallet_state_watch_rx.clone(),
+        process_stats_collector: process_stats_collector.clone(),
+    }));
+
     let telemetry_manager: TelemetryManager = TelemetryManager::new(
         cpu_miner_status_watch_rx.clone(),
         app_in_memory_config.clone(),
@@ -321,7 +359,7 @@ fn main() {
         gpu_status_rx.clone(),
         base_node_watch_rx.clone(),
         tor_watch_rx.clone(),
-        stats_collector.build(),
+        process_stats_collector,
         node_manager.clone(),
     );
 
@@ -350,6 +388,12 @@ fn main() {
         base_node_watch_rx.clone(),
         app_in_memory_config.clone(),
     );
+    let auto_mining_mode_manager = AutoMiningModeManager::new(
+        cpu_miner_status_watch_rx.clone(),
+        gpu_status_rx.clone(),
+        base_node_watch_rx.clone(),
+        node_manager.clone(),
+    );
     let app_state = UniverseAppState {
         node_status_watch_rx: Arc::new(base_node_watch_rx),
         wallet_state_watch_rx: Arc::new(wallet_state_watch_rx.clone()),
@@ -363,6 +407,7 @@ fn main() {
         tor_manager,
         updates_manager,
         mining_status_manager: Arc::new(RwLock::new(mining_status_manager)),
+        auto_mining_mode_manager,
         websocket_message_tx: Arc::new(websocket_message_tx),
         websocket_manager_status_rx: Arc::new(websocket_manager_status_rx.clone()),
         websocket_manager,
@@ -444,6 +489,13 @@ fn main() {
 
             // Do this after logging has started otherwise we can't actually see any errors
             app.manage(app_state_clone);
+
+            // The main window is created here instead of from the config so daemon mode can run without a webview
+            if !daemon::is_daemon_requested(app.handle())
+                && let Some(window_config) = app.config().app.windows.first()
+            {
+                tauri::WebviewWindowBuilder::from_config(app.handle(), window_config)?.build()?;
+            }
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -470,6 +522,7 @@ fn main() {
             commands::set_application_language,
             commands::set_auto_update,
             commands::set_cpu_mining_enabled,
+            commands::set_cpu_miner_type,
             commands::set_display_mode,
             commands::set_gpu_mining_enabled,
             commands::set_mine_on_app_start,
@@ -494,6 +547,11 @@ fn main() {
             commands::proceed_with_update,
             commands::set_pre_release,
             commands::toggle_device_exclusion,
+            commands::set_gpu_device_overrides,
+            commands::set_thermal_throttle_settings,
+            commands::set_auto_mining_mode_settings,
+            commands::set_tariff_schedule_settings,
+            commands::set_metrics_exporter_settings,
             commands::set_airdrop_tokens,
             commands::get_airdrop_tokens,
             commands::frontend_ready,
@@ -502,6 +560,11 @@ fn main() {
             commands::websocket_get_status,
             commands::reconnect,
             commands::send_one_sided_to_stealth_address,
+            commands::get_address_book_contacts,
+            commands::save_address_book_contact,
+            commands::remove_address_book_contact,
+            commands::import_address_book,
+            commands::export_address_book,
             commands::verify_address_for_send,
             commands::validate_minotari_amount,
             commands::trigger_phases_restart,
@@ -529,6 +592,17 @@ fn main() {
             commands::update_selected_cpu_pool_config,
             commands::reset_gpu_pool_config,
             commands::reset_cpu_pool_config,
+            commands::add_custom_cpu_pool,
+            commands::add_custom_gpu_pool,
+            commands::edit_custom_cpu_pool,
+            commands::edit_custom_gpu_pool,
+            commands::delete_custom_cpu_pool,
+            commands::delete_custom_gpu_pool,
+            commands::set_pool_failover_settings,
+            commands::set_cpu_failover_pools,
+            commands::set_gpu_failover_pools,
+            commands::get_pool_daily_earnings,
+            commands::get_pool_payout_history,
             commands::restart_phases,
             commands::list_connected_peers,
             commands::set_feedback_fields,
@@ -542,6 +616,8 @@ fn main() {
             commands::set_custom_node_directory,
             commands::add_scheduler_event,
             commands::remove_scheduler_event,
+            commands::set_scheduler_event_missed_run_policy,
+            commands::get_scheduler_history,
             commands::pause_scheduler_event,
             commands::resume_scheduler_event,
             // MCP commands
@@ -553,12 +629,21 @@ fn main() {
             mcp::commands::set_mcp_port,
             mcp::commands::set_mcp_max_transaction_amount,
             mcp::commands::set_mcp_tier_enabled,
+            mcp::commands::create_mcp_client,
+            mcp::commands::revoke_mcp_client,
             mcp::commands::get_mcp_audit_log,
             mcp::commands::export_mcp_audit_log,
+            mcp::commands::verify_mcp_audit_log,
+            mcp::commands::export_mcp_audit_bundle,
             mcp::commands::set_mcp_transactions_enabled,
+            mcp::commands::set_mcp_stdio_enabled,
+            mcp::commands::get_mcp_stdio_command,
+            mcp::commands::set_mcp_spending_limits,
+            mcp::commands::set_mcp_address_lists,
+            mcp::commands::get_mcp_spending_summary,
             mcp::commands::mcp_transaction_dialog_response,
         ])
-        .build(tauri::generate_context!())
+        .build(context)
         .inspect_err(|e| {
             error!(
                 target: LOG_TARGET_APP_LOGIC,
@@ -587,6 +672,8 @@ fn main() {
         false
     };
 
+    let is_daemon = daemon::is_daemon_requested(&app);
+
     let power_monitor = SystemStatus::current().start_listener();
 
     let is_restart_requested = Arc::new(AtomicBool::new(false));
@@ -612,7 +699,9 @@ fn main() {
                     warn!(target: LOG_TARGET_APP_LOGIC, "Failed to initialize process wrapper sidecar: {}. Processes will spawn without orphan protection.", e);
                 }
 
-                if is_headless {
+                if is_daemon {
+                    daemon::start_daemon(handle_clone);
+                } else if is_headless {
                     #[cfg(feature = "test-mode")]
                     {
                         headless::init_credential_store(&handle_clone);
@@ -635,9 +724,9 @@ fn main() {
                     "App shutdown request [ExitRequested] caught with code: {code:#?}"
                 );
 
-                // In headless mode there are no windows, so Tauri fires ExitRequested
-                // immediately. Prevent exit so the backend keeps running for tests.
-                if is_headless && code.is_none() {
+                // In headless and daemon mode there are no windows, so Tauri fires ExitRequested
+                // immediately. Prevent exit so the backend keeps running until ShutdownManager exits.
+                if (is_headless || is_daemon) && code.is_none() {
                     api.prevent_exit();
                     return;
                 }
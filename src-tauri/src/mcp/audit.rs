@@ -21,23 +21,35 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::LOG_TARGET_APP_LOGIC;
-use dirs::config_dir;
+use dirs::{config_dir, data_local_dir};
+use keyring::{Entry, Error as KeyringError};
 use log::{error, info, warn};
+use ring::rand::SystemRandom;
+use ring::signature::{ED25519, Ed25519KeyPair, KeyPair, UnparsedPublicKey};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
-use std::sync::LazyLock;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
 use std::time::SystemTime;
 use tari_common::configuration::Network;
 use tokio::io::AsyncWriteExt;
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, watch};
 
 use crate::APPLICATION_FOLDER_ID;
 
 const MAX_BUFFER_SIZE: usize = 500;
 const MAX_LOG_LINES: usize = 10_000;
+const AUDIT_LOG_FILE_NAME: &str = "mcp_audit.jsonl";
+/// Key file used before the signing key moved into the keyring, imported once and removed
+const LEGACY_SIGNING_KEY_FILE_NAME: &str = "mcp_audit_signing_key.pk8";
+const SIGNING_KEY_KEYRING_USERNAME: &str = "mcp_audit_signing_key";
+const CHECKPOINT_DIR_NAME: &str = "mcp_audit_checkpoints";
+const AUDIT_BUNDLE_VERSION: u32 = 1;
+/// `prev_hash` of the very first entry of a chain
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
 
 static INSTANCE: LazyLock<RwLock<AuditLog>> = LazyLock::new(|| RwLock::new(AuditLog::new()));
 
@@ -50,6 +62,273 @@ pub struct AuditEntry {
     pub duration_ms: Option<u64>,
     pub client_info: Option<String>,
     pub details: Option<String>,
+    /// SHA-256 of the previous JSONL line, set by `AuditLog::record`. Entries written before
+    /// chaining was introduced have none.
+    #[serde(default)]
+    pub prev_hash: Option<String>,
+}
+
+/// Position in the audit log where the hash chain does not hold
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BrokenLink {
+    pub file: String,
+    /// 1-based line number within the file
+    pub line: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditVerification {
+    pub valid: bool,
+    pub files_checked: Vec<String>,
+    pub entries_checked: usize,
+    /// Hash of the last entry, anchors the whole chain
+    pub head_hash: Option<String>,
+    pub first_broken_link: Option<BrokenLink>,
+}
+
+/// Signed record of where the chain starts and ends. It is kept outside the log directory so
+/// deleting rotated logs, cutting entries off the tail or rewriting the chain is detected.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditCheckpoint {
+    /// Expected `prev_hash` of the oldest retained entry, moves forward when rotated logs
+    /// are cleaned up
+    pub anchor_hash: String,
+    /// Hash of the newest entry
+    pub head_hash: String,
+    /// Hex Ed25519 signature over the anchor and head hashes
+    pub signature: String,
+}
+
+impl AuditCheckpoint {
+    fn message(anchor_hash: &str, head_hash: &str) -> String {
+        format!("tari-universe-mcp-audit-checkpoint-v1:{anchor_hash}:{head_hash}")
+    }
+
+    fn sign(key_pair: &Ed25519KeyPair, anchor_hash: &str, head_hash: &str) -> Self {
+        let signature = key_pair.sign(Self::message(anchor_hash, head_hash).as_bytes());
+        Self {
+            anchor_hash: anchor_hash.to_string(),
+            head_hash: head_hash.to_string(),
+            signature: hex::encode(signature.as_ref()),
+        }
+    }
+
+    fn is_signed_by(&self, public_key: &[u8]) -> bool {
+        let Ok(signature) = hex::decode(&self.signature) else {
+            return false;
+        };
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(
+                Self::message(&self.anchor_hash, &self.head_hash).as_bytes(),
+                &signature,
+            )
+            .is_ok()
+    }
+
+    fn read(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    async fn write(&self, path: &Path) -> Result<(), anyhow::Error> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        // Write then rename so a crash never leaves a half written checkpoint
+        let temp_path = path.with_extension("json.tmp");
+        tokio::fs::write(&temp_path, serde_json::to_vec(self)?).await?;
+        tokio::fs::rename(&temp_path, path).await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AuditBundleFile {
+    name: String,
+    contents: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AuditBundlePayload {
+    version: u32,
+    exported_at: SystemTime,
+    head_hash: Option<String>,
+    #[serde(default)]
+    checkpoint: Option<AuditCheckpoint>,
+    files: Vec<AuditBundleFile>,
+}
+
+/// Audit log export signed with the installation's Ed25519 audit key.
+/// `signature` covers the exact bytes of `payload`, which is kept as a JSON string for that reason.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditBundle {
+    payload: String,
+    public_key: String,
+    signature: String,
+}
+
+fn hash_line(line: &str) -> String {
+    hex::encode(Sha256::digest(line.as_bytes()))
+}
+
+/// Walks the chain through the given files, oldest first. The first entry has to point at the
+/// checkpoint's anchor, or at [`GENESIS_HASH`] without a checkpoint, and the last entry has to be
+/// the checkpoint's head.
+fn verify_chain(
+    files: &[(String, String)],
+    checkpoint: Option<&AuditCheckpoint>,
+) -> AuditVerification {
+    let anchor_hash = checkpoint.map_or(GENESIS_HASH, |checkpoint| checkpoint.anchor_hash.as_str());
+    let mut previous_hash: Option<String> = None;
+    let mut is_chained = false;
+    let mut entries_checked = 0;
+    let mut first_broken_link = None;
+    let mut last_line = 0;
+
+    'files: for (file, contents) in files {
+        for (index, line) in contents.lines().enumerate() {
+            last_line = index + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let broken = |reason: String| BrokenLink {
+                file: file.clone(),
+                line: index + 1,
+                reason,
+            };
+            let entry = match serde_json::from_str::<AuditEntry>(line) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    first_broken_link = Some(broken(format!("Entry is not valid JSON: {e}")));
+                    break 'files;
+                }
+            };
+            match (&entry.prev_hash, &previous_hash) {
+                (Some(prev_hash), None) if prev_hash != anchor_hash => {
+                    first_broken_link = Some(broken(format!(
+                        "prev_hash {prev_hash} does not match the chain anchor {anchor_hash}, earlier entries are missing"
+                    )));
+                    break 'files;
+                }
+                (Some(prev_hash), Some(expected)) if prev_hash != expected => {
+                    first_broken_link = Some(broken(format!(
+                        "prev_hash {prev_hash} does not match the previous entry hash {expected}"
+                    )));
+                    break 'files;
+                }
+                (Some(_), _) => is_chained = true,
+                (None, _) if is_chained => {
+                    first_broken_link = Some(broken("Entry is missing prev_hash".to_string()));
+                    break 'files;
+                }
+                (None, _) => {}
+            }
+            previous_hash = Some(hash_line(line));
+            entries_checked += 1;
+        }
+    }
+
+    if first_broken_link.is_none()
+        && let Some(checkpoint) = checkpoint
+    {
+        let head_hash = previous_hash.as_deref().unwrap_or(anchor_hash);
+        if head_hash != checkpoint.head_hash {
+            first_broken_link = Some(BrokenLink {
+                file: files
+                    .last()
+                    .map_or_else(|| AUDIT_LOG_FILE_NAME.to_string(), |(name, _)| name.clone()),
+                line: last_line + 1,
+                reason: format!(
+                    "Chain ends at {head_hash} but the signed checkpoint expects {}, entries were removed or added outside the app",
+                    checkpoint.head_hash
+                ),
+            });
+        }
+    }
+
+    AuditVerification {
+        valid: first_broken_link.is_none(),
+        files_checked: files.iter().map(|(name, _)| name.clone()).collect(),
+        entries_checked,
+        head_hash: previous_hash,
+        first_broken_link,
+    }
+}
+
+fn signing_key_entry() -> Result<Entry, anyhow::Error> {
+    let username = format!(
+        "{SIGNING_KEY_KEYRING_USERNAME}_{}",
+        Network::get_current_or_user_setting_or_default().as_key_str()
+    );
+    Ok(Entry::new(APPLICATION_FOLDER_ID, &username)?)
+}
+
+fn parse_signing_key(pkcs8: &[u8]) -> Result<Ed25519KeyPair, anyhow::Error> {
+    Ed25519KeyPair::from_pkcs8(pkcs8)
+        .map_err(|e| anyhow::anyhow!("Invalid MCP audit signing key: {e}"))
+}
+
+fn load_signing_key(entry: &Entry) -> Result<Option<Ed25519KeyPair>, anyhow::Error> {
+    match entry.get_secret() {
+        Ok(pkcs8) => Ok(Some(parse_signing_key(&pkcs8)?)),
+        Err(KeyringError::NoEntry) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Loads the signing key from the keyring, creating it (or importing the legacy key file) when
+/// there is none. The flag is set when the key was not in the keyring before.
+fn load_or_create_signing_key(
+    entry: &Entry,
+    legacy_path: &Path,
+) -> Result<(Ed25519KeyPair, bool), anyhow::Error> {
+    if let Some(key_pair) = load_signing_key(entry)? {
+        return Ok((key_pair, false));
+    }
+
+    let pkcs8 = match std::fs::read(legacy_path) {
+        Ok(pkcs8) => {
+            info!(target: LOG_TARGET_APP_LOGIC, "Moving MCP audit signing key from {legacy_path:?} into the keyring");
+            pkcs8
+        }
+        Err(_) => Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+            .map_err(|e| anyhow::anyhow!("Failed to generate MCP audit signing key: {e}"))?
+            .as_ref()
+            .to_vec(),
+    };
+    let key_pair = parse_signing_key(&pkcs8)?;
+    entry.set_secret(&pkcs8)?;
+    if legacy_path.exists() {
+        let _unused = std::fs::remove_file(legacy_path);
+    }
+    info!(target: LOG_TARGET_APP_LOGIC, "Stored MCP audit signing key in the keyring");
+    Ok((key_pair, true))
+}
+
+fn sign_bundle(
+    key_pair: &Ed25519KeyPair,
+    files: Vec<(String, String)>,
+    checkpoint: Option<AuditCheckpoint>,
+) -> Result<AuditBundle, anyhow::Error> {
+    let verification = verify_chain(&files, checkpoint.as_ref());
+    let payload = serde_json::to_string(&AuditBundlePayload {
+        version: AUDIT_BUNDLE_VERSION,
+        exported_at: SystemTime::now(),
+        head_hash: verification.head_hash,
+        checkpoint,
+        files: files
+            .into_iter()
+            .map(|(name, contents)| AuditBundleFile { name, contents })
+            .collect(),
+    })?;
+    let signature = key_pair.sign(payload.as_bytes());
+
+    Ok(AuditBundle {
+        public_key: hex::encode(key_pair.public_key().as_ref()),
+        signature: hex::encode(signature.as_ref()),
+        payload,
+    })
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,16 +344,34 @@ pub struct AuditLog {
     buffer: VecDeque<AuditEntry>,
     log_path: PathBuf,
     line_count: usize,
+    last_hash: String,
+    checkpoint_path: PathBuf,
+    /// Loaded from the keyring by [`AuditLog::initialize`]
+    signing_key: Option<Arc<Ed25519KeyPair>>,
+    /// Anchor of the signed checkpoint, `None` while checkpoints are not written because the
+    /// stored one is missing or was not signed by our key
+    checkpoint_anchor: Option<String>,
+    /// Notified after every recorded entry, drives the audit log resource subscriptions
+    changes: watch::Sender<()>,
 }
 
 impl AuditLog {
     fn new() -> Self {
-        let log_path = Self::_get_log_path();
+        Self::with_paths(Self::_get_log_path(), Self::_get_checkpoint_path())
+    }
+
+    fn with_paths(log_path: PathBuf, checkpoint_path: PathBuf) -> Self {
         let line_count = Self::_count_lines(&log_path);
+        let last_hash = Self::_last_hash(&log_path);
         Self {
             buffer: VecDeque::with_capacity(MAX_BUFFER_SIZE),
             log_path,
             line_count,
+            last_hash,
+            checkpoint_path,
+            signing_key: None,
+            checkpoint_anchor: None,
+            changes: watch::channel(()).0,
         }
     }
 
@@ -82,13 +379,188 @@ impl AuditLog {
         &INSTANCE
     }
 
+    pub async fn subscribe_to_changes() -> watch::Receiver<()> {
+        Self::current().read().await.changes.subscribe()
+    }
+
     fn _get_log_path() -> PathBuf {
         let config_dir = config_dir().unwrap_or_else(std::env::temp_dir);
         config_dir
             .join(APPLICATION_FOLDER_ID)
             .join("app_configs")
             .join(Network::get_current_or_user_setting_or_default().as_key_str())
-            .join("mcp_audit.jsonl")
+            .join(AUDIT_LOG_FILE_NAME)
+    }
+
+    fn _get_checkpoint_path() -> PathBuf {
+        data_local_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join(APPLICATION_FOLDER_ID)
+            .join(CHECKPOINT_DIR_NAME)
+            .join(format!(
+                "{}.json",
+                Network::get_current_or_user_setting_or_default().as_key_str()
+            ))
+    }
+
+    fn _get_legacy_signing_key_path(log_path: &Path) -> PathBuf {
+        log_path.with_file_name(LEGACY_SIGNING_KEY_FILE_NAME)
+    }
+
+    /// Hash of the last entry in the file, `None` when it has none
+    fn _last_line_hash(path: &Path) -> Option<String> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        contents
+            .lines()
+            .rev()
+            .find(|line| !line.trim().is_empty())
+            .map(hash_line)
+    }
+
+    /// `prev_hash` of the oldest retained entry, used as anchor for the first checkpoint
+    fn _oldest_prev_hash(log_path: &Path) -> String {
+        Self::_read_chain_files(log_path)
+            .ok()
+            .and_then(|files| {
+                files.iter().find_map(|(_, contents)| {
+                    contents
+                        .lines()
+                        .find(|line| !line.trim().is_empty())
+                        .map(|line| {
+                            serde_json::from_str::<AuditEntry>(line)
+                                .ok()
+                                .and_then(|entry| entry.prev_hash)
+                        })
+                })
+            })
+            .flatten()
+            .unwrap_or_else(|| GENESIS_HASH.to_string())
+    }
+
+    /// Loads the signing key and adopts the stored checkpoint. The keyring lookup and the log
+    /// reads block, so they run on the blocking pool before the MCP server and the control
+    /// socket record anything. Entries recorded before are covered by the next checkpoint.
+    pub async fn initialize() {
+        let (log_path, checkpoint_path) = {
+            let log = Self::current().read().await;
+            (log.log_path.clone(), log.checkpoint_path.clone())
+        };
+        let loaded = tokio::task::spawn_blocking(move || {
+            Self::_load_checkpointing(&log_path, &checkpoint_path)
+        })
+        .await;
+        match loaded {
+            Ok(Some((key_pair, checkpoint_anchor))) => {
+                let mut log = Self::current().write().await;
+                log.signing_key = Some(Arc::new(key_pair));
+                log.checkpoint_anchor = checkpoint_anchor;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to initialize MCP audit checkpoints: {e:?}");
+            }
+        }
+    }
+
+    /// Signing key and checkpoint anchor, `None` when the key can't be loaded. A checkpoint is
+    /// only created from the current logs when the key is new, otherwise a missing or foreign
+    /// checkpoint stays reported by `verify` instead of being replaced.
+    fn _load_checkpointing(
+        log_path: &Path,
+        checkpoint_path: &Path,
+    ) -> Option<(Ed25519KeyPair, Option<String>)> {
+        let (key_pair, is_new_key) = match signing_key_entry().and_then(|entry| {
+            load_or_create_signing_key(&entry, &Self::_get_legacy_signing_key_path(log_path))
+        }) {
+            Ok(result) => result,
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to load MCP audit signing key, checkpoints are disabled: {e:?}");
+                return None;
+            }
+        };
+
+        let checkpoint_anchor = match AuditCheckpoint::read(checkpoint_path) {
+            Some(checkpoint) if checkpoint.is_signed_by(key_pair.public_key().as_ref()) => {
+                Some(checkpoint.anchor_hash)
+            }
+            Some(_) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "MCP audit checkpoint is not signed by the audit key, leaving it in place");
+                None
+            }
+            None if is_new_key => Some(Self::_oldest_prev_hash(log_path)),
+            None => {
+                error!(target: LOG_TARGET_APP_LOGIC, "MCP audit checkpoint is missing, not recreating it");
+                None
+            }
+        };
+        Some((key_pair, checkpoint_anchor))
+    }
+
+    async fn _write_checkpoint(&self) {
+        let (Some(key_pair), Some(anchor_hash)) = (&self.signing_key, &self.checkpoint_anchor)
+        else {
+            return;
+        };
+        let checkpoint = AuditCheckpoint::sign(key_pair, anchor_hash, &self.last_hash);
+        if let Err(e) = checkpoint.write(&self.checkpoint_path).await {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to write MCP audit checkpoint: {e:?}");
+        }
+    }
+
+    /// Hash of the newest entry, looking into the rotated logs when the current one is empty
+    /// so the chain continues across rotations and restarts
+    fn _last_hash(path: &Path) -> String {
+        let mut candidates = vec![path.to_path_buf()];
+        candidates.extend(Self::_rotated_log_paths(path).into_iter().rev());
+        candidates
+            .iter()
+            .filter_map(|candidate| std::fs::read_to_string(candidate).ok())
+            .find_map(|contents| {
+                contents
+                    .lines()
+                    .rev()
+                    .find(|line| !line.trim().is_empty())
+                    .map(hash_line)
+            })
+            .unwrap_or_else(|| GENESIS_HASH.to_string())
+    }
+
+    /// Rotated logs oldest first, their names embed the rotation timestamp
+    fn _rotated_log_paths(path: &Path) -> Vec<PathBuf> {
+        let mut rotated: Vec<PathBuf> = Vec::new();
+        if let Some(parent) = path.parent()
+            && let Ok(entries) = std::fs::read_dir(parent)
+        {
+            for entry in entries.flatten() {
+                let candidate = entry.path();
+                let is_rotated = candidate.to_str().is_some_and(|s| {
+                    s.contains("mcp_audit.") && s.ends_with(".jsonl") && candidate != path
+                });
+                if is_rotated {
+                    rotated.push(candidate);
+                }
+            }
+        }
+        rotated.sort();
+        rotated
+    }
+
+    /// Rotated logs followed by the current log, as (file name, contents)
+    fn _read_chain_files(log_path: &Path) -> Result<Vec<(String, String)>, anyhow::Error> {
+        let mut paths = Self::_rotated_log_paths(log_path);
+        if log_path.exists() {
+            paths.push(log_path.to_path_buf());
+        }
+        paths
+            .into_iter()
+            .map(|path| {
+                let name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                Ok((name, std::fs::read_to_string(&path)?))
+            })
+            .collect()
     }
 
     fn _count_lines(path: &PathBuf) -> usize {
@@ -99,22 +571,30 @@ impl AuditLog {
     }
 
     pub async fn record(entry: AuditEntry) {
-        let cloned = entry.clone();
-        let mut log = Self::current().write().await;
+        Self::current().write().await._append(entry).await;
+    }
 
-        // Add to ring buffer
-        if log.buffer.len() >= MAX_BUFFER_SIZE {
-            log.buffer.pop_front();
-        }
-        log.buffer.push_back(entry);
+    async fn _append(&mut self, mut entry: AuditEntry) {
+        // Resource reads are audited but don't notify, a client subscribed to the audit log
+        // would otherwise re-read it on every notification forever
+        let notifies_subscribers = entry.tool_name != "read_resource";
 
         // Check if rotation needed
-        if log.line_count >= MAX_LOG_LINES {
-            log._rotate().await;
+        if self.line_count >= MAX_LOG_LINES {
+            self._rotate().await;
+        }
+
+        // Chain to the previous entry, rotation keeps `last_hash` so the new file continues the chain
+        entry.prev_hash = Some(self.last_hash.clone());
+        let cloned = entry.clone();
+
+        // Add to ring buffer
+        if self.buffer.len() >= MAX_BUFFER_SIZE {
+            self.buffer.pop_front();
         }
+        self.buffer.push_back(entry);
 
-        let log_path = log.log_path.clone();
-        let current_count = &mut log.line_count;
+        let log_path = self.log_path.clone();
 
         // Write to file (outside of heavy processing but still within lock for line_count accuracy)
         if let Ok(serialized) = serde_json::to_string(&cloned) {
@@ -130,7 +610,9 @@ impl AuditLog {
                 Ok(mut file) => {
                     let line = format!("{serialized}\n");
                     if file.write_all(line.as_bytes()).await.is_ok() {
-                        *current_count += 1;
+                        self.line_count += 1;
+                        self.last_hash = hash_line(&serialized);
+                        self._write_checkpoint().await;
                     }
                 }
                 Err(e) => {
@@ -138,6 +620,9 @@ impl AuditLog {
                 }
             }
         }
+        if notifies_subscribers {
+            self.changes.send_replace(());
+        }
     }
 
     async fn _rotate(&mut self) {
@@ -152,34 +637,22 @@ impl AuditLog {
         self._cleanup_old_rotated_logs().await;
     }
 
-    async fn _cleanup_old_rotated_logs(&self) {
+    async fn _cleanup_old_rotated_logs(&mut self) {
         const MAX_ROTATED_LOGS: usize = 5;
-        if let Some(parent) = self.log_path.parent() {
-            let mut rotated: Vec<PathBuf> = Vec::new();
-            if let Ok(mut entries) = tokio::fs::read_dir(parent).await {
-                while let Ok(Some(entry)) = entries.next_entry().await {
-                    let path = entry.path();
-                    let is_rotated = path
-                        .to_str()
-                        .map(|s| {
-                            s.contains("mcp_audit.")
-                                && s.ends_with(".jsonl")
-                                && path != self.log_path
-                        })
-                        .unwrap_or(false);
-                    if is_rotated {
-                        rotated.push(path);
-                    }
+        let rotated = Self::_rotated_log_paths(&self.log_path);
+        if rotated.len() > MAX_ROTATED_LOGS {
+            for old in &rotated[..rotated.len() - MAX_ROTATED_LOGS] {
+                // The next file starts where this one ended, which becomes the new anchor
+                let last_hash = Self::_last_line_hash(old);
+                if let Err(e) = tokio::fs::remove_file(old).await {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove old MCP audit log {old:?}: {e:?}");
+                    break;
                 }
-            }
-            rotated.sort();
-            if rotated.len() > MAX_ROTATED_LOGS {
-                for old in &rotated[..rotated.len() - MAX_ROTATED_LOGS] {
-                    if let Err(e) = tokio::fs::remove_file(old).await {
-                        warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove old MCP audit log {old:?}: {e:?}");
-                    } else {
-                        info!(target: LOG_TARGET_APP_LOGIC, "Removed old MCP audit log: {old:?}");
-                    }
+                info!(target: LOG_TARGET_APP_LOGIC, "Removed old MCP audit log: {old:?}");
+                if let Some(last_hash) = last_hash
+                    && self.checkpoint_anchor.is_some()
+                {
+                    self.checkpoint_anchor = Some(last_hash);
                 }
             }
         }
@@ -190,6 +663,60 @@ impl AuditLog {
         log.buffer.iter().rev().take(count).cloned().collect()
     }
 
+    /// Verifies the hash chain over the rotated logs and the current log against the signed
+    /// checkpoint
+    pub async fn verify() -> Result<AuditVerification, anyhow::Error> {
+        let log = Self::current().read().await;
+        let files = Self::_read_chain_files(&log.log_path)?;
+        let key_pair = match &log.signing_key {
+            Some(key_pair) => Some(key_pair.clone()),
+            None => load_signing_key(&signing_key_entry()?)?.map(Arc::new),
+        };
+        let checkpoint = AuditCheckpoint::read(&log.checkpoint_path);
+
+        let failure = match (&key_pair, &checkpoint) {
+            (None, None) => return Ok(verify_chain(&files, None)),
+            (Some(key_pair), Some(checkpoint))
+                if checkpoint.is_signed_by(key_pair.public_key().as_ref()) =>
+            {
+                return Ok(verify_chain(&files, Some(checkpoint)));
+            }
+            (Some(_), Some(_)) => "The checkpoint is not signed by the audit signing key",
+            (None, Some(_)) => "The audit signing key is missing from the keyring",
+            (Some(_), None) => "The signed checkpoint is missing",
+        };
+        let mut verification = verify_chain(&files, None);
+        verification.valid = false;
+        verification.first_broken_link = Some(BrokenLink {
+            file: CHECKPOINT_DIR_NAME.to_string(),
+            line: 0,
+            reason: failure.to_string(),
+        });
+        Ok(verification)
+    }
+
+    /// Exports the rotated logs, the current log and the checkpoint as a signed bundle
+    pub async fn export_signed_bundle() -> Result<String, anyhow::Error> {
+        let log = Self::current().read().await;
+        let key_pair = match &log.signing_key {
+            Some(key_pair) => key_pair.clone(),
+            None => Arc::new(
+                load_or_create_signing_key(
+                    &signing_key_entry()?,
+                    &Self::_get_legacy_signing_key_path(&log.log_path),
+                )?
+                .0,
+            ),
+        };
+        let checkpoint = AuditCheckpoint::read(&log.checkpoint_path);
+        let bundle = sign_bundle(
+            &key_pair,
+            Self::_read_chain_files(&log.log_path)?,
+            checkpoint,
+        )?;
+        Ok(serde_json::to_string_pretty(&bundle)?)
+    }
+
     pub async fn export() -> Result<String, anyhow::Error> {
         let log = Self::current().read().await;
         let path = &log.log_path;
@@ -200,3 +727,254 @@ impl AuditLog {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(tool_name: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: SystemTime::UNIX_EPOCH,
+            tool_name: tool_name.to_string(),
+            tier: "control".to_string(),
+            status: AuditStatus::Success,
+            duration_ms: Some(1),
+            client_info: Some("default".to_string()),
+            details: None,
+            prev_hash: None,
+        }
+    }
+
+    /// Serializes the entries as chained JSONL lines, starting from `prev_hash`
+    fn chained_lines(prev_hash: &str, tools: &[&str]) -> (String, String) {
+        let mut prev_hash = prev_hash.to_string();
+        let mut contents = String::new();
+        for tool in tools {
+            let mut entry = entry(tool);
+            entry.prev_hash = Some(prev_hash);
+            let line = serde_json::to_string(&entry).unwrap();
+            prev_hash = hash_line(&line);
+            contents.push_str(&line);
+            contents.push('\n');
+        }
+        (contents, prev_hash)
+    }
+
+    #[test]
+    fn valid_chain_is_anchored_across_rotated_files() {
+        let (rotated, rotated_head) = chained_lines(GENESIS_HASH, &["a", "b"]);
+        let (current, head) = chained_lines(&rotated_head, &["c"]);
+        let result = verify_chain(
+            &[
+                ("mcp_audit.20260101_000000.jsonl".to_string(), rotated),
+                ("mcp_audit.jsonl".to_string(), current),
+            ],
+            None,
+        );
+        assert!(result.valid);
+        assert_eq!(result.entries_checked, 3);
+        assert_eq!(result.head_hash, Some(head));
+    }
+
+    #[test]
+    fn reports_first_edited_entry() {
+        let (contents, _) = chained_lines(GENESIS_HASH, &["a", "b", "c"]);
+        let tampered = contents.replacen("\"tool_name\":\"b\"", "\"tool_name\":\"x\"", 1);
+        let result = verify_chain(&[("mcp_audit.jsonl".to_string(), tampered)], None);
+        assert!(!result.valid);
+        // Editing line 2 breaks the link stored in line 3
+        assert_eq!(result.first_broken_link.unwrap().line, 3);
+    }
+
+    #[test]
+    fn reports_chain_break_between_files() {
+        let (rotated, _) = chained_lines(GENESIS_HASH, &["a"]);
+        let (current, _) = chained_lines(GENESIS_HASH, &["b"]);
+        let result = verify_chain(
+            &[
+                ("mcp_audit.20260101_000000.jsonl".to_string(), rotated),
+                ("mcp_audit.jsonl".to_string(), current),
+            ],
+            None,
+        );
+        let broken = result.first_broken_link.unwrap();
+        assert_eq!(broken.file, "mcp_audit.jsonl");
+        assert_eq!(broken.line, 1);
+    }
+
+    #[test]
+    fn legacy_entries_before_the_chain_are_accepted() {
+        let legacy = serde_json::to_string(&entry("legacy")).unwrap();
+        let (chained, _) = chained_lines(&hash_line(&legacy), &["a"]);
+        let result = verify_chain(
+            &[(
+                "mcp_audit.jsonl".to_string(),
+                format!("{legacy}\n{chained}"),
+            )],
+            None,
+        );
+        assert!(result.valid);
+
+        let stripped = format!("{chained}{legacy}\n");
+        let result = verify_chain(&[("mcp_audit.jsonl".to_string(), stripped)], None);
+        assert_eq!(result.first_broken_link.unwrap().line, 2);
+    }
+
+    fn mock_entry() -> Entry {
+        Entry::new_with_credential(
+            keyring::mock::default_credential_builder()
+                .build(None, "audit-test", "signing-key")
+                .unwrap(),
+        )
+    }
+
+    fn test_key_pair() -> Ed25519KeyPair {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn first_entry_must_point_at_the_anchor() {
+        let (_, rotated_head) = chained_lines(GENESIS_HASH, &["a", "b"]);
+        let (current, head) = chained_lines(&rotated_head, &["c"]);
+
+        // Deleting the oldest rotated file without a checkpoint breaks the genesis anchor
+        let result = verify_chain(&[("mcp_audit.jsonl".to_string(), current.clone())], None);
+        assert_eq!(result.first_broken_link.unwrap().line, 1);
+
+        // A checkpoint moved forward by cleanup accepts it
+        let key_pair = test_key_pair();
+        let checkpoint = AuditCheckpoint::sign(&key_pair, &rotated_head, &head);
+        let result = verify_chain(
+            &[("mcp_audit.jsonl".to_string(), current)],
+            Some(&checkpoint),
+        );
+        assert!(result.valid);
+    }
+
+    #[test]
+    fn checkpoint_detects_truncation_and_rewrites() {
+        let key_pair = test_key_pair();
+        let (contents, head) = chained_lines(GENESIS_HASH, &["a", "b", "c"]);
+        let checkpoint = AuditCheckpoint::sign(&key_pair, GENESIS_HASH, &head);
+        assert!(
+            verify_chain(
+                &[("mcp_audit.jsonl".to_string(), contents)],
+                Some(&checkpoint)
+            )
+            .valid
+        );
+
+        let (truncated, _) = chained_lines(GENESIS_HASH, &["a", "b"]);
+        let result = verify_chain(
+            &[("mcp_audit.jsonl".to_string(), truncated)],
+            Some(&checkpoint),
+        );
+        assert_eq!(result.first_broken_link.unwrap().line, 3);
+
+        let (rewritten, _) = chained_lines(GENESIS_HASH, &["a", "x", "c"]);
+        assert!(
+            !verify_chain(
+                &[("mcp_audit.jsonl".to_string(), rewritten)],
+                Some(&checkpoint)
+            )
+            .valid
+        );
+
+        // The checkpoint itself can't be moved without the key
+        let mut forged = checkpoint.clone();
+        forged.head_hash = GENESIS_HASH.to_string();
+        assert!(checkpoint.is_signed_by(key_pair.public_key().as_ref()));
+        assert!(!forged.is_signed_by(key_pair.public_key().as_ref()));
+    }
+
+    #[test]
+    fn signing_key_is_kept_in_the_keyring() {
+        let dir = tempfile::tempdir().unwrap();
+        let legacy_path = dir.path().join(LEGACY_SIGNING_KEY_FILE_NAME);
+        let legacy_key = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        std::fs::write(&legacy_path, legacy_key.as_ref()).unwrap();
+        let entry = mock_entry();
+
+        let (key_pair, is_new_key) = load_or_create_signing_key(&entry, &legacy_path).unwrap();
+        assert!(is_new_key);
+        assert!(!legacy_path.exists());
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            Ed25519KeyPair::from_pkcs8(legacy_key.as_ref())
+                .unwrap()
+                .public_key()
+                .as_ref()
+        );
+
+        let (reloaded, is_new_key) = load_or_create_signing_key(&entry, &legacy_path).unwrap();
+        assert!(!is_new_key);
+        assert_eq!(
+            reloaded.public_key().as_ref(),
+            key_pair.public_key().as_ref()
+        );
+    }
+
+    #[tokio::test]
+    async fn resource_reads_do_not_notify_subscribers() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut log = AuditLog::with_paths(
+            dir.path().join(AUDIT_LOG_FILE_NAME),
+            dir.path().join("checkpoint.json"),
+        );
+        let mut changes = log.changes.subscribe();
+
+        let mut read = entry("read_resource");
+        read.details = Some(crate::mcp::resources::AUDIT_LOG_URI.to_string());
+        log._append(read).await;
+        assert!(!changes.has_changed().unwrap());
+        assert_eq!(log.line_count, 1);
+
+        log._append(entry("start_mining")).await;
+        assert!(changes.has_changed().unwrap());
+    }
+
+    #[tokio::test]
+    async fn append_writes_signed_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let checkpoint_path = dir.path().join("checkpoints").join("checkpoint.json");
+        let mut log = AuditLog::with_paths(
+            dir.path().join(AUDIT_LOG_FILE_NAME),
+            checkpoint_path.clone(),
+        );
+        let key_pair = test_key_pair();
+        let public_key = key_pair.public_key().as_ref().to_vec();
+        log.signing_key = Some(Arc::new(key_pair));
+        log.checkpoint_anchor = Some(GENESIS_HASH.to_string());
+
+        log._append(entry("start_mining")).await;
+
+        let checkpoint = AuditCheckpoint::read(&checkpoint_path).unwrap();
+        assert!(checkpoint.is_signed_by(&public_key));
+        assert_eq!(checkpoint.anchor_hash, GENESIS_HASH);
+        assert_eq!(checkpoint.head_hash, log.last_hash);
+    }
+
+    #[test]
+    fn signed_bundle_verifies_with_embedded_public_key() {
+        let key_pair = test_key_pair();
+        let (contents, head) = chained_lines(GENESIS_HASH, &["a"]);
+        let checkpoint = AuditCheckpoint::sign(&key_pair, GENESIS_HASH, &head);
+
+        let bundle = sign_bundle(
+            &key_pair,
+            vec![("mcp_audit.jsonl".to_string(), contents)],
+            Some(checkpoint.clone()),
+        )
+        .unwrap();
+        let public_key = hex::decode(&bundle.public_key).unwrap();
+        let signature = hex::decode(&bundle.signature).unwrap();
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(bundle.payload.as_bytes(), &signature)
+            .unwrap();
+
+        let payload: AuditBundlePayload = serde_json::from_str(&bundle.payload).unwrap();
+        assert_eq!(payload.head_hash, Some(head));
+        assert_eq!(payload.checkpoint, Some(checkpoint));
+    }
+}
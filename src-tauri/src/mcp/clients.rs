@@ -0,0 +1,248 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! MCP clients resolved from bearer tokens.
+//!
+//! The legacy `bearer_token` resolves to the `default` client which may call every enabled
+//! tier. Named client tokens are limited to their scopes, and the token in the stdio
+//! credential file resolves to the named client the stdio transport is bound to. The global
+//! tier switches apply to every client.
+
+use rmcp::RoleServer;
+use rmcp::service::RequestContext;
+
+use crate::configs::config_mcp::{ConfigMcpContent, McpClientToken, McpScope};
+
+pub const DEFAULT_CLIENT_NAME: &str = "default";
+
+#[derive(Debug)]
+pub enum ClientAuthError {
+    Unknown,
+    Expired,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct McpClient {
+    pub name: String,
+    /// `None` grants every tier, used by the default client
+    pub scopes: Option<Vec<McpScope>>,
+    pub max_transaction_amount: Option<u64>,
+    pub rate_limit_per_minute: Option<u32>,
+}
+
+impl McpClient {
+    fn default_client() -> Self {
+        Self {
+            name: DEFAULT_CLIENT_NAME.to_string(),
+            scopes: None,
+            max_transaction_amount: None,
+            rate_limit_per_minute: None,
+        }
+    }
+
+    fn from_token(client: &McpClientToken) -> Self {
+        Self {
+            name: client.name.clone(),
+            scopes: Some(client.scopes.clone()),
+            max_transaction_amount: client.max_transaction_amount,
+            rate_limit_per_minute: client.rate_limit_per_minute,
+        }
+    }
+
+    /// Resolves the client owning the bearer token
+    pub fn resolve(config: &ConfigMcpContent, token: &str) -> Result<Self, ClientAuthError> {
+        if let Some(client) = config.find_client_token(token) {
+            if client.is_expired() {
+                return Err(ClientAuthError::Expired);
+            }
+            return Ok(Self::from_token(client));
+        }
+
+        match config.bearer_token() {
+            Some(bearer_token) if tokens_match(bearer_token, token) => {
+                if config.is_token_expired() {
+                    return Err(ClientAuthError::Expired);
+                }
+                Ok(Self::default_client())
+            }
+            _ => Err(ClientAuthError::Unknown),
+        }
+    }
+
+    /// Resolves the named client bound to the stdio transport when the token matches the one
+    /// in its credential file, `None` when the token is not the stdio token
+    pub fn resolve_stdio(
+        config: &ConfigMcpContent,
+        expected: &str,
+        token: &str,
+    ) -> Option<Result<Self, ClientAuthError>> {
+        if !tokens_match(expected, token) {
+            return None;
+        }
+        let result = match config.stdio_client_token() {
+            None => Err(ClientAuthError::Unknown),
+            Some(client) if client.is_expired() => Err(ClientAuthError::Expired),
+            Some(client) => Ok(Self::from_token(client)),
+        };
+        Some(result)
+    }
+
+    /// Client attached to the HTTP request by the auth middleware. Requests without one get
+    /// a client without scopes so nothing is callable.
+    pub fn from_context(context: &RequestContext<RoleServer>) -> Self {
+        context
+            .extensions
+            .get::<axum08::http::request::Parts>()
+            .and_then(|parts| parts.extensions.get::<McpClient>())
+            .cloned()
+            .unwrap_or_else(|| Self {
+                name: "unauthenticated".to_string(),
+                scopes: Some(Vec::new()),
+                max_transaction_amount: None,
+                rate_limit_per_minute: None,
+            })
+    }
+
+    pub fn is_allowed(&self, tool_name: &str, tier: &str) -> bool {
+        match &self.scopes {
+            None => true,
+            Some(scopes) => scopes.iter().any(|scope| scope.covers(tool_name, tier)),
+        }
+    }
+}
+
+/// Constant-time comparison via hashing to avoid timing side-channels
+fn tokens_match(expected: &str, provided: &str) -> bool {
+    ring::digest::digest(&ring::digest::SHA256, expected.as_bytes()).as_ref()
+        == ring::digest::digest(&ring::digest::SHA256, provided.as_bytes()).as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ensure_test_identity() {
+        crate::configs::config_mcp::token_cipher::set_anon_id("test-anon-id".to_string());
+    }
+
+    fn config_with_client(client: McpClientToken) -> ConfigMcpContent {
+        let mut config = ConfigMcpContent::default();
+        config.ensure_token();
+        config.upsert_client_token(client);
+        config
+    }
+
+    #[test]
+    fn resolves_default_and_named_clients() {
+        ensure_test_identity();
+        let client = McpClientToken::new(
+            "agent".to_string(),
+            vec![McpScope::Read],
+            Some(7),
+            Some(1_000_000),
+            Some(10),
+        );
+        let token = client.token.clone();
+        let config = config_with_client(client);
+
+        let default = McpClient::resolve(&config, config.bearer_token().as_ref().unwrap()).unwrap();
+        assert_eq!(default.name, DEFAULT_CLIENT_NAME);
+        assert!(default.is_allowed("send_transaction", "transaction"));
+
+        let named = McpClient::resolve(&config, &token).unwrap();
+        assert_eq!(named.name, "agent");
+        assert_eq!(named.max_transaction_amount, Some(1_000_000));
+        assert!(named.is_allowed("get_chain_status", "read"));
+        assert!(!named.is_allowed("start_mining", "control"));
+
+        assert!(matches!(
+            McpClient::resolve(&config, "tu_unknown"),
+            Err(ClientAuthError::Unknown)
+        ));
+    }
+
+    #[test]
+    fn rejects_expired_named_client() {
+        ensure_test_identity();
+        let mut client =
+            McpClientToken::new("old".to_string(), vec![McpScope::Control], None, None, None);
+        client.expires_at = Some(std::time::SystemTime::UNIX_EPOCH);
+        let token = client.token.clone();
+        let config = config_with_client(client);
+
+        assert!(matches!(
+            McpClient::resolve(&config, &token),
+            Err(ClientAuthError::Expired)
+        ));
+    }
+
+    #[test]
+    fn stdio_token_resolves_to_the_bound_client() {
+        ensure_test_identity();
+        let client = McpClientToken::new(
+            "desktop-agent".to_string(),
+            vec![McpScope::Read],
+            None,
+            None,
+            None,
+        );
+        let client_token = client.token.clone();
+        let mut config = config_with_client(client);
+        let stdio_token = ConfigMcpContent::generate_token();
+
+        // Not bound to a client yet
+        assert!(matches!(
+            McpClient::resolve_stdio(&config, &stdio_token, &stdio_token),
+            Some(Err(ClientAuthError::Unknown))
+        ));
+
+        config.set_stdio_client(Some("desktop-agent".to_string()));
+        let stdio = McpClient::resolve_stdio(&config, &stdio_token, &stdio_token)
+            .unwrap()
+            .unwrap();
+        assert_eq!(stdio.name, "desktop-agent");
+        assert!(stdio.is_allowed("get_chain_status", "read"));
+        assert!(!stdio.is_allowed("send_transaction", "transaction"));
+
+        // Other tokens fall through to the regular resolution
+        assert!(McpClient::resolve_stdio(&config, &stdio_token, &client_token).is_none());
+
+        config.remove_client_token("desktop-agent".to_string());
+        assert!(matches!(
+            McpClient::resolve_stdio(&config, &stdio_token, &stdio_token),
+            Some(Err(ClientAuthError::Unknown))
+        ));
+        assert!(!config.stdio_enabled());
+    }
+
+    #[test]
+    fn tool_scope_allows_single_tool() {
+        let client = McpClient {
+            name: "pools".to_string(),
+            scopes: Some(vec![McpScope::Tool("get_pool_status".to_string())]),
+            max_transaction_amount: None,
+            rate_limit_per_minute: None,
+        };
+        assert!(client.is_allowed("get_pool_status", "control"));
+        assert!(!client.is_allowed("select_pool", "control"));
+    }
+}
@@ -22,11 +22,15 @@
 
 use std::time::SystemTime;
 
-use crate::configs::config_mcp::{ConfigMcp, ConfigMcpContent, SECONDS_PER_DAY};
+use crate::configs::config_mcp::{
+    ConfigMcp, ConfigMcpContent, McpClientToken, McpScope, SECONDS_PER_DAY,
+};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
-use crate::mcp::audit::AuditLog;
+use crate::mcp::audit::{AuditLog, AuditVerification};
 use crate::mcp::server::McpServerManager;
+use crate::mcp::spending::{McpSpendingLedger, McpSpendingSummary};
+use crate::utils::address_utils::verify_tari_address;
 
 #[tauri::command]
 pub async fn get_mcp_config() -> Result<serde_json::Value, String> {
@@ -126,6 +130,50 @@ pub async fn set_mcp_port(port: u16) -> Result<(), String> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_mcp_stdio_enabled(
+    enabled: bool,
+    client_name: Option<String>,
+) -> Result<(), String> {
+    if enabled {
+        // The proxy gets the scopes and limits of an existing named client
+        let client_name = client_name.ok_or("Select the client the stdio transport acts as")?;
+        let content = ConfigMcp::content().await;
+        if !content
+            .client_tokens()
+            .iter()
+            .any(|client| client.name == client_name)
+        {
+            return Err(format!("Unknown MCP client: {client_name}"));
+        }
+        ConfigMcp::update_field(ConfigMcpContent::set_stdio_client, Some(client_name))
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    ConfigMcp::update_field(ConfigMcpContent::set_stdio_enabled, enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    // Restart if currently running so the credential file is written or removed
+    let content = ConfigMcp::content().await;
+    if *content.enabled() {
+        McpServerManager::restart()
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
+/// Command line an agent uses to launch the stdio proxy
+#[tauri::command]
+pub async fn get_mcp_stdio_command() -> Result<Vec<String>, String> {
+    let executable = std::env::current_exe().map_err(|e| e.to_string())?;
+    Ok(vec![
+        executable.to_string_lossy().to_string(),
+        crate::mcp::stdio::STDIO_SUBCOMMAND.to_string(),
+    ])
+}
+
 #[tauri::command]
 pub async fn set_mcp_max_transaction_amount(amount: Option<u64>) -> Result<(), String> {
     ConfigMcp::update_field(ConfigMcpContent::set_max_transaction_amount, amount)
@@ -152,6 +200,62 @@ pub async fn set_mcp_tier_enabled(tier: String, enabled: bool) -> Result<(), Str
     Ok(())
 }
 
+/// Creates a named client token and returns it, it is not shown again afterwards
+#[tauri::command]
+pub async fn create_mcp_client(
+    name: String,
+    scopes: Vec<String>,
+    expiry_days: Option<u32>,
+    max_transaction_amount: Option<u64>,
+    rate_limit_per_minute: Option<u32>,
+    pin: Option<String>,
+) -> Result<String, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name == crate::mcp::clients::DEFAULT_CLIENT_NAME {
+        return Err(format!("Invalid MCP client name: '{name}'"));
+    }
+    let scopes = scopes
+        .into_iter()
+        .map(McpScope::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    if scopes.is_empty() {
+        return Err("At least one scope is required".to_string());
+    }
+
+    // Granting transactions needs the same PIN check as enabling the transaction tier
+    if scopes.iter().any(McpScope::grants_transactions) {
+        let pin_str = pin.ok_or("PIN is required to grant access to transaction tools")?;
+        let pin_password = tari_utilities::SafePassword::from(pin_str);
+        crate::pin::PinManager::validate_pin(pin_password)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    let client = McpClientToken::new(
+        name,
+        scopes,
+        expiry_days,
+        max_transaction_amount,
+        rate_limit_per_minute,
+    );
+    let token = client.token.clone();
+    ConfigMcp::update_field(ConfigMcpContent::upsert_client_token, client)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+
+    Ok(token)
+}
+
+#[tauri::command]
+pub async fn revoke_mcp_client(name: String) -> Result<(), String> {
+    ConfigMcp::update_field(ConfigMcpContent::remove_client_token, name)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn get_mcp_audit_log(count: usize) -> Result<Vec<serde_json::Value>, String> {
     let entries = AuditLog::get_recent(count).await;
@@ -166,6 +270,18 @@ pub async fn export_mcp_audit_log() -> Result<String, String> {
     AuditLog::export().await.map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn verify_mcp_audit_log() -> Result<AuditVerification, String> {
+    AuditLog::verify().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn export_mcp_audit_bundle() -> Result<String, String> {
+    AuditLog::export_signed_bundle()
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn set_mcp_transactions_enabled(enabled: bool, pin: String) -> Result<(), String> {
     if !crate::pin::PinManager::pin_locked().await {
@@ -185,6 +301,82 @@ pub async fn set_mcp_transactions_enabled(enabled: bool, pin: String) -> Result<
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_mcp_spending_limits(
+    daily_budget: Option<u64>,
+    weekly_budget: Option<u64>,
+    allowlist_confirmation_threshold: Option<u64>,
+    pin: String,
+) -> Result<(), String> {
+    validate_spending_pin(pin).await?;
+    if let (Some(daily), Some(weekly)) = (daily_budget, weekly_budget)
+        && daily > weekly
+    {
+        return Err("Daily budget cannot exceed the weekly budget".to_string());
+    }
+    ConfigMcp::update_field(ConfigMcpContent::set_daily_budget, daily_budget)
+        .await
+        .map_err(|e| e.to_string())?;
+    ConfigMcp::update_field(ConfigMcpContent::set_weekly_budget, weekly_budget)
+        .await
+        .map_err(|e| e.to_string())?;
+    ConfigMcp::update_field(
+        ConfigMcpContent::set_allowlist_confirmation_threshold,
+        allowlist_confirmation_threshold,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_mcp_address_lists(
+    allowlist: Vec<String>,
+    denylist: Vec<String>,
+    pin: String,
+) -> Result<(), String> {
+    validate_spending_pin(pin).await?;
+    for address in allowlist.iter().chain(denylist.iter()) {
+        verify_tari_address(address).map_err(|e| format!("Invalid address '{address}': {e}"))?;
+    }
+    if let Some(address) = allowlist.iter().find(|a| denylist.contains(a)) {
+        return Err(format!(
+            "Address '{address}' cannot be on both the allowlist and the denylist"
+        ));
+    }
+    ConfigMcp::update_field(ConfigMcpContent::set_allowlisted_addresses, allowlist)
+        .await
+        .map_err(|e| e.to_string())?;
+    ConfigMcp::update_field(ConfigMcpContent::set_denylisted_addresses, denylist)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_mcp_spending_summary() -> Result<McpSpendingSummary, String> {
+    let config = ConfigMcp::content().await;
+    Ok(McpSpendingLedger::current()
+        .read()
+        .await
+        .summary(*config.daily_budget(), *config.weekly_budget()))
+}
+
+async fn validate_spending_pin(pin: String) -> Result<(), String> {
+    if !crate::pin::PinManager::pin_locked().await {
+        return Err(
+            "Cannot change MCP spending settings without a PIN configured. Please set up a PIN first."
+                .to_string(),
+        );
+    }
+    let pin_password = tari_utilities::SafePassword::from(pin);
+    crate::pin::PinManager::validate_pin(pin_password)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn mcp_transaction_dialog_response(
     request_id: String,
@@ -21,7 +21,11 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod audit;
+pub mod clients;
 pub mod commands;
 pub mod rate_limiter;
+pub mod resources;
 pub mod server;
+pub mod spending;
+pub mod stdio;
 pub mod tools;
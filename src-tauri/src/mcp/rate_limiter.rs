@@ -20,8 +20,8 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::collections::VecDeque;
-use std::time::Instant;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
 use crate::configs::config_mcp::ConfigMcp;
 use crate::configs::trait_config::ConfigImpl;
@@ -64,6 +64,39 @@ impl TransactionRateLimiter {
     }
 }
 
+/// Sliding window limit of tool calls per named client
+pub struct ClientRateLimiter {
+    timestamps: HashMap<String, VecDeque<Instant>>,
+}
+
+impl ClientRateLimiter {
+    pub fn new() -> Self {
+        Self {
+            timestamps: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` if the client made fewer than `limit` calls within the last minute
+    pub fn check_request_allowed(&mut self, client: &str, limit: u32, now: Instant) -> bool {
+        let window = Duration::from_secs(60);
+        let timestamps = self.timestamps.entry(client.to_string()).or_default();
+
+        while timestamps
+            .front()
+            .is_some_and(|t| now.duration_since(*t) > window)
+        {
+            timestamps.pop_front();
+        }
+
+        if timestamps.len() >= limit as usize {
+            return false;
+        }
+
+        timestamps.push_back(now);
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +216,17 @@ mod tests {
 
         assert!(limiter.timestamps.len() < limit, "Should be below limit");
     }
+
+    #[test]
+    fn client_limiter_tracks_clients_separately() {
+        let mut limiter = ClientRateLimiter::new();
+        let now = Instant::now();
+        assert!(limiter.check_request_allowed("a", 2, now));
+        assert!(limiter.check_request_allowed("a", 2, now));
+        assert!(!limiter.check_request_allowed("a", 2, now));
+        assert!(limiter.check_request_allowed("b", 2, now));
+
+        let later = now + Duration::from_secs(61);
+        assert!(limiter.check_request_allowed("a", 2, later));
+    }
 }
@@ -0,0 +1,325 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! MCP resources and resource-update subscriptions.
+//!
+//! Resources expose the same data as the read tools (mining status, chain status, wallet
+//! balance, scheduled events) plus the recent audit log, so agents can read state without
+//! a tool call. Clients that subscribe to a resource get `notifications/resources/updated`
+//! whenever the underlying watch channel reports a relevant change, instead of polling.
+//! The audit log and the scheduler notify their own changes.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use log::{info, warn};
+use rmcp::model::{AnnotateAble, RawResource, Resource, ResourceUpdatedNotificationParam};
+use rmcp::{Peer, RoleServer};
+use tokio::sync::{Mutex, watch};
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::event_scheduler::EventScheduler;
+use crate::mcp::audit::AuditLog;
+use crate::mcp::tools::{chain, mining, scheduler, wallet};
+use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::consts::GpuMinerStatus;
+use crate::mining::gpu::manager::GpuManager;
+use crate::node::node_adapter::BaseNodeStatus;
+use crate::tasks_tracker::TasksTrackers;
+use crate::wallet::wallet_manager::WalletManager;
+use crate::wallet::wallet_types::WalletState;
+
+pub const MINING_STATUS_URI: &str = "tari://mining/status";
+pub const CHAIN_STATUS_URI: &str = "tari://chain/status";
+pub const WALLET_BALANCE_URI: &str = "tari://wallet/balance";
+pub const AUDIT_LOG_URI: &str = "tari://audit/log";
+pub const SCHEDULED_EVENTS_URI: &str = "tari://scheduler/events";
+
+const AUDIT_LOG_RESOURCE_ENTRIES: usize = 100;
+
+struct ResourceDefinition {
+    uri: &'static str,
+    name: &'static str,
+    description: &'static str,
+    tier: &'static str,
+}
+
+// Tiers match the tools returning the same data
+const RESOURCES: [ResourceDefinition; 5] = [
+    ResourceDefinition {
+        uri: MINING_STATUS_URI,
+        name: "mining_status",
+        description: "CPU/GPU running state, mining mode and enabled settings. Updated when a miner starts or stops.",
+        tier: "control",
+    },
+    ResourceDefinition {
+        uri: CHAIN_STATUS_URI,
+        name: "chain_status",
+        description: "Block height, block time, block reward, sync status and peer count. Updated on every node status change.",
+        tier: "read",
+    },
+    ResourceDefinition {
+        uri: WALLET_BALANCE_URI,
+        name: "wallet_balance",
+        description: "Available, pending and timelocked wallet balance in micro XTM. Updated when the balance changes.",
+        tier: "read",
+    },
+    ResourceDefinition {
+        uri: AUDIT_LOG_URI,
+        name: "audit_log",
+        description: "The most recent MCP audit log entries, newest first.",
+        tier: "read",
+    },
+    ResourceDefinition {
+        uri: SCHEDULED_EVENTS_URI,
+        name: "scheduled_events",
+        description: "Scheduled mining events including tariff generated ones.",
+        tier: "control",
+    },
+];
+
+/// Tier required to read the resource, `None` for unknown URIs
+pub fn tier_for(uri: &str) -> Option<&'static str> {
+    RESOURCES
+        .iter()
+        .find(|resource| resource.uri == uri)
+        .map(|resource| resource.tier)
+}
+
+pub fn list_resources() -> Vec<Resource> {
+    RESOURCES
+        .iter()
+        .map(|definition| {
+            let mut resource = RawResource::new(definition.uri, definition.name);
+            resource.description = Some(definition.description.to_string());
+            resource.mime_type = Some("application/json".to_string());
+            resource.no_annotation()
+        })
+        .collect()
+}
+
+pub async fn read_resource(
+    uri: &str,
+    node_status_rx: &watch::Receiver<BaseNodeStatus>,
+    wallet_manager: &WalletManager,
+) -> Result<String, String> {
+    match uri {
+        MINING_STATUS_URI => mining::get_mining_status().await,
+        CHAIN_STATUS_URI => chain::get_chain_status(&node_status_rx.borrow()),
+        WALLET_BALANCE_URI => wallet::get_wallet_balance(wallet_manager).await,
+        AUDIT_LOG_URI => {
+            serde_json::to_string(&AuditLog::get_recent(AUDIT_LOG_RESOURCE_ENTRIES).await)
+                .map_err(|e| e.to_string())
+        }
+        SCHEDULED_EVENTS_URI => scheduler::list_scheduled_events().await,
+        _ => Err(format!("Unknown resource: {uri}")),
+    }
+}
+
+/// Only the parts of each status the resource content depends on, so hash rate ticks
+/// don't flood subscribers with updates
+fn mining_fingerprint(cpu: &CpuMinerStatus, gpu: &GpuMinerStatus) -> String {
+    format!("{}:{}", cpu.is_mining, gpu.is_mining)
+}
+
+fn wallet_fingerprint(state: Option<&WalletState>) -> String {
+    state
+        .and_then(|state| state.balance.as_ref())
+        .map(|balance| {
+            format!(
+                "{}:{}:{}:{}",
+                balance.available_balance,
+                balance.timelocked_balance,
+                balance.pending_incoming_balance,
+                balance.pending_outgoing_balance
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Resource subscriptions of one MCP session
+#[derive(Clone, Default)]
+pub struct ResourceSubscriptions {
+    uris: Arc<Mutex<HashSet<String>>>,
+    is_watching: Arc<AtomicBool>,
+}
+
+impl ResourceSubscriptions {
+    pub async fn subscribe(
+        &self,
+        uri: String,
+        peer: Peer<RoleServer>,
+        node_status_rx: watch::Receiver<BaseNodeStatus>,
+        wallet_state_rx: watch::Receiver<Option<WalletState>>,
+    ) {
+        self.uris.lock().await.insert(uri);
+        if !self.is_watching.swap(true, Ordering::SeqCst) {
+            self.spawn_watcher(peer, node_status_rx, wallet_state_rx)
+                .await;
+        }
+    }
+
+    pub async fn unsubscribe(&self, uri: &str) {
+        self.uris.lock().await.remove(uri);
+    }
+
+    async fn spawn_watcher(
+        &self,
+        peer: Peer<RoleServer>,
+        mut node_status_rx: watch::Receiver<BaseNodeStatus>,
+        mut wallet_state_rx: watch::Receiver<Option<WalletState>>,
+    ) {
+        let mut cpu_status_rx = CpuManager::read().await.subscribe_to_status();
+        let mut gpu_status_rx = GpuManager::read().await.subscribe_to_status();
+        let mut audit_log_rx = AuditLog::subscribe_to_changes().await;
+        let mut scheduler_rx = EventScheduler::instance().subscribe_to_changes();
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        let uris = self.uris.clone();
+        let is_watching = self.is_watching.clone();
+
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut fingerprints: HashMap<&'static str, String> = HashMap::new();
+                loop {
+                    let changed = tokio::select! {
+                        result = cpu_status_rx.changed() => result.map(|()| MINING_STATUS_URI),
+                        result = gpu_status_rx.changed() => result.map(|()| MINING_STATUS_URI),
+                        result = node_status_rx.changed() => result.map(|()| CHAIN_STATUS_URI),
+                        result = wallet_state_rx.changed() => result.map(|()| WALLET_BALANCE_URI),
+                        result = audit_log_rx.changed() => result.map(|()| AUDIT_LOG_URI),
+                        result = scheduler_rx.changed() => result.map(|()| SCHEDULED_EVENTS_URI),
+                        _ = shutdown_signal.wait() => break,
+                    };
+                    let Ok(uri) = changed else {
+                        break;
+                    };
+                    if !uris.lock().await.contains(uri) {
+                        continue;
+                    }
+
+                    let fingerprint = match uri {
+                        MINING_STATUS_URI => Some(mining_fingerprint(
+                            &cpu_status_rx.borrow_and_update(),
+                            &gpu_status_rx.borrow_and_update(),
+                        )),
+                        CHAIN_STATUS_URI => Some(
+                            chain::get_chain_status(&node_status_rx.borrow_and_update())
+                                .unwrap_or_default(),
+                        ),
+                        WALLET_BALANCE_URI => Some(wallet_fingerprint(
+                            wallet_state_rx.borrow_and_update().as_ref(),
+                        )),
+                        // Every audit entry and scheduler change alters the content
+                        _ => None,
+                    };
+                    if let Some(fingerprint) = fingerprint {
+                        if fingerprints.get(uri) == Some(&fingerprint) {
+                            continue;
+                        }
+                        fingerprints.insert(uri, fingerprint);
+                    }
+
+                    if let Err(e) = peer
+                        .notify_resource_updated(ResourceUpdatedNotificationParam {
+                            uri: uri.to_string(),
+                        })
+                        .await
+                    {
+                        // The session is gone, a new subscription starts a new watcher
+                        warn!(target: LOG_TARGET_APP_LOGIC, "MCP resource notification failed, stopping watcher: {e}");
+                        break;
+                    }
+                }
+                is_watching.store(false, Ordering::SeqCst);
+                info!(target: LOG_TARGET_APP_LOGIC, "MCP resource watcher stopped");
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::wallet_types::WalletBalance;
+    use tari_transaction_components::tari_amount::MicroMinotari;
+
+    #[test]
+    fn every_listed_resource_has_a_tier() {
+        let resources = list_resources();
+        assert_eq!(resources.len(), RESOURCES.len());
+        for resource in &resources {
+            assert!(tier_for(&resource.raw.uri).is_some());
+        }
+        assert_eq!(tier_for("tari://unknown"), None);
+    }
+
+    #[test]
+    fn fingerprints_ignore_hash_rate_changes() {
+        let idle = CpuMinerStatus::default();
+        let busy = CpuMinerStatus {
+            hash_rate: 1234.0,
+            ..CpuMinerStatus::default()
+        };
+        let gpu = GpuMinerStatus::default();
+        assert_eq!(
+            mining_fingerprint(&idle, &gpu),
+            mining_fingerprint(&busy, &gpu)
+        );
+
+        let started = CpuMinerStatus {
+            is_mining: true,
+            ..CpuMinerStatus::default()
+        };
+        assert_ne!(
+            mining_fingerprint(&idle, &gpu),
+            mining_fingerprint(&started, &gpu)
+        );
+    }
+
+    #[test]
+    fn wallet_fingerprint_tracks_balance_only() {
+        let balance = WalletBalance {
+            available_balance: MicroMinotari(10),
+            timelocked_balance: MicroMinotari(0),
+            pending_incoming_balance: MicroMinotari(0),
+            pending_outgoing_balance: MicroMinotari(0),
+        };
+        let state = WalletState {
+            scanned_height: 1,
+            balance: Some(balance.clone()),
+            network: None,
+        };
+        let rescanned = WalletState {
+            scanned_height: 2,
+            ..state.clone()
+        };
+        assert_eq!(
+            wallet_fingerprint(Some(&state)),
+            wallet_fingerprint(Some(&rescanned))
+        );
+        assert_eq!(wallet_fingerprint(None), "");
+    }
+}
@@ -28,7 +28,6 @@ use log::{error, info, warn};
 use rmcp::transport::StreamableHttpServerConfig;
 use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
 use rmcp::transport::streamable_http_server::tower::StreamableHttpService;
-use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 
@@ -36,9 +35,12 @@ use crate::LOG_TARGET_APP_LOGIC;
 use crate::configs::config_mcp::{ConfigMcp, ConfigMcpContent, SECONDS_PER_DAY};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
+use crate::mcp::clients::{ClientAuthError, DEFAULT_CLIENT_NAME, McpClient};
+use crate::mcp::stdio;
 use crate::mcp::tools::TariMcpHandler;
 use crate::node::node_adapter::BaseNodeStatus;
 use crate::wallet::wallet_manager::WalletManager;
+use crate::wallet::wallet_types::WalletState;
 
 static INSTANCE: LazyLock<RwLock<McpServerManager>> =
     LazyLock::new(|| RwLock::new(McpServerManager::new()));
@@ -51,6 +53,9 @@ pub struct McpServerManager {
     bound_port: Option<u16>,
     node_status_rx: Option<Arc<tokio::sync::watch::Receiver<BaseNodeStatus>>>,
     wallet_manager: Option<WalletManager>,
+    wallet_state_rx: Option<Arc<tokio::sync::watch::Receiver<Option<WalletState>>>>,
+    /// Token written to the stdio credential file, rotated on every start
+    stdio_token: Option<String>,
 }
 
 impl McpServerManager {
@@ -61,6 +66,8 @@ impl McpServerManager {
             bound_port: None,
             node_status_rx: None,
             wallet_manager: None,
+            wallet_state_rx: None,
+            stdio_token: None,
         }
     }
 
@@ -71,10 +78,12 @@ impl McpServerManager {
     pub async fn initialize(
         node_status_rx: Arc<tokio::sync::watch::Receiver<BaseNodeStatus>>,
         wallet_manager: WalletManager,
+        wallet_state_rx: Arc<tokio::sync::watch::Receiver<Option<WalletState>>>,
     ) {
         let mut manager = Self::current().write().await;
         manager.node_status_rx = Some(node_status_rx);
         manager.wallet_manager = Some(wallet_manager);
+        manager.wallet_state_rx = Some(wallet_state_rx);
     }
 
     pub fn port(&self) -> Option<u16> {
@@ -103,10 +112,9 @@ impl McpServerManager {
             anyhow::bail!("MCP server is not enabled");
         }
 
-        let token = config
-            .bearer_token()
-            .clone()
-            .ok_or_else(|| anyhow::anyhow!("MCP server has no bearer token configured"))?;
+        if config.bearer_token().is_none() {
+            anyhow::bail!("MCP server has no bearer token configured");
+        }
 
         let configured_port = *config.port();
 
@@ -133,11 +141,15 @@ impl McpServerManager {
         let bound_port = listener.local_addr()?.port();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP server listening on 127.0.0.1:{bound_port}");
 
-        let wallet_manager = {
+        let (wallet_manager, wallet_state_rx) = {
             let manager = Self::current().read().await;
-            manager.wallet_manager.clone().ok_or_else(|| {
+            let wallet_manager = manager.wallet_manager.clone().ok_or_else(|| {
                 anyhow::anyhow!("MCP server not initialized — WalletManager not available")
-            })?
+            })?;
+            let wallet_state_rx = manager.wallet_state_rx.clone().ok_or_else(|| {
+                anyhow::anyhow!("MCP server not initialized — wallet state not available")
+            })?;
+            (wallet_manager, wallet_state_rx)
         };
 
         // Build the rmcp StreamableHttpService
@@ -147,6 +159,7 @@ impl McpServerManager {
                     Ok(TariMcpHandler::new(
                         node_status_rx.clone(),
                         wallet_manager.clone(),
+                        wallet_state_rx.clone(),
                     ))
                 },
                 LocalSessionManager::default().into(),
@@ -154,13 +167,9 @@ impl McpServerManager {
             );
 
         // Build axum 0.8 router with bearer auth middleware
-        let protected_router =
-            axum::Router::new()
-                .nest_service("/mcp", mcp_service)
-                .layer(axum::middleware::from_fn(move |req, next| {
-                    let token = token.clone();
-                    auth_middleware(token, req, next)
-                }));
+        let protected_router = axum::Router::new()
+            .nest_service("/mcp", mcp_service)
+            .layer(axum::middleware::from_fn(auth_middleware));
 
         // Shutdown channel
         let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(false);
@@ -177,12 +186,24 @@ impl McpServerManager {
             info!(target: LOG_TARGET_APP_LOGIC, "MCP server stopped");
         });
 
+        let stdio_token = if *config.stdio_enabled() {
+            stdio::write_credentials(bound_port)
+                .inspect_err(|e| {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to write MCP stdio credentials: {e:?}");
+                })
+                .ok()
+        } else {
+            stdio::remove_credentials();
+            None
+        };
+
         // Store state
         {
             let mut manager = Self::current().write().await;
             manager.server_handle = Some(handle);
             manager.shutdown_tx = Some(shutdown_tx);
             manager.bound_port = Some(bound_port);
+            manager.stdio_token = stdio_token;
         }
 
         EventsEmitter::emit_mcp_server_status_update(true, Some(bound_port)).await;
@@ -196,9 +217,12 @@ impl McpServerManager {
             let handle = manager.server_handle.take();
             let tx = manager.shutdown_tx.take();
             manager.bound_port = None;
+            manager.stdio_token = None;
             (handle, tx)
         };
 
+        stdio::remove_credentials();
+
         if let Some(tx) = shutdown_tx {
             let _unused = tx.send(true);
         }
@@ -232,44 +256,97 @@ impl McpServerManager {
     }
 }
 
+/// Resolves the client of a request, the stdio token takes precedence over the other tokens
+fn resolve_request_client(
+    config: &ConfigMcpContent,
+    stdio_token: Option<&str>,
+    provided: &str,
+) -> Result<McpClient, ClientAuthError> {
+    stdio_token
+        .and_then(|expected| McpClient::resolve_stdio(config, expected, provided))
+        .unwrap_or_else(|| McpClient::resolve(config, provided))
+}
+
 async fn auth_middleware(
-    expected_token: String,
-    req: axum::http::Request<axum::body::Body>,
+    mut req: axum::http::Request<axum::body::Body>,
     next: axum::middleware::Next,
 ) -> Result<axum::response::Response, axum::http::StatusCode> {
-    let auth_header = req
+    let provided = req
         .headers()
         .get("Authorization")
-        .and_then(|v: &axum::http::HeaderValue| v.to_str().ok());
-
-    match auth_header {
-        Some(header) if header.starts_with("Bearer ") => {
-            let provided = &header[7..];
-            // Constant-time comparison via hashing to avoid timing side-channels
-            let provided_hash = Sha256::digest(provided.as_bytes());
-            let expected_hash = Sha256::digest(expected_token.as_bytes());
-            if provided_hash == expected_hash {
-                let config = ConfigMcp::content().await;
-                if config.is_token_expired() {
-                    return Err(axum::http::StatusCode::UNAUTHORIZED);
-                }
-                // Sliding-window refresh: bump expiry on each successful request
-                // so the token only expires after `token_expiry_days` of inactivity.
-                let _unused = ConfigMcp::update_field(
-                    ConfigMcpContent::set_token_expires_at,
-                    Some(
-                        SystemTime::now()
-                            + std::time::Duration::from_secs(
-                                u64::from(*config.token_expiry_days()) * SECONDS_PER_DAY,
-                            ),
-                    ),
-                )
-                .await;
-                Ok(next.run(req).await)
-            } else {
-                Err(axum::http::StatusCode::UNAUTHORIZED)
-            }
+        .and_then(|v: &axum::http::HeaderValue| v.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .ok_or(axum::http::StatusCode::UNAUTHORIZED)?;
+
+    // Resolved on every request so client tokens added while running are accepted
+    let config = ConfigMcp::content().await;
+    let stdio_token = McpServerManager::current().read().await.stdio_token.clone();
+    let client = match resolve_request_client(&config, stdio_token.as_deref(), provided) {
+        Ok(client) => client,
+        Err(ClientAuthError::Expired) => {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP request rejected: token expired");
+            return Err(axum::http::StatusCode::UNAUTHORIZED);
         }
-        _ => Err(axum::http::StatusCode::UNAUTHORIZED),
+        Err(ClientAuthError::Unknown) => return Err(axum::http::StatusCode::UNAUTHORIZED),
+    };
+
+    if client.name == DEFAULT_CLIENT_NAME {
+        // Sliding-window refresh: bump expiry on each successful request
+        // so the token only expires after `token_expiry_days` of inactivity.
+        let _unused = ConfigMcp::update_field(
+            ConfigMcpContent::set_token_expires_at,
+            Some(
+                SystemTime::now()
+                    + std::time::Duration::from_secs(
+                        u64::from(*config.token_expiry_days()) * SECONDS_PER_DAY,
+                    ),
+            ),
+        )
+        .await;
+    }
+
+    // rmcp forwards the request parts to the handler, which reads the client from them
+    req.extensions_mut().insert(client);
+    Ok(next.run(req).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::config_mcp::{McpClientToken, McpScope};
+
+    #[test]
+    fn stdio_token_resolves_to_the_bound_client_only() {
+        crate::configs::config_mcp::token_cipher::set_anon_id("test-anon-id".to_string());
+        let mut config = ConfigMcpContent::default();
+        let bearer_token = config.ensure_token().to_string();
+        config.upsert_client_token(McpClientToken::new(
+            "desktop-agent".to_string(),
+            vec![McpScope::Read],
+            None,
+            None,
+            None,
+        ));
+        let stdio_token = ConfigMcpContent::generate_token();
+
+        // Without a bound client the stdio token is rejected instead of falling back
+        assert!(matches!(
+            resolve_request_client(&config, Some(&stdio_token), &stdio_token),
+            Err(ClientAuthError::Unknown)
+        ));
+
+        config.set_stdio_client(Some("desktop-agent".to_string()));
+        let client = resolve_request_client(&config, Some(&stdio_token), &stdio_token).unwrap();
+        assert_eq!(client.name, "desktop-agent");
+        assert!(!client.is_allowed("start_mining", "control"));
+
+        // The stdio token stops working once the server stopped and cleared it
+        assert!(matches!(
+            resolve_request_client(&config, None, &stdio_token),
+            Err(ClientAuthError::Unknown)
+        ));
+
+        let default = resolve_request_client(&config, Some(&stdio_token), &bearer_token).unwrap();
+        assert_eq!(default.name, DEFAULT_CLIENT_NAME);
     }
 }
@@ -0,0 +1,351 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    time::{Duration, SystemTime},
+};
+
+use dirs::config_dir;
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tari_common_types::tari_address::TariAddress;
+use tokio::{io::AsyncWriteExt, sync::RwLock};
+
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+const SPENDING_FILE_NAME: &str = "mcp_spending.jsonl";
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+const WEEK: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// Fee set aside when checking a send against the budgets, the real fee is only known after sending
+pub const FEE_RESERVE: u64 = 10_000;
+
+static INSTANCE: LazyLock<RwLock<McpSpendingLedger>> =
+    LazyLock::new(|| RwLock::new(McpSpendingLedger::new()));
+
+/// Transaction sent through MCP, amounts are in micro minotari
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct McpSpendRecord {
+    pub timestamp: SystemTime,
+    pub amount: u64,
+    #[serde(default)]
+    pub fee: u64,
+    pub destination: String,
+}
+
+/// Spend within the rolling budget windows, in micro minotari
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct McpSpendingSummary {
+    pub spent_last_day: u64,
+    pub spent_last_week: u64,
+    pub daily_budget: Option<u64>,
+    pub weekly_budget: Option<u64>,
+}
+
+/// Persisted spend of MCP initiated transactions, used to enforce the daily and weekly budgets
+/// Budgets use rolling windows (last 24 hours, last 7 days) so they do not depend on the timezone
+pub struct McpSpendingLedger {
+    path: PathBuf,
+    records: Vec<McpSpendRecord>,
+    /// Set when a record could not be written, budgets would reset on restart so sends are refused
+    is_unsaved: bool,
+}
+
+impl McpSpendingLedger {
+    fn new() -> Self {
+        let path = config_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join(APPLICATION_FOLDER_ID)
+            .join("app_configs")
+            .join(Network::get_current_or_user_setting_or_default().as_key_str())
+            .join(SPENDING_FILE_NAME);
+        Self::with_path(path, SystemTime::now())
+    }
+
+    fn with_path(path: PathBuf, now: SystemTime) -> Self {
+        let records = read_records(&path);
+        let recent: Vec<McpSpendRecord> = records
+            .iter()
+            .filter(|record| is_within(record, now, WEEK))
+            .cloned()
+            .collect();
+        // Nothing older than the weekly window is ever needed again
+        if recent.len() < records.len()
+            && let Err(e) = write_records(&path, &recent)
+        {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to compact MCP spending ledger: {e}");
+        }
+        Self {
+            path,
+            records: recent,
+            is_unsaved: false,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    pub fn summary(
+        &self,
+        daily_budget: Option<u64>,
+        weekly_budget: Option<u64>,
+    ) -> McpSpendingSummary {
+        let now = SystemTime::now();
+        McpSpendingSummary {
+            spent_last_day: spent_within(&self.records, now, DAY),
+            spent_last_week: spent_within(&self.records, now, WEEK),
+            daily_budget,
+            weekly_budget,
+        }
+    }
+
+    /// Fails if sending `amount` plus the fee reserve would exceed one of the budgets,
+    /// or if budgets are set and earlier spend could not be saved
+    pub async fn check_budgets(
+        &mut self,
+        amount: u64,
+        daily_budget: Option<u64>,
+        weekly_budget: Option<u64>,
+    ) -> Result<(), String> {
+        if daily_budget.is_none() && weekly_budget.is_none() {
+            return Ok(());
+        }
+        if self.is_unsaved {
+            // Rewriting the whole ledger also restores the records whose append failed
+            write_records(&self.path, &self.records).map_err(|e| {
+                format!("MCP spending ledger could not be saved, sends are blocked: {e}")
+            })?;
+            self.is_unsaved = false;
+        }
+        check_budgets(
+            &self.records,
+            amount.saturating_add(FEE_RESERVE),
+            daily_budget,
+            weekly_budget,
+            SystemTime::now(),
+        )
+    }
+
+    /// Keeps the spend in memory even when it can't be written, further sends are then
+    /// refused until the ledger is saved
+    pub async fn record(
+        &mut self,
+        amount: u64,
+        fee: u64,
+        destination: String,
+    ) -> Result<(), anyhow::Error> {
+        let record = McpSpendRecord {
+            timestamp: SystemTime::now(),
+            amount,
+            fee,
+            destination,
+        };
+        let result = append_record(&self.path, &record).await;
+        self.records.push(record);
+        if result.is_err() {
+            self.is_unsaved = true;
+        }
+        result
+    }
+}
+
+fn is_within(record: &McpSpendRecord, now: SystemTime, window: Duration) -> bool {
+    now.duration_since(record.timestamp)
+        .map(|age| age < window)
+        // Records from the future (clock changes) count as recent
+        .unwrap_or(true)
+}
+
+fn spent_within(records: &[McpSpendRecord], now: SystemTime, window: Duration) -> u64 {
+    records
+        .iter()
+        .filter(|record| is_within(record, now, window))
+        .fold(0u64, |total, record| {
+            total
+                .saturating_add(record.amount)
+                .saturating_add(record.fee)
+        })
+}
+
+fn check_budgets(
+    records: &[McpSpendRecord],
+    amount: u64,
+    daily_budget: Option<u64>,
+    weekly_budget: Option<u64>,
+    now: SystemTime,
+) -> Result<(), String> {
+    for (budget, window, label) in [
+        (daily_budget, DAY, "daily"),
+        (weekly_budget, WEEK, "weekly"),
+    ] {
+        let Some(budget) = budget else {
+            continue;
+        };
+        let spent = spent_within(records, now, window);
+        if spent.saturating_add(amount) > budget {
+            return Err(format!(
+                "Amount {amount} µT exceeds the {label} MCP budget: {spent} µT of {budget} µT already spent"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Whether the destination belongs to one of the listed addresses
+/// Addresses are matched by their spend key so emoji, base58 and hex forms and differing
+/// address features all match the same wallet. Invalid list entries are ignored.
+pub fn is_listed(addresses: &[String], destination: &TariAddress) -> bool {
+    addresses.iter().any(|address| {
+        address
+            .parse::<TariAddress>()
+            .is_ok_and(|listed| listed.public_spend_key() == destination.public_spend_key())
+    })
+}
+
+fn read_records(path: &Path) -> Vec<McpSpendRecord> {
+    let Ok(file) = File::open(path) else {
+        return Vec::new();
+    };
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+fn write_records(path: &Path, records: &[McpSpendRecord]) -> Result<(), anyhow::Error> {
+    let mut file = File::create(path)?;
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+    }
+    Ok(())
+}
+
+async fn append_record(path: &Path, record: &McpSpendRecord) -> Result<(), anyhow::Error> {
+    let serialized = serde_json::to_string(record)?;
+    if let Some(parent) = path.parent() {
+        let _unused = tokio::fs::create_dir_all(parent).await;
+    }
+    let result = async {
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+        file.write_all(format!("{serialized}\n").as_bytes()).await?;
+        file.flush().await
+    }
+    .await;
+    result.map_err(|e| {
+        error!(target: LOG_TARGET_APP_LOGIC, "Failed to write MCP spending ledger {path:?}: {e:?}");
+        e.into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(age: Duration, amount: u64) -> McpSpendRecord {
+        McpSpendRecord {
+            timestamp: SystemTime::now() - age,
+            amount,
+            fee: 0,
+            destination: "dest".to_string(),
+        }
+    }
+
+    #[test]
+    fn budgets_use_rolling_windows() {
+        let now = SystemTime::now();
+        let records = vec![
+            record(Duration::from_secs(60), 300),
+            record(DAY + Duration::from_secs(60), 500),
+            record(WEEK + Duration::from_secs(60), 10_000),
+        ];
+        assert_eq!(spent_within(&records, now, DAY), 300);
+        assert_eq!(spent_within(&records, now, WEEK), 800);
+
+        assert!(check_budgets(&records, 700, Some(1_000), Some(2_000), now).is_ok());
+        assert!(check_budgets(&records, 701, Some(1_000), None, now).is_err());
+        let error = check_budgets(&records, 1_201, None, Some(2_000), now).unwrap_err();
+        assert!(error.contains("weekly"));
+        assert!(check_budgets(&records, u64::MAX, None, None, now).is_ok());
+    }
+
+    #[tokio::test]
+    async fn spend_persists_and_old_records_are_compacted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SPENDING_FILE_NAME);
+        write_records(
+            &path,
+            &[
+                record(WEEK + DAY, 10_000),
+                record(Duration::from_secs(60), 100),
+            ],
+        )
+        .unwrap();
+
+        let mut ledger = McpSpendingLedger::with_path(path.clone(), SystemTime::now());
+        assert_eq!(ledger.records.len(), 1);
+        assert_eq!(read_records(&path).len(), 1);
+
+        ledger.record(250, 50, "dest".to_string()).await.unwrap();
+        let reloaded = McpSpendingLedger::with_path(path, SystemTime::now());
+        assert_eq!(reloaded.summary(None, None).spent_last_day, 400);
+    }
+
+    #[tokio::test]
+    async fn budgets_include_fees() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut ledger =
+            McpSpendingLedger::with_path(dir.path().join(SPENDING_FILE_NAME), SystemTime::now());
+        ledger.record(1_000, 500, "dest".to_string()).await.unwrap();
+
+        let budget = 1_500 + FEE_RESERVE + 100;
+        assert!(ledger.check_budgets(100, Some(budget), None).await.is_ok());
+        assert!(ledger.check_budgets(101, Some(budget), None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn unsaved_spend_blocks_sends_with_budgets() {
+        let dir = tempfile::tempdir().unwrap();
+        // A directory in place of the ledger file makes every write fail
+        let path = dir.path().join(SPENDING_FILE_NAME);
+        std::fs::create_dir(&path).unwrap();
+        let mut ledger = McpSpendingLedger::with_path(path.clone(), SystemTime::now());
+
+        assert!(ledger.record(100, 10, "dest".to_string()).await.is_err());
+        assert_eq!(ledger.summary(None, None).spent_last_day, 110);
+        assert!(ledger.check_budgets(1, None, None).await.is_ok());
+        assert!(ledger.check_budgets(1, Some(u64::MAX), None).await.is_err());
+
+        std::fs::remove_dir(&path).unwrap();
+        assert!(ledger.check_budgets(1, Some(u64::MAX), None).await.is_ok());
+        assert_eq!(read_records(&path).len(), 1);
+    }
+}
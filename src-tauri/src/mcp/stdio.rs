@@ -0,0 +1,811 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Stdio transport for desktop agents that launch MCP servers as a child process,
+//! invoked as `<binary> mcp-stdio`.
+//!
+//! The proxy does not run the tools itself, it forwards each JSON-RPC message read from
+//! stdin to the streamable HTTP server of the running instance and writes the replies to
+//! stdout, one message per line. Instead of the bearer token the proxy authenticates with
+//! the credentials the app writes to `mcp_stdio.json` (mode `0600`, or an ACL granting only
+//! the current user on Windows) in the app config dir on every server start when the stdio
+//! transport is enabled. The proxy acts as the named client the transport is bound to.
+//! When the app restarts the proxy re-reads the rotated credentials and replays the
+//! initialize handshake, so the agent keeps its connection. Diagnostics go to stderr.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use dirs::config_dir;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::{Mutex, RwLock, mpsc};
+use tokio::task::JoinHandle;
+
+use crate::APPLICATION_FOLDER_ID;
+use crate::configs::config_mcp::ConfigMcpContent;
+
+pub const STDIO_SUBCOMMAND: &str = "mcp-stdio";
+pub const STDIO_CREDENTIALS_FILE_NAME: &str = "mcp_stdio.json";
+const SESSION_ID_HEADER: &str = "Mcp-Session-Id";
+const PROTOCOL_VERSION_HEADER: &str = "MCP-Protocol-Version";
+/// JSON-RPC server error code used when the app could not be reached
+const PROXY_ERROR_CODE: i64 = -32000;
+const WRITER_DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StdioCredentials {
+    pub url: String,
+    pub token: String,
+}
+
+pub fn credentials_path() -> PathBuf {
+    config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(APPLICATION_FOLDER_ID)
+        .join(STDIO_CREDENTIALS_FILE_NAME)
+}
+
+/// Writes fresh credentials for the server on `port` and returns the token they contain
+pub fn write_credentials(port: u16) -> Result<String, anyhow::Error> {
+    write_credentials_to(&credentials_path(), port)
+}
+
+fn write_credentials_to(path: &Path, port: u16) -> Result<String, anyhow::Error> {
+    let credentials = StdioCredentials {
+        url: format!("http://127.0.0.1:{port}/mcp"),
+        token: ConfigMcpContent::generate_token(),
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let file = options.open(path)?;
+    #[cfg(windows)]
+    if let Err(e) = restrict_to_current_user(path) {
+        drop(file);
+        remove_credentials_at(path);
+        return Err(e);
+    }
+    serde_json::to_writer(file, &credentials)?;
+    Ok(credentials.token)
+}
+
+/// Replaces the inherited ACL of the file with full access for the current user only, the
+/// Windows counterpart of mode `0600`. Runs before the token is written.
+#[cfg(windows)]
+fn restrict_to_current_user(path: &Path) -> Result<(), anyhow::Error> {
+    use std::os::windows::process::CommandExt;
+
+    use crate::consts::PROCESS_CREATION_NO_WINDOW;
+
+    let output = std::process::Command::new("icacls")
+        .arg(path)
+        .args([
+            "/inheritance:r",
+            "/grant:r",
+            &format!("{}:F", whoami::username()),
+        ])
+        .creation_flags(PROCESS_CREATION_NO_WINDOW)
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Failed to restrict access to {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+pub fn remove_credentials() {
+    remove_credentials_at(&credentials_path());
+}
+
+fn remove_credentials_at(path: &Path) {
+    if path.exists() {
+        let _unused = std::fs::remove_file(path);
+    }
+}
+
+/// Runs the proxy when the first argument is [`STDIO_SUBCOMMAND`] and returns the process
+/// exit code, or `None` when the app should start normally.
+pub fn run_if_requested(args: &[String]) -> Option<i32> {
+    if args.first().map(String::as_str) != Some(STDIO_SUBCOMMAND) {
+        return None;
+    }
+
+    let credentials = match read_credentials(&credentials_path()) {
+        Ok(credentials) => credentials,
+        Err(e) => {
+            eprintln!("{e}");
+            return Some(1);
+        }
+    };
+
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Could not start the async runtime: {e}");
+            return Some(1);
+        }
+    };
+
+    match runtime.block_on(run_proxy(credentials)) {
+        Ok(()) => Some(0),
+        Err(e) => {
+            eprintln!("{e}");
+            Some(1)
+        }
+    }
+}
+
+fn read_credentials(path: &Path) -> Result<StdioCredentials, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        format!(
+            "Could not read {}: {e}. Is Tari Universe running with the MCP stdio transport enabled?",
+            path.display()
+        )
+    })?;
+    serde_json::from_str(&contents).map_err(|e| format!("Invalid {}: {e}", path.display()))
+}
+
+fn is_initialize(message: &Value) -> bool {
+    message.get("method").and_then(Value::as_str) == Some("initialize")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Session {
+    id: String,
+    protocol_version: Option<String>,
+}
+
+#[derive(Default)]
+struct SessionState {
+    session: Option<Session>,
+    /// Initialize request of the agent, replayed to open a new session when the app no
+    /// longer knows ours, e.g. after it restarted
+    initialize: Option<Value>,
+    listener: Option<JoinHandle<()>>,
+}
+
+impl SessionState {
+    fn clear(&mut self) {
+        self.session = None;
+        if let Some(listener) = self.listener.take() {
+            listener.abort();
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Proxy {
+    http: reqwest::Client,
+    credentials_path: PathBuf,
+    credentials: Arc<RwLock<StdioCredentials>>,
+    state: Arc<Mutex<SessionState>>,
+    /// Held while a session is renewed so concurrent requests don't open several
+    renewing: Arc<Mutex<()>>,
+    stdout_tx: mpsc::UnboundedSender<String>,
+}
+
+impl Proxy {
+    fn new(
+        credentials_path: PathBuf,
+        credentials: StdioCredentials,
+        stdout_tx: mpsc::UnboundedSender<String>,
+    ) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            credentials_path,
+            credentials: Arc::new(RwLock::new(credentials)),
+            state: Arc::new(Mutex::new(SessionState::default())),
+            renewing: Arc::new(Mutex::new(())),
+            stdout_tx,
+        }
+    }
+
+    async fn session(&self) -> Option<Session> {
+        self.state.lock().await.session.clone()
+    }
+
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        session: Option<&Session>,
+    ) -> reqwest::RequestBuilder {
+        let credentials = self.credentials.read().await;
+        let mut request = self
+            .http
+            .request(method, &credentials.url)
+            .bearer_auth(&credentials.token)
+            .header(
+                reqwest::header::ACCEPT,
+                "application/json, text/event-stream",
+            );
+        if let Some(session) = session {
+            request = request.header(SESSION_ID_HEADER, &session.id);
+            if let Some(version) = &session.protocol_version {
+                request = request.header(PROTOCOL_VERSION_HEADER, version);
+            }
+        }
+        request
+    }
+
+    async fn send(
+        &self,
+        message: &Value,
+        session: Option<&Session>,
+    ) -> Result<reqwest::Response, String> {
+        self.request(reqwest::Method::POST, session)
+            .await
+            .json(message)
+            .send()
+            .await
+            .map_err(|e| format!("Could not reach Tari Universe: {e}"))
+    }
+
+    /// Posts one message and forwards every reply to stdout
+    async fn forward(&self, message: Value) {
+        let id = message.get("id").cloned();
+        if is_initialize(&message) {
+            self.state.lock().await.initialize = Some(message.clone());
+        }
+        match self.post(&message).await {
+            Ok(response) => {
+                for reply in self.accept(response).await {
+                    self.write(&reply);
+                }
+            }
+            Err(e) => self.reply_error(id, e),
+        }
+    }
+
+    /// Posts a message, re-reading the credentials when the app rejects them and opening a
+    /// new session when the app dropped ours. Both happen when the app restarts.
+    async fn post(&self, message: &Value) -> Result<reqwest::Response, String> {
+        let mut credentials_reloaded = false;
+        let mut session_renewed = false;
+        loop {
+            // A new initialize always starts a new session
+            let session = if is_initialize(message) {
+                None
+            } else {
+                self.session().await
+            };
+            let response = self.send(message, session.as_ref()).await?;
+            match response.status() {
+                reqwest::StatusCode::UNAUTHORIZED if !credentials_reloaded => {
+                    credentials_reloaded = true;
+                    self.reload_credentials().await?;
+                }
+                reqwest::StatusCode::NOT_FOUND if !session_renewed => {
+                    let Some(expired) = session else {
+                        return Err("Tari Universe rejected the request: 404 Not Found".into());
+                    };
+                    session_renewed = true;
+                    self.renew_session(&expired).await?;
+                }
+                status if status.is_success() => return Ok(response),
+                status => return Err(format!("Tari Universe rejected the request: {status}")),
+            }
+        }
+    }
+
+    /// The app writes a new token, and possibly a new port, on every server start
+    async fn reload_credentials(&self) -> Result<(), String> {
+        let credentials = read_credentials(&self.credentials_path)?;
+        let mut current = self.credentials.write().await;
+        if *current == credentials {
+            return Err("Tari Universe rejected the stdio credentials".to_string());
+        }
+        *current = credentials;
+        Ok(())
+    }
+
+    /// Drops the session the app no longer knows and opens a new one by replaying the
+    /// initialize handshake of the agent
+    async fn renew_session(&self, expired: &Session) -> Result<(), String> {
+        let _renewing = self.renewing.lock().await;
+        let initialize = {
+            let mut state = self.state.lock().await;
+            if state.session.as_ref() != Some(expired) {
+                // Another request already renewed it
+                return Ok(());
+            }
+            state.clear();
+            state.initialize.clone()
+        };
+        let initialize = initialize
+            .ok_or_else(|| "The Tari Universe session ended, reconnect the agent".to_string())?;
+
+        let response = self.send(&initialize, None).await?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Tari Universe rejected the new session: {}",
+                response.status()
+            ));
+        }
+        self.accept(response).await;
+        let session = self
+            .session()
+            .await
+            .ok_or_else(|| "Tari Universe did not assign a new session".to_string())?;
+        let initialized = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        self.send(&initialized, Some(&session)).await?;
+        Ok(())
+    }
+
+    /// Reads the replies of a successful post and adopts the session the server assigned
+    async fn accept(&self, response: reqwest::Response) -> Vec<Value> {
+        let assigned = response
+            .headers()
+            .get(SESSION_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let replies = self.read_replies(response).await;
+
+        if let Some(id) = assigned {
+            let mut state = self.state.lock().await;
+            if state.session.as_ref().map(|session| &session.id) != Some(&id) {
+                let protocol_version = replies.iter().find_map(|reply| {
+                    reply
+                        .get("result")?
+                        .get("protocolVersion")?
+                        .as_str()
+                        .map(str::to_string)
+                });
+                let session = Session {
+                    id,
+                    protocol_version,
+                };
+                state.clear();
+                let proxy = self.clone();
+                let listening = session.clone();
+                state.listener = Some(tokio::spawn(async move { proxy.listen(listening).await }));
+                state.session = Some(session);
+            }
+        }
+        replies
+    }
+
+    async fn read_replies(&self, response: reqwest::Response) -> Vec<Value> {
+        let is_event_stream = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/event-stream"));
+
+        if !is_event_stream {
+            // 202 Accepted for notifications carries no body
+            return match response.bytes().await {
+                Ok(body) if !body.is_empty() => serde_json::from_slice(&body).into_iter().collect(),
+                _ => Vec::new(),
+            };
+        }
+
+        let mut replies = Vec::new();
+        let mut buffer = String::new();
+        let mut stream = response.bytes_stream();
+        while let Some(Ok(chunk)) = stream.next().await {
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+            replies.extend(
+                drain_sse_events(&mut buffer)
+                    .iter()
+                    .filter_map(|data| serde_json::from_str(data).ok()),
+            );
+        }
+        replies
+    }
+
+    /// Forwards server initiated messages such as resource update notifications
+    async fn listen(&self, session: Session) {
+        let Ok(response) = self
+            .request(reqwest::Method::GET, Some(&session))
+            .await
+            .send()
+            .await
+        else {
+            return;
+        };
+        if !response.status().is_success() {
+            return;
+        }
+        let mut buffer = String::new();
+        let mut stream = response.bytes_stream();
+        while let Some(Ok(chunk)) = stream.next().await {
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+            for data in drain_sse_events(&mut buffer) {
+                if let Ok(message) = serde_json::from_str::<Value>(&data) {
+                    self.write(&message);
+                }
+            }
+        }
+    }
+
+    /// Ends the session and stops listening, called when the agent closed stdin
+    async fn close(&self) {
+        let session = {
+            let mut state = self.state.lock().await;
+            let session = state.session.clone();
+            state.clear();
+            session
+        };
+        if let Some(session) = session {
+            let _unused = self
+                .request(reqwest::Method::DELETE, Some(&session))
+                .await
+                .send()
+                .await;
+        }
+    }
+
+    fn reply_error(&self, id: Option<Value>, message: String) {
+        match id {
+            Some(id) => self.write(&json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": PROXY_ERROR_CODE, "message": message },
+            })),
+            None => eprintln!("{message}"),
+        }
+    }
+
+    fn write(&self, message: &Value) {
+        let _unused = self.stdout_tx.send(message.to_string());
+    }
+}
+
+async fn run_proxy(credentials: StdioCredentials) -> Result<(), anyhow::Error> {
+    let (stdout_tx, mut stdout_rx) = mpsc::unbounded_channel::<String>();
+    let writer = tokio::spawn(async move {
+        let mut stdout = tokio::io::stdout();
+        while let Some(line) = stdout_rx.recv().await {
+            if stdout.write_all(line.as_bytes()).await.is_err()
+                || stdout.write_all(b"\n").await.is_err()
+                || stdout.flush().await.is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    let proxy = Proxy::new(credentials_path(), credentials, stdout_tx);
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let message: Value = match serde_json::from_str(&line) {
+            Ok(message) => message,
+            Err(e) => {
+                eprintln!("Ignoring invalid JSON-RPC message: {e}");
+                continue;
+            }
+        };
+
+        if proxy.session().await.is_some() {
+            // Requests are handled concurrently so a pending transaction confirmation
+            // does not hold up other tool calls
+            let proxy = proxy.clone();
+            tokio::spawn(async move { proxy.forward(message).await });
+        } else {
+            // Until the server assigned a session, messages are forwarded one at a time
+            proxy.forward(message).await;
+        }
+    }
+
+    proxy.close().await;
+    // Requests still in flight keep the writer alive, the client is gone so don't wait long
+    drop(proxy);
+    let _unused = tokio::time::timeout(WRITER_DRAIN_TIMEOUT, writer).await;
+    Ok(())
+}
+
+/// Removes complete server-sent events from `buffer` and returns their data payloads
+fn drain_sse_events(buffer: &mut String) -> Vec<String> {
+    let normalized = buffer.replace("\r\n", "\n");
+    let Some(end) = normalized.rfind("\n\n") else {
+        *buffer = normalized;
+        return Vec::new();
+    };
+
+    let events = normalized[..end]
+        .split("\n\n")
+        .filter_map(|event| {
+            let data: Vec<&str> = event
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect();
+            (!data.is_empty()).then(|| data.join("\n"))
+        })
+        .filter(|data| !data.trim().is_empty())
+        .collect();
+    *buffer = normalized[end + 2..].to_string();
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+    use wiremock::matchers::{body_partial_json, header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn drains_complete_events_and_keeps_partial_ones() {
+        let mut buffer = "event: message\r\ndata: {\"id\":1}\r\n\r\ndata: {\"id\"".to_string();
+        assert_eq!(
+            drain_sse_events(&mut buffer),
+            vec!["{\"id\":1}".to_string()]
+        );
+        assert_eq!(buffer, "data: {\"id\"");
+
+        buffer.push_str(":2}\n\n: keep-alive\n\n");
+        assert_eq!(
+            drain_sse_events(&mut buffer),
+            vec!["{\"id\":2}".to_string()]
+        );
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn joins_multi_line_data() {
+        let mut buffer = "data: {\"a\":\ndata: 1}\n\n".to_string();
+        assert_eq!(
+            drain_sse_events(&mut buffer),
+            vec!["{\"a\":\n1}".to_string()]
+        );
+    }
+
+    #[test]
+    fn ignores_regular_app_arguments() {
+        assert_eq!(run_if_requested(&["--headless".to_string()]), None);
+        assert_eq!(run_if_requested(&[]), None);
+    }
+
+    #[test]
+    fn writes_and_removes_credentials() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested").join(STDIO_CREDENTIALS_FILE_NAME);
+
+        let token = write_credentials_to(&path, 19222).unwrap();
+        let credentials = read_credentials(&path).unwrap();
+        assert_eq!(credentials.url, "http://127.0.0.1:19222/mcp");
+        assert_eq!(credentials.token, token);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // Every server start rotates the token
+        let rotated = write_credentials_to(&path, 19223).unwrap();
+        assert_ne!(rotated, token);
+        assert_eq!(read_credentials(&path).unwrap().token, rotated);
+
+        remove_credentials_at(&path);
+        assert!(!path.exists());
+        assert!(read_credentials(&path).is_err());
+    }
+
+    fn test_proxy(
+        server: &MockServer,
+        credentials_path: PathBuf,
+        token: &str,
+    ) -> (Proxy, mpsc::UnboundedReceiver<String>) {
+        let (stdout_tx, stdout_rx) = mpsc::unbounded_channel();
+        let credentials = StdioCredentials {
+            url: format!("{}/mcp", server.uri()),
+            token: token.to_string(),
+        };
+        (
+            Proxy::new(credentials_path, credentials, stdout_tx),
+            stdout_rx,
+        )
+    }
+
+    fn reply(id: u64) -> ResponseTemplate {
+        ResponseTemplate::new(200)
+            .set_body_json(json!({ "jsonrpc": "2.0", "id": id, "result": { "tools": [] } }))
+    }
+
+    #[tokio::test]
+    async fn rereads_credentials_when_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("Authorization", "Bearer tu_old"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(header("Authorization", "Bearer tu_new"))
+            .respond_with(reply(1))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(STDIO_CREDENTIALS_FILE_NAME);
+        let (proxy, mut stdout_rx) = test_proxy(&server, path.clone(), "tu_old");
+        let restarted = StdioCredentials {
+            url: format!("{}/mcp", server.uri()),
+            token: "tu_new".to_string(),
+        };
+        std::fs::write(&path, serde_json::to_string(&restarted).unwrap()).unwrap();
+
+        proxy
+            .forward(json!({ "jsonrpc": "2.0", "id": 1, "method": "tools/list" }))
+            .await;
+
+        let written: Value = serde_json::from_str(&stdout_rx.recv().await.unwrap()).unwrap();
+        assert_eq!(written["id"], 1);
+        assert!(written.get("error").is_none());
+        assert_eq!(*proxy.credentials.read().await, restarted);
+    }
+
+    #[tokio::test]
+    async fn reports_credentials_that_stay_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(STDIO_CREDENTIALS_FILE_NAME);
+        let (proxy, mut stdout_rx) = test_proxy(&server, path.clone(), "tu_old");
+        let unchanged = proxy.credentials.read().await.clone();
+        std::fs::write(&path, serde_json::to_string(&unchanged).unwrap()).unwrap();
+
+        proxy
+            .forward(json!({ "jsonrpc": "2.0", "id": 1, "method": "tools/list" }))
+            .await;
+
+        let written: Value = serde_json::from_str(&stdout_rx.recv().await.unwrap()).unwrap();
+        assert_eq!(written["error"]["code"], PROXY_ERROR_CODE);
+    }
+
+    #[tokio::test]
+    async fn renews_the_session_the_app_dropped() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header(SESSION_ID_HEADER, "old"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": "initialize" })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(SESSION_ID_HEADER, "new")
+                    .set_body_json(json!({
+                        "jsonrpc": "2.0",
+                        "id": 0,
+                        "result": { "protocolVersion": "2025-03-26" },
+                    })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(header(SESSION_ID_HEADER, "new"))
+            .and(body_partial_json(
+                json!({ "method": "notifications/initialized" }),
+            ))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(header(SESSION_ID_HEADER, "new"))
+            .and(body_partial_json(json!({ "method": "tools/list" })))
+            .respond_with(reply(2))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(405))
+            .mount(&server)
+            .await;
+
+        let dir = tempdir().unwrap();
+        let (proxy, mut stdout_rx) = test_proxy(
+            &server,
+            dir.path().join(STDIO_CREDENTIALS_FILE_NAME),
+            "tu_token",
+        );
+        {
+            let mut state = proxy.state.lock().await;
+            state.session = Some(Session {
+                id: "old".to_string(),
+                protocol_version: None,
+            });
+            state.initialize =
+                Some(json!({ "jsonrpc": "2.0", "id": 0, "method": "initialize", "params": {} }));
+        }
+
+        proxy
+            .forward(json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }))
+            .await;
+
+        // Only the reply to the agent's request is forwarded, not the replayed handshake
+        let written: Value = serde_json::from_str(&stdout_rx.recv().await.unwrap()).unwrap();
+        assert_eq!(written["id"], 2);
+        assert!(written.get("error").is_none());
+        assert_eq!(
+            proxy.session().await,
+            Some(Session {
+                id: "new".to_string(),
+                protocol_version: Some("2025-03-26".to_string()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn drops_the_session_when_it_cannot_be_renewed() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempdir().unwrap();
+        let (proxy, mut stdout_rx) = test_proxy(
+            &server,
+            dir.path().join(STDIO_CREDENTIALS_FILE_NAME),
+            "tu_token",
+        );
+        proxy.state.lock().await.session = Some(Session {
+            id: "old".to_string(),
+            protocol_version: None,
+        });
+
+        proxy
+            .forward(json!({ "jsonrpc": "2.0", "id": 3, "method": "tools/list" }))
+            .await;
+
+        let written: Value = serde_json::from_str(&stdout_rx.recv().await.unwrap()).unwrap();
+        assert_eq!(written["error"]["code"], PROXY_ERROR_CODE);
+        assert_eq!(proxy.session().await, None);
+    }
+}
@@ -213,3 +213,31 @@ pub async fn get_gpu_devices() -> Result<String, String> {
 
     serde_json::to_string(&result).map_err(|e| e.to_string())
 }
+
+pub async fn set_gpu_device_excluded(device_index: u32, excluded: bool) -> Result<String, String> {
+    if ConfigMining::content()
+        .await
+        .gpu_devices_settings()
+        .get(device_index)
+        .is_none()
+    {
+        return Err(format!("Unknown GPU device: {device_index}"));
+    }
+
+    let update = if excluded {
+        ConfigMiningContent::enable_gpu_device_exclusion
+    } else {
+        ConfigMiningContent::disable_gpu_device_exclusion
+    };
+    ConfigMining::update_field(update, device_index)
+        .await
+        .map_err(|e| format!("Failed to update GPU device exclusion: {e}"))?;
+
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+
+    let result = json!({
+        "device_index": device_index,
+        "excluded": excluded,
+    });
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
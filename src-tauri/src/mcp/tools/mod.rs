@@ -24,34 +24,45 @@ pub mod chain;
 #[cfg(test)]
 mod chain_test;
 pub mod mining;
+pub mod pools;
 pub mod scheduler;
 pub mod transaction;
 pub mod wallet;
 
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 use std::time::Instant;
 
 use log::info;
 use rmcp::handler::server::router::tool::ToolRouter;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::*;
-use rmcp::{ServerHandler, tool, tool_handler, tool_router};
+use rmcp::service::RequestContext;
+use rmcp::{ErrorData, RoleServer, ServerHandler, tool, tool_handler, tool_router};
 use schemars::JsonSchema;
 use serde::Deserialize;
-use tokio::sync::watch;
+use tokio::sync::{Mutex, watch};
 
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::configs::config_mcp::ConfigMcp;
 use crate::configs::trait_config::ConfigImpl;
 use crate::mcp::audit::{AuditEntry, AuditLog, AuditStatus};
+use crate::mcp::clients::McpClient;
+use crate::mcp::rate_limiter::ClientRateLimiter;
+use crate::mcp::resources::{self, ResourceSubscriptions};
 use crate::node::node_adapter::BaseNodeStatus;
 use crate::wallet::wallet_manager::WalletManager;
+use crate::wallet::wallet_types::WalletState;
+
+static CLIENT_RATE_LIMITER: LazyLock<Mutex<ClientRateLimiter>> =
+    LazyLock::new(|| Mutex::new(ClientRateLimiter::new()));
 
 #[derive(Clone)]
 pub struct TariMcpHandler {
     tool_router: ToolRouter<Self>,
     node_status_rx: Arc<watch::Receiver<BaseNodeStatus>>,
     wallet_manager: WalletManager,
+    wallet_state_rx: Arc<watch::Receiver<Option<WalletState>>>,
+    resource_subscriptions: ResourceSubscriptions,
 }
 
 // rmcp 2.0's `#[tool_handler]` defaults to rebuilding the router via
@@ -66,7 +77,13 @@ impl ServerHandler for TariMcpHandler {
         // rmcp 2.0 marked ServerInfo (InitializeResult) and Implementation as
         // #[non_exhaustive], so they must be built via constructors + `with_*`
         // setters rather than struct literals.
-        ServerInfo::new(ServerCapabilities::builder().enable_tools().build())
+        ServerInfo::new(
+            ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .build(),
+        )
             .with_protocol_version(ProtocolVersion::V_2025_03_26)
             .with_server_info(
                 Implementation::new("tari-universe", env!("CARGO_PKG_VERSION"))
@@ -76,8 +93,87 @@ impl ServerHandler for TariMcpHandler {
                     ),
             )
             .with_instructions(
-                "Tari Universe MCP server. Available tool categories: mining (start/stop/mode), wallet (address/balance), chain (block height/sync status), and scheduler (scheduled mining events). Use get_mining_status, get_wallet_address, and get_chain_status to get an overview.",
+                "Tari Universe MCP server. Available tool categories: mining (start/stop/mode/GPU devices), pools (select/configure/status), wallet (address/balance), chain (block height/sync status), and scheduler (scheduled mining events). Use get_mining_status, get_wallet_address, and get_chain_status to get an overview. Mining status, chain status, wallet balance, scheduled events and the audit log are also available as resources under tari://; subscribe to them to be notified of changes instead of polling.",
+            )
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, ErrorData> {
+        let client = McpClient::from_context(&context);
+        let mut enabled = Vec::new();
+        for resource in resources::list_resources() {
+            if let Some(tier) = resources::tier_for(&resource.raw.uri)
+                && Self::is_tier_enabled(tier).await
+                && client.is_allowed("read_resource", tier)
+            {
+                enabled.push(resource);
+            }
+        }
+        Ok(ListResourcesResult::with_all_items(enabled))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, ErrorData> {
+        let client = McpClient::from_context(&context);
+        let uri = request.uri;
+        let tier = self.check_resource_tier(&client, &uri).await?;
+        let start = Instant::now();
+        let result =
+            resources::read_resource(&uri, &self.node_status_rx, &self.wallet_manager).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        AuditLog::record(AuditEntry {
+            timestamp: std::time::SystemTime::now(),
+            tool_name: "read_resource".to_string(),
+            tier: tier.to_string(),
+            status,
+            duration_ms: Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+            client_info: Some(client.name.clone()),
+            details: Some(uri.clone()),
+            prev_hash: None,
+        })
+        .await;
+        let contents = result.map_err(|e| ErrorData::internal_error(e, None))?;
+        Ok(ReadResourceResult::new(vec![ResourceContents::text(
+            contents, uri,
+        )]))
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let client = McpClient::from_context(&context);
+        self.check_resource_tier(&client, &request.uri).await?;
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP client subscribed to {}", request.uri);
+        self.resource_subscriptions
+            .subscribe(
+                request.uri,
+                context.peer,
+                (*self.node_status_rx).clone(),
+                (*self.wallet_state_rx).clone(),
             )
+            .await;
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        self.resource_subscriptions.unsubscribe(&request.uri).await;
+        Ok(())
     }
 }
 
@@ -91,10 +187,46 @@ struct StartStopMiningParams {
 
 #[derive(Deserialize, JsonSchema)]
 struct SetMiningModeParams {
-    /// Mining mode name: Eco, Turbo, Ludicrous, or Custom
+    /// Mining mode name: Eco, Turbo, Ludicrous, Custom, or Auto
     mode: String,
 }
 
+#[derive(Deserialize, JsonSchema)]
+struct SelectPoolParams {
+    /// Device the pool is used by: "cpu" or "gpu"
+    device: String,
+    /// Pool key as returned by list_pools (e.g., LuckyPoolRANDOMX or Custom:<id>)
+    pool: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ConfigurePoolParams {
+    /// Device whose selected pool is configured: "cpu" or "gpu"
+    device: String,
+    /// New stratum URL of the pool, only supported for custom pools
+    pool_url: Option<String>,
+    /// New stats API URL of the pool, only supported for custom pools
+    stats_url: Option<String>,
+    /// New worker name, only supported for custom pools. Empty string clears it.
+    worker_name: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct TogglePoolMiningParams {
+    /// Device to toggle pool mining for: "cpu" or "gpu"
+    device: String,
+    /// Mine to the selected pool when true, solo mine when false
+    enabled: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SetGpuDeviceExcludedParams {
+    /// Index of the GPU device as returned by get_gpu_devices
+    device_index: u32,
+    /// Exclude the device from mining when true, include it again when false
+    excluded: bool,
+}
+
 #[derive(Deserialize, JsonSchema)]
 struct GetTransactionHistoryParams {
     /// Maximum number of transactions to return. Defaults to 20.
@@ -129,11 +261,11 @@ struct CancelScheduledEventParams {
 
 #[derive(Deserialize, JsonSchema)]
 struct SendTransactionParams {
-    /// Tari address to send to (base58, hex, or emoji format)
+    /// Tari address to send to (base58, hex, or emoji format) or an address book contact name
     destination: String,
     /// Amount to send in XTM (e.g., "1.5")
     amount: String,
-    /// Optional payment ID for the transaction
+    /// Optional payment ID for the transaction, defaults to the contact's preset
     payment_id: Option<String>,
 }
 
@@ -141,20 +273,96 @@ impl TariMcpHandler {
     pub fn new(
         node_status_rx: Arc<watch::Receiver<BaseNodeStatus>>,
         wallet_manager: WalletManager,
+        wallet_state_rx: Arc<watch::Receiver<Option<WalletState>>>,
     ) -> Self {
         Self {
             tool_router: Self::tool_router(),
             node_status_rx,
             wallet_manager,
+            wallet_state_rx,
+            resource_subscriptions: ResourceSubscriptions::default(),
         }
     }
 
+    /// Resolves the tier of a resource URI, rejecting unknown URIs and unauthorized clients
+    async fn check_resource_tier(
+        &self,
+        client: &McpClient,
+        uri: &str,
+    ) -> Result<&'static str, ErrorData> {
+        let Some(tier) = resources::tier_for(uri) else {
+            return Err(ErrorData::resource_not_found(
+                format!("Unknown resource: {uri}"),
+                None,
+            ));
+        };
+        self.authorize(client, "read_resource", tier)
+            .await
+            .map_err(|e| ErrorData::invalid_request(e, None))?;
+        Ok(tier)
+    }
+
+    /// Checks the global tier switch, the client's scopes and its rate limit
+    async fn authorize(
+        &self,
+        client: &McpClient,
+        tool_name: &str,
+        tier: &str,
+    ) -> Result<(), String> {
+        if !Self::is_tier_enabled(tier).await {
+            let label = match tier {
+                "read" => "Read",
+                "control" => "Control",
+                _ => "Transaction",
+            };
+            return Err(format!("{label} tier is disabled"));
+        }
+        if !client.is_allowed(tool_name, tier) {
+            self.audit_tool_call(client, tool_name, tier, AuditStatus::Denied, None)
+                .await;
+            return Err(format!(
+                "Client '{}' is not permitted to call {tool_name}",
+                client.name
+            ));
+        }
+        if let Some(limit) = client.rate_limit_per_minute
+            && !CLIENT_RATE_LIMITER.lock().await.check_request_allowed(
+                &client.name,
+                limit,
+                std::time::Instant::now(),
+            )
+        {
+            self.audit_tool_call(client, tool_name, tier, AuditStatus::RateLimited, None)
+                .await;
+            return Err(format!(
+                "Rate limit of {limit} calls per minute exceeded for client '{}'",
+                client.name
+            ));
+        }
+        Ok(())
+    }
+
     async fn audit_tool_call(
         &self,
+        client: &McpClient,
+        tool_name: &str,
+        tier: &str,
+        status: AuditStatus,
+        duration_ms: Option<u64>,
+    ) {
+        self.audit_tool_call_with_details(client, tool_name, tier, status, duration_ms, None)
+            .await;
+    }
+
+    /// Records a tool call together with what it changed, e.g. the pool that was selected
+    async fn audit_tool_call_with_details(
+        &self,
+        client: &McpClient,
         tool_name: &str,
         tier: &str,
         status: AuditStatus,
         duration_ms: Option<u64>,
+        details: Option<String>,
     ) {
         let entry = AuditEntry {
             timestamp: std::time::SystemTime::now(),
@@ -162,8 +370,9 @@ impl TariMcpHandler {
             tier: tier.to_string(),
             status,
             duration_ms,
-            client_info: None,
-            details: None,
+            client_info: Some(client.name.clone()),
+            details,
+            prev_hash: None,
         };
         AuditLog::record(entry).await;
     }
@@ -188,13 +397,22 @@ impl TariMcpHandler {
         name = "get_mining_status",
         description = "Get current mining status: CPU/GPU running state, mining mode, and enabled settings"
     )]
-    async fn get_mining_status(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn get_mining_status(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_mining_status", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_mining_status", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "get_mining_status",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::get_mining_status().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -202,6 +420,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_mining_status",
             "control",
             status,
@@ -216,13 +435,19 @@ impl TariMcpHandler {
         name = "get_mining_mode",
         description = "Get the currently selected mining mode and its CPU/GPU usage percentages"
     )]
-    async fn get_mining_mode(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn get_mining_mode(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_mining_mode", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_mining_mode", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "get_mining_mode",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::get_mining_mode().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -230,6 +455,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_mining_mode",
             "control",
             status,
@@ -242,15 +468,24 @@ impl TariMcpHandler {
     /// List all available mining modes and their configurations.
     #[tool(
         name = "list_mining_modes",
-        description = "List all available mining modes (Eco, Turbo, Ludicrous, Custom) with their CPU/GPU usage settings"
+        description = "List all available mining modes (Eco, Turbo, Ludicrous, Custom, Auto) with their CPU/GPU usage settings"
     )]
-    async fn list_mining_modes(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn list_mining_modes(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "list_mining_modes", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("list_mining_modes", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "list_mining_modes",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::list_mining_modes().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -258,6 +493,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "list_mining_modes",
             "control",
             status,
@@ -275,14 +511,20 @@ impl TariMcpHandler {
     async fn start_mining(
         &self,
         Parameters(params): Parameters<StartStopMiningParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "start_mining", "control").await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: start_mining called (cpu={:?}, gpu={:?})", params.cpu, params.gpu);
-        self.audit_tool_call("start_mining", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "start_mining",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::start_mining(params.cpu, params.gpu).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -290,6 +532,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "start_mining",
             "control",
             status,
@@ -307,14 +550,20 @@ impl TariMcpHandler {
     async fn stop_mining(
         &self,
         Parameters(params): Parameters<StartStopMiningParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "stop_mining", "control").await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: stop_mining called (cpu={:?}, gpu={:?})", params.cpu, params.gpu);
-        self.audit_tool_call("stop_mining", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "stop_mining",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::stop_mining(params.cpu, params.gpu).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -322,6 +571,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "stop_mining",
             "control",
             status,
@@ -334,19 +584,26 @@ impl TariMcpHandler {
     /// Set the mining mode.
     #[tool(
         name = "set_mining_mode",
-        description = "Switch mining mode to Eco, Turbo, Ludicrous, or Custom"
+        description = "Switch mining mode to Eco, Turbo, Ludicrous, Custom, or Auto"
     )]
     async fn set_mining_mode(
         &self,
         Parameters(params): Parameters<SetMiningModeParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "set_mining_mode", "control")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: set_mining_mode called (mode={})", params.mode);
-        self.audit_tool_call("set_mining_mode", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "set_mining_mode",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::set_mining_mode(params.mode).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -354,6 +611,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "set_mining_mode",
             "control",
             status,
@@ -368,13 +626,19 @@ impl TariMcpHandler {
         name = "get_gpu_devices",
         description = "List GPU devices with vendor, name, availability, and current parameters (temperature, usage)"
     )]
-    async fn get_gpu_devices(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn get_gpu_devices(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_gpu_devices", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_gpu_devices", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "get_gpu_devices",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::get_gpu_devices().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -382,6 +646,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_gpu_devices",
             "control",
             status,
@@ -391,6 +656,243 @@ impl TariMcpHandler {
         result
     }
 
+    /// Exclude or include a GPU device.
+    #[tool(
+        name = "set_gpu_device_excluded",
+        description = "Exclude a GPU device from mining or include it again"
+    )]
+    async fn set_gpu_device_excluded(
+        &self,
+        Parameters(params): Parameters<SetGpuDeviceExcludedParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "set_gpu_device_excluded", "control")
+            .await?;
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: set_gpu_device_excluded called (device_index={}, excluded={})", params.device_index, params.excluded);
+        self.audit_tool_call(
+            &client,
+            "set_gpu_device_excluded",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = mining::set_gpu_device_excluded(params.device_index, params.excluded).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            &client,
+            "set_gpu_device_excluded",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    // ==================== Pool Tools (Control tier) ====================
+
+    /// List the configured CPU and GPU pools.
+    #[tool(
+        name = "list_pools",
+        description = "List configured CPU and GPU pools with the selected pool and whether pool mining is enabled"
+    )]
+    async fn list_pools(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "list_pools", "control").await?;
+        let start = Instant::now();
+        self.audit_tool_call(&client, "list_pools", "control", AuditStatus::Started, None)
+            .await;
+        let result = pools::list_pools().await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            &client,
+            "list_pools",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Select the CPU or GPU pool.
+    #[tool(
+        name = "select_pool",
+        description = "Select the pool used for CPU or GPU pool mining"
+    )]
+    async fn select_pool(
+        &self,
+        Parameters(params): Parameters<SelectPoolParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "select_pool", "control").await?;
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: select_pool called (device={}, pool={})", params.device, params.pool);
+        let details = format!("device={}, pool={}", params.device, params.pool);
+        self.audit_tool_call_with_details(
+            &client,
+            "select_pool",
+            "control",
+            AuditStatus::Started,
+            None,
+            Some(details.clone()),
+        )
+        .await;
+        let result = pools::select_pool(&params.device, params.pool).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call_with_details(
+            &client,
+            "select_pool",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+            Some(details),
+        )
+        .await;
+        result
+    }
+
+    /// Configure the selected CPU or GPU pool.
+    #[tool(
+        name = "configure_pool",
+        description = "Update the URL, stats URL, or worker name of the selected CPU or GPU pool"
+    )]
+    async fn configure_pool(
+        &self,
+        Parameters(params): Parameters<ConfigurePoolParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "configure_pool", "control").await?;
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: configure_pool called (device={})", params.device);
+        let changes = pools::PoolConfigChanges {
+            pool_url: params.pool_url,
+            stats_url: params.stats_url,
+            worker_name: params.worker_name,
+        };
+        let details = format!("device={}, {}", params.device, changes.describe());
+        self.audit_tool_call_with_details(
+            &client,
+            "configure_pool",
+            "control",
+            AuditStatus::Started,
+            None,
+            Some(details.clone()),
+        )
+        .await;
+        let result = pools::configure_selected_pool(&params.device, changes).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call_with_details(
+            &client,
+            "configure_pool",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+            Some(details),
+        )
+        .await;
+        result
+    }
+
+    /// Toggle CPU or GPU pool mining.
+    #[tool(
+        name = "toggle_pool_mining",
+        description = "Enable or disable pool mining for CPU or GPU. Disabling switches the device to solo mining"
+    )]
+    async fn toggle_pool_mining(
+        &self,
+        Parameters(params): Parameters<TogglePoolMiningParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "toggle_pool_mining", "control")
+            .await?;
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: toggle_pool_mining called (device={}, enabled={})", params.device, params.enabled);
+        let details = format!("device={}, enabled={}", params.device, params.enabled);
+        self.audit_tool_call_with_details(
+            &client,
+            "toggle_pool_mining",
+            "control",
+            AuditStatus::Started,
+            None,
+            Some(details.clone()),
+        )
+        .await;
+        let result = pools::set_pool_mining_enabled(&params.device, params.enabled).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call_with_details(
+            &client,
+            "toggle_pool_mining",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+            Some(details),
+        )
+        .await;
+        result
+    }
+
+    /// Get the current status of the CPU and GPU pools.
+    #[tool(
+        name = "get_pool_status",
+        description = "Get accepted shares, unpaid and balance of the CPU and GPU pools"
+    )]
+    async fn get_pool_status(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_pool_status", "control")
+            .await?;
+        let start = Instant::now();
+        self.audit_tool_call(
+            &client,
+            "get_pool_status",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = pools::get_pool_status().await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            &client,
+            "get_pool_status",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
     // ==================== Wallet Tools (Read tier) ====================
 
     /// Get the wallet's Tari address in multiple formats.
@@ -398,13 +900,22 @@ impl TariMcpHandler {
         name = "get_wallet_address",
         description = "Get the wallet's Tari address in emoji, base58, and hex formats"
     )]
-    async fn get_wallet_address(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_wallet_address(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_wallet_address", "read")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_wallet_address", "read", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "get_wallet_address",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = wallet::get_wallet_address().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -412,6 +923,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_wallet_address",
             "read",
             status,
@@ -426,13 +938,22 @@ impl TariMcpHandler {
         name = "get_wallet_balance",
         description = "Get the wallet balance including available, pending, and timelocked amounts"
     )]
-    async fn get_wallet_balance(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_wallet_balance(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_wallet_balance", "read")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_wallet_balance", "read", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "get_wallet_balance",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = wallet::get_wallet_balance(&self.wallet_manager).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -440,6 +961,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_wallet_balance",
             "read",
             status,
@@ -457,12 +979,14 @@ impl TariMcpHandler {
     async fn get_transaction_history(
         &self,
         Parameters(params): Parameters<GetTransactionHistoryParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_transaction_history", "read")
+            .await?;
         let start = Instant::now();
         self.audit_tool_call(
+            &client,
             "get_transaction_history",
             "read",
             AuditStatus::Started,
@@ -476,6 +1000,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_transaction_history",
             "read",
             status,
@@ -492,13 +1017,21 @@ impl TariMcpHandler {
         name = "get_chain_status",
         description = "Get chain status: block height, block time, block reward, sync status, peer count"
     )]
-    async fn get_chain_status(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_chain_status(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_chain_status", "read").await?;
         let start = Instant::now();
-        self.audit_tool_call("get_chain_status", "read", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "get_chain_status",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let status = *self.node_status_rx.borrow();
         let result = chain::get_chain_status(&status);
         let audit_status = if result.is_ok() {
@@ -507,6 +1040,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_chain_status",
             "read",
             audit_status,
@@ -521,13 +1055,21 @@ impl TariMcpHandler {
         name = "get_network_info",
         description = "Get network info: network name, sync status, connection count"
     )]
-    async fn get_network_info(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_network_info(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "get_network_info", "read").await?;
         let start = Instant::now();
-        self.audit_tool_call("get_network_info", "read", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            &client,
+            "get_network_info",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let status = *self.node_status_rx.borrow();
         let result = chain::get_network_info(&status);
         let audit_status = if result.is_ok() {
@@ -536,6 +1078,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "get_network_info",
             "read",
             audit_status,
@@ -550,14 +1093,18 @@ impl TariMcpHandler {
     /// List all scheduled mining events.
     #[tool(
         name = "list_scheduled_events",
-        description = "List all scheduled mining events with their timing and state"
+        description = "List all scheduled mining events with their timing and state, including the tariff_* events generated from the electricity tariff file"
     )]
-    async fn list_scheduled_events(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn list_scheduled_events(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "list_scheduled_events", "control")
+            .await?;
         let start = Instant::now();
         self.audit_tool_call(
+            &client,
             "list_scheduled_events",
             "control",
             AuditStatus::Started,
@@ -571,6 +1118,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "list_scheduled_events",
             "control",
             status,
@@ -588,16 +1136,18 @@ impl TariMcpHandler {
     async fn schedule_mining_window(
         &self,
         Parameters(params): Parameters<ScheduleMiningWindowParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "schedule_mining_window", "control")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: schedule_mining_window called (id={}, mode={}, start={}:{:02} {}, end={}:{:02} {})",
             params.event_id, params.mining_mode,
             params.start_hour, params.start_minute.unwrap_or(0), params.start_period,
             params.end_hour, params.end_minute.unwrap_or(0), params.end_period);
         self.audit_tool_call(
+            &client,
             "schedule_mining_window",
             "control",
             AuditStatus::Started,
@@ -621,6 +1171,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "schedule_mining_window",
             "control",
             status,
@@ -638,13 +1189,15 @@ impl TariMcpHandler {
     async fn cancel_scheduled_event(
         &self,
         Parameters(params): Parameters<CancelScheduledEventParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "cancel_scheduled_event", "control")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: cancel_scheduled_event called (id={})", params.event_id);
         self.audit_tool_call(
+            &client,
             "cancel_scheduled_event",
             "control",
             AuditStatus::Started,
@@ -658,6 +1211,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &client,
             "cancel_scheduled_event",
             "control",
             status,
@@ -672,18 +1226,20 @@ impl TariMcpHandler {
     /// Send a one-sided stealth transaction to a Tari address.
     #[tool(
         name = "send_transaction",
-        description = "Send XTM to a Tari address. Requires PIN confirmation via in-app dialog (120s timeout). Amount is in XTM (e.g., '1.5')"
+        description = "Send XTM to a Tari address. Requires PIN confirmation via in-app dialog (120s timeout). Subject to configured daily/weekly budgets and address denylist; small sends to allowlisted addresses may skip the confirmation dialog but never the PIN. Amount is in XTM (e.g., '1.5')"
     )]
     async fn send_transaction(
         &self,
         Parameters(params): Parameters<SendTransactionParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("transaction").await {
-            return Err("Transaction tier is disabled".to_string());
-        }
+        let client = McpClient::from_context(&context);
+        self.authorize(&client, "send_transaction", "transaction")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: send_transaction called (destination={}, amount={})", params.destination, params.amount);
         self.audit_tool_call(
+            &client,
             "send_transaction",
             "transaction",
             AuditStatus::Started,
@@ -696,6 +1252,7 @@ impl TariMcpHandler {
             params.destination,
             params.amount,
             params.payment_id,
+            client.max_transaction_amount,
             &self.wallet_manager,
             &app_handle,
         )
@@ -703,11 +1260,15 @@ impl TariMcpHandler {
 
         let status = match &result {
             Ok(_) => AuditStatus::Success,
-            Err(transaction::TransactionError::Denied(_)) => AuditStatus::Denied,
+            Err(
+                transaction::TransactionError::Denied(_)
+                | transaction::TransactionError::BudgetExceeded(_),
+            ) => AuditStatus::Denied,
             Err(transaction::TransactionError::RateLimited(_)) => AuditStatus::RateLimited,
             Err(_) => AuditStatus::Error,
         };
         self.audit_tool_call(
+            &client,
             "send_transaction",
             "transaction",
             status,
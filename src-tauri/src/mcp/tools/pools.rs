@@ -0,0 +1,470 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use log::warn;
+use serde_json::{Value, json};
+use tauri::ipc::InvokeError;
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::commands;
+use crate::configs::config_pools::ConfigPools;
+use crate::configs::pools::cpu_pools::CpuPool;
+use crate::configs::pools::gpu_pools::GpuPool;
+use crate::configs::pools::{BasePoolData, PoolOrigin};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events_emitter::EventsEmitter;
+use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::manager::GpuManager;
+use crate::mining::pools::PoolManagerInterfaceTrait;
+use crate::mining::pools::cpu_pool_manager::CpuPoolManager;
+use crate::mining::pools::gpu_pool_manager::GpuPoolManager;
+use crate::setup::setup_manager::SetupManager;
+
+/// Device the pool tools operate on
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PoolDevice {
+    Cpu,
+    Gpu,
+}
+
+impl PoolDevice {
+    fn parse(device: &str) -> Result<Self, String> {
+        match device.to_lowercase().as_str() {
+            "cpu" => Ok(Self::Cpu),
+            "gpu" => Ok(Self::Gpu),
+            _ => Err(format!(
+                "Invalid device '{device}', expected 'cpu' or 'gpu'"
+            )),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Cpu => "CPU",
+            Self::Gpu => "GPU",
+        }
+    }
+}
+
+/// Changes applied to the selected pool, unset fields keep their current value
+pub struct PoolConfigChanges {
+    pub pool_url: Option<String>,
+    pub stats_url: Option<String>,
+    pub worker_name: Option<String>,
+}
+
+impl PoolConfigChanges {
+    /// Summary of the provided changes for the audit log
+    pub fn describe(&self) -> String {
+        let fields = [
+            ("pool_url", &self.pool_url),
+            ("stats_url", &self.stats_url),
+            ("worker_name", &self.worker_name),
+        ];
+        fields
+            .iter()
+            .filter_map(|(name, value)| value.as_ref().map(|value| format!("{name}={value}")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+const STRATUM_SCHEMES: [&str; 3] = ["stratum+tcp://", "stratum+ssl://", "stratum://"];
+
+fn pool_json<T: ToString>(pool: &BasePoolData<T>, is_selected: bool) -> serde_json::Value {
+    json!({
+        "pool": pool.pool_type.to_string(),
+        "pool_name": pool.pool_name,
+        "pool_url": pool.pool_url,
+        "stats_url": pool.stats_url,
+        "origin": format!("{:?}", pool.pool_origin),
+        "worker_name": pool.worker_name(),
+        "selected": is_selected,
+    })
+}
+
+fn sorted_pools<T: ToString>(
+    pools: &HashMap<T, BasePoolData<T>>,
+    selected: &T,
+) -> Vec<serde_json::Value> {
+    let selected = selected.to_string();
+    let mut result: Vec<serde_json::Value> = pools
+        .values()
+        .map(|pool| pool_json(pool, pool.pool_type.to_string() == selected))
+        .collect();
+    result.sort_by(|a, b| a["pool"].as_str().cmp(&b["pool"].as_str()));
+    result
+}
+
+/// Checks the url is a stratum url with a host and a port, as the miners expect
+fn validate_pool_url(pool_url: &str) -> Result<(), String> {
+    let address = STRATUM_SCHEMES
+        .iter()
+        .find_map(|scheme| pool_url.strip_prefix(scheme))
+        .ok_or_else(|| {
+            format!(
+                "Pool URL must start with one of: {}",
+                STRATUM_SCHEMES.join(", ")
+            )
+        })?;
+    match address.rsplit_once(':') {
+        Some((host, port))
+            if !host.is_empty()
+                && !host.contains(['/', ' '])
+                && port.parse::<u16>().is_ok_and(|port| port != 0) =>
+        {
+            Ok(())
+        }
+        _ => Err(format!(
+            "Pool URL must be in the form <scheme>host:port, got {pool_url}"
+        )),
+    }
+}
+
+fn apply_changes<T>(pool: &mut BasePoolData<T>, changes: PoolConfigChanges) -> Result<(), String> {
+    // The built in pools use a fixed url, stats url and worker name. The stats url is fetched
+    // with the mining address, so it can't be pointed elsewhere either
+    let is_custom = matches!(pool.pool_origin, PoolOrigin::Custom);
+    if let Some(pool_url) = changes.pool_url {
+        if !is_custom {
+            return Err("Pool URL can only be changed for custom pools".to_string());
+        }
+        let pool_url = pool_url.trim().to_string();
+        validate_pool_url(&pool_url)?;
+        pool.pool_url = pool_url;
+    }
+    if let Some(stats_url) = changes.stats_url {
+        if !is_custom {
+            return Err("Stats URL can only be changed for custom pools".to_string());
+        }
+        pool.stats_url = stats_url;
+    }
+    if let Some(worker_name) = changes.worker_name {
+        if !is_custom {
+            return Err("Worker name can only be changed for custom pools".to_string());
+        }
+        pool.worker_name = Some(worker_name).filter(|name| !name.is_empty());
+    }
+    Ok(())
+}
+
+fn command_error(error: InvokeError) -> String {
+    match error {
+        InvokeError(Value::String(e)) => e,
+        InvokeError(e) => e.to_string(),
+    }
+}
+
+/// Runs a pool change with the device's miner stopped and restarts it afterwards,
+/// the same way the frontend does, so the miner picks up the new pool
+async fn with_miner_restarted(
+    device: PoolDevice,
+    change: impl Future<Output = Result<(), String>>,
+) -> Result<(), String> {
+    let was_running = match device {
+        PoolDevice::Cpu => CpuManager::read().await.is_running(),
+        PoolDevice::Gpu => GpuManager::read().await.is_running(),
+    };
+
+    if was_running {
+        match device {
+            PoolDevice::Cpu => commands::stop_cpu_mining().await?,
+            PoolDevice::Gpu => commands::stop_gpu_mining().await?,
+        }
+    }
+
+    let result = change.await;
+
+    // Restart even when the change failed so the device keeps mining to the previous pool
+    if was_running {
+        let restarted = match device {
+            PoolDevice::Cpu => commands::start_cpu_mining().await,
+            PoolDevice::Gpu => commands::start_gpu_mining().await,
+        };
+        if let Err(e) = restarted {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP: failed to restart {} mining after pool change: {e}", device.label());
+            return result.and(Err(format!(
+                "Pool changed but {} mining failed to restart: {e}",
+                device.label()
+            )));
+        }
+    }
+
+    result
+}
+
+pub async fn list_pools() -> Result<String, String> {
+    let config = ConfigPools::content().await;
+
+    let result = json!({
+        "cpu": {
+            "pool_mining_enabled": config.cpu_pool_enabled(),
+            "selected_pool": config.current_cpu_pool_type().to_string(),
+            "pools": sorted_pools(config.cpu_pools(), config.current_cpu_pool_type()),
+        },
+        "gpu": {
+            "pool_mining_enabled": config.gpu_pool_enabled(),
+            "selected_pool": config.current_gpu_pool_type().to_string(),
+            "pools": sorted_pools(config.gpu_pools(), config.current_gpu_pool_type()),
+        },
+    });
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+pub async fn select_pool(device: &str, pool: String) -> Result<String, String> {
+    let device = PoolDevice::parse(device)?;
+    let config = ConfigPools::content().await;
+    let is_known = match device {
+        PoolDevice::Cpu => config
+            .cpu_pools()
+            .contains_key(&CpuPool::from_string(&pool).map_err(|e| e.to_string())?),
+        PoolDevice::Gpu => config
+            .gpu_pools()
+            .contains_key(&GpuPool::from_string(&pool).map_err(|e| e.to_string())?),
+    };
+    if !is_known {
+        return Err(format!("Unknown {} pool: {pool}", device.label()));
+    }
+
+    with_miner_restarted(device, async {
+        match device {
+            PoolDevice::Cpu => commands::change_cpu_pool(pool).await,
+            PoolDevice::Gpu => commands::change_gpu_pool(pool).await,
+        }
+        .map_err(|e| {
+            format!(
+                "Failed to select {} pool: {}",
+                device.label(),
+                command_error(e)
+            )
+        })
+    })
+    .await?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+
+    list_pools().await
+}
+
+pub async fn configure_selected_pool(
+    device: &str,
+    changes: PoolConfigChanges,
+) -> Result<String, String> {
+    let device = PoolDevice::parse(device)?;
+    let config = ConfigPools::content().await;
+    let updated = match device {
+        PoolDevice::Cpu => {
+            let mut pool = config.current_cpu_pool();
+            apply_changes(&mut pool, changes)?;
+            with_miner_restarted(device, async {
+                commands::update_selected_cpu_pool_config(pool.clone())
+                    .await
+                    .map_err(|e| format!("Failed to update CPU pool: {}", command_error(e)))?;
+                CpuPoolManager::handle_new_selected_pool(pool.clone()).await;
+                Ok(())
+            })
+            .await?;
+            pool_json(&pool, true)
+        }
+        PoolDevice::Gpu => {
+            let mut pool = config.current_gpu_pool();
+            apply_changes(&mut pool, changes)?;
+            with_miner_restarted(device, async {
+                commands::update_selected_gpu_pool_config(pool.clone())
+                    .await
+                    .map_err(|e| format!("Failed to update GPU pool: {}", command_error(e)))?;
+                GpuPoolManager::handle_new_selected_pool(pool.clone()).await;
+                Ok(())
+            })
+            .await?;
+            pool_json(&pool, true)
+        }
+    };
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+
+    serde_json::to_string(&updated).map_err(|e| e.to_string())
+}
+
+pub async fn set_pool_mining_enabled(device: &str, enabled: bool) -> Result<String, String> {
+    let device = PoolDevice::parse(device)?;
+    let setup_manager = SetupManager::get_instance();
+    let result = match (device, enabled) {
+        (PoolDevice::Cpu, true) => setup_manager.turn_on_cpu_pool_feature().await,
+        (PoolDevice::Cpu, false) => setup_manager.turn_off_cpu_pool_feature().await,
+        (PoolDevice::Gpu, true) => setup_manager.turn_on_gpu_pool_feature().await,
+        (PoolDevice::Gpu, false) => setup_manager.turn_off_gpu_pool_feature().await,
+    };
+    result.map_err(|e| format!("Failed to toggle pool mining: {e}"))?;
+
+    let config = ConfigPools::content().await;
+    let result = json!({
+        "cpu_pool_mining_enabled": config.cpu_pool_enabled(),
+        "gpu_pool_mining_enabled": config.gpu_pool_enabled(),
+    });
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+pub async fn get_pool_status() -> Result<String, String> {
+    let config = ConfigPools::content().await;
+
+    let result = json!({
+        "cpu": {
+            "pool_mining_enabled": config.cpu_pool_enabled(),
+            "selected_pool": config.current_cpu_pool_type().to_string(),
+            "statuses": CpuPoolManager::get_pool_statuses().await,
+        },
+        "gpu": {
+            "pool_mining_enabled": config.gpu_pool_enabled(),
+            "selected_pool": config.current_gpu_pool_type().to_string(),
+            "statuses": GpuPoolManager::get_pool_statuses().await,
+        },
+    });
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_pool() -> BasePoolData<CpuPool> {
+        BasePoolData {
+            pool_name: "My pool".to_string(),
+            pool_url: "stratum+tcp://pool.example:3333".to_string(),
+            stats_url: String::new(),
+            pool_type: CpuPool::Custom {
+                id: "mine".to_string(),
+            },
+            pool_origin: PoolOrigin::Custom,
+            worker_name: None,
+            stats_mapping: None,
+            algorithm: None,
+        }
+    }
+
+    #[test]
+    fn parses_pool_device() {
+        assert_eq!(PoolDevice::parse("CPU"), Ok(PoolDevice::Cpu));
+        assert_eq!(PoolDevice::parse("gpu"), Ok(PoolDevice::Gpu));
+        assert!(PoolDevice::parse("asic").is_err());
+    }
+
+    #[test]
+    fn applies_only_provided_changes() {
+        let mut pool = custom_pool();
+        apply_changes(
+            &mut pool,
+            PoolConfigChanges {
+                pool_url: None,
+                stats_url: Some("https://pool.example/stats".to_string()),
+                worker_name: Some("rig-1".to_string()),
+            },
+        )
+        .unwrap();
+        assert_eq!(pool.pool_url, "stratum+tcp://pool.example:3333");
+        assert_eq!(pool.stats_url, "https://pool.example/stats");
+        assert_eq!(pool.worker_name(), Some("rig-1".to_string()));
+    }
+
+    #[test]
+    fn rejects_worker_name_for_built_in_pools() {
+        let mut pool = CpuPool::LuckyPoolRANDOMX.default_content();
+        let changes = PoolConfigChanges {
+            pool_url: None,
+            stats_url: None,
+            worker_name: Some("rig-1".to_string()),
+        };
+        assert!(apply_changes(&mut pool, changes).is_err());
+    }
+
+    #[test]
+    fn rejects_stats_url_for_built_in_pools() {
+        let mut pool = CpuPool::LuckyPoolRANDOMX.default_content();
+        let original_url = pool.stats_url.clone();
+        let changes = PoolConfigChanges {
+            pool_url: None,
+            stats_url: Some("https://attacker.example/stats/%TARI_ADDRESS%".to_string()),
+            worker_name: None,
+        };
+        assert!(apply_changes(&mut pool, changes).is_err());
+        assert_eq!(pool.stats_url, original_url);
+    }
+
+    #[test]
+    fn rejects_pool_url_for_built_in_pools() {
+        let mut pool = CpuPool::LuckyPoolRANDOMX.default_content();
+        let original_url = pool.pool_url.clone();
+        let changes = PoolConfigChanges {
+            pool_url: Some("stratum+tcp://attacker.example:3333".to_string()),
+            stats_url: None,
+            worker_name: None,
+        };
+        assert!(apply_changes(&mut pool, changes).is_err());
+        assert_eq!(pool.pool_url, original_url);
+    }
+
+    #[test]
+    fn validates_custom_pool_url() {
+        for invalid in [
+            " ",
+            "http://pool.example:3333",
+            "pool.example:3333",
+            "stratum+tcp://pool.example",
+            "stratum+tcp://:3333",
+            "stratum+tcp://pool.example:0",
+            "stratum+tcp://pool.example/path:3333",
+        ] {
+            let mut pool = custom_pool();
+            let changes = PoolConfigChanges {
+                pool_url: Some(invalid.to_string()),
+                stats_url: None,
+                worker_name: None,
+            };
+            assert!(apply_changes(&mut pool, changes).is_err(), "{invalid}");
+        }
+
+        let mut pool = custom_pool();
+        let changes = PoolConfigChanges {
+            pool_url: Some(" stratum+ssl://other.example:443 ".to_string()),
+            stats_url: None,
+            worker_name: None,
+        };
+        apply_changes(&mut pool, changes).unwrap();
+        assert_eq!(pool.pool_url, "stratum+ssl://other.example:443");
+    }
+
+    #[test]
+    fn describes_only_provided_changes() {
+        let changes = PoolConfigChanges {
+            pool_url: Some("stratum+tcp://pool.example:3333".to_string()),
+            stats_url: None,
+            worker_name: Some("rig-1".to_string()),
+        };
+        assert_eq!(
+            changes.describe(),
+            "pool_url=stratum+tcp://pool.example:3333, worker_name=rig-1"
+        );
+    }
+}
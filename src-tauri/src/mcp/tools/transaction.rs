@@ -30,9 +30,12 @@ use crate::configs::config_mcp::ConfigMcp;
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
 use crate::mcp::rate_limiter::TransactionRateLimiter;
+use crate::mcp::spending::{FEE_RESERVE, McpSpendingLedger, is_listed};
 use crate::pin::PinManager;
+use crate::utils::address_utils::verify_tari_address;
 use crate::wallet::wallet_manager::WalletManager;
 use log::{info, warn};
+use tari_common_types::tari_address::TariAddress;
 use tari_transaction_components::tari_amount::{MicroMinotari, Minotari};
 
 const DIALOG_TIMEOUT_SECS: u64 = 120;
@@ -43,6 +46,8 @@ pub enum TransactionError {
     NoPinConfigured(String),
     InvalidAmount(String),
     RateLimited(String),
+    BudgetExceeded(String),
+    InvalidAddress(String),
     Denied(String),
     Timeout(String),
     WalletError(String),
@@ -56,6 +61,8 @@ impl fmt::Display for TransactionError {
             | TransactionError::NoPinConfigured(msg)
             | TransactionError::InvalidAmount(msg)
             | TransactionError::RateLimited(msg)
+            | TransactionError::BudgetExceeded(msg)
+            | TransactionError::InvalidAddress(msg)
             | TransactionError::Denied(msg)
             | TransactionError::Timeout(msg)
             | TransactionError::WalletError(msg)
@@ -107,6 +114,29 @@ fn validate_amount(
     Ok(amount_u64)
 }
 
+/// Allowlisted destinations are sent without the confirmation dialog when the amount is
+/// below the configured threshold. The PIN can't be skipped: the spend key is only
+/// available from the seed, which is encrypted with the PIN, so signing always asks for it.
+fn skips_confirmation(
+    config: &crate::configs::config_mcp::ConfigMcpContent,
+    destination: &TariAddress,
+    amount: u64,
+) -> bool {
+    (*config.allowlist_confirmation_threshold()).is_some_and(|threshold| amount < threshold)
+        && is_listed(config.allowlisted_addresses(), destination)
+}
+
+/// Named MCP clients can have a lower limit than the global `max_transaction_amount`
+fn check_client_max_amount(amount: u64, client_max_amount: Option<u64>) -> Result<(), String> {
+    match client_max_amount {
+        Some(max_amount) if amount > max_amount => Err(format!(
+            "Amount {} µT exceeds maximum allowed {} µT for this client",
+            amount, max_amount
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[derive(serde::Serialize)]
 struct SendTransactionSuccess {
     status: &'static str,
@@ -119,6 +149,7 @@ pub async fn send_transaction(
     destination: String,
     amount: String,
     payment_id: Option<String>,
+    client_max_amount: Option<u64>,
     wallet_manager: &WalletManager,
     app_handle: &tauri::AppHandle,
 ) -> Result<String, TransactionError> {
@@ -139,14 +170,20 @@ pub async fn send_transaction(
 
     // 3. Parse and validate amount
     let amount_u64 = validate_amount(&amount, &config).map_err(TransactionError::InvalidAmount)?;
+    check_client_max_amount(amount_u64, client_max_amount)
+        .map_err(TransactionError::InvalidAmount)?;
+
+    // 4. Resolve address book contacts and reject denylisted destinations
+    let (destination, payment_id, destination_address) =
+        resolve_allowed_destination(destination, payment_id, &config).await?;
 
-    // 4. Acquire serialization gate (one dialog at a time)
+    // 5. Acquire serialization gate (one dialog at a time)
     let _permit = TXN_DIALOG_GATE
         .acquire()
         .await
         .map_err(|_| TransactionError::InternalError("Transaction gate closed".to_string()))?;
 
-    // 5. Rate limit check (after acquiring gate to avoid burning quota)
+    // 6. Rate limit check (after acquiring gate to avoid burning quota)
     if !TXN_RATE_LIMITER
         .lock()
         .await
@@ -158,37 +195,22 @@ pub async fn send_transaction(
         ));
     }
 
-    // 6. Generate request ID
-    let request_id = format!("mcp_tx_{}", uuid::Uuid::new_v4());
+    // 7. Budget check (under the gate so concurrent requests can't overspend)
+    let skips_confirmation =
+        check_budgets_and_allowlist(&config, &destination_address, amount_u64).await?;
 
-    // 7. Create oneshot channel and set INFLIGHT
-    let (tx, rx) = tokio::sync::oneshot::channel::<TxnDialogResponse>();
-    {
-        let mut inflight = INFLIGHT.lock().await;
-        *inflight = Some(InFlightTxn {
-            request_id: request_id.clone(),
-            tx,
-        });
-    }
+    // 8. Generate request ID
+    let request_id = format!("mcp_tx_{}", uuid::Uuid::new_v4());
 
-    // 8. Format display amount
+    // 9. Format display amount
     let amount_display = format!("{} XTM", amount);
 
-    info!(target: LOG_TARGET_APP_LOGIC, "MCP: send_transaction dialog emitted (request_id={}, destination={}, amount={})", request_id, destination, amount_display);
-
-    // 9. Emit confirmation event to frontend
-    EventsEmitter::emit_mcp_transaction_confirmation(
-        crate::events::McpTransactionConfirmationPayload {
-            request_id: request_id.clone(),
-            destination: destination.clone(),
-            amount_micro_minotari: amount_u64,
-            amount_display: amount_display.clone(),
-        },
-    )
-    .await;
-
-    // 10. Wait for user confirmation
-    await_confirmation(rx).await?;
+    // 10. Wait for user confirmation unless the allowlist skips it
+    if skips_confirmation {
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: send_transaction to allowlisted destination below threshold, skipping confirmation (request_id={}, destination={}, amount={})", request_id, destination, amount_display);
+    } else {
+        request_confirmation(&request_id, &destination, amount_u64, &amount_display).await?;
+    }
 
     // 11. Execute transaction (PIN dialog is triggered by PinManager during signing)
     info!(target: LOG_TARGET_APP_LOGIC, "MCP: executing send_transaction (destination={}, amount={})", destination, amount_display);
@@ -202,7 +224,9 @@ pub async fn send_transaction(
         .await;
 
     match tx_result {
-        Ok(()) => {
+        Ok(tx_id) => {
+            record_spend(wallet_manager, &tx_id, amount_u64, &destination).await;
+
             EventsEmitter::emit_mcp_transaction_result(
                 crate::events::McpTransactionResultPayload {
                     request_id,
@@ -240,6 +264,103 @@ pub async fn send_transaction(
     }
 }
 
+/// Fails when the amount would exceed the budgets, otherwise returns whether the allowlist
+/// lets the send skip the confirmation dialog
+async fn check_budgets_and_allowlist(
+    config: &crate::configs::config_mcp::ConfigMcpContent,
+    destination_address: &TariAddress,
+    amount: u64,
+) -> Result<bool, TransactionError> {
+    McpSpendingLedger::current()
+        .write()
+        .await
+        .check_budgets(amount, *config.daily_budget(), *config.weekly_budget())
+        .await
+        .map_err(TransactionError::BudgetExceeded)?;
+    Ok(skips_confirmation(config, destination_address, amount))
+}
+
+/// Resolves address book contacts and rejects invalid and denylisted destinations
+async fn resolve_allowed_destination(
+    destination: String,
+    payment_id: Option<String>,
+    config: &crate::configs::config_mcp::ConfigMcpContent,
+) -> Result<(String, Option<String>, TariAddress), TransactionError> {
+    let (destination, payment_id) =
+        crate::address_book::resolve_destination(destination, payment_id).await;
+    let destination_address = verify_tari_address(&destination).map_err(|e| {
+        TransactionError::InvalidAddress(format!("Invalid destination '{destination}': {e}"))
+    })?;
+    if is_listed(config.denylisted_addresses(), &destination_address) {
+        return Err(TransactionError::Denied(
+            "Destination address is on the MCP denylist".to_string(),
+        ));
+    }
+    Ok((destination, payment_id, destination_address))
+}
+
+/// Shows the confirmation dialog in the app and waits for the user's answer
+async fn request_confirmation(
+    request_id: &str,
+    destination: &str,
+    amount_micro_minotari: u64,
+    amount_display: &str,
+) -> Result<(), TransactionError> {
+    let (tx, rx) = tokio::sync::oneshot::channel::<TxnDialogResponse>();
+    {
+        let mut inflight = INFLIGHT.lock().await;
+        *inflight = Some(InFlightTxn {
+            request_id: request_id.to_string(),
+            tx,
+        });
+    }
+
+    info!(target: LOG_TARGET_APP_LOGIC, "MCP: send_transaction dialog emitted (request_id={}, destination={}, amount={})", request_id, destination, amount_display);
+
+    EventsEmitter::emit_mcp_transaction_confirmation(
+        crate::events::McpTransactionConfirmationPayload {
+            request_id: request_id.to_string(),
+            destination: destination.to_string(),
+            amount_micro_minotari,
+            amount_display: amount_display.to_string(),
+        },
+    )
+    .await;
+
+    await_confirmation(rx).await
+}
+
+/// Adds a sent transaction and its fee to the spending ledger
+async fn record_spend(wallet_manager: &WalletManager, tx_id: &str, amount: u64, destination: &str) {
+    let fee = sent_transaction_fee(wallet_manager, tx_id).await;
+    if let Err(e) = McpSpendingLedger::current()
+        .write()
+        .await
+        .record(amount, fee, destination.to_string())
+        .await
+    {
+        warn!(target: LOG_TARGET_APP_LOGIC, "MCP: failed to save spend of transaction {tx_id}, further MCP sends are blocked until it is saved: {e}");
+    }
+}
+
+/// Fee of a just sent transaction as reported by the wallet, the budget fee reserve if it
+/// can't be found
+async fn sent_transaction_fee(wallet_manager: &WalletManager, tx_id: &str) -> u64 {
+    match wallet_manager
+        .get_transactions(Some(0), Some(20), None)
+        .await
+    {
+        Ok(transactions) => transactions
+            .into_iter()
+            .find(|tx| tx.tx_id == tx_id)
+            .map_or(FEE_RESERVE, |tx| tx.fee),
+        Err(e) => {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP: failed to read the fee of transaction {tx_id}: {e}");
+            FEE_RESERVE
+        }
+    }
+}
+
 async fn await_confirmation(
     rx: tokio::sync::oneshot::Receiver<TxnDialogResponse>,
 ) -> Result<(), TransactionError> {
@@ -374,6 +495,13 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn client_max_amount_is_enforced() {
+        assert!(check_client_max_amount(1_000_000, None).is_ok());
+        assert!(check_client_max_amount(1_000_000, Some(1_000_000)).is_ok());
+        assert!(check_client_max_amount(1_000_001, Some(1_000_000)).is_err());
+    }
+
     // =========================================================================
     // respond_to_transaction
     // =========================================================================
@@ -0,0 +1,605 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Opt-in Prometheus / OpenMetrics exporter.
+//!
+//! When `metrics_exporter_enabled` is set in the core config, a small HTTP server serves
+//! `GET /metrics` in the Prometheus text exposition format on the configured address and
+//! port (127.0.0.1:9464 by default). Every scrape reads the current values from the same
+//! watch channels that feed the UI: process watcher stats, CPU and GPU miner status, base
+//! node status, wallet balance and the pool statuses of the CPU and GPU pool managers.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+
+use axum08 as axum;
+use log::{error, info, warn};
+use tokio::sync::{RwLock, watch};
+use tokio::task::JoinHandle;
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_core::ConfigCore;
+use crate::configs::trait_config::ConfigImpl;
+use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::gpu::consts::GpuMinerStatus;
+use crate::mining::pools::cpu_pool_manager::CpuPoolManager;
+use crate::mining::pools::gpu_pool_manager::GpuPoolManager;
+use crate::mining::pools::{PoolManagerInterfaceTrait, PoolStatus};
+use crate::node::node_adapter::BaseNodeStatus;
+use crate::process_stats_collector::ProcessStatsCollector;
+use crate::process_watcher::ProcessWatcherStats;
+use crate::wallet::wallet_types::WalletState;
+
+pub const DEFAULT_METRICS_EXPORTER_PORT: u16 = 9464;
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+const METRIC_PREFIX: &str = "tari_universe";
+const SHUTDOWN_TIMEOUT_SECS: u64 = 5;
+
+static INSTANCE: LazyLock<RwLock<MetricsExporter>> =
+    LazyLock::new(|| RwLock::new(MetricsExporter::new()));
+
+/// Watch channels the exporter reads on every scrape
+#[derive(Clone)]
+pub(crate) struct MetricsSources {
+    pub cpu_miner_status_rx: watch::Receiver<CpuMinerStatus>,
+    pub gpu_miner_status_rx: watch::Receiver<GpuMinerStatus>,
+    pub node_status_rx: watch::Receiver<BaseNodeStatus>,
+    pub wallet_state_rx: watch::Receiver<Option<WalletState>>,
+    pub process_stats_collector: ProcessStatsCollector,
+}
+
+impl MetricsSources {
+    async fn snapshot(&self) -> MetricsSnapshot {
+        let stats = &self.process_stats_collector;
+        MetricsSnapshot {
+            processes: vec![
+                ("cpu_miner", stats.get_cpu_miner_stats()),
+                ("gpu_miner", stats.get_gpu_miner_stats()),
+                ("mm_proxy", stats.get_mm_proxy_stats()),
+                ("node", stats.get_minotari_node_stats()),
+                ("tor", stats.get_tor_stats()),
+                ("wallet", stats.get_wallet_stats()),
+            ],
+            cpu_miner: self.cpu_miner_status_rx.borrow().clone(),
+            gpu_miner: self.gpu_miner_status_rx.borrow().clone(),
+            node: *self.node_status_rx.borrow(),
+            wallet: self.wallet_state_rx.borrow().clone(),
+            cpu_pools: CpuPoolManager::get_pool_statuses().await,
+            gpu_pools: GpuPoolManager::get_pool_statuses().await,
+        }
+    }
+}
+
+struct MetricsSnapshot {
+    processes: Vec<(&'static str, ProcessWatcherStats)>,
+    cpu_miner: CpuMinerStatus,
+    gpu_miner: GpuMinerStatus,
+    node: BaseNodeStatus,
+    wallet: Option<WalletState>,
+    cpu_pools: HashMap<String, PoolStatus>,
+    gpu_pools: HashMap<String, PoolStatus>,
+}
+
+#[derive(Clone, Copy)]
+enum MetricKind {
+    Gauge,
+    Counter,
+}
+
+impl MetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            MetricKind::Gauge => "gauge",
+            MetricKind::Counter => "counter",
+        }
+    }
+}
+
+type Labels = Vec<(&'static str, String)>;
+
+#[derive(Default)]
+struct MetricsWriter {
+    output: String,
+}
+
+impl MetricsWriter {
+    fn family(&mut self, name: &str, kind: MetricKind, help: &str, samples: Vec<(Labels, f64)>) {
+        if samples.is_empty() {
+            return;
+        }
+        let name = format!("{METRIC_PREFIX}_{name}");
+        let _unused = writeln!(self.output, "# HELP {name} {help}");
+        let _unused = writeln!(self.output, "# TYPE {name} {}", kind.as_str());
+        for (labels, value) in samples {
+            let _unused = if labels.is_empty() {
+                writeln!(self.output, "{name} {value}")
+            } else {
+                let labels = labels
+                    .iter()
+                    .map(|(key, value)| format!("{key}=\"{}\"", escape_label_value(value)))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                writeln!(self.output, "{name}{{{labels}}} {value}")
+            };
+        }
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn bool_value(value: bool) -> f64 {
+    if value { 1.0 } else { 0.0 }
+}
+
+fn render_metrics(snapshot: &MetricsSnapshot) -> String {
+    let mut writer = MetricsWriter::default();
+    write_process_metrics(&mut writer, snapshot);
+    write_miner_metrics(&mut writer, snapshot);
+    write_node_metrics(&mut writer, snapshot);
+    write_wallet_metrics(&mut writer, snapshot);
+    write_pool_metrics(&mut writer, snapshot);
+    writer.output
+}
+
+fn write_process_metrics(writer: &mut MetricsWriter, snapshot: &MetricsSnapshot) {
+    let process_samples = |value: fn(&ProcessWatcherStats) -> f64| {
+        snapshot
+            .processes
+            .iter()
+            .map(|(process, stats)| (vec![("process", (*process).to_string())], value(stats)))
+            .collect::<Vec<_>>()
+    };
+    writer.family(
+        "process_uptime_seconds",
+        MetricKind::Gauge,
+        "Time since the process was last started",
+        process_samples(|stats| stats.current_uptime.as_secs_f64()),
+    );
+    writer.family(
+        "process_health_checks_total",
+        MetricKind::Counter,
+        "Health checks performed on the process",
+        process_samples(|stats| stats.total_health_checks as f64),
+    );
+    writer.family(
+        "process_warnings_total",
+        MetricKind::Counter,
+        "Health checks that reported a warning",
+        process_samples(|stats| stats.num_warnings as f64),
+    );
+    writer.family(
+        "process_failures_total",
+        MetricKind::Counter,
+        "Health checks that reported a failure",
+        process_samples(|stats| stats.num_failures as f64),
+    );
+    writer.family(
+        "process_restarts_total",
+        MetricKind::Counter,
+        "Restarts of the process by the process watcher",
+        process_samples(|stats| stats.num_restarts as f64),
+    );
+    writer.family(
+        "process_health_check_duration_seconds_total",
+        MetricKind::Counter,
+        "Total time spent in health checks",
+        process_samples(|stats| stats.total_health_check_duration.as_secs_f64()),
+    );
+    writer.family(
+        "process_health_check_duration_seconds_max",
+        MetricKind::Gauge,
+        "Longest health check so far",
+        process_samples(|stats| stats.max_health_check_duration.as_secs_f64()),
+    );
+}
+
+fn write_miner_metrics(writer: &mut MetricsWriter, snapshot: &MetricsSnapshot) {
+    let device = |name: &str| vec![("device", name.to_string())];
+    writer.family(
+        "miner_is_mining",
+        MetricKind::Gauge,
+        "Whether the miner is currently mining",
+        vec![
+            (device("cpu"), bool_value(snapshot.cpu_miner.is_mining)),
+            (device("gpu"), bool_value(snapshot.gpu_miner.is_mining)),
+        ],
+    );
+    writer.family(
+        "miner_hashrate",
+        MetricKind::Gauge,
+        "Current miner hash rate in hashes per second",
+        vec![
+            (device("cpu"), snapshot.cpu_miner.hash_rate),
+            (device("gpu"), snapshot.gpu_miner.hash_rate),
+        ],
+    );
+    writer.family(
+        "miner_estimated_earnings_micro_minotari",
+        MetricKind::Gauge,
+        "Estimated daily earnings of the miner",
+        vec![
+            (device("cpu"), snapshot.cpu_miner.estimated_earnings as f64),
+            (device("gpu"), snapshot.gpu_miner.estimated_earnings as f64),
+        ],
+    );
+    writer.family(
+        "cpu_miner_connected",
+        MetricKind::Gauge,
+        "Whether the CPU miner is connected to its pool or node",
+        vec![(
+            vec![],
+            bool_value(snapshot.cpu_miner.connection.is_connected),
+        )],
+    );
+    writer.family(
+        "gpu_miner_algorithm_info",
+        MetricKind::Gauge,
+        "Algorithm the GPU miner is configured for",
+        vec![(
+            vec![("algorithm", format!("{:?}", snapshot.gpu_miner.algorithm))],
+            1.0,
+        )],
+    );
+}
+
+fn write_node_metrics(writer: &mut MetricsWriter, snapshot: &MetricsSnapshot) {
+    writer.family(
+        "node_block_height",
+        MetricKind::Gauge,
+        "Current block height of the base node",
+        vec![(vec![], snapshot.node.block_height as f64)],
+    );
+    writer.family(
+        "node_block_time_seconds",
+        MetricKind::Gauge,
+        "Timestamp of the tip block",
+        vec![(vec![], snapshot.node.block_time as f64)],
+    );
+    writer.family(
+        "node_block_reward_micro_minotari",
+        MetricKind::Gauge,
+        "Block reward at the current height",
+        vec![(vec![], snapshot.node.block_reward.as_u64() as f64)],
+    );
+    writer.family(
+        "node_is_synced",
+        MetricKind::Gauge,
+        "Whether the base node is synced",
+        vec![(vec![], bool_value(snapshot.node.is_synced))],
+    );
+    writer.family(
+        "node_connections",
+        MetricKind::Gauge,
+        "Number of peer connections of the base node",
+        vec![(vec![], snapshot.node.num_connections as f64)],
+    );
+}
+
+fn write_wallet_metrics(writer: &mut MetricsWriter, snapshot: &MetricsSnapshot) {
+    let Some(wallet) = &snapshot.wallet else {
+        return;
+    };
+    writer.family(
+        "wallet_scanned_height",
+        MetricKind::Gauge,
+        "Block height the wallet has scanned up to",
+        vec![(vec![], wallet.scanned_height as f64)],
+    );
+    if let Some(balance) = &wallet.balance {
+        let state = |name: &str| vec![("state", name.to_string())];
+        writer.family(
+            "wallet_balance_micro_minotari",
+            MetricKind::Gauge,
+            "Wallet balance by state",
+            vec![
+                (
+                    state("available"),
+                    balance.available_balance.as_u64() as f64,
+                ),
+                (
+                    state("timelocked"),
+                    balance.timelocked_balance.as_u64() as f64,
+                ),
+                (
+                    state("pending_incoming"),
+                    balance.pending_incoming_balance.as_u64() as f64,
+                ),
+                (
+                    state("pending_outgoing"),
+                    balance.pending_outgoing_balance.as_u64() as f64,
+                ),
+            ],
+        );
+    }
+}
+
+fn write_pool_metrics(writer: &mut MetricsWriter, snapshot: &MetricsSnapshot) {
+    let mut pools: Vec<(&str, &String, &PoolStatus)> = snapshot
+        .cpu_pools
+        .iter()
+        .map(|(pool, status)| ("cpu", pool, status))
+        .chain(
+            snapshot
+                .gpu_pools
+                .iter()
+                .map(|(pool, status)| ("gpu", pool, status)),
+        )
+        .collect();
+    // Stable output order keeps scrapes diffable
+    pools.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    let pool_samples = |value: fn(&PoolStatus) -> f64| {
+        pools
+            .iter()
+            .map(|(device, pool, status)| {
+                (
+                    vec![("device", (*device).to_string()), ("pool", (*pool).clone())],
+                    value(status),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    writer.family(
+        "pool_accepted_shares",
+        MetricKind::Gauge,
+        "Accepted shares as reported by the pool",
+        pool_samples(|status| status.accepted_shares as f64),
+    );
+    writer.family(
+        "pool_unpaid",
+        MetricKind::Gauge,
+        "Unpaid balance as reported by the pool",
+        pool_samples(|status| status.unpaid),
+    );
+    writer.family(
+        "pool_balance",
+        MetricKind::Gauge,
+        "Balance as reported by the pool",
+        pool_samples(|status| status.balance),
+    );
+    writer.family(
+        "pool_min_payout",
+        MetricKind::Gauge,
+        "Minimum payout threshold of the pool",
+        pool_samples(|status| status.min_payout as f64),
+    );
+}
+
+pub struct MetricsExporter {
+    server_handle: Option<JoinHandle<()>>,
+    shutdown_tx: Option<watch::Sender<bool>>,
+    bound_address: Option<SocketAddr>,
+    sources: Option<MetricsSources>,
+}
+
+impl MetricsExporter {
+    fn new() -> Self {
+        Self {
+            server_handle: None,
+            shutdown_tx: None,
+            bound_address: None,
+            sources: None,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    pub(crate) async fn initialize(sources: MetricsSources) {
+        Self::current().write().await.sources = Some(sources);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.server_handle.is_some()
+    }
+
+    pub async fn start() -> Result<SocketAddr, anyhow::Error> {
+        {
+            let exporter = Self::current().read().await;
+            if exporter.is_running()
+                && let Some(address) = exporter.bound_address
+            {
+                info!(target: LOG_TARGET_APP_LOGIC, "Metrics exporter already running on {address}");
+                return Ok(address);
+            }
+        }
+
+        let config = ConfigCore::content().await;
+        if !*config.metrics_exporter_enabled() {
+            anyhow::bail!("Metrics exporter is not enabled");
+        }
+
+        let sources = Self::current()
+            .read()
+            .await
+            .sources
+            .clone()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Metrics exporter not initialized — call MetricsExporter::initialize() first"
+                )
+            })?;
+
+        let address = SocketAddr::new(
+            *config.metrics_exporter_bind_address(),
+            *config.metrics_exporter_port(),
+        );
+        let listener = tokio::net::TcpListener::bind(address)
+            .await
+            .map_err(|e| anyhow::anyhow!("Metrics exporter failed to bind to {address}: {e}"))?;
+        let bound_address = listener.local_addr()?;
+        info!(target: LOG_TARGET_APP_LOGIC, "Metrics exporter listening on http://{bound_address}/metrics");
+
+        let router = axum::Router::new().route(
+            "/metrics",
+            axum::routing::get(move || {
+                let sources = sources.clone();
+                async move {
+                    let body = render_metrics(&sources.snapshot().await);
+                    (
+                        [(axum::http::header::CONTENT_TYPE, METRICS_CONTENT_TYPE)],
+                        body,
+                    )
+                }
+            }),
+        );
+
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let handle = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, router)
+                .with_graceful_shutdown(async move {
+                    let _unused = shutdown_rx.wait_for(|v| *v).await;
+                })
+                .await
+            {
+                error!(target: LOG_TARGET_APP_LOGIC, "Metrics exporter exited with error: {e:?}");
+            }
+            info!(target: LOG_TARGET_APP_LOGIC, "Metrics exporter stopped");
+        });
+
+        let mut exporter = Self::current().write().await;
+        exporter.server_handle = Some(handle);
+        exporter.shutdown_tx = Some(shutdown_tx);
+        exporter.bound_address = Some(bound_address);
+
+        Ok(bound_address)
+    }
+
+    pub async fn stop() {
+        let (handle, shutdown_tx) = {
+            let mut exporter = Self::current().write().await;
+            exporter.bound_address = None;
+            (exporter.server_handle.take(), exporter.shutdown_tx.take())
+        };
+
+        if let Some(tx) = shutdown_tx {
+            let _unused = tx.send(true);
+        }
+
+        if let Some(handle) = handle {
+            let timeout = tokio::time::timeout(
+                std::time::Duration::from_secs(SHUTDOWN_TIMEOUT_SECS),
+                handle,
+            );
+            if timeout.await.is_err() {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Metrics exporter shutdown timed out after {SHUTDOWN_TIMEOUT_SECS}s");
+            }
+        }
+    }
+
+    /// Applies the current config: restarts the exporter when enabled, stops it otherwise
+    pub async fn apply_config() -> Result<(), anyhow::Error> {
+        Self::stop().await;
+        if *ConfigCore::content().await.metrics_exporter_enabled() {
+            Self::start().await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mining::gpu::consts::GpuMiningAlgorithm;
+    use crate::wallet::wallet_types::WalletBalance;
+    use std::time::Duration;
+    use tari_transaction_components::tari_amount::MicroMinotari;
+
+    fn snapshot() -> MetricsSnapshot {
+        MetricsSnapshot {
+            processes: vec![(
+                "node",
+                ProcessWatcherStats {
+                    current_uptime: Duration::from_secs(90),
+                    num_restarts: 2,
+                    ..Default::default()
+                },
+            )],
+            cpu_miner: CpuMinerStatus {
+                is_mining: true,
+                hash_rate: 1500.5,
+                ..Default::default()
+            },
+            gpu_miner: GpuMinerStatus::default_with_algorithm(GpuMiningAlgorithm::SHA3X),
+            node: BaseNodeStatus {
+                block_height: 4242,
+                is_synced: true,
+                ..Default::default()
+            },
+            wallet: Some(WalletState {
+                scanned_height: 4240,
+                balance: Some(WalletBalance {
+                    available_balance: MicroMinotari(1_000_000),
+                    timelocked_balance: MicroMinotari(0),
+                    pending_incoming_balance: MicroMinotari(5),
+                    pending_outgoing_balance: MicroMinotari(0),
+                }),
+                network: None,
+            }),
+            cpu_pools: HashMap::from([(
+                "Lucky \"Pool\"".to_string(),
+                PoolStatus {
+                    accepted_shares: 12,
+                    ..Default::default()
+                },
+            )]),
+            gpu_pools: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn renders_prometheus_text_format() {
+        let output = render_metrics(&snapshot());
+
+        assert!(output.contains("# TYPE tari_universe_process_restarts_total counter\n"));
+        assert!(output.contains("tari_universe_process_restarts_total{process=\"node\"} 2\n"));
+        assert!(output.contains("tari_universe_process_uptime_seconds{process=\"node\"} 90\n"));
+        assert!(output.contains("tari_universe_miner_hashrate{device=\"cpu\"} 1500.5\n"));
+        assert!(output.contains("tari_universe_miner_is_mining{device=\"gpu\"} 0\n"));
+        assert!(output.contains("tari_universe_gpu_miner_algorithm_info{algorithm=\"SHA3X\"} 1\n"));
+        assert!(output.contains("tari_universe_node_block_height 4242\n"));
+        assert!(output.contains(
+            "tari_universe_wallet_balance_micro_minotari{state=\"available\"} 1000000\n"
+        ));
+        assert!(output.contains(
+            "tari_universe_pool_accepted_shares{device=\"cpu\",pool=\"Lucky \\\"Pool\\\"\"} 12\n"
+        ));
+    }
+
+    #[test]
+    fn skips_wallet_metrics_until_wallet_state_is_known() {
+        let output = render_metrics(&MetricsSnapshot {
+            wallet: None,
+            ..snapshot()
+        });
+
+        assert!(!output.contains("wallet_"));
+        assert!(output.contains("# HELP tari_universe_node_is_synced"));
+    }
+}
@@ -0,0 +1,483 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use log::{debug, error, info, warn};
+use tokio::{
+    sync::watch,
+    time::{MissedTickBehavior, interval},
+};
+
+use crate::{
+    LOG_TARGET_APP_LOGIC,
+    configs::{
+        config_mining::{AUTO_MINING_MODE_NAME, ConfigMining, ConfigMiningContent},
+        trait_config::ConfigImpl,
+    },
+    events_emitter::EventsEmitter,
+    mining::{
+        cpu::{CpuMinerStatus, manager::CpuManager},
+        gpu::{
+            consts::{GpuMinerStatus, GpuMiningAlgorithm},
+            manager::GpuManager,
+        },
+    },
+    node::{
+        node_adapter::{BaseNodeStatus, PowDifficulties},
+        node_manager::NodeManager,
+    },
+    tasks_tracker::TasksTrackers,
+};
+
+const AUTO_MODE_EVALUATION_INTERVAL: Duration = Duration::from_secs(10 * 60);
+// Usage bounds follow the Eco and Ludicrous modes
+const AUTO_MODE_CPU_USAGE_RANGE: (u32, u32) = (1, 85);
+const AUTO_MODE_GPU_USAGE_RANGE: (u32, u32) = (1, 95);
+// Revenue has to differ from the electricity cost by this fraction before switching, avoids flapping around break-even
+const AUTO_MODE_SWITCH_MARGIN: f64 = 0.1;
+// Evaluations in a row that have to agree on a new usage before it is applied. Hashrate sampled
+// at the Eco usage is extrapolated a hundredfold, so a single noisy sample must not switch usage.
+const AUTO_MODE_SWITCH_CONFIRMATIONS: u32 = 2;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Observed mining of a single device kind
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceMiningSample {
+    /// Hashrate observed while running at `usage_percentage`
+    pub hash_rate: f64,
+    pub usage_percentage: u32,
+    pub network_difficulty: Option<u64>,
+    /// Power draw at full usage
+    pub max_watts: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoModeInputs {
+    pub cpu: DeviceMiningSample,
+    pub gpu: DeviceMiningSample,
+    pub block_reward_xtm: f64,
+    pub electricity_price_per_kwh: f64,
+    /// XTM price in the same currency as the electricity price
+    pub xtm_price: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoModeDecision {
+    pub cpu_usage_percentage: u32,
+    pub gpu_usage_percentage: u32,
+    pub rationale: String,
+}
+
+/// Pick CPU and GPU usage for the Auto mining mode
+/// Hashrate and power draw are assumed to scale linearly with usage, so profit is maximised at one
+/// of the bounds: full usage while mining earns more than the electricity costs, the Eco level otherwise.
+/// Devices without enough data, including prices that were not set, keep their current usage.
+pub fn decide_auto_mode(
+    inputs: &AutoModeInputs,
+    current_cpu_usage_percentage: u32,
+    current_gpu_usage_percentage: u32,
+) -> AutoModeDecision {
+    let (cpu_usage_percentage, cpu_rationale) = decide_device_usage(
+        &inputs.cpu,
+        inputs,
+        current_cpu_usage_percentage,
+        AUTO_MODE_CPU_USAGE_RANGE,
+    );
+    let (gpu_usage_percentage, gpu_rationale) = decide_device_usage(
+        &inputs.gpu,
+        inputs,
+        current_gpu_usage_percentage,
+        AUTO_MODE_GPU_USAGE_RANGE,
+    );
+
+    AutoModeDecision {
+        cpu_usage_percentage,
+        gpu_usage_percentage,
+        rationale: format!(
+            "CPU {cpu_usage_percentage}%: {cpu_rationale}; GPU {gpu_usage_percentage}%: {gpu_rationale}"
+        ),
+    }
+}
+
+fn decide_device_usage(
+    sample: &DeviceMiningSample,
+    inputs: &AutoModeInputs,
+    current_usage_percentage: u32,
+    (min_usage_percentage, max_usage_percentage): (u32, u32),
+) -> (u32, String) {
+    // A price of 0 is the default and means the user did not provide one
+    if inputs.electricity_price_per_kwh <= 0.0 || inputs.xtm_price <= 0.0 {
+        return (
+            current_usage_percentage,
+            "electricity or XTM price not set".to_string(),
+        );
+    }
+    if sample.hash_rate <= 0.0 || sample.usage_percentage == 0 {
+        return (
+            current_usage_percentage,
+            "no hashrate observed yet".to_string(),
+        );
+    }
+    let Some(network_difficulty) = sample.network_difficulty.filter(|d| *d > 0) else {
+        return (
+            current_usage_percentage,
+            "network difficulty unknown".to_string(),
+        );
+    };
+
+    let full_usage_hash_rate = sample.hash_rate * 100.0 / f64::from(sample.usage_percentage);
+    let blocks_per_day = full_usage_hash_rate * SECONDS_PER_DAY / network_difficulty as f64;
+    let revenue_per_day = blocks_per_day * inputs.block_reward_xtm * inputs.xtm_price;
+    let cost_per_day = sample.max_watts / 1000.0 * 24.0 * inputs.electricity_price_per_kwh;
+    let estimate = format!(
+        "{revenue_per_day:.4} revenue vs {cost_per_day:.4} electricity per day at full usage"
+    );
+
+    if revenue_per_day >= cost_per_day * (1.0 + AUTO_MODE_SWITCH_MARGIN) {
+        (max_usage_percentage, format!("{estimate}, profitable"))
+    } else if revenue_per_day <= cost_per_day * (1.0 - AUTO_MODE_SWITCH_MARGIN) {
+        (min_usage_percentage, format!("{estimate}, unprofitable"))
+    } else {
+        (
+            current_usage_percentage,
+            format!("{estimate}, close to break-even"),
+        )
+    }
+}
+
+/// Holds back a usage change of one device kind until consecutive evaluations agree on it
+#[derive(Debug, Default)]
+struct UsageSwitchConfirmation {
+    pending_usage_percentage: Option<u32>,
+    confirmations: u32,
+}
+
+impl UsageSwitchConfirmation {
+    /// Returns the usage to apply, the current one until the decided usage was confirmed
+    fn confirm(&mut self, current_usage_percentage: u32, decided_usage_percentage: u32) -> u32 {
+        if decided_usage_percentage == current_usage_percentage {
+            *self = Self::default();
+            return current_usage_percentage;
+        }
+        if self.pending_usage_percentage == Some(decided_usage_percentage) {
+            self.confirmations += 1;
+        } else {
+            self.pending_usage_percentage = Some(decided_usage_percentage);
+            self.confirmations = 1;
+        }
+        if self.confirmations < AUTO_MODE_SWITCH_CONFIRMATIONS {
+            return current_usage_percentage;
+        }
+        *self = Self::default();
+        decided_usage_percentage
+    }
+}
+
+#[derive(Debug, Default)]
+struct AutoModeSwitchConfirmations {
+    cpu: UsageSwitchConfirmation,
+    gpu: UsageSwitchConfirmation,
+}
+
+/// Periodically re-evaluates the Auto mining mode while it is selected and restarts miners when usage changes
+#[derive(Clone)]
+pub struct AutoMiningModeManager {
+    cpu_miner_status_watch_rx: watch::Receiver<CpuMinerStatus>,
+    gpu_miner_status_watch_rx: watch::Receiver<GpuMinerStatus>,
+    node_status_watch_rx: watch::Receiver<BaseNodeStatus>,
+    node_manager: NodeManager,
+    is_started: Arc<AtomicBool>,
+}
+
+impl AutoMiningModeManager {
+    pub fn new(
+        cpu_miner_status_watch_rx: watch::Receiver<CpuMinerStatus>,
+        gpu_miner_status_watch_rx: watch::Receiver<GpuMinerStatus>,
+        node_status_watch_rx: watch::Receiver<BaseNodeStatus>,
+        node_manager: NodeManager,
+    ) -> Self {
+        Self {
+            cpu_miner_status_watch_rx,
+            gpu_miner_status_watch_rx,
+            node_status_watch_rx,
+            node_manager,
+            is_started: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub async fn start(&self) {
+        if self.is_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let self_clone = self.clone();
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut ticker = interval(AUTO_MODE_EVALUATION_INTERVAL);
+                ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                let mut confirmations = AutoModeSwitchConfirmations::default();
+                loop {
+                    tokio::select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET_APP_LOGIC, "Auto mining mode manager received shutdown signal");
+                            break;
+                        }
+                        _ = ticker.tick() => {
+                            if let Err(e) = self_clone.evaluate(&mut confirmations).await {
+                                warn!(target: LOG_TARGET_APP_LOGIC, "Auto mining mode evaluation failed: {e}");
+                            }
+                        }
+                    }
+                }
+            });
+    }
+
+    async fn evaluate(
+        &self,
+        confirmations: &mut AutoModeSwitchConfirmations,
+    ) -> Result<(), anyhow::Error> {
+        let config = ConfigMining::content().await;
+        if config.selected_mining_mode() != AUTO_MINING_MODE_NAME {
+            *confirmations = AutoModeSwitchConfirmations::default();
+            return Ok(());
+        }
+        let cpu_status = self.cpu_miner_status_watch_rx.borrow().clone();
+        let gpu_status = self.gpu_miner_status_watch_rx.borrow().clone();
+        if !cpu_status.is_mining && !gpu_status.is_mining {
+            *confirmations = AutoModeSwitchConfirmations::default();
+            return Ok(());
+        }
+
+        let difficulties = self
+            .node_manager
+            .get_current_service()
+            .await?
+            .get_network_difficulties()
+            .await
+            .unwrap_or_else(|e| {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to read network difficulties: {e}");
+                PowDifficulties::default()
+            });
+        let block_reward = self.node_status_watch_rx.borrow().block_reward;
+
+        let current_cpu_usage_percentage = config.get_selected_cpu_usage_percentage();
+        let current_gpu_usage_percentage = config.get_selected_gpu_usage_percentage();
+        let inputs = AutoModeInputs {
+            cpu: DeviceMiningSample {
+                hash_rate: if cpu_status.is_mining {
+                    cpu_status.hash_rate
+                } else {
+                    0.0
+                },
+                usage_percentage: current_cpu_usage_percentage,
+                network_difficulty: difficulties.randomx_t.or(difficulties.randomx_m),
+                max_watts: *config.auto_mode_cpu_watts(),
+            },
+            gpu: DeviceMiningSample {
+                hash_rate: if gpu_status.is_mining {
+                    gpu_status.hash_rate
+                } else {
+                    0.0
+                },
+                usage_percentage: current_gpu_usage_percentage,
+                network_difficulty: match gpu_status.algorithm {
+                    GpuMiningAlgorithm::C29 => difficulties.cuckaroo,
+                    GpuMiningAlgorithm::SHA3X => difficulties.sha3x,
+                },
+                max_watts: *config.auto_mode_gpu_watts(),
+            },
+            block_reward_xtm: block_reward.as_u64() as f64 / 1_000_000.0,
+            electricity_price_per_kwh: *config.auto_mode_electricity_price_per_kwh(),
+            xtm_price: *config.auto_mode_xtm_price(),
+        };
+
+        let mut decision = decide_auto_mode(
+            &inputs,
+            current_cpu_usage_percentage,
+            current_gpu_usage_percentage,
+        );
+        decision.cpu_usage_percentage = confirmations
+            .cpu
+            .confirm(current_cpu_usage_percentage, decision.cpu_usage_percentage);
+        decision.gpu_usage_percentage = confirmations
+            .gpu
+            .confirm(current_gpu_usage_percentage, decision.gpu_usage_percentage);
+        if decision.cpu_usage_percentage == current_cpu_usage_percentage
+            && decision.gpu_usage_percentage == current_gpu_usage_percentage
+        {
+            debug!(target: LOG_TARGET_APP_LOGIC, "Auto mining mode unchanged: {}", decision.rationale);
+            return Ok(());
+        }
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Auto mining mode switching CPU {current_cpu_usage_percentage}% -> {}%, GPU {current_gpu_usage_percentage}% -> {}%. Rationale: {}", decision.cpu_usage_percentage, decision.gpu_usage_percentage, decision.rationale);
+        ConfigMining::update_field(
+            ConfigMiningContent::update_auto_mode_usage,
+            (decision.cpu_usage_percentage, decision.gpu_usage_percentage),
+        )
+        .await?;
+        EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+
+        // Miners read their usage on start
+        if decision.cpu_usage_percentage != current_cpu_usage_percentage {
+            let mut cpu_manager = CpuManager::write().await;
+            if cpu_manager.is_running() {
+                cpu_manager.stop_mining().await?;
+                if let Err(e) = cpu_manager.start_mining().await {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to restart cpu miner for auto mining mode: {e}");
+                }
+            }
+        }
+        if decision.gpu_usage_percentage != current_gpu_usage_percentage {
+            let mut gpu_manager = GpuManager::write().await;
+            if gpu_manager.is_running() {
+                gpu_manager.stop_mining().await?;
+                if let Err(e) = gpu_manager.start_mining().await {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to restart gpu miner for auto mining mode: {e}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(cpu_hash_rate: f64, gpu_hash_rate: f64) -> AutoModeInputs {
+        AutoModeInputs {
+            cpu: DeviceMiningSample {
+                hash_rate: cpu_hash_rate,
+                usage_percentage: 50,
+                network_difficulty: Some(86_400_000),
+                max_watts: 100.0,
+            },
+            gpu: DeviceMiningSample {
+                hash_rate: gpu_hash_rate,
+                usage_percentage: 100,
+                network_difficulty: Some(86_400_000),
+                max_watts: 100.0,
+            },
+            // 0.1 per block
+            block_reward_xtm: 10.0,
+            xtm_price: 0.01,
+            // 2.4 kWh per day, 0.24 per day
+            electricity_price_per_kwh: 0.1,
+        }
+    }
+
+    #[test]
+    fn profitable_device_runs_at_full_usage() {
+        // Full usage 1000 H/s -> 1 block per day -> 0.1 revenue, below 0.24 electricity
+        // GPU 3000 H/s -> 0.3 revenue
+        let decision = decide_auto_mode(&inputs(500.0, 3_000.0), 50, 50);
+
+        assert_eq!(decision.cpu_usage_percentage, AUTO_MODE_CPU_USAGE_RANGE.0);
+        assert_eq!(decision.gpu_usage_percentage, AUTO_MODE_GPU_USAGE_RANGE.1);
+        assert!(decision.rationale.contains("unprofitable"));
+        assert!(
+            decision
+                .rationale
+                .contains("0.3000 revenue vs 0.2400 electricity")
+        );
+    }
+
+    #[test]
+    fn keeps_usage_close_to_break_even() {
+        // GPU 2500 H/s -> 0.25 revenue vs 0.24 electricity
+        let decision = decide_auto_mode(&inputs(0.0, 2_500.0), 30, 40);
+
+        assert_eq!(decision.cpu_usage_percentage, 30);
+        assert_eq!(decision.gpu_usage_percentage, 40);
+        assert!(decision.rationale.contains("no hashrate observed yet"));
+        assert!(decision.rationale.contains("close to break-even"));
+    }
+
+    #[test]
+    fn unset_prices_keep_usage() {
+        let mut without_electricity_price = inputs(500.0, 3_000.0);
+        without_electricity_price.electricity_price_per_kwh = 0.0;
+        let mut without_xtm_price = inputs(500.0, 3_000.0);
+        without_xtm_price.xtm_price = 0.0;
+
+        for inputs in [without_electricity_price, without_xtm_price] {
+            let decision = decide_auto_mode(&inputs, 1, 50);
+
+            assert_eq!(decision.cpu_usage_percentage, 1);
+            assert_eq!(decision.gpu_usage_percentage, 50);
+            assert!(decision.rationale.contains("price not set"));
+        }
+    }
+
+    #[test]
+    fn noisy_samples_around_the_threshold_do_not_flip_usage() {
+        let (eco, full) = AUTO_MODE_CPU_USAGE_RANGE;
+        let mut confirmation = UsageSwitchConfirmation::default();
+
+        // Alternating decisions never reach the required confirmations
+        assert_eq!(confirmation.confirm(eco, full), eco);
+        assert_eq!(confirmation.confirm(eco, eco), eco);
+        assert_eq!(confirmation.confirm(eco, full), eco);
+        assert_eq!(confirmation.confirm(eco, eco), eco);
+
+        // A decision that holds is applied
+        assert_eq!(confirmation.confirm(eco, full), eco);
+        assert_eq!(confirmation.confirm(eco, full), full);
+        assert_eq!(confirmation.confirm(full, full), full);
+    }
+
+    #[test]
+    fn eco_usage_within_the_margin_stays_put() {
+        // At 1% usage the CPU sample is extrapolated a hundredfold. 0.25 revenue vs 0.24
+        // electricity is within the margin so the Eco usage is kept.
+        let mut inputs = inputs(0.0, 0.0);
+        inputs.cpu.hash_rate = 25.0;
+        inputs.cpu.usage_percentage = 1;
+
+        let decision = decide_auto_mode(&inputs, 1, 50);
+
+        assert_eq!(decision.cpu_usage_percentage, 1);
+        assert!(decision.rationale.contains("close to break-even"));
+    }
+
+    #[test]
+    fn unknown_difficulty_keeps_usage() {
+        let mut inputs = inputs(500.0, 3_000.0);
+        inputs.gpu.network_difficulty = None;
+
+        let decision = decide_auto_mode(&inputs, 20, 20);
+
+        assert_eq!(decision.gpu_usage_percentage, 20);
+        assert!(decision.rationale.contains("network difficulty unknown"));
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    binaries::Binaries,
+    utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
+};
+
+#[derive(Eq, Hash, PartialEq, Clone, Deserialize, Serialize, Debug, Default)]
+pub enum CpuMinerType {
+    #[default]
+    Xmrig,
+    SrbMiner,
+}
+
+impl CpuMinerType {
+    pub fn get_expected_features(&self) -> Vec<CpuMinerFeature> {
+        match self {
+            CpuMinerType::Xmrig => vec![CpuMinerFeature::SoloMining, CpuMinerFeature::PoolMining],
+            CpuMinerType::SrbMiner => vec![CpuMinerFeature::PoolMining],
+        }
+    }
+
+    pub fn supported_platforms(&self) -> Vec<CurrentOperatingSystem> {
+        match self {
+            CpuMinerType::Xmrig => vec![
+                CurrentOperatingSystem::Windows,
+                CurrentOperatingSystem::Linux,
+                CurrentOperatingSystem::MacOS,
+            ],
+            CpuMinerType::SrbMiner => vec![
+                CurrentOperatingSystem::Windows,
+                CurrentOperatingSystem::Linux,
+            ],
+        }
+    }
+
+    pub fn binary(&self) -> Binaries {
+        match self {
+            CpuMinerType::Xmrig => Binaries::Xmrig,
+            CpuMinerType::SrbMiner => Binaries::SrbMiner,
+        }
+    }
+
+    pub fn is_supported_on_current_platform(&self) -> bool {
+        let current_os = PlatformUtils::detect_current_os();
+        self.supported_platforms().contains(&current_os)
+    }
+    pub fn is_solo_mining_supported(&self) -> bool {
+        self.get_expected_features()
+            .contains(&CpuMinerFeature::SoloMining)
+    }
+}
+
+impl std::fmt::Display for CpuMinerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            CpuMinerType::Xmrig => "Xmrig",
+            CpuMinerType::SrbMiner => "SrbMiner",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Eq, Hash, PartialEq, Clone, Serialize)]
+pub enum CpuMinerFeature {
+    /// Support for solo mining through the local merge mining proxy
+    SoloMining,
+    /// Support for mining in a pool
+    PoolMining,
+}
+
+#[derive(Clone, Serialize)]
+pub struct CpuMiner {
+    pub miner_type: CpuMinerType,
+    pub is_healthy: bool,
+    pub last_error: Option<String>,
+    pub features: Vec<CpuMinerFeature>,
+}
+
+impl CpuMiner {
+    pub fn new(miner_type: CpuMinerType, is_healthy: bool, last_error: Option<String>) -> Self {
+        Self {
+            miner_type: miner_type.clone(),
+            features: miner_type.get_expected_features(),
+            is_healthy,
+            last_error,
+        }
+    }
+}
+
+/// Defines priority of miners to be used when the selected miner is not available
+/// The first miner in the list has the highest priority
+/// Used for selecting fallback miner
+pub const CPU_MINERS_PRIORITY: &[CpuMinerType] = &[CpuMinerType::Xmrig, CpuMinerType::SrbMiner];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RandomXMode {
+    /// Uses the 256MB cache only, slower but light on memory
+    Light,
+    /// Uses the full 2GB dataset
+    Fast,
+}
@@ -20,9 +20,9 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{sync::LazyLock, thread};
+use std::{collections::HashMap, sync::LazyLock, thread};
 
-use log::{error, info};
+use log::{error, info, warn};
 use tari_shutdown::Shutdown;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_sentry::sentry;
@@ -37,19 +37,22 @@ use tokio::{
 use crate::{
     LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES, UniverseAppState,
     configs::{
-        config_mining::ConfigMining,
-        config_pools::ConfigPools,
-        config_wallet::ConfigWallet,
-        pools::{PoolOrigin, cpu_pools::CpuPool},
-        trait_config::ConfigImpl,
+        config_mining::ConfigMining, config_pools::ConfigPools, config_wallet::ConfigWallet,
+        pools::cpu_pools::CpuPool, trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
     internal_wallet::InternalWallet,
     mining::{
         CpuConnectionType, MinerControlsState, MiningError,
-        cpu::{CpuMinerStatus, miners::xmrig::XmrigAdapter},
+        cpu::{
+            CpuMinerStatus,
+            consts::{CPU_MINERS_PRIORITY, CpuMiner, CpuMinerType, RandomXMode},
+            interface::{CpuMinerInterface, CpuMinerInterfaceTrait},
+            miners::{srbminer::SrbMinerAdapter, xmrig::XmrigAdapter},
+        },
         pools::{PoolManagerInterfaceTrait, cpu_pool_manager::CpuPoolManager},
     },
+    mm_proxy_manager::MmProxyManager,
     node::node_adapter::BaseNodeStatus,
     process_adapter::ProcessAdapter,
     process_watcher::{ProcessWatcher, ProcessWatcherStats},
@@ -61,8 +64,11 @@ static INSTANCE: LazyLock<RwLock<CpuManager>> = LazyLock::new(|| RwLock::new(Cpu
 
 pub struct CpuManager {
     app_handle: Option<AppHandle>,
+    // ======= Miner config =======
+    selected_miner: CpuMinerType,
+    available_miners: HashMap<CpuMinerType, CpuMiner>,
     // ======= Process watcher =======
-    process_watcher: ProcessWatcher<XmrigAdapter>,
+    process_watcher: ProcessWatcher<CpuMinerInterface>,
     // ======= Parameters tracking =======
     status_thread_shutdown: Shutdown,
     process_stats_collector: Sender<ProcessWatcherStats>,
@@ -77,6 +83,11 @@ pub struct CpuManager {
     pool: Option<CpuPool>,
     #[allow(dead_code)]
     intensity_percentage: Option<u32>,
+    // ======= Thermal throttling =======
+    thermal_throttle_percentage: Option<u32>,
+    // ======= Solo fallback =======
+    /// Selected miner replaced for solo mining, restored once pool mining is back
+    solo_fallback_from: Option<CpuMinerType>,
 }
 
 impl CpuManager {
@@ -87,12 +98,15 @@ impl CpuManager {
         // This grace only covers the window before the HTTP API answers,
         // and bounds how fast a stalled miner is restarted.
         let mut process_watcher = ProcessWatcher::new(
-            XmrigAdapter::new(Sender::new(CpuMinerStatus::default())),
+            CpuMinerInterface::Xmrig(XmrigAdapter::new(Sender::new(CpuMinerStatus::default()))),
             Sender::new(ProcessWatcherStats::default()),
         );
         process_watcher.expected_startup_time = std::time::Duration::from_secs(30);
         Self {
             app_handle: None,
+            // ======= Miner config =======
+            selected_miner: CpuMinerType::Xmrig,
+            available_miners: HashMap::new(),
             // ======= Process watcher =======
             process_watcher,
             // ======= Parameters tracking =======
@@ -106,10 +120,13 @@ impl CpuManager {
             tari_address: None,
             pool: None,
             intensity_percentage: None,
+            // ======= Thermal throttling =======
+            thermal_throttle_percentage: None,
+            // ======= Solo fallback =======
+            solo_fallback_from: None,
         }
     }
 
-    #[allow(dead_code)]
     pub async fn read() -> tokio::sync::RwLockReadGuard<'static, CpuManager> {
         INSTANCE.read().await
     }
@@ -118,6 +135,11 @@ impl CpuManager {
         INSTANCE.write().await
     }
 
+    /// Receiver for the miner status updates that are also forwarded to the frontend
+    pub fn subscribe_to_status(&self) -> Receiver<CpuMinerStatus> {
+        self.cpu_external_status_channel.subscribe()
+    }
+
     pub async fn load_app_handle(&mut self, app_handle: AppHandle) {
         self.app_handle = Some(app_handle);
     }
@@ -133,8 +155,131 @@ impl CpuManager {
         instance.cpu_external_status_channel = status_channel;
         instance.node_status_channel = node_status_channel;
 
-        instance.process_watcher.adapter.summary_broadcast =
-            instance.cpu_internal_status_channel.clone();
+        let selected_miner = instance.selected_miner.clone();
+        instance.process_watcher.adapter = instance.resolve_miner_interface(&selected_miner);
+    }
+
+    pub async fn load_miner(
+        &mut self,
+        miner: CpuMinerType,
+        is_healthy: bool,
+        last_error: Option<String>,
+    ) {
+        let miner = CpuMiner::new(miner, is_healthy, last_error);
+        self.available_miners
+            .insert(miner.miner_type.clone(), miner);
+    }
+
+    // Loads the miner saved in the mining config
+    // If it is not available or not healthy, falls back to the first healthy miner based on CPU_MINERS_PRIORITY
+    pub async fn load_saved_miner(&mut self) -> Result<(), anyhow::Error> {
+        let saved_cpu_miner_type = ConfigMining::content().await.cpu_miner_type().clone();
+        let is_saved_miner_healthy = self
+            .available_miners
+            .get(&saved_cpu_miner_type)
+            .is_some_and(|m| m.is_healthy);
+
+        let selected_cpu_miner_type = if is_saved_miner_healthy {
+            saved_cpu_miner_type
+        } else {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Saved cpu miner {saved_cpu_miner_type} is not available, falling back based on priority");
+            CPU_MINERS_PRIORITY
+                .iter()
+                .find(|miner_type| {
+                    self.available_miners
+                        .get(miner_type)
+                        .is_some_and(|m| m.is_healthy)
+                })
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("No available cpu miners to load"))?
+        };
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Loaded cpu miner: {selected_cpu_miner_type}");
+
+        self.switch_miner(selected_cpu_miner_type).await?;
+
+        Ok(())
+    }
+
+    pub async fn switch_miner(&mut self, new_miner: CpuMinerType) -> Result<(), anyhow::Error> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching cpu miner to: {new_miner}");
+        if !self.available_miners.contains_key(&new_miner) {
+            return Err(anyhow::anyhow!("Selected cpu miner is not available"));
+        }
+
+        if self.process_watcher.is_running() {
+            self.stop_mining().await.ok();
+        }
+
+        self.selected_miner = new_miner.clone();
+        self.solo_fallback_from = None;
+        self.process_watcher.adapter = self.resolve_miner_interface(&new_miner);
+        info!(target: LOG_TARGET_APP_LOGIC, "Switched cpu miner to: {new_miner}");
+        EventsEmitter::emit_update_selected_cpu_miner(new_miner).await;
+        Ok(())
+    }
+
+    /// First healthy miner other than the selected one, based on priority
+    fn find_fallback_miner(&self) -> Option<CpuMinerType> {
+        CPU_MINERS_PRIORITY
+            .iter()
+            .find(|miner_type| {
+                *miner_type != &self.selected_miner
+                    && self
+                        .available_miners
+                        .get(miner_type)
+                        .is_some_and(|m| m.is_healthy)
+            })
+            .cloned()
+    }
+
+    pub fn has_fallback_miner(&self) -> bool {
+        self.find_fallback_miner().is_some()
+    }
+
+    /// Marks current selected miner as unhealthy and switches to another one based on priority
+    /// If no other miners are available, we just mark the current one as unhealthy
+    pub async fn handle_unhealthy_miner(&mut self) -> Result<(), anyhow::Error> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Handling unhealthy cpu miner");
+
+        if let Some(current_miner) = self.available_miners.get_mut(&self.selected_miner) {
+            current_miner.is_healthy = false;
+            current_miner.last_error =
+                Some("Miner process crashed or became unresponsive".to_string());
+        }
+
+        if let Some(fallback_miner) = self.find_fallback_miner() {
+            info!(target: LOG_TARGET_APP_LOGIC, "Switching to fallback cpu miner: {fallback_miner}");
+            TasksTrackers::current()
+                .cpu_mining_phase
+                .get_task_tracker()
+                .await
+                .spawn(async move {
+                    CpuManager::write().await.switch_miner(fallback_miner).await.unwrap_or_else(
+                        |e| {
+                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to switch to fallback cpu miner: {e}");
+                        },
+                    );
+                    CpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
+                        error!(target: LOG_TARGET_APP_LOGIC, "Failed to start mining with fallback cpu miner: {e}");
+                    });
+                });
+        } else {
+            error!(target: LOG_TARGET_APP_LOGIC, "No healthy cpu miners left to switch to");
+        }
+
+        Ok(())
+    }
+
+    fn resolve_miner_interface(&self, miner_type: &CpuMinerType) -> CpuMinerInterface {
+        match miner_type {
+            CpuMinerType::Xmrig => CpuMinerInterface::Xmrig(XmrigAdapter::new(
+                self.cpu_internal_status_channel.clone(),
+            )),
+            CpuMinerType::SrbMiner => CpuMinerInterface::SrbMiner(SrbMinerAdapter::new(
+                self.cpu_internal_status_channel.clone(),
+            )),
+        }
     }
     pub async fn start_mining(&mut self) -> Result<(), anyhow::Error> {
         info!(target: LOG_TARGET_APP_LOGIC, "Starting cpu miner");
@@ -177,7 +322,7 @@ impl CpuManager {
 
         EventsEmitter::emit_update_cpu_miner_state(MinerControlsState::Initiated).await;
 
-        if let Some(app_handle) = &self.app_handle {
+        if let Some(app_handle) = self.app_handle.clone() {
             let base_path = app_handle
                 .path()
                 .app_local_data_dir()
@@ -199,62 +344,15 @@ impl CpuManager {
                 .get_task_tracker()
                 .await;
 
+            let is_cpu_pool_enabled = *ConfigPools::content().await.cpu_pool_enabled();
+            self.select_miner_for_connection(is_cpu_pool_enabled)
+                .await?;
             let app_state = app_handle.state::<UniverseAppState>();
-            let mmproxy_manager = &app_state.mm_proxy_manager;
-
-            if *ConfigPools::content().await.cpu_pool_enabled() {
-                let pool_url = ConfigPools::content()
-                    .await
-                    .current_cpu_pool()
-                    .pool_url
-                    .clone();
-                let tari_address = InternalWallet::tari_address().await;
-
-                // Worker name format depends on the pool
-                // LuckyPool: .Tari-Universe
-                // Kryptex: /Tari-Universe
-                // SupportXTM: Not specified so we use None
-                let worker_name = match ConfigPools::content().await.current_cpu_pool().pool_origin
-                {
-                    PoolOrigin::LuckyPool => Some(".Tari-universe"),
-                    PoolOrigin::SupportXTM => None,
-                    PoolOrigin::Kryptex => Some("/Tari-universe"),
-                };
-
-                self.process_watcher.adapter.connection_type = CpuConnectionType::Pool {
-                    pool_url,
-                    worker_name: worker_name.map(|s| s.to_string()),
-                };
-                self.process_watcher.adapter.address = tari_address.to_base58();
-            } else {
-                let host_name = "127.0.0.1".to_string();
-                let mmproxy_port = mmproxy_manager.get_monero_port().await?;
-                let local_proxy_url = format!("{host_name}:{mmproxy_port}");
-                let monero_address = ConfigWallet::content().await.monero_address().clone();
-
-                self.process_watcher.adapter.connection_type =
-                    CpuConnectionType::LocalMMProxy { local_proxy_url };
-                self.process_watcher.adapter.address = monero_address.to_string();
-            }
-
-            let binary = crate::binaries::Binaries::Xmrig;
-
-            let cpu_usage_percentage = ConfigMining::content()
-                .await
-                .get_selected_cpu_usage_percentage();
-
-            if cpu_usage_percentage <= 1 {
-                self.process_watcher.adapter.cpu_threads =
-                    Some(Self::determine_number_of_cores_to_use(10).await);
-
-                self.process_watcher.adapter.extra_options =
-                    vec!["--randomx-mode=light".to_string()]
-            } else {
-                self.process_watcher.adapter.cpu_threads =
-                    Some(Self::determine_number_of_cores_to_use(cpu_usage_percentage).await);
+            self.load_connection_args(is_cpu_pool_enabled, &app_state.mm_proxy_manager)
+                .await?;
+            self.load_cpu_usage_args().await?;
 
-                self.process_watcher.adapter.extra_options = vec!["--randomx-mode=fast".to_string()]
-            }
+            let binary = self.selected_miner.binary();
 
             self.process_watcher
                 .start(
@@ -267,7 +365,7 @@ impl CpuManager {
                 )
                 .await?;
 
-            if *ConfigPools::content().await.cpu_pool_enabled() {
+            if is_cpu_pool_enabled {
                 CpuPoolManager::start_stats_watcher().await;
             }
 
@@ -277,10 +375,153 @@ impl CpuManager {
         Ok(())
     }
 
+    /// Restores the miner replaced for solo mining once the pool is back, or replaces the selected
+    /// miner for this session when it can't solo mine
+    async fn select_miner_for_connection(
+        &mut self,
+        is_cpu_pool_enabled: bool,
+    ) -> Result<(), anyhow::Error> {
+        // Go back to the miner replaced for solo mining once the pool is enabled again
+        if is_cpu_pool_enabled && let Some(previous_miner) = self.solo_fallback_from.take() {
+            let is_previous_miner_healthy = self
+                .available_miners
+                .get(&previous_miner)
+                .is_some_and(|m| m.is_healthy);
+            if is_previous_miner_healthy {
+                info!(target: LOG_TARGET_APP_LOGIC, "Pool mining is back, restoring cpu miner {previous_miner}");
+                self.selected_miner = previous_miner.clone();
+                self.process_watcher.adapter = self.resolve_miner_interface(&previous_miner);
+                EventsEmitter::emit_update_selected_cpu_miner(previous_miner).await;
+            }
+        }
+        if !is_cpu_pool_enabled && !self.selected_miner.is_solo_mining_supported() {
+            // Solo mining goes through mmproxy which only xmrig can talk to.
+            // Fall back for this session without touching the saved selection.
+            let fallback_miner = CPU_MINERS_PRIORITY
+                .iter()
+                .find(|miner_type| {
+                    miner_type.is_solo_mining_supported()
+                        && self
+                            .available_miners
+                            .get(miner_type)
+                            .is_some_and(|m| m.is_healthy)
+                })
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("No available cpu miner supports solo mining"))?;
+            info!(target: LOG_TARGET_APP_LOGIC, "Cpu miner {} does not support solo mining, using {fallback_miner}", self.selected_miner);
+            self.solo_fallback_from = Some(self.selected_miner.clone());
+            self.selected_miner = fallback_miner.clone();
+            self.process_watcher.adapter = self.resolve_miner_interface(&fallback_miner);
+            EventsEmitter::emit_update_selected_cpu_miner(fallback_miner).await;
+        }
+        Ok(())
+    }
+
+    async fn load_connection_args(
+        &mut self,
+        is_cpu_pool_enabled: bool,
+        mmproxy_manager: &MmProxyManager,
+    ) -> Result<(), anyhow::Error> {
+        if is_cpu_pool_enabled {
+            let pool_url = ConfigPools::content()
+                .await
+                .current_cpu_pool()
+                .pool_url
+                .clone();
+            let tari_address = InternalWallet::tari_address().await;
+
+            // Worker name format depends on the pool
+            let worker_name = ConfigPools::content()
+                .await
+                .current_cpu_pool()
+                .worker_name();
+
+            self.process_watcher
+                .adapter
+                .load_connection_type(CpuConnectionType::Pool {
+                    pool_url,
+                    worker_name,
+                })
+                .await?;
+            self.process_watcher
+                .adapter
+                .load_address(&tari_address.to_base58())
+                .await?;
+        } else {
+            let host_name = "127.0.0.1".to_string();
+            let mmproxy_port = mmproxy_manager.get_monero_port().await?;
+            let local_proxy_url = format!("{host_name}:{mmproxy_port}");
+            let monero_address = ConfigWallet::content().await.monero_address().clone();
+
+            self.process_watcher
+                .adapter
+                .load_connection_type(CpuConnectionType::LocalMMProxy { local_proxy_url })
+                .await?;
+            self.process_watcher
+                .adapter
+                .load_address(&monero_address.to_string())
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn load_cpu_usage_args(&mut self) -> Result<(), anyhow::Error> {
+        let cpu_usage_percentage = Self::apply_thermal_throttle_limit(
+            ConfigMining::content()
+                .await
+                .get_selected_cpu_usage_percentage(),
+            self.thermal_throttle_percentage,
+        );
+
+        if cpu_usage_percentage <= 1 {
+            self.process_watcher
+                .adapter
+                .load_cpu_threads(Some(Self::determine_number_of_cores_to_use(10).await))
+                .await?;
+            self.process_watcher
+                .adapter
+                .load_randomx_mode(RandomXMode::Light)
+                .await?;
+        } else {
+            self.process_watcher
+                .adapter
+                .load_cpu_threads(Some(
+                    Self::determine_number_of_cores_to_use(cpu_usage_percentage).await,
+                ))
+                .await?;
+            self.process_watcher
+                .adapter
+                .load_randomx_mode(RandomXMode::Fast)
+                .await?;
+        }
+        Ok(())
+    }
+
     pub fn is_running(&self) -> bool {
         self.process_watcher.is_running()
     }
 
+    /// Lower the CPU threads below the selected mining mode, `None` goes back to the mining mode threads
+    /// Running miner is restarted as it reads the threads count on start
+    pub async fn set_thermal_throttle(
+        &mut self,
+        usage_percentage: Option<u32>,
+    ) -> Result<(), anyhow::Error> {
+        if self.thermal_throttle_percentage == usage_percentage {
+            return Ok(());
+        }
+        self.thermal_throttle_percentage = usage_percentage;
+        if self.is_running() {
+            self.stop_mining().await?;
+            self.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    fn apply_thermal_throttle_limit(usage_percentage: u32, throttle: Option<u32>) -> u32 {
+        throttle.map_or(usage_percentage, |throttle| usage_percentage.min(throttle))
+    }
+
     async fn determine_number_of_cores_to_use(cpu_usage_percentage: u32) -> u32 {
         let max_cpu_available = thread::available_parallelism();
         let max_cpu_available = match max_cpu_available {
@@ -394,9 +635,10 @@ impl CpuManager {
     }
 
     async fn handle_pool_connection_type_status_change(
-        gpu_status: CpuMinerStatus,
+        cpu_status: CpuMinerStatus,
     ) -> CpuMinerStatus {
-        gpu_status
+        CpuPoolManager::handle_connection_status_change(cpu_status.connection.is_connected).await;
+        cpu_status
     }
 
     #[allow(dead_code)]
@@ -0,0 +1,373 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use anyhow::Error;
+use async_trait::async_trait;
+use log::{info, warn};
+use serde::Deserialize;
+use tari_shutdown::Shutdown;
+use tokio::sync::watch::Sender;
+
+#[cfg(target_os = "windows")]
+use crate::utils::windows_setup_utils::add_firewall_rule;
+
+use crate::{
+    LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES,
+    mining::{
+        CpuConnectionType,
+        cpu::{
+            CpuMinerConnectionStatus, CpuMinerStatus, interface::CpuMinerInterfaceTrait,
+            manager::CpuManager,
+        },
+    },
+    port_allocator::PortAllocator,
+    process_adapter::{
+        HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, ProcessStartupSpec,
+        StatusMonitor,
+    },
+};
+
+/// Alternative RandomX miner, pool mining only.
+/// SRBMiner runs with GPUs disabled so it never competes with the GPU miner for devices.
+pub struct SrbMinerAdapter {
+    pub connection_type: CpuConnectionType,
+    pub address: String,
+    pub http_api_port: u16,
+    pub cpu_threads: Option<u32>,
+    pub summary_broadcast: Sender<CpuMinerStatus>,
+}
+
+impl SrbMinerAdapter {
+    pub fn new(summary_broadcast: Sender<CpuMinerStatus>) -> Self {
+        let http_api_port = PortAllocator::new().assign_port_with_fallback();
+        Self {
+            connection_type: CpuConnectionType::default(),
+            address: String::new(),
+            http_api_port,
+            cpu_threads: None,
+            summary_broadcast,
+        }
+    }
+}
+
+impl CpuMinerInterfaceTrait for SrbMinerAdapter {
+    async fn load_address(&mut self, address: &str) -> Result<(), anyhow::Error> {
+        self.address = address.to_string();
+        Ok(())
+    }
+    async fn load_connection_type(
+        &mut self,
+        connection_type: CpuConnectionType,
+    ) -> Result<(), anyhow::Error> {
+        self.connection_type = connection_type;
+        Ok(())
+    }
+    async fn load_cpu_threads(&mut self, cpu_threads: Option<u32>) -> Result<(), anyhow::Error> {
+        self.cpu_threads = cpu_threads;
+        Ok(())
+    }
+}
+
+impl ProcessAdapter for SrbMinerAdapter {
+    type StatusMonitor = SrbMinerStatusMonitor;
+    type ProcessInstance = ProcessInstance;
+
+    fn spawn_inner(
+        &self,
+        data_dir: PathBuf,
+        _config_dir: PathBuf,
+        log_dir: PathBuf,
+        binary_version_path: PathBuf,
+        _is_first_start: bool,
+    ) -> Result<(ProcessInstance, Self::StatusMonitor), anyhow::Error> {
+        let inner_shutdown = Shutdown::new();
+
+        let mut args = vec![
+            "--algorithm".to_string(),
+            "randomx".to_string(),
+            "--disable-gpu".to_string(),
+        ];
+
+        match &self.connection_type {
+            CpuConnectionType::Pool {
+                pool_url,
+                worker_name,
+            } => {
+                let extended_user_address = match worker_name {
+                    Some(worker_name) => format!("{}{}", self.address, worker_name),
+                    None => self.address.to_string(),
+                };
+                args.push("--pool".to_string());
+                args.push(pool_url.clone());
+                args.push("--wallet".to_string());
+                args.push(extended_user_address);
+            }
+            CpuConnectionType::LocalMMProxy { .. } => {
+                return Err(anyhow::anyhow!("SRBMiner does not support solo mining"));
+            }
+        }
+
+        let log_file = log_dir.join("srbminer").join("srbminer.log");
+        std::fs::create_dir_all(
+            log_file
+                .parent()
+                .expect("Could not get srbminer root log dir"),
+        )?;
+        args.push("--log-file".to_string());
+        args.push(log_file.to_string_lossy().to_string());
+
+        args.push("--api-enable".to_string());
+        args.push("--api-port".to_string());
+        args.push(self.http_api_port.to_string());
+
+        // don't specify threads for ludicrous mode
+        if let Some(cpu_threads) = self.cpu_threads {
+            args.push("--cpu-threads".to_string());
+            args.push(cpu_threads.to_string());
+        }
+
+        #[cfg(target_os = "windows")]
+        add_firewall_rule(
+            "SRBMiner-MULTI.exe".to_string(),
+            binary_version_path.clone(),
+        )?;
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Binary file path: {}", binary_version_path.display());
+
+        Ok((
+            ProcessInstance {
+                shutdown: inner_shutdown,
+                handle: None,
+                startup_spec: ProcessStartupSpec {
+                    file_path: binary_version_path,
+                    envs: None,
+                    args,
+                    data_dir,
+                    pid_file_name: self.pid_file_name().to_string(),
+                    name: self.name().to_string(),
+                },
+            },
+            SrbMinerStatusMonitor {
+                summary_broadcast: self.summary_broadcast.clone(),
+                http_api_port: self.http_api_port,
+                has_hashed: Arc::new(AtomicBool::new(false)),
+            },
+        ))
+    }
+
+    fn name(&self) -> &str {
+        "srbminer"
+    }
+
+    fn pid_file_name(&self) -> &str {
+        "srbminer_pid"
+    }
+}
+
+// Fallback to other cpu miner should be triggered only once per session
+static WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone)]
+pub struct SrbMinerStatusMonitor {
+    http_api_port: u16,
+    summary_broadcast: Sender<CpuMinerStatus>,
+    /// Same as for xmrig, 0 hashrate before the first nonzero reading means
+    /// the RandomX dataset is still initializing.
+    has_hashed: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl StatusMonitor for SrbMinerStatusMonitor {
+    async fn handle_unhealthy(
+        &self,
+        duration_since_last_healthy_status: Duration,
+    ) -> Result<HandleUnhealthyResult, anyhow::Error> {
+        self.has_hashed.store(false, Ordering::Relaxed);
+        info!(target: LOG_TARGET_STATUSES, "Handling unhealthy status for SRBMiner | Duration since last healthy status: {:?}", duration_since_last_healthy_status.as_secs());
+        if duration_since_last_healthy_status.as_secs().gt(&(60 * 3)) // Fallback after 3 minutes of unhealthiness
+            && !WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED.load(Ordering::SeqCst)
+        {
+            match CpuManager::write().await.handle_unhealthy_miner().await {
+                Ok(_) => {
+                    info!(target: LOG_TARGET_STATUSES, "SRBMiner: stopped due to prolonged unhealthiness.");
+                    WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED.store(true, Ordering::SeqCst);
+                    Ok(HandleUnhealthyResult::Stop)
+                }
+                Err(error) => {
+                    warn!(target: LOG_TARGET_STATUSES, "SRBMiner: Failed to handle unhealthy miner: {error} | Continuing to monitor.");
+                    Ok(HandleUnhealthyResult::Continue)
+                }
+            }
+        } else {
+            Ok(HandleUnhealthyResult::Continue)
+        }
+    }
+
+    async fn check_health(&self, uptime: Duration, timeout_duration: Duration) -> HealthStatus {
+        match tokio::time::timeout(timeout_duration, self.status()).await {
+            Ok(Ok(status)) => {
+                let _result = self.summary_broadcast.send(status.clone());
+
+                if status.hash_rate.le(&0.0) {
+                    if !self.has_hashed.load(Ordering::Relaxed) && uptime < Duration::from_secs(600)
+                    {
+                        warn!(target: LOG_TARGET_STATUSES, "SRBMiner hash rate is 0 (still initializing)");
+                        return HealthStatus::Initializing;
+                    }
+                    warn!(target: LOG_TARGET_STATUSES, "SRBMiner hash rate is 0");
+                    return HealthStatus::Unhealthy;
+                }
+
+                self.has_hashed.store(true, Ordering::Relaxed);
+                HealthStatus::Healthy
+            }
+            Ok(Err(e)) => {
+                warn!(target: LOG_TARGET_STATUSES, "Failed to get SRBMiner summary: {e}");
+                let _result = self.summary_broadcast.send(CpuMinerStatus::default());
+                HealthStatus::Unhealthy
+            }
+            Err(_timeout_error) => {
+                warn!(target: LOG_TARGET_STATUSES, "Timeout while getting SRBMiner summary");
+                let _result = self.summary_broadcast.send(CpuMinerStatus::default());
+                HealthStatus::Unhealthy
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct SrbMinerSummary {
+    #[serde(default)]
+    pub(crate) algorithms: Vec<SrbMinerAlgorithm>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct SrbMinerAlgorithm {
+    pub(crate) hashrate: SrbMinerHashrate,
+    pub(crate) pool: Option<SrbMinerPool>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct SrbMinerHashrate {
+    pub(crate) cpu: Option<SrbMinerDeviceHashrate>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct SrbMinerDeviceHashrate {
+    pub(crate) total: Option<f64>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct SrbMinerPool {
+    #[serde(default)]
+    pub(crate) uptime: u64,
+}
+
+impl SrbMinerSummary {
+    fn into_status(self) -> CpuMinerStatus {
+        let randomx = self.algorithms.into_iter().next();
+        let hash_rate = randomx
+            .as_ref()
+            .and_then(|algorithm| algorithm.hashrate.cpu.as_ref())
+            .and_then(|cpu| cpu.total)
+            .unwrap_or(0.0);
+        let is_connected = randomx
+            .as_ref()
+            .and_then(|algorithm| algorithm.pool.as_ref())
+            .is_some_and(|pool| pool.uptime > 0);
+
+        CpuMinerStatus {
+            is_mining: true,
+            estimated_earnings: 0,
+            hash_rate,
+            connection: CpuMinerConnectionStatus { is_connected },
+        }
+    }
+}
+
+impl SrbMinerStatusMonitor {
+    pub async fn status(&self) -> Result<CpuMinerStatus, Error> {
+        let client = reqwest::Client::new();
+        let response = match client
+            .get(format!("http://127.0.0.1:{}", self.http_api_port))
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                warn!(target: LOG_TARGET_STATUSES, "Error in getting response from SRBMiner status: {e}");
+                return Ok(CpuMinerStatus::default());
+            }
+        };
+        let text = response.text().await?;
+        let body: SrbMinerSummary = match serde_json::from_str(&text) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!(target: LOG_TARGET_STATUSES, "Error decoding body in SRBMiner status: {e}");
+                return Ok(CpuMinerStatus::default());
+            }
+        };
+
+        info!(target: LOG_TARGET_STATUSES, "SRBMiner status: {:?}", body);
+
+        Ok(body.into_status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_maps_cpu_hashrate_and_pool_uptime() {
+        let body = r#"{
+            "algorithms": [{
+                "name": "randomx",
+                "hashrate": { "cpu": { "total": 4210.5 } },
+                "pool": { "pool": "turx.luckypool.io:10118", "uptime": 120 }
+            }]
+        }"#;
+        let summary: SrbMinerSummary = serde_json::from_str(body).unwrap();
+        let status = summary.into_status();
+
+        assert!((status.hash_rate - 4210.5).abs() < f64::EPSILON);
+        assert!(status.connection.is_connected);
+    }
+
+    #[test]
+    fn summary_without_algorithms_reports_no_hashrate() {
+        let summary: SrbMinerSummary = serde_json::from_str("{}").unwrap();
+        let status = summary.into_status();
+
+        assert!(status.hash_rate.abs() < f64::EPSILON);
+        assert!(!status.connection.is_connected);
+    }
+}
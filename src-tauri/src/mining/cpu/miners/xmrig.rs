@@ -32,7 +32,12 @@ use tari_shutdown::Shutdown;
 use tokio::sync::watch::Sender;
 use uuid::Uuid;
 
+use crate::configs::config_pools::ConfigPools;
+use crate::configs::trait_config::ConfigImpl;
 use crate::mining::CpuConnectionType;
+use crate::mining::cpu::consts::RandomXMode;
+use crate::mining::cpu::interface::CpuMinerInterfaceTrait;
+use crate::mining::cpu::manager::CpuManager;
 use crate::mining::cpu::{CpuMinerConnectionStatus, CpuMinerStatus};
 use crate::port_allocator::PortAllocator;
 use crate::process_adapter::{
@@ -68,6 +73,31 @@ impl XmrigAdapter {
     }
 }
 
+impl CpuMinerInterfaceTrait for XmrigAdapter {
+    async fn load_address(&mut self, address: &str) -> Result<(), anyhow::Error> {
+        self.address = address.to_string();
+        Ok(())
+    }
+    async fn load_connection_type(
+        &mut self,
+        connection_type: CpuConnectionType,
+    ) -> Result<(), anyhow::Error> {
+        self.connection_type = connection_type;
+        Ok(())
+    }
+    async fn load_cpu_threads(&mut self, cpu_threads: Option<u32>) -> Result<(), anyhow::Error> {
+        self.cpu_threads = cpu_threads;
+        Ok(())
+    }
+    async fn load_randomx_mode(&mut self, randomx_mode: RandomXMode) -> Result<(), anyhow::Error> {
+        self.extra_options = match randomx_mode {
+            RandomXMode::Light => vec!["--randomx-mode=light".to_string()],
+            RandomXMode::Fast => vec!["--randomx-mode=fast".to_string()],
+        };
+        Ok(())
+    }
+}
+
 impl ProcessAdapter for XmrigAdapter {
     type StatusMonitor = XmrigStatusMonitor;
     type ProcessInstance = ProcessInstance;
@@ -187,6 +217,8 @@ impl ProcessAdapter for XmrigAdapter {
 // This is a flag to indicate if the fallback to solo mining has been triggered
 // We want to avoid triggering it multiple times per session
 static WAS_FALLBACK_TO_SOLO_MINING_TRIGGERED: AtomicBool = AtomicBool::new(false);
+// Fallback to other cpu miner should be triggered only once per session
+static WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED: AtomicBool = AtomicBool::new(false);
 
 #[derive(Clone)]
 pub struct XmrigStatusMonitor {
@@ -215,6 +247,23 @@ impl StatusMonitor for XmrigStatusMonitor {
         // as Initializing, not as a stall of the previous instance.
         self.has_hashed.store(false, Ordering::Relaxed);
         self.zero_streak.store(0, Ordering::Relaxed);
+        // Fallback to another pool miner after 3 minutes of unhealthiness, solo mining needs xmrig
+        if duration_since_last_healthy_status.as_secs().gt(&(60 * 3))
+            && !WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED.load(Ordering::SeqCst)
+            && *ConfigPools::content().await.cpu_pool_enabled()
+            && CpuManager::read().await.has_fallback_miner()
+        {
+            match CpuManager::write().await.handle_unhealthy_miner().await {
+                Ok(_) => {
+                    info!(target: LOG_TARGET_STATUSES, "XmrigAdapter: stopped due to prolonged unhealthiness.");
+                    WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED.store(true, Ordering::SeqCst);
+                    return Ok(HandleUnhealthyResult::Stop);
+                }
+                Err(error) => {
+                    warn!(target: LOG_TARGET_STATUSES, "XmrigAdapter: Failed to handle unhealthy miner: {error} | Continuing to monitor.");
+                }
+            }
+        }
         // Fallback to solo mining if the miner has been unhealthy for more than 30 minutes
         info!(target: LOG_TARGET_STATUSES, "Handling unhealthy status for Xmrig | Duration since last healthy status: {:?}", duration_since_last_healthy_status.as_secs());
         if duration_since_last_healthy_status.as_secs().gt(&(60 * 30))
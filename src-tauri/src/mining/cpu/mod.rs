@@ -22,6 +22,8 @@
 
 use serde::Serialize;
 
+pub mod consts;
+pub mod interface;
 pub mod manager;
 pub mod miners;
 
@@ -23,7 +23,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    configs::pools::gpu_pools::GpuPool,
+    binaries::Binaries,
+    configs::pools::{BasePoolData, gpu_pools::GpuPool, is_valid_stratum_url},
     utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
@@ -49,6 +50,7 @@ impl GpuMinerStatus {
 #[derive(Eq, Hash, PartialEq, Clone, Deserialize, Serialize, Debug)]
 pub enum GpuMinerType {
     LolMiner,
+    Graxil,
 }
 
 impl GpuMinerType {
@@ -57,44 +59,86 @@ impl GpuMinerType {
             GpuMinerType::LolMiner => vec![
                 GpuMinerFeature::PoolMining,
                 GpuMinerFeature::DeviceExclusion,
+                GpuMinerFeature::TemperatureLimit,
+            ],
+            GpuMinerType::Graxil => vec![
+                GpuMinerFeature::PoolMining,
+                GpuMinerFeature::DeviceExclusion,
+                GpuMinerFeature::MiningIntensity,
             ],
         }
     }
 
+    pub fn has_feature(&self, feature: &GpuMinerFeature) -> bool {
+        self.get_expected_features().contains(feature)
+    }
+
     pub fn main_algorithm(&self) -> GpuMiningAlgorithm {
         match self {
             GpuMinerType::LolMiner => GpuMiningAlgorithm::C29,
+            GpuMinerType::Graxil => GpuMiningAlgorithm::SHA3X,
         }
     }
 
     pub fn supported_algorithms(&self) -> Vec<GpuMiningAlgorithm> {
         match self {
             GpuMinerType::LolMiner => vec![GpuMiningAlgorithm::C29],
+            GpuMinerType::Graxil => vec![GpuMiningAlgorithm::SHA3X],
         }
     }
 
+    /// Whether this miner can take over from `current` when it becomes unhealthy
+    /// The fallback has to mine the same algorithm, otherwise the pool and the mined coin would change
+    pub fn can_replace(&self, current: &GpuMinerType) -> bool {
+        self != current
+            && self
+                .supported_algorithms()
+                .contains(&current.main_algorithm())
+    }
+
     pub fn supported_platforms(&self) -> Vec<CurrentOperatingSystem> {
         match self {
             GpuMinerType::LolMiner => vec![
                 CurrentOperatingSystem::Windows,
                 CurrentOperatingSystem::Linux,
             ],
+            GpuMinerType::Graxil => vec![
+                CurrentOperatingSystem::Windows,
+                CurrentOperatingSystem::Linux,
+            ],
         }
     }
 
     pub fn supported_pools(&self) -> Vec<GpuPool> {
         match self {
             GpuMinerType::LolMiner => vec![GpuPool::KryptexPoolC29, GpuPool::LuckyPoolC29],
+            GpuMinerType::Graxil => vec![GpuPool::KryptexPoolSHA3X, GpuPool::LuckyPoolSHA3X],
         }
     }
 
-    pub fn is_pool_supported(&self, pool: &GpuPool) -> bool {
-        self.supported_pools().contains(pool)
+    // Custom pools are supported when they mine one of the miner algorithms and have a valid stratum url
+    pub fn is_pool_supported(&self, pool: &BasePoolData<GpuPool>) -> bool {
+        if pool.pool_type.is_custom() {
+            pool.algorithm
+                .as_ref()
+                .is_some_and(|algorithm| self.supported_algorithms().contains(algorithm))
+                && is_valid_stratum_url(&pool.pool_url)
+        } else {
+            self.supported_pools().contains(&pool.pool_type)
+        }
     }
 
     pub fn default_pool(&self) -> Option<GpuPool> {
         match self {
             GpuMinerType::LolMiner => Some(GpuPool::LuckyPoolC29),
+            GpuMinerType::Graxil => Some(GpuPool::LuckyPoolSHA3X),
+        }
+    }
+
+    pub fn binary(&self) -> Binaries {
+        match self {
+            GpuMinerType::LolMiner => Binaries::LolMiner,
+            GpuMinerType::Graxil => Binaries::Graxil,
         }
     }
 
@@ -116,6 +160,7 @@ impl std::fmt::Display for GpuMinerType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             GpuMinerType::LolMiner => "LolMiner",
+            GpuMinerType::Graxil => "Graxil",
         };
         write!(f, "{s}")
     }
@@ -125,6 +170,7 @@ impl std::fmt::Display for GpuMinerType {
 pub enum GpuMiningAlgorithm {
     #[default]
     C29,
+    SHA3X,
 }
 
 #[derive(Eq, Hash, PartialEq, Clone, Serialize)]
@@ -135,6 +181,10 @@ pub enum GpuMinerFeature {
     PoolMining,
     /// Support for excluding specific GPU devices
     DeviceExclusion,
+    /// Scales the work by the intensity percentage of the mining mode
+    MiningIntensity,
+    /// Pauses devices above a temperature and resumes them once cooled down
+    TemperatureLimit,
 }
 
 #[derive(Clone, Serialize)]
@@ -161,4 +211,16 @@ impl GpuMiner {
 /// Defines priority of miners to be used when multiple miners are available
 /// The first miner in the list has the highest priority
 /// Used for selecting default or fallback miner
-pub const MINERS_PRIORITY: &[GpuMinerType] = &[GpuMinerType::LolMiner];
+pub const MINERS_PRIORITY: &[GpuMinerType] = &[GpuMinerType::LolMiner, GpuMinerType::Graxil];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_miner_must_mine_the_same_algorithm() {
+        assert!(!GpuMinerType::Graxil.can_replace(&GpuMinerType::LolMiner));
+        assert!(!GpuMinerType::LolMiner.can_replace(&GpuMinerType::Graxil));
+        assert!(!GpuMinerType::LolMiner.can_replace(&GpuMinerType::LolMiner));
+    }
+}
@@ -25,15 +25,26 @@ use std::time::Duration;
 use axum::async_trait;
 
 use crate::{
+    configs::config_mining::GpuDevicesSettings,
     mining::{
         GpuConnectionType,
-        gpu::miners::lolminer::{LolMinerGpuMiner, LolMinerGpuMinerStatusMonitor},
+        gpu::miners::{
+            graxil::{GraxilGpuMiner, GraxilGpuMinerStatusMonitor},
+            lolminer::{LolMinerGpuMiner, LolMinerGpuMinerStatusMonitor},
+        },
     },
     process_adapter::{
         HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, StatusMonitor,
     },
 };
 
+/// Temperatures in Celsius at which a miner pauses and resumes every device
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GpuThermalLimit {
+    pub stop_temperature: u32,
+    pub resume_temperature: u32,
+}
+
 pub trait GpuMinerInterfaceTrait: Send + Sync {
     async fn load_tari_address(&mut self, tari_address: &str) -> Result<(), anyhow::Error>;
     async fn load_worker_name(&mut self, worker_name: Option<&str>) -> Result<(), anyhow::Error>;
@@ -52,21 +63,37 @@ pub trait GpuMinerInterfaceTrait: Send + Sync {
     ) -> Result<(), anyhow::Error> {
         Ok(())
     }
+    async fn load_devices_settings(
+        &mut self,
+        _devices_settings: GpuDevicesSettings,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+    /// Only loaded for miners with the `TemperatureLimit` feature
+    async fn load_thermal_limit(
+        &mut self,
+        _thermal_limit: Option<GpuThermalLimit>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
 }
 
 pub enum GpuMinerInterface {
     LolMiner(LolMinerGpuMiner),
+    Graxil(GraxilGpuMiner),
 }
 
 impl GpuMinerInterfaceTrait for GpuMinerInterface {
     async fn load_tari_address(&mut self, tari_address: &str) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.load_tari_address(tari_address).await,
+            GpuMinerInterface::Graxil(miner) => miner.load_tari_address(tari_address).await,
         }
     }
     async fn load_worker_name(&mut self, worker_name: Option<&str>) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.load_worker_name(worker_name).await,
+            GpuMinerInterface::Graxil(miner) => miner.load_worker_name(worker_name).await,
         }
     }
     async fn load_intensity_percentage(
@@ -77,6 +104,9 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
             GpuMinerInterface::LolMiner(miner) => {
                 miner.load_intensity_percentage(intensity_percentage).await
             }
+            GpuMinerInterface::Graxil(miner) => {
+                miner.load_intensity_percentage(intensity_percentage).await
+            }
         }
     }
     async fn load_connection_type(
@@ -85,12 +115,14 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
     ) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.load_connection_type(connection_type).await,
+            GpuMinerInterface::Graxil(miner) => miner.load_connection_type(connection_type).await,
         }
     }
 
     async fn detect_devices(&mut self) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.detect_devices().await,
+            GpuMinerInterface::Graxil(miner) => miner.detect_devices().await,
         }
     }
 
@@ -102,6 +134,28 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
             GpuMinerInterface::LolMiner(miner) => {
                 miner.load_excluded_devices(excluded_devices).await
             }
+            GpuMinerInterface::Graxil(miner) => miner.load_excluded_devices(excluded_devices).await,
+        }
+    }
+
+    async fn load_devices_settings(
+        &mut self,
+        devices_settings: GpuDevicesSettings,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => {
+                miner.load_devices_settings(devices_settings).await
+            }
+            GpuMinerInterface::Graxil(miner) => miner.load_devices_settings(devices_settings).await,
+        }
+    }
+    async fn load_thermal_limit(
+        &mut self,
+        thermal_limit: Option<GpuThermalLimit>,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => miner.load_thermal_limit(thermal_limit).await,
+            GpuMinerInterface::Graxil(miner) => miner.load_thermal_limit(thermal_limit).await,
         }
     }
 }
@@ -109,6 +163,7 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
 #[derive(Clone)]
 pub enum GpuMinerStatusInterface {
     LolMiner(LolMinerGpuMinerStatusMonitor),
+    Graxil(GraxilGpuMinerStatusMonitor),
 }
 
 #[async_trait]
@@ -123,6 +178,11 @@ impl StatusMonitor for GpuMinerStatusInterface {
                     .handle_unhealthy(duration_since_last_healthy_status)
                     .await
             }
+            GpuMinerStatusInterface::Graxil(monitor) => {
+                monitor
+                    .handle_unhealthy(duration_since_last_healthy_status)
+                    .await
+            }
         }
     }
     async fn check_health(&self, uptime: Duration, timeout_duration: Duration) -> HealthStatus {
@@ -130,6 +190,9 @@ impl StatusMonitor for GpuMinerStatusInterface {
             GpuMinerStatusInterface::LolMiner(monitor) => {
                 monitor.check_health(uptime, timeout_duration).await
             }
+            GpuMinerStatusInterface::Graxil(monitor) => {
+                monitor.check_health(uptime, timeout_duration).await
+            }
         }
     }
 }
@@ -153,16 +216,25 @@ impl ProcessAdapter for GpuMinerInterface {
                 binary_version_path,
                 is_first_start,
             ),
+            GpuMinerInterface::Graxil(miner) => miner.spawn_inner(
+                base_folder,
+                config_folder,
+                log_folder,
+                binary_version_path,
+                is_first_start,
+            ),
         }
     }
     fn name(&self) -> &str {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.name(),
+            GpuMinerInterface::Graxil(miner) => miner.name(),
         }
     }
     fn pid_file_name(&self) -> &str {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.pid_file_name(),
+            GpuMinerInterface::Graxil(miner) => miner.pid_file_name(),
         }
     }
 }
@@ -36,11 +36,10 @@ use tokio::{
 
 use crate::{
     LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES, UniverseAppState,
-    binaries::Binaries,
     configs::{
-        config_mining::ConfigMining,
+        config_mining::{ConfigMining, ConfigMiningContent},
         config_pools::ConfigPools,
-        pools::{PoolOrigin, gpu_pools::GpuPool},
+        pools::gpu_pools::GpuPool,
         trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
@@ -48,9 +47,12 @@ use crate::{
     mining::{
         GpuConnectionType, MinerControlsState, MiningError,
         gpu::{
-            consts::{GpuMiner, GpuMinerStatus, GpuMinerType, MINERS_PRIORITY},
-            interface::{GpuMinerInterface, GpuMinerInterfaceTrait},
-            miners::lolminer::LolMinerGpuMiner,
+            consts::{
+                GpuMiner, GpuMinerFeature, GpuMinerStatus, GpuMinerType, GpuMiningAlgorithm,
+                MINERS_PRIORITY,
+            },
+            interface::{GpuMinerInterface, GpuMinerInterfaceTrait, GpuThermalLimit},
+            miners::{graxil::GraxilGpuMiner, lolminer::LolMinerGpuMiner},
         },
         pools::{PoolManagerInterfaceTrait, gpu_pool_manager::GpuPoolManager},
     },
@@ -86,6 +88,8 @@ pub struct GpuManager {
     intensity_percentage: Option<u32>,
     #[allow(dead_code)]
     worker_name: Option<String>,
+    // ======= Thermal throttling =======
+    thermal_throttle_percentage: Option<u32>,
 }
 
 impl GpuManager {
@@ -116,6 +120,8 @@ impl GpuManager {
             pool: None,
             intensity_percentage: None,
             worker_name: None,
+            // ======= Thermal throttling =======
+            thermal_throttle_percentage: None,
         }
     }
 
@@ -127,6 +133,15 @@ impl GpuManager {
         INSTANCE.write().await
     }
 
+    pub fn selected_miner_algorithm(&self) -> GpuMiningAlgorithm {
+        self.selected_miner.main_algorithm()
+    }
+
+    /// Receiver for the miner status updates that are also forwarded to the frontend
+    pub fn subscribe_to_status(&self) -> Receiver<GpuMinerStatus> {
+        self.gpu_external_status_channel.subscribe()
+    }
+
     pub async fn load_app_handle(&mut self, app_handle: AppHandle) {
         self.app_handle = Some(app_handle);
     }
@@ -143,15 +158,25 @@ impl GpuManager {
         instance.node_status_channel = node_status_channel;
     }
 
-    // Loads the saved miner - always uses LolMiner since it's the only GPU miner
+    // Loads the first healthy miner based on MINERS_PRIORITY
+    // If none of the miners is healthy, falls back to the first available one
     pub async fn load_saved_miner(&mut self) -> Result<(), anyhow::Error> {
-        let selected_gpu_miner_type = GpuMinerType::LolMiner;
+        let selected_gpu_miner_type = MINERS_PRIORITY
+            .iter()
+            .find(|miner_type| {
+                self.available_miners
+                    .get(miner_type)
+                    .is_some_and(|m| m.is_healthy)
+            })
+            .or_else(|| {
+                MINERS_PRIORITY
+                    .iter()
+                    .find(|miner_type| self.available_miners.contains_key(miner_type))
+            })
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No available gpu miners to load"))?;
 
-        if self.available_miners.contains_key(&selected_gpu_miner_type) {
-            info!(target: LOG_TARGET_APP_LOGIC, "Loaded gpu miner: {selected_gpu_miner_type}");
-        } else {
-            return Err(anyhow::anyhow!("No available gpu miners to load"));
-        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Loaded gpu miner: {selected_gpu_miner_type}");
 
         self.switch_miner(selected_gpu_miner_type).await?;
 
@@ -175,9 +200,7 @@ impl GpuManager {
     async fn handle_pool_connection_load(&mut self) -> Result<(), anyhow::Error> {
         let current_pool_data = ConfigPools::content().await.current_gpu_pool().clone();
         if self.selected_miner.is_pool_mining_supported()
-            && self
-                .selected_miner
-                .is_pool_supported(&current_pool_data.pool_type)
+            && self.selected_miner.is_pool_supported(&current_pool_data)
         {
             self.process_watcher
                 .adapter
@@ -199,7 +222,7 @@ impl GpuManager {
                     is_healthy
                         && *miner_type != &self.selected_miner
                         && miner_type.is_pool_mining_supported()
-                        && miner_type.is_pool_supported(&current_pool_data.pool_type)
+                        && miner_type.is_pool_supported(&current_pool_data)
                 })
                 .cloned();
 
@@ -341,9 +364,12 @@ impl GpuManager {
                     .await;
 
                 let tari_address = InternalWallet::tari_address().await;
-                let gpu_usage_percentage = ConfigMining::content()
-                    .await
-                    .get_selected_gpu_usage_percentage();
+                let gpu_usage_percentage = Self::apply_thermal_throttle_limit(
+                    ConfigMining::content()
+                        .await
+                        .get_selected_gpu_usage_percentage(),
+                    self.thermal_throttle_percentage,
+                );
 
                 if *ConfigPools::content().await.gpu_pool_enabled() {
                     self.handle_pool_connection_load().await?;
@@ -353,22 +379,25 @@ impl GpuManager {
                     self.handle_node_connection_load(grpc_node_address).await?;
                 }
 
-                let binary = match self.selected_miner {
-                    GpuMinerType::LolMiner => Binaries::LolMiner,
-                };
+                let binary = self.selected_miner.binary();
 
                 // Worker name format depends on the pool
-                // LuckyPool: .Tari-Universe
-                // Kryptex: /Tari-Universe
-                // SupportXTM: Not specified so we use None
-                let worker_name = match ConfigPools::content().await.current_gpu_pool().pool_origin
-                {
-                    PoolOrigin::LuckyPool => Some(".Tari-universe"),
-                    PoolOrigin::SupportXTM => None,
-                    PoolOrigin::Kryptex => Some("/Tari-universe"),
-                };
+                let worker_name = ConfigPools::content()
+                    .await
+                    .current_gpu_pool()
+                    .worker_name();
 
-                let excluded_devices = ConfigMining::content().await.get_excluded_devices();
+                // Pool connection load can switch the miner, so the algorithm is resolved afterwards
+                let selected_gpu_pool = if *ConfigPools::content().await.gpu_pool_enabled() {
+                    Some(ConfigPools::content().await.current_gpu_pool_type().clone())
+                } else {
+                    None
+                };
+                let mining_config = ConfigMining::content().await;
+                let excluded_devices = mining_config.get_unassigned_devices(
+                    &self.selected_miner.main_algorithm(),
+                    selected_gpu_pool.as_ref(),
+                );
 
                 self.process_watcher
                     .adapter
@@ -376,7 +405,7 @@ impl GpuManager {
                     .await?;
                 self.process_watcher
                     .adapter
-                    .load_worker_name(worker_name)
+                    .load_worker_name(worker_name.as_deref())
                     .await?;
                 self.process_watcher
                     .adapter
@@ -386,6 +415,14 @@ impl GpuManager {
                     .adapter
                     .load_excluded_devices(excluded_devices)
                     .await?;
+                self.process_watcher
+                    .adapter
+                    .load_devices_settings(mining_config.gpu_devices_settings().clone())
+                    .await?;
+                self.process_watcher
+                    .adapter
+                    .load_thermal_limit(self.thermal_limit(&mining_config))
+                    .await?;
 
                 info!(target: LOG_TARGET_APP_LOGIC, "Starting gpu miner process watcher with binary: {:?}", binary);
 
@@ -422,6 +459,59 @@ impl GpuManager {
         self.process_watcher.is_running()
     }
 
+    /// Whether thermal throttling works by lowering the intensity of the selected miner
+    pub fn supports_intensity_control(&self) -> bool {
+        self.selected_miner
+            .has_feature(&GpuMinerFeature::MiningIntensity)
+    }
+
+    /// Miners without intensity control pause hot devices themselves at the throttle ceiling
+    fn thermal_limit(&self, mining_config: &ConfigMiningContent) -> Option<GpuThermalLimit> {
+        let uses_temperature_limit = !self.supports_intensity_control()
+            && self
+                .selected_miner
+                .has_feature(&GpuMinerFeature::TemperatureLimit);
+        (uses_temperature_limit && *mining_config.thermal_throttle_enabled()).then(|| {
+            GpuThermalLimit {
+                stop_temperature: *mining_config.gpu_max_temperature(),
+                resume_temperature: mining_config
+                    .gpu_max_temperature()
+                    .saturating_sub(*mining_config.thermal_throttle_hysteresis()),
+            }
+        })
+    }
+
+    /// Lower the intensity below the selected mining mode, `None` goes back to the mining mode intensity
+    /// Running miner is restarted as it reads the intensity on start, miners without intensity
+    /// control are left running as the thermal limit they started with already covers them
+    pub async fn set_thermal_throttle(
+        &mut self,
+        usage_percentage: Option<u32>,
+    ) -> Result<(), anyhow::Error> {
+        if self.thermal_throttle_percentage == usage_percentage {
+            return Ok(());
+        }
+        self.thermal_throttle_percentage = usage_percentage;
+        if self.is_running() && self.supports_intensity_control() {
+            self.stop_mining().await?;
+            self.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    /// Restarts a running miner which pauses devices itself so it picks up changed throttle settings
+    pub async fn reload_thermal_limit(&mut self) -> Result<(), anyhow::Error> {
+        if self.is_running() && !self.supports_intensity_control() {
+            self.stop_mining().await?;
+            self.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    fn apply_thermal_throttle_limit(usage_percentage: u32, throttle: Option<u32>) -> u32 {
+        throttle.map_or(usage_percentage, |throttle| usage_percentage.min(throttle))
+    }
+
     pub async fn stop_mining(&mut self) -> Result<(), anyhow::Error> {
         info!(target: LOG_TARGET_APP_LOGIC, "Stopping gpu miner");
         {
@@ -491,7 +581,7 @@ impl GpuManager {
                     .map(|m| m.is_healthy)
                     .unwrap_or(false);
 
-                is_healthy && *miner_type != &self.selected_miner
+                is_healthy && miner_type.can_replace(&self.selected_miner)
             })
             .cloned();
 
@@ -512,7 +602,7 @@ impl GpuManager {
                     });
                 });
         } else {
-            error!(target: LOG_TARGET_APP_LOGIC, "No healthy gpu miners left to switch to");
+            error!(target: LOG_TARGET_APP_LOGIC, "No healthy gpu miner mining {:?} left to switch to", self.selected_miner_algorithm());
             //TODO Probably we will need to handle it better in the future, app modules maybe need to know that no miners are healthy ?
         }
 
@@ -590,6 +680,9 @@ impl GpuManager {
             GpuMinerType::LolMiner => GpuMinerInterface::LolMiner(LolMinerGpuMiner::new(
                 self.gpu_internal_status_channel.clone(),
             )),
+            GpuMinerType::Graxil => GpuMinerInterface::Graxil(GraxilGpuMiner::new(
+                self.gpu_internal_status_channel.clone(),
+            )),
         }
     }
 
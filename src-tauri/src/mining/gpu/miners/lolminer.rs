@@ -38,7 +38,7 @@ use crate::{
     APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES,
     binaries::{Binaries, BinaryResolver},
     configs::{
-        config_mining::{ConfigMining, ConfigMiningContent},
+        config_mining::{ConfigMining, ConfigMiningContent, GpuDevicesSettings},
         trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
@@ -46,7 +46,7 @@ use crate::{
         GpuConnectionType,
         gpu::{
             consts::{GpuMinerStatus, GpuMinerType},
-            interface::{GpuMinerInterfaceTrait, GpuMinerStatusInterface},
+            interface::{GpuMinerInterfaceTrait, GpuMinerStatusInterface, GpuThermalLimit},
             manager::GpuManager,
             miners::GpuCommonInformation,
         },
@@ -59,6 +59,9 @@ use crate::{
     process_utils::launch_child_process,
 };
 
+// Device resumes mining once it cools down this many degrees below its temperature cap
+const TEMPERATURE_RESUME_MARGIN: u32 = 10;
+
 #[derive(Default)]
 pub struct LolMinerGpuMiner {
     pub tari_address: Option<String>,
@@ -68,6 +71,9 @@ pub struct LolMinerGpuMiner {
     pub gpu_status_sender: Sender<GpuMinerStatus>,
     pub gpu_devices: Vec<GpuCommonInformation>,
     pub excluded_devices: Vec<u32>,
+    pub devices_settings: GpuDevicesSettings,
+    /// lolMiner has no intensity setting, thermal throttling pauses hot devices instead
+    pub thermal_limit: Option<GpuThermalLimit>,
 }
 
 impl LolMinerGpuMiner {
@@ -80,6 +86,8 @@ impl LolMinerGpuMiner {
             gpu_status_sender,
             gpu_devices: vec![],
             excluded_devices: vec![],
+            devices_settings: GpuDevicesSettings::new(),
+            thermal_limit: None,
         }
     }
 }
@@ -116,6 +124,22 @@ impl GpuMinerInterfaceTrait for LolMinerGpuMiner {
         Ok(())
     }
 
+    async fn load_devices_settings(
+        &mut self,
+        devices_settings: GpuDevicesSettings,
+    ) -> Result<(), anyhow::Error> {
+        self.devices_settings = devices_settings;
+        Ok(())
+    }
+
+    async fn load_thermal_limit(
+        &mut self,
+        thermal_limit: Option<GpuThermalLimit>,
+    ) -> Result<(), anyhow::Error> {
+        self.thermal_limit = thermal_limit;
+        Ok(())
+    }
+
     async fn detect_devices(&mut self) -> Result<(), anyhow::Error> {
         let config_path =
             dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Failed to get config directory"))?;
@@ -233,29 +257,41 @@ impl ProcessAdapter for LolMinerGpuMiner {
         let log_file_path = log_folder.join("lolminer.txt");
         args.push(log_file_path.to_string_lossy().to_string());
 
-        // Add device selection if there are excluded devices
-        if !self.excluded_devices.is_empty() && !self.gpu_devices.is_empty() {
-            let devices_to_use: Vec<String> = self
-                .gpu_devices
-                .iter()
-                .map(|d| d.device_id)
-                .filter(|id| !self.excluded_devices.contains(id))
-                .map(|id| id.to_string())
-                .collect();
+        let devices_to_use: Vec<u32> = self
+            .gpu_devices
+            .iter()
+            .map(|d| d.device_id)
+            .filter(|id| !self.excluded_devices.contains(id))
+            .collect();
+        let temperature_args = device_temperature_args(
+            &devices_to_use,
+            &self.devices_settings,
+            self.thermal_limit.as_ref(),
+        );
 
+        // Add device selection if there are excluded devices or per-device arguments which follow its order
+        if (!self.excluded_devices.is_empty() || !temperature_args.is_empty())
+            && !self.gpu_devices.is_empty()
+        {
             if devices_to_use.is_empty() {
                 return Err(crate::mining::MiningError::AllDevicesExcluded.into());
             } else {
+                let devices_to_use = devices_to_use
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",");
                 args.push("--devices".to_string());
-                args.push(devices_to_use.join(","));
+                args.push(devices_to_use.clone());
                 info!(
                     target: LOG_TARGET_APP_LOGIC,
                     "Lolminer using devices: {} (excluded: {:?})",
-                    devices_to_use.join(","),
+                    devices_to_use,
                     self.excluded_devices
                 );
             }
         }
+        args.extend(temperature_args);
 
         #[cfg(target_os = "windows")]
         add_firewall_rule("lolMiner.exe".to_string(), binary_version_path.clone())?;
@@ -437,6 +473,53 @@ struct Algorithm {
     total_performance: f64,
 }
 
+/// Per device `--tstop`/`--tstart` values. The lower of the device cap and the thermal
+/// throttle ceiling applies, devices without either are passed as 0 which lolMiner ignores.
+fn device_temperature_args(
+    devices: &[u32],
+    devices_settings: &GpuDevicesSettings,
+    thermal_limit: Option<&GpuThermalLimit>,
+) -> Vec<String> {
+    let limits: Vec<Option<GpuThermalLimit>> = devices
+        .iter()
+        .map(|device_id| {
+            let device_limit = devices_settings
+                .get(*device_id)
+                .and_then(|settings| settings.overrides().max_temperature)
+                .map(|cap| GpuThermalLimit {
+                    stop_temperature: cap,
+                    resume_temperature: cap.saturating_sub(TEMPERATURE_RESUME_MARGIN),
+                });
+            match (device_limit, thermal_limit) {
+                (Some(device), Some(global))
+                    if global.stop_temperature < device.stop_temperature =>
+                {
+                    Some(*global)
+                }
+                (Some(device), _) => Some(device),
+                (None, global) => global.copied(),
+            }
+        })
+        .collect();
+    if limits.iter().all(Option::is_none) {
+        return vec![];
+    }
+
+    let join = |temperature: fn(&GpuThermalLimit) -> u32| {
+        limits
+            .iter()
+            .map(|limit| limit.as_ref().map_or(0, temperature).to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+    vec![
+        "--tstop".to_string(),
+        join(|limit| limit.stop_temperature),
+        "--tstart".to_string(),
+        join(|limit| limit.resume_temperature),
+    ]
+}
+
 fn extract_device_names(output_str: &str) -> Vec<String> {
     let lines: Vec<&str> = output_str.lines().collect();
     let mut device_names = Vec::new();
@@ -467,3 +550,55 @@ fn extract_device_names(output_str: &str) -> Vec<String> {
     }
     device_names
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::config_mining::GpuDeviceOverrides;
+
+    fn devices_settings(caps: &[(u32, Option<u32>)]) -> GpuDevicesSettings {
+        let mut settings = GpuDevicesSettings::new();
+        for (device_id, max_temperature) in caps {
+            settings.add(*device_id);
+            settings.set_overrides(
+                *device_id,
+                GpuDeviceOverrides {
+                    max_temperature: *max_temperature,
+                    ..Default::default()
+                },
+            );
+        }
+        settings
+    }
+
+    #[test]
+    fn no_temperature_args_without_caps() {
+        let settings = devices_settings(&[(0, None), (1, None)]);
+
+        assert!(device_temperature_args(&[0, 1], &settings, None).is_empty());
+    }
+
+    #[test]
+    fn temperature_args_follow_devices_order() {
+        let settings = devices_settings(&[(0, Some(80)), (1, None), (2, Some(5))]);
+
+        assert_eq!(
+            device_temperature_args(&[2, 1, 0], &settings, None),
+            vec!["--tstop", "5,0,80", "--tstart", "0,0,70"]
+        );
+    }
+
+    #[test]
+    fn thermal_limit_applies_below_device_caps() {
+        let settings = devices_settings(&[(0, Some(80)), (1, None), (2, Some(70))]);
+        let thermal_limit = GpuThermalLimit {
+            stop_temperature: 75,
+            resume_temperature: 67,
+        };
+
+        assert_eq!(
+            device_temperature_args(&[0, 1, 2], &settings, Some(&thermal_limit)),
+            vec!["--tstop", "75,75,70", "--tstart", "67,67,60"]
+        );
+    }
+}
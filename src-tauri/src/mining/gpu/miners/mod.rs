@@ -22,6 +22,7 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod graxil;
 pub mod lolminer;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
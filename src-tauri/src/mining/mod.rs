@@ -22,6 +22,7 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod auto_mode;
 pub mod cpu;
 pub mod gpu;
 pub mod pools;
@@ -0,0 +1,192 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{
+    LOG_TARGET_STATUSES,
+    configs::pools::{PoolStatsField, PoolStatsMapping},
+    mining::pools::{PoolStatus, adapters::PoolApiAdapter},
+    requests::clients::http_client::HttpClient,
+};
+use log::info;
+use serde_json::Value;
+
+/// Generic declarative pool stats adapter
+/// Reads the pool status fields from the stats response using the JSON pointers and scaling from `PoolStatsMapping`,
+/// so pools can be supported without writing a dedicated adapter
+#[derive(Clone, Debug)]
+pub struct JsonPathPoolAdapter {
+    name: String,
+    stats_url: String,
+    mapping: PoolStatsMapping,
+}
+
+impl JsonPathPoolAdapter {
+    pub fn new(name: String, stats_url: String, mapping: PoolStatsMapping) -> Self {
+        Self {
+            name,
+            stats_url,
+            mapping,
+        }
+    }
+
+    // Pools are not consistent with number types, so numeric strings are accepted as well
+    fn read_number(data: &Value, pointer: &str) -> Result<f64, anyhow::Error> {
+        let value = data
+            .pointer(pointer)
+            .ok_or_else(|| anyhow::anyhow!("Field {pointer} not found in pool stats"))?;
+        match value {
+            Value::Number(number) => number
+                .as_f64()
+                .ok_or_else(|| anyhow::anyhow!("Field {pointer} is not a valid number")),
+            Value::String(text) => text
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| anyhow::anyhow!("Field {pointer} is not a valid number")),
+            Value::Null => Ok(0.0),
+            _ => Err(anyhow::anyhow!("Field {pointer} is not a number")),
+        }
+    }
+
+    fn read_field(data: &Value, field: Option<&PoolStatsField>) -> Result<f64, anyhow::Error> {
+        let Some(field) = field else {
+            return Ok(0.0);
+        };
+        let mut total = 0.0;
+        for pointer in &field.pointers {
+            total += Self::read_number(data, pointer)?;
+        }
+        Ok(total * field.scale)
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn read_integer_field(
+        data: &Value,
+        field: Option<&PoolStatsField>,
+    ) -> Result<u64, anyhow::Error> {
+        // Scaling formatted values back can leave float noise, e.g. 1.005 * 1e6 = 1004999.9999999999
+        Ok(Self::read_field(data, field)?.round().max(0.0) as u64)
+    }
+}
+
+impl PoolApiAdapter for JsonPathPoolAdapter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn reports_accepted_shares(&self) -> bool {
+        self.mapping.accepted_shares.is_some()
+    }
+
+    fn convert_api_data(&self, data: &str) -> Result<PoolStatus, anyhow::Error> {
+        if let Some(marker) = &self.mapping.empty_response_marker
+            && data.contains(marker.as_str())
+        {
+            return Ok(PoolStatus::default());
+        }
+
+        let response: Value = serde_json::from_str(data)?;
+        let pool_status = PoolStatus {
+            accepted_shares: Self::read_integer_field(
+                &response,
+                self.mapping.accepted_shares.as_ref(),
+            )?,
+            unpaid: Self::read_field(&response, self.mapping.unpaid.as_ref())?,
+            balance: Self::read_field(&response, self.mapping.balance.as_ref())?,
+            min_payout: Self::read_integer_field(&response, self.mapping.min_payout.as_ref())?,
+        };
+        Ok(pool_status)
+    }
+    async fn request_pool_status(&self, address: String) -> Result<PoolStatus, anyhow::Error> {
+        let url = self
+            .stats_url
+            .replace("%TARI_ADDRESS%", &address.to_string());
+        info!(target: LOG_TARGET_STATUSES, "Requesting {} pool status from: {url}", self.name);
+        let pool_status_response = HttpClient::with_retries(3).send_get_request(&url).await?;
+        let response_text = pool_status_response.text().await?;
+        let pool_status = self.convert_api_data(response_text.as_str())?;
+        Ok(pool_status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter(mapping: &str) -> JsonPathPoolAdapter {
+        JsonPathPoolAdapter::new(
+            "Custom:test".to_string(),
+            "https://example.com/%TARI_ADDRESS%".to_string(),
+            serde_json::from_str(mapping).unwrap(),
+        )
+    }
+
+    #[test]
+    fn reads_nested_numbers_and_numeric_strings() {
+        let adapter = adapter(
+            r#"{"accepted_shares":"/stats/shares","unpaid":"/stats/balance/pending","min_payout":"/config/threshold"}"#,
+        );
+        let status = adapter
+            .convert_api_data(
+                r#"{"stats":{"shares":"42","balance":{"pending":1500}},"config":{"threshold":2000000}}"#,
+            )
+            .unwrap();
+
+        assert_eq!(status.accepted_shares, 42);
+        assert!(adapter.reports_accepted_shares());
+        assert!((status.unpaid - 1500.0).abs() < f64::EPSILON);
+        assert!(status.balance.abs() < f64::EPSILON);
+        assert_eq!(status.min_payout, 2_000_000);
+    }
+
+    #[test]
+    fn sums_and_scales_fields() {
+        let adapter = adapter(
+            r#"{"unpaid":{"pointers":["/locked","/unlocked"],"scale":1000},"min_payout":{"pointers":["/threshold"],"scale":1000000}}"#,
+        );
+        let status = adapter
+            .convert_api_data(r#"{"locked":1.5,"unlocked":"2.25","threshold":1.005}"#)
+            .unwrap();
+
+        assert!((status.unpaid - 3750.0).abs() < f64::EPSILON);
+        assert_eq!(status.min_payout, 1_005_000);
+        // Without a mapping the shares always read 0, failover must not treat that as stalled
+        assert!(!adapter.reports_accepted_shares());
+    }
+
+    #[test]
+    fn empty_response_marker_returns_default_status() {
+        let adapter =
+            adapter(r#"{"unpaid":"/unpaid","empty_response_marker":"Address not found"}"#);
+        let status = adapter
+            .convert_api_data(r#"{"error":"Address not found"}"#)
+            .unwrap();
+
+        assert_eq!(status, PoolStatus::default());
+    }
+
+    #[test]
+    fn fails_when_mapped_field_is_missing() {
+        let adapter = adapter(r#"{"unpaid":"/missing"}"#);
+
+        assert!(adapter.convert_api_data(r#"{"unpaid":1}"#).is_err());
+    }
+}
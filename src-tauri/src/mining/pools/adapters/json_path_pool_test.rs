@@ -0,0 +1,201 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Replays recorded stats responses of the built-in pools through a mock server and checks that the
+//! declarative json path adapter produces the same pool status as the hand-written adapters
+
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::mining::pools::PoolStatus;
+use crate::mining::pools::adapters::PoolApiAdapter;
+use crate::mining::pools::adapters::json_path_pool::JsonPathPoolAdapter;
+use crate::mining::pools::adapters::kryptex_pool::KryptexPoolAdapter;
+use crate::mining::pools::adapters::lucky_pool::LuckyPoolAdapter;
+use crate::mining::pools::adapters::support_xmr_pool::SupportXmrPoolAdapter;
+
+const TARI_ADDRESS: &str =
+    "12FrDe5d3XFpnGmUCUWrJt37hTpAN1dX5KqJWTLGwAaTdkaHYWKmy6pQm3Ejns2HYYT7V8tDm1ZTKfaNj6rTUkzTcWM";
+
+// ======= Recorded responses =======
+
+const LUCKY_POOL_RESPONSE: &str = r#"{"stats":{"address":"12FrDe5d3XFpnGmUCUWrJt37hTpAN1dX5KqJWTLGwAaTdkaHYWKmy6pQm3Ejns2HYYT7V8tDm1ZTKfaNj6rTUkzTcWM","hashes":"48213776","lastShare":"1760701563","acceptedShares":"1873","rejectedShares":"4","paid":"35216004","paymentThreshold":"20000000","unlocked":"1204330","locked":148213,"hashrate":"2.41 KH"},"payments":[],"charts":{}}"#;
+
+const LUCKY_POOL_ADDRESS_NOT_FOUND_RESPONSE: &str = r#"{"error":"Address not found"}"#;
+
+const KRYPTEX_POOL_RESPONSE: &str = r#"{"total":4.351287,"unconfirmed":0.402113,"confirmed":3.949174,"threshold":10.0,"reached_pct":43.51,"last_active":1760701490}"#;
+
+const SUPPORT_XMR_POOL_RESPONSE: &str = r#"{"hash":3120,"identifier":"global","lastHash":1760701551,"totalHashes":981733120,"validShares":2214,"invalidShares":1,"expiry":1760702151,"amtPaid":8120000,"amtDue":671342,"txnCount":3}"#;
+
+// ======= Declarative mappings of the built-in pools =======
+
+const LUCKY_POOL_MAPPING: &str = r#"{
+    "accepted_shares": "/stats/acceptedShares",
+    "unpaid": { "pointers": ["/stats/unlocked", "/stats/locked"] },
+    "balance": "/stats/paid",
+    "min_payout": "/stats/paymentThreshold",
+    "empty_response_marker": "Address not found"
+}"#;
+
+const KRYPTEX_POOL_MAPPING: &str = r#"{
+    "unpaid": { "pointers": ["/total"], "scale": 1000000 },
+    "min_payout": { "pointers": ["/threshold"], "scale": 1000000 }
+}"#;
+
+const SUPPORT_XMR_POOL_MAPPING: &str = r#"{
+    "accepted_shares": "/validShares",
+    "unpaid": "/amtDue",
+    "balance": { "pointers": ["/amtPaid", "/amtDue"] }
+}"#;
+
+async fn serve_stats(server: &MockServer, stats_path: &str, status_code: u16, body: &str) {
+    Mock::given(method("GET"))
+        .and(path(stats_path))
+        .and(query_param("address", TARI_ADDRESS))
+        .respond_with(ResponseTemplate::new(status_code).set_body_string(body))
+        .mount(server)
+        .await;
+}
+
+fn json_path_adapter(stats_url: &str, mapping: &str) -> JsonPathPoolAdapter {
+    JsonPathPoolAdapter::new(
+        "Custom:test".to_string(),
+        stats_url.to_string(),
+        serde_json::from_str(mapping).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn lucky_pool_mapping_matches_lucky_pool_adapter() {
+    let server = MockServer::start().await;
+    serve_stats(&server, "/api/stats_address", 200, LUCKY_POOL_RESPONSE).await;
+    let stats_url = format!("{}/api/stats_address?address=%TARI_ADDRESS%", server.uri());
+
+    let expected = LuckyPoolAdapter::new("LuckyPoolRANDOMX".to_string(), stats_url.clone())
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await
+        .unwrap();
+    let status = json_path_adapter(&stats_url, LUCKY_POOL_MAPPING)
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(status, expected);
+    assert_eq!(status.accepted_shares, 1873);
+    assert!((status.unpaid - 1_352_543.0).abs() < f64::EPSILON);
+    assert!((status.balance - 35_216_004.0).abs() < f64::EPSILON);
+    assert_eq!(status.min_payout, 20_000_000);
+}
+
+#[tokio::test]
+async fn lucky_pool_mapping_handles_unknown_address() {
+    let server = MockServer::start().await;
+    serve_stats(
+        &server,
+        "/api/stats_address",
+        200,
+        LUCKY_POOL_ADDRESS_NOT_FOUND_RESPONSE,
+    )
+    .await;
+    let stats_url = format!("{}/api/stats_address?address=%TARI_ADDRESS%", server.uri());
+
+    let expected = LuckyPoolAdapter::new("LuckyPoolRANDOMX".to_string(), stats_url.clone())
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await
+        .unwrap();
+    let status = json_path_adapter(&stats_url, LUCKY_POOL_MAPPING)
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(status, expected);
+    assert_eq!(status, PoolStatus::default());
+}
+
+#[tokio::test]
+async fn kryptex_pool_mapping_matches_kryptex_pool_adapter() {
+    let server = MockServer::start().await;
+    let stats_path = format!("/xtm-rx/api/v1/miner/balance/{TARI_ADDRESS}");
+    Mock::given(method("GET"))
+        .and(path(stats_path.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_string(KRYPTEX_POOL_RESPONSE))
+        .mount(&server)
+        .await;
+    let stats_url = format!(
+        "{}/xtm-rx/api/v1/miner/balance/%TARI_ADDRESS%",
+        server.uri()
+    );
+
+    let kryptex_adapter =
+        KryptexPoolAdapter::new("KryptexPoolRANDOMX".to_string(), stats_url.clone());
+    let expected = kryptex_adapter
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await
+        .unwrap();
+    let json_adapter = json_path_adapter(&stats_url, KRYPTEX_POOL_MAPPING);
+    let status = json_adapter
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(status, expected);
+    assert_eq!(status.accepted_shares, 0);
+    // Kryptex has no share count, so the 0 must not feed the failover share monitor
+    assert!(!kryptex_adapter.reports_accepted_shares());
+    assert!(!json_adapter.reports_accepted_shares());
+    assert_eq!(status.min_payout, 10_000_000);
+}
+
+#[tokio::test]
+async fn support_xmr_pool_mapping_matches_support_xmr_pool_adapter() {
+    let server = MockServer::start().await;
+    serve_stats(&server, "/api/miner/stats", 200, SUPPORT_XMR_POOL_RESPONSE).await;
+    let stats_url = format!("{}/api/miner/stats?address=%TARI_ADDRESS%", server.uri());
+
+    let expected =
+        SupportXmrPoolAdapter::new("SupportXTMPoolRANDOMX".to_string(), stats_url.clone())
+            .request_pool_status(TARI_ADDRESS.to_string())
+            .await
+            .unwrap();
+    let status = json_path_adapter(&stats_url, SUPPORT_XMR_POOL_MAPPING)
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(status, expected);
+    assert_eq!(status.accepted_shares, 2214);
+    assert!((status.unpaid - 671_342.0).abs() < f64::EPSILON);
+    assert!((status.balance - 8_791_342.0).abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn json_path_adapter_fails_on_error_status() {
+    let server = MockServer::start().await;
+    serve_stats(&server, "/api/stats_address", 404, "").await;
+    let stats_url = format!("{}/api/stats_address?address=%TARI_ADDRESS%", server.uri());
+
+    let result = json_path_adapter(&stats_url, LUCKY_POOL_MAPPING)
+        .request_pool_status(TARI_ADDRESS.to_string())
+        .await;
+
+    assert!(result.is_err());
+}
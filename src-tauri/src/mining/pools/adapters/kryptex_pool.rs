@@ -55,6 +55,10 @@ impl PoolApiAdapter for KryptexPoolAdapter {
         &self.name
     }
 
+    fn reports_accepted_shares(&self) -> bool {
+        false
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn convert_api_data(&self, data: &str) -> Result<PoolStatus, anyhow::Error> {
         let response: KryptexPoolStatusResponseBody = serde_json::from_str(data)?;
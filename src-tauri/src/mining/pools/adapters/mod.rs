@@ -20,20 +20,34 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::configs::pools::PoolStatsMapping;
 use crate::mining::pools::{
     PoolStatus,
     adapters::{
-        kryptex_pool::KryptexPoolAdapter, lucky_pool::LuckyPoolAdapter,
+        json_path_pool::JsonPathPoolAdapter, kryptex_pool::KryptexPoolAdapter,
+        lucky_pool::LuckyPoolAdapter, no_stats_pool::NoStatsPoolAdapter,
         support_xmr_pool::SupportXmrPoolAdapter,
     },
 };
 
+pub mod json_path_pool;
+#[cfg(test)]
+mod json_path_pool_test;
 pub mod kryptex_pool;
 pub mod lucky_pool;
+pub mod no_stats_pool;
 pub mod support_xmr_pool;
 
 pub(crate) trait PoolApiAdapter: Clone {
     fn name(&self) -> &str;
+    /// Whether the pool exposes a stats api at all
+    fn supports_stats(&self) -> bool {
+        true
+    }
+    /// Whether the stats contain the accepted shares, pool failover only watches shares of pools that do
+    fn reports_accepted_shares(&self) -> bool {
+        self.supports_stats()
+    }
     fn convert_api_data(&self, data: &str) -> Result<PoolStatus, anyhow::Error>;
     async fn request_pool_status(&self, address: String) -> Result<PoolStatus, anyhow::Error>;
 }
@@ -43,6 +57,24 @@ pub enum PoolApiAdapters {
     LuckyPool(LuckyPoolAdapter),
     SupportXmr(SupportXmrPoolAdapter),
     Kryptex(KryptexPoolAdapter),
+    JsonPath(JsonPathPoolAdapter),
+    NoStats(NoStatsPoolAdapter),
+}
+
+impl PoolApiAdapters {
+    /// Custom pools use the generic json path adapter when stats are configured, otherwise they have no stats at all
+    pub fn for_custom_pool(
+        name: String,
+        stats_url: String,
+        stats_mapping: Option<PoolStatsMapping>,
+    ) -> Self {
+        match stats_mapping {
+            Some(mapping) if !stats_url.is_empty() => {
+                PoolApiAdapters::JsonPath(JsonPathPoolAdapter::new(name, stats_url, mapping))
+            }
+            _ => PoolApiAdapters::NoStats(NoStatsPoolAdapter::new(name)),
+        }
+    }
 }
 
 impl PoolApiAdapter for PoolApiAdapters {
@@ -51,6 +83,28 @@ impl PoolApiAdapter for PoolApiAdapters {
             PoolApiAdapters::LuckyPool(adapter) => adapter.name(),
             PoolApiAdapters::SupportXmr(adapter) => adapter.name(),
             PoolApiAdapters::Kryptex(adapter) => adapter.name(),
+            PoolApiAdapters::JsonPath(adapter) => adapter.name(),
+            PoolApiAdapters::NoStats(adapter) => adapter.name(),
+        }
+    }
+
+    fn supports_stats(&self) -> bool {
+        match self {
+            PoolApiAdapters::LuckyPool(adapter) => adapter.supports_stats(),
+            PoolApiAdapters::SupportXmr(adapter) => adapter.supports_stats(),
+            PoolApiAdapters::Kryptex(adapter) => adapter.supports_stats(),
+            PoolApiAdapters::JsonPath(adapter) => adapter.supports_stats(),
+            PoolApiAdapters::NoStats(adapter) => adapter.supports_stats(),
+        }
+    }
+
+    fn reports_accepted_shares(&self) -> bool {
+        match self {
+            PoolApiAdapters::LuckyPool(adapter) => adapter.reports_accepted_shares(),
+            PoolApiAdapters::SupportXmr(adapter) => adapter.reports_accepted_shares(),
+            PoolApiAdapters::Kryptex(adapter) => adapter.reports_accepted_shares(),
+            PoolApiAdapters::JsonPath(adapter) => adapter.reports_accepted_shares(),
+            PoolApiAdapters::NoStats(adapter) => adapter.reports_accepted_shares(),
         }
     }
 
@@ -59,6 +113,8 @@ impl PoolApiAdapter for PoolApiAdapters {
             PoolApiAdapters::LuckyPool(adapter) => adapter.convert_api_data(data),
             PoolApiAdapters::SupportXmr(adapter) => adapter.convert_api_data(data),
             PoolApiAdapters::Kryptex(adapter) => adapter.convert_api_data(data),
+            PoolApiAdapters::JsonPath(adapter) => adapter.convert_api_data(data),
+            PoolApiAdapters::NoStats(adapter) => adapter.convert_api_data(data),
         }
     }
     async fn request_pool_status(&self, address: String) -> Result<PoolStatus, anyhow::Error> {
@@ -66,6 +122,8 @@ impl PoolApiAdapter for PoolApiAdapters {
             PoolApiAdapters::LuckyPool(adapter) => adapter.request_pool_status(address).await,
             PoolApiAdapters::SupportXmr(adapter) => adapter.request_pool_status(address).await,
             PoolApiAdapters::Kryptex(adapter) => adapter.request_pool_status(address).await,
+            PoolApiAdapters::JsonPath(adapter) => adapter.request_pool_status(address).await,
+            PoolApiAdapters::NoStats(adapter) => adapter.request_pool_status(address).await,
         }
     }
 }
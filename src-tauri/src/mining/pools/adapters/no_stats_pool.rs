@@ -0,0 +1,52 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::mining::pools::{PoolStatus, adapters::PoolApiAdapter};
+
+/// Adapter for pools without a stats api, pool manager skips fetching stats for it
+#[derive(Clone, Debug)]
+pub struct NoStatsPoolAdapter {
+    name: String,
+}
+
+impl NoStatsPoolAdapter {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl PoolApiAdapter for NoStatsPoolAdapter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_stats(&self) -> bool {
+        false
+    }
+
+    fn convert_api_data(&self, _data: &str) -> Result<PoolStatus, anyhow::Error> {
+        Ok(PoolStatus::default())
+    }
+    async fn request_pool_status(&self, _address: String) -> Result<PoolStatus, anyhow::Error> {
+        Err(anyhow::anyhow!("Pool {} does not provide stats", self.name))
+    }
+}
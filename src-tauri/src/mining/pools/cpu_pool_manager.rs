@@ -20,26 +20,37 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, sync::LazyLock};
+use std::{collections::HashMap, sync::LazyLock, time::Duration};
 
+use log::{error, info, warn};
 use tokio::{
     spawn,
-    sync::{RwLock, RwLockWriteGuard},
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 use crate::{
+    LOG_TARGET_APP_LOGIC,
     configs::{
-        config_pools::ConfigPoolsContent,
+        config_pools::{ConfigPools, ConfigPoolsContent},
         pools::{BasePoolData, cpu_pools::CpuPool},
+        trait_config::ConfigImpl,
     },
+    events::PoolFailoverPayload,
     events_emitter::EventsEmitter,
-    mining::pools::{
-        PoolManagerInterfaceTrait, PoolStatus,
-        adapters::{
-            PoolApiAdapters, kryptex_pool::KryptexPoolAdapter, lucky_pool::LuckyPoolAdapter,
-            support_xmr_pool::SupportXmrPoolAdapter,
+    mining::{
+        cpu::manager::CpuManager,
+        pools::{
+            PoolManagerInterfaceTrait, PoolStatus,
+            adapters::{
+                PoolApiAdapters, kryptex_pool::KryptexPoolAdapter, lucky_pool::LuckyPoolAdapter,
+                support_xmr_pool::SupportXmrPoolAdapter,
+            },
+            failover::{
+                PoolFailoverKind, PoolFailoverReason, apply_latency_ranking, next_failover_pool,
+                rank_pools_by_latency,
+            },
+            pools_manager::PoolManager,
         },
-        pools_manager::PoolManager,
     },
     systemtray_manager::{SystemTrayEvents, SystemTrayManager},
     tasks_tracker::TasksTrackers,
@@ -49,6 +60,9 @@ static INSTANCE: LazyLock<CpuPoolManager> = LazyLock::new(CpuPoolManager::new);
 
 pub struct CpuPoolManager {
     pool_status_manager: RwLock<PoolManager>,
+    /// Failover pools ordered by the last startup latency probe, kept out of the config
+    /// so the order the user picked is never overwritten
+    latency_ranking: RwLock<Vec<CpuPool>>,
 }
 
 impl CpuPoolManager {
@@ -61,16 +75,32 @@ impl CpuPoolManager {
             pool_adapter,
             TasksTrackers::current().cpu_mining_phase.clone(),
             Self::construct_callback_for_pool_status_update(),
+            Self::construct_callback_for_pool_failover(),
         );
         Self {
             pool_status_manager: RwLock::new(pool_manager),
+            latency_ranking: RwLock::new(Vec::new()),
         }
     }
 
+    pub async fn load_failover_thresholds(config_content: &ConfigPoolsContent) {
+        INSTANCE
+            .pool_status_manager
+            .read()
+            .await
+            .load_failover_thresholds(
+                Duration::from_secs(*config_content.pool_failover_disconnect_threshold_secs()),
+                Duration::from_secs(*config_content.pool_failover_missing_shares_threshold_secs()),
+            )
+            .await;
+    }
+
     pub async fn initialize_from_pool_config(config_content: &ConfigPoolsContent) {
         let cpu_pool_content = config_content.current_cpu_pool().clone();
         let pool_adapter = Self::resolve_pool_adapter(cpu_pool_content);
 
+        Self::load_failover_thresholds(config_content).await;
+
         if *config_content.cpu_pool_enabled() {
             INSTANCE
                 .pool_status_manager
@@ -87,9 +117,114 @@ impl CpuPoolManager {
                 .await;
         }
     }
+
+    /// Rank the failover pools by stratum connect latency and select the fastest reachable one
+    /// Only applied when the currently selected pool is part of the failover list, so a manually picked pool is kept
+    /// The ranking stays in memory and is used for failover while `probe_pool_latency_on_startup` is enabled
+    pub async fn probe_failover_pools_latency() {
+        let config = ConfigPools::content().await;
+        let pools_with_urls = config
+            .cpu_failover_pools()
+            .iter()
+            .filter_map(|pool| {
+                config
+                    .cpu_pools()
+                    .get(pool)
+                    .map(|pool_data| (pool.clone(), pool_data.pool_url.clone()))
+            })
+            .collect::<Vec<_>>();
+        if pools_with_urls.is_empty() {
+            return;
+        }
+
+        let ranked_pools = rank_pools_by_latency(pools_with_urls).await;
+        *INSTANCE.latency_ranking.write().await =
+            ranked_pools.iter().map(|(pool, _)| pool.clone()).collect();
+
+        let current_pool = config.current_cpu_pool_type().clone();
+        if let Some((fastest_pool, Some(latency))) = ranked_pools.first()
+            && *fastest_pool != current_pool
+            && config.cpu_failover_pools().contains(&current_pool)
+        {
+            let reason = PoolFailoverReason::LatencyProbe {
+                latency_ms: u64::try_from(latency.as_millis()).unwrap_or(u64::MAX),
+            };
+            Self::switch_pool(current_pool, fastest_pool.clone(), reason).await;
+        }
+    }
+
+    /// Failover list in the configured order, or by latency when the user opted into latency ranking
+    async fn failover_order(config: &ConfigPoolsContent) -> Vec<CpuPool> {
+        if *config.probe_pool_latency_on_startup() {
+            apply_latency_ranking(
+                config.cpu_failover_pools(),
+                &INSTANCE.latency_ranking.read().await,
+            )
+        } else {
+            config.cpu_failover_pools().clone()
+        }
+    }
+
+    /// Move to the next pool from the failover list after the current one was reported unhealthy
+    async fn switch_to_next_failover_pool(reason: PoolFailoverReason) {
+        let config = ConfigPools::content().await;
+        if !*config.pool_failover_enabled() || !*config.cpu_pool_enabled() {
+            return;
+        }
+
+        let current_pool = config.current_cpu_pool_type().clone();
+        let failover_pools = Self::failover_order(&config).await;
+        match next_failover_pool(&failover_pools, &current_pool, |pool| {
+            config.cpu_pools().contains_key(pool)
+        }) {
+            Some(next_pool) => Self::switch_pool(current_pool, next_pool, reason).await,
+            None => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Cpu pool {current_pool} is unhealthy ({reason:?}) but there is no other failover pool configured");
+            }
+        }
+    }
+
+    async fn switch_pool(from_pool: CpuPool, to_pool: CpuPool, reason: PoolFailoverReason) {
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching cpu pool from {from_pool} to {to_pool}, reason: {reason:?}");
+
+        if let Err(e) =
+            ConfigPools::update_field(ConfigPoolsContent::set_current_cpu_pool, to_pool.clone())
+                .await
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to save failover cpu pool: {e}");
+            return;
+        }
+        let config = ConfigPools::content().await;
+        Self::handle_new_selected_pool(config.current_cpu_pool()).await;
+
+        EventsEmitter::emit_pools_config_loaded(&config).await;
+        EventsEmitter::emit_pool_failover(PoolFailoverPayload {
+            kind: PoolFailoverKind::Cpu,
+            from_pool: from_pool.key_string(),
+            to_pool: to_pool.key_string(),
+            reason,
+        })
+        .await;
+
+        // Miner reads the pool url on start, so it has to be restarted to pick up the new pool
+        let mut cpu_manager = CpuManager::write().await;
+        if cpu_manager.is_running() {
+            if let Err(e) = cpu_manager.stop_mining().await {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to stop cpu miner for pool failover: {e}");
+                return;
+            }
+            if let Err(e) = cpu_manager.start_mining().await {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to restart cpu miner after pool failover: {e}");
+            }
+        }
+    }
 }
 
 impl PoolManagerInterfaceTrait<CpuPool> for CpuPoolManager {
+    async fn get_read_manager() -> RwLockReadGuard<'static, PoolManager> {
+        INSTANCE.pool_status_manager.read().await
+    }
+
     async fn get_write_manager() -> RwLockWriteGuard<'static, PoolManager> {
         INSTANCE.pool_status_manager.write().await
     }
@@ -108,6 +243,13 @@ impl PoolManagerInterfaceTrait<CpuPool> for CpuPoolManager {
         }
     }
 
+    fn construct_callback_for_pool_failover() -> impl Fn(PoolFailoverReason) + Send + Sync + 'static
+    {
+        move |reason: PoolFailoverReason| {
+            spawn(CpuPoolManager::switch_to_next_failover_pool(reason));
+        }
+    }
+
     fn resolve_pool_adapter(pool: BasePoolData<CpuPool>) -> PoolApiAdapters {
         match pool.pool_type {
             CpuPool::LuckyPoolRANDOMX => PoolApiAdapters::LuckyPool(LuckyPoolAdapter::new(
@@ -121,6 +263,11 @@ impl PoolManagerInterfaceTrait<CpuPool> for CpuPoolManager {
                 pool.pool_type.key_string(),
                 pool.stats_url,
             )),
+            CpuPool::Custom { .. } => PoolApiAdapters::for_custom_pool(
+                pool.pool_type.key_string(),
+                pool.stats_url,
+                pool.stats_mapping,
+            ),
         }
     }
 }
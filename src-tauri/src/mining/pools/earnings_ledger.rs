@@ -0,0 +1,474 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    time::{Duration, SystemTime},
+};
+
+use chrono::{DateTime, Utc};
+use dirs::config_dir;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tari_common::configuration::Network;
+use tokio::{io::AsyncWriteExt, sync::RwLock};
+
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC, mining::pools::PoolStatus};
+
+const SNAPSHOTS_FILE_NAME: &str = "pool_earnings.jsonl";
+const PAYOUTS_FILE_NAME: &str = "pool_payouts.jsonl";
+// Stats are refreshed every minute while mining, there is no point in storing all of them
+const MIN_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(15 * 60);
+// Roughly three years of snapshots for a single pool
+const MAX_STORED_SNAPSHOTS: usize = 100_000;
+
+static INSTANCE: LazyLock<RwLock<PoolEarningsLedger>> =
+    LazyLock::new(|| RwLock::new(PoolEarningsLedger::new()));
+
+/// Point in time pool stats for a single pool and mining address
+/// Amounts are in the same units as `PoolStatus`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PoolEarningsSnapshot {
+    pub timestamp: SystemTime,
+    pub pool: String,
+    pub address: String,
+    pub accepted_shares: u64,
+    pub unpaid: f64,
+    pub balance: f64,
+}
+
+/// Payout detected between two consecutive snapshots, `unpaid` dropped while `balance` rose
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PoolPayout {
+    pub timestamp: SystemTime,
+    pub pool: String,
+    pub address: String,
+    pub amount: f64,
+    pub unpaid_before: f64,
+    pub unpaid_after: f64,
+    pub balance_before: f64,
+    pub balance_after: f64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DailyPoolEarnings {
+    /// UTC date in the YYYY-MM-DD format
+    pub date: String,
+    pub pool: String,
+    pub address: String,
+    pub earned: f64,
+    pub paid_out: f64,
+    pub accepted_shares: u64,
+}
+
+/// Persisted time series of pool stats snapshots with payout detection
+/// Snapshots and detected payouts are appended to separate jsonl files next to the app configs
+pub struct PoolEarningsLedger {
+    snapshots_path: PathBuf,
+    payouts_path: PathBuf,
+    last_observed: HashMap<(String, String), PoolEarningsSnapshot>,
+    last_persisted_at: HashMap<(String, String), SystemTime>,
+}
+
+impl PoolEarningsLedger {
+    fn new() -> Self {
+        let ledger_dir = config_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join(APPLICATION_FOLDER_ID)
+            .join("app_configs")
+            .join(Network::get_current_or_user_setting_or_default().as_key_str());
+        Self::with_paths(
+            ledger_dir.join(SNAPSHOTS_FILE_NAME),
+            ledger_dir.join(PAYOUTS_FILE_NAME),
+        )
+    }
+
+    fn with_paths(snapshots_path: PathBuf, payouts_path: PathBuf) -> Self {
+        let mut snapshots = read_jsonl::<PoolEarningsSnapshot>(&snapshots_path);
+        if snapshots.len() > MAX_STORED_SNAPSHOTS {
+            snapshots.drain(..snapshots.len() - MAX_STORED_SNAPSHOTS);
+            if let Err(e) = write_jsonl(&snapshots_path, &snapshots) {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to compact pool earnings ledger: {e}");
+            }
+        }
+
+        let mut last_observed = HashMap::new();
+        let mut last_persisted_at = HashMap::new();
+        for snapshot in snapshots {
+            let key = (snapshot.pool.clone(), snapshot.address.clone());
+            last_persisted_at.insert(key.clone(), snapshot.timestamp);
+            last_observed.insert(key, snapshot);
+        }
+
+        Self {
+            snapshots_path,
+            payouts_path,
+            last_observed,
+            last_persisted_at,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    /// Record fetched pool stats of the given pool and mining address
+    pub async fn record(pool: &str, address: &str, status: &PoolStatus) {
+        Self::current()
+            .write()
+            .await
+            .record_status(pool, address, status, SystemTime::now())
+            .await;
+    }
+
+    async fn record_status(
+        &mut self,
+        pool: &str,
+        address: &str,
+        status: &PoolStatus,
+        now: SystemTime,
+    ) {
+        let snapshot = PoolEarningsSnapshot {
+            timestamp: now,
+            pool: pool.to_string(),
+            address: address.to_string(),
+            accepted_shares: status.accepted_shares,
+            unpaid: status.unpaid,
+            balance: status.balance,
+        };
+        let key = (snapshot.pool.clone(), snapshot.address.clone());
+
+        let payout = self
+            .last_observed
+            .get(&key)
+            .and_then(|previous| detect_payout(previous, &snapshot));
+        if let Some(payout) = &payout {
+            info!(target: LOG_TARGET_APP_LOGIC, "Detected pool payout of {} from {pool}", payout.amount);
+            append_jsonl(&self.payouts_path, payout).await;
+        }
+
+        let is_snapshot_due = self.last_persisted_at.get(&key).is_none_or(|persisted_at| {
+            now.duration_since(*persisted_at).unwrap_or_default() >= MIN_SNAPSHOT_INTERVAL
+        });
+        // Snapshot around a payout is always stored so the daily earnings stay accurate
+        if is_snapshot_due || payout.is_some() {
+            append_jsonl(&self.snapshots_path, &snapshot).await;
+            self.last_persisted_at.insert(key.clone(), now);
+        }
+
+        self.last_observed.insert(key, snapshot);
+    }
+
+    /// Earnings per UTC day, newest first
+    /// ### Arguments
+    /// * `pool` - Only include this pool when set
+    /// * `days` - Number of days to include, counting today
+    pub async fn daily_earnings(
+        pool: Option<&str>,
+        days: u32,
+    ) -> Result<Vec<DailyPoolEarnings>, anyhow::Error> {
+        let snapshots_path = Self::current().read().await.snapshots_path.clone();
+        let snapshots = read_jsonl_async::<PoolEarningsSnapshot>(&snapshots_path)
+            .await?
+            .into_iter()
+            .filter(|snapshot| pool.is_none_or(|pool| snapshot.pool == pool))
+            .collect::<Vec<_>>();
+
+        let first_date = format_date(
+            SystemTime::now() - Duration::from_secs(u64::from(days.saturating_sub(1)) * 86_400),
+        );
+        let mut daily_earnings = aggregate_daily_earnings(&snapshots)
+            .into_iter()
+            .filter(|earnings| earnings.date >= first_date)
+            .collect::<Vec<_>>();
+        daily_earnings.reverse();
+        Ok(daily_earnings)
+    }
+
+    /// Detected payouts, newest first
+    pub async fn payout_history(pool: Option<&str>) -> Result<Vec<PoolPayout>, anyhow::Error> {
+        let payouts_path = Self::current().read().await.payouts_path.clone();
+        let mut payouts = read_jsonl_async::<PoolPayout>(&payouts_path)
+            .await?
+            .into_iter()
+            .filter(|payout| pool.is_none_or(|pool| payout.pool == pool))
+            .collect::<Vec<_>>();
+        payouts.reverse();
+        Ok(payouts)
+    }
+}
+
+pub(crate) fn detect_payout(
+    previous: &PoolEarningsSnapshot,
+    current: &PoolEarningsSnapshot,
+) -> Option<PoolPayout> {
+    if current.unpaid < previous.unpaid && current.balance > previous.balance {
+        return Some(PoolPayout {
+            timestamp: current.timestamp,
+            pool: current.pool.clone(),
+            address: current.address.clone(),
+            amount: current.balance - previous.balance,
+            unpaid_before: previous.unpaid,
+            unpaid_after: current.unpaid,
+            balance_before: previous.balance,
+            balance_after: current.balance,
+        });
+    }
+    None
+}
+
+/// Sum the earnings between consecutive snapshots of every pool and address per UTC day
+/// Snapshots are expected in chronological order, results are ordered by date
+pub(crate) fn aggregate_daily_earnings(
+    snapshots: &[PoolEarningsSnapshot],
+) -> Vec<DailyPoolEarnings> {
+    let mut previous_snapshots: HashMap<(&str, &str), &PoolEarningsSnapshot> = HashMap::new();
+    let mut daily: BTreeMap<(String, String, String), DailyPoolEarnings> = BTreeMap::new();
+
+    for snapshot in snapshots {
+        let key = (snapshot.pool.as_str(), snapshot.address.as_str());
+        let Some(previous) = previous_snapshots.insert(key, snapshot) else {
+            continue;
+        };
+
+        let paid_out = detect_payout(previous, snapshot).map_or(0.0, |payout| payout.amount);
+        // Whatever left unpaid through a payout was earned before, so only the remaining growth counts
+        let earned = (snapshot.unpaid - previous.unpaid + paid_out).max(0.0);
+        // Pools reset the share counter between rounds
+        let accepted_shares = if snapshot.accepted_shares >= previous.accepted_shares {
+            snapshot.accepted_shares - previous.accepted_shares
+        } else {
+            snapshot.accepted_shares
+        };
+
+        let date = format_date(snapshot.timestamp);
+        let entry = daily
+            .entry((
+                date.clone(),
+                snapshot.pool.clone(),
+                snapshot.address.clone(),
+            ))
+            .or_insert_with(|| DailyPoolEarnings {
+                date,
+                pool: snapshot.pool.clone(),
+                address: snapshot.address.clone(),
+                earned: 0.0,
+                paid_out: 0.0,
+                accepted_shares: 0,
+            });
+        entry.earned += earned;
+        entry.paid_out += paid_out;
+        entry.accepted_shares += accepted_shares;
+    }
+
+    daily.into_values().collect()
+}
+
+fn format_date(timestamp: SystemTime) -> String {
+    DateTime::<Utc>::from(timestamp)
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+fn read_jsonl<T: DeserializeOwned>(path: &Path) -> Vec<T> {
+    let Ok(file) = File::open(path) else {
+        return Vec::new();
+    };
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+async fn read_jsonl_async<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, anyhow::Error> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = tokio::fs::read_to_string(path).await?;
+    Ok(content
+        .lines()
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Skipping malformed pool ledger entry: {e}");
+                None
+            }
+        })
+        .collect())
+}
+
+fn write_jsonl<T: Serialize>(path: &Path, entries: &[T]) -> Result<(), anyhow::Error> {
+    let mut file = File::create(path)?;
+    for entry in entries {
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    }
+    Ok(())
+}
+
+async fn append_jsonl<T: Serialize>(path: &Path, entry: &T) {
+    let Ok(serialized) = serde_json::to_string(entry) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _unused = tokio::fs::create_dir_all(parent).await;
+    }
+    match tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await
+    {
+        Ok(mut file) => {
+            if let Err(e) = file.write_all(format!("{serialized}\n").as_bytes()).await {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to write pool earnings ledger {path:?}: {e:?}");
+            }
+        }
+        Err(e) => {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to open pool earnings ledger {path:?}: {e:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 86_400;
+
+    fn snapshot(
+        seconds: u64,
+        accepted_shares: u64,
+        unpaid: f64,
+        balance: f64,
+    ) -> PoolEarningsSnapshot {
+        PoolEarningsSnapshot {
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+            pool: "LuckyPoolRANDOMX".to_string(),
+            address: "address".to_string(),
+            accepted_shares,
+            unpaid,
+            balance,
+        }
+    }
+
+    fn status(accepted_shares: u64, unpaid: f64, balance: f64) -> PoolStatus {
+        PoolStatus {
+            accepted_shares,
+            unpaid,
+            balance,
+            min_payout: 0,
+        }
+    }
+
+    #[test]
+    fn detects_payout_when_unpaid_drops_and_balance_rises() {
+        let payout = detect_payout(
+            &snapshot(0, 10, 2_100_000.0, 5_000_000.0),
+            &snapshot(60, 12, 100_000.0, 7_000_000.0),
+        )
+        .unwrap();
+
+        assert!((payout.amount - 2_000_000.0).abs() < f64::EPSILON);
+        assert!((payout.unpaid_before - 2_100_000.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn ignores_unpaid_growth_and_balance_only_changes() {
+        let previous = snapshot(0, 10, 100.0, 500.0);
+
+        assert!(detect_payout(&previous, &snapshot(60, 11, 150.0, 500.0)).is_none());
+        assert!(detect_payout(&previous, &snapshot(60, 11, 150.0, 600.0)).is_none());
+        assert!(detect_payout(&previous, &snapshot(60, 11, 50.0, 500.0)).is_none());
+    }
+
+    #[test]
+    fn aggregates_earnings_and_payouts_per_day() {
+        let daily = aggregate_daily_earnings(&[
+            snapshot(0, 0, 0.0, 0.0),
+            snapshot(3_600, 20, 400.0, 0.0),
+            snapshot(DAY + 60, 50, 900.0, 0.0),
+            // Payout of 1000 with 100 earned since the previous snapshot
+            snapshot(DAY + 3_600, 10, 0.0, 1_000.0),
+        ]);
+
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].date, "1970-01-01");
+        assert!((daily[0].earned - 400.0).abs() < f64::EPSILON);
+        assert_eq!(daily[0].accepted_shares, 20);
+        assert_eq!(daily[1].date, "1970-01-02");
+        assert!((daily[1].earned - 600.0).abs() < f64::EPSILON);
+        assert!((daily[1].paid_out - 1_000.0).abs() < f64::EPSILON);
+        assert_eq!(daily[1].accepted_shares, 40);
+    }
+
+    #[tokio::test]
+    async fn persists_snapshots_at_interval_and_payouts() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshots_path = dir.path().join(SNAPSHOTS_FILE_NAME);
+        let payouts_path = dir.path().join(PAYOUTS_FILE_NAME);
+        let mut ledger =
+            PoolEarningsLedger::with_paths(snapshots_path.clone(), payouts_path.clone());
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(DAY);
+
+        ledger
+            .record_status("pool", "address", &status(1, 100.0, 0.0), start)
+            .await;
+        ledger
+            .record_status(
+                "pool",
+                "address",
+                &status(2, 200.0, 0.0),
+                start + Duration::from_secs(60),
+            )
+            .await;
+        ledger
+            .record_status(
+                "pool",
+                "address",
+                &status(3, 10.0, 250.0),
+                start + Duration::from_secs(120),
+            )
+            .await;
+
+        let snapshots = read_jsonl::<PoolEarningsSnapshot>(&snapshots_path);
+        assert_eq!(snapshots.len(), 2);
+        assert!((snapshots[1].balance - 250.0).abs() < f64::EPSILON);
+
+        let payouts = read_jsonl::<PoolPayout>(&payouts_path);
+        assert_eq!(payouts.len(), 1);
+        assert!((payouts[0].amount - 250.0).abs() < f64::EPSILON);
+
+        // Reloaded ledger continues from the last persisted snapshot
+        let reloaded = PoolEarningsLedger::with_paths(snapshots_path, payouts_path);
+        assert_eq!(
+            reloaded
+                .last_observed
+                .get(&("pool".to_string(), "address".to_string())),
+            snapshots.last()
+        );
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use futures::future::join_all;
+use log::{debug, info};
+use serde::Serialize;
+use tokio::{net::TcpStream, time::Instant};
+
+use crate::{LOG_TARGET_APP_LOGIC, configs::pools::stratum_host_port};
+
+const STRATUM_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub enum PoolFailoverKind {
+    Cpu,
+    Gpu,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum PoolFailoverReason {
+    /// Miner reported no pool connection for longer than the threshold
+    Disconnected { seconds: u64 },
+    /// Pool stats did not report new accepted shares for longer than the threshold
+    NoNewShares { seconds: u64 },
+    /// Faster pool was picked by the startup latency probe
+    LatencyProbe { latency_ms: u64 },
+}
+
+/// Tracks the health of the currently selected pool and decides when mining should move to the next failover pool.
+/// It only keeps timestamps, so callers are responsible for feeding it observations and for resetting it on pool changes.
+#[derive(Debug)]
+pub(crate) struct PoolFailoverMonitor {
+    disconnect_threshold: Duration,
+    missing_shares_threshold: Duration,
+    disconnected_since: Option<Instant>,
+    last_accepted_shares: Option<u64>,
+    last_share_progress_at: Option<Instant>,
+}
+
+impl PoolFailoverMonitor {
+    pub fn new(disconnect_threshold: Duration, missing_shares_threshold: Duration) -> Self {
+        Self {
+            disconnect_threshold,
+            missing_shares_threshold,
+            disconnected_since: None,
+            last_accepted_shares: None,
+            last_share_progress_at: None,
+        }
+    }
+
+    pub fn load_thresholds(
+        &mut self,
+        disconnect_threshold: Duration,
+        missing_shares_threshold: Duration,
+    ) {
+        self.disconnect_threshold = disconnect_threshold;
+        self.missing_shares_threshold = missing_shares_threshold;
+    }
+
+    pub fn reset(&mut self) {
+        self.disconnected_since = None;
+        self.last_accepted_shares = None;
+        self.last_share_progress_at = None;
+    }
+
+    pub fn observe_connection(
+        &mut self,
+        is_connected: bool,
+        now: Instant,
+    ) -> Option<PoolFailoverReason> {
+        if is_connected {
+            self.disconnected_since = None;
+            return None;
+        }
+
+        let disconnected_since = *self.disconnected_since.get_or_insert(now);
+        let disconnected_for = now.saturating_duration_since(disconnected_since);
+        if disconnected_for >= self.disconnect_threshold {
+            // Start counting again so we don't trigger on every following status update
+            self.disconnected_since = Some(now);
+            return Some(PoolFailoverReason::Disconnected {
+                seconds: disconnected_for.as_secs(),
+            });
+        }
+        None
+    }
+
+    pub fn observe_accepted_shares(
+        &mut self,
+        accepted_shares: u64,
+        now: Instant,
+    ) -> Option<PoolFailoverReason> {
+        // Pools reset their counters between rounds, so any change counts as progress
+        if self.last_accepted_shares != Some(accepted_shares) {
+            self.last_accepted_shares = Some(accepted_shares);
+            self.last_share_progress_at = Some(now);
+            return None;
+        }
+
+        let last_progress_at = *self.last_share_progress_at.get_or_insert(now);
+        let stalled_for = now.saturating_duration_since(last_progress_at);
+        if stalled_for >= self.missing_shares_threshold {
+            self.last_share_progress_at = Some(now);
+            return Some(PoolFailoverReason::NoNewShares {
+                seconds: stalled_for.as_secs(),
+            });
+        }
+        None
+    }
+}
+
+/// Pick the pool following `current` in the failover list, wrapping around to the start.
+/// When `current` is not part of the list, the first available pool is returned.
+pub(crate) fn next_failover_pool<T: PartialEq + Clone>(
+    failover_pools: &[T],
+    current: &T,
+    is_available: impl Fn(&T) -> bool,
+) -> Option<T> {
+    let start = failover_pools
+        .iter()
+        .position(|pool| pool == current)
+        .map_or(0, |index| index + 1);
+
+    (0..failover_pools.len())
+        .map(|offset| &failover_pools[(start + offset) % failover_pools.len()])
+        .find(|pool| *pool != current && is_available(pool))
+        .cloned()
+}
+
+/// Measure how long it takes to open a tcp connection to the pool stratum endpoint
+pub(crate) async fn probe_stratum_latency(pool_url: &str) -> Option<Duration> {
+    let address = stratum_host_port(pool_url);
+    let started_at = Instant::now();
+    match tokio::time::timeout(STRATUM_PROBE_TIMEOUT, TcpStream::connect(address)).await {
+        Ok(Ok(_stream)) => Some(started_at.elapsed()),
+        Ok(Err(e)) => {
+            debug!(target: LOG_TARGET_APP_LOGIC, "Stratum probe to {address} failed: {e}");
+            None
+        }
+        Err(_) => {
+            debug!(target: LOG_TARGET_APP_LOGIC, "Stratum probe to {address} timed out");
+            None
+        }
+    }
+}
+
+/// Probe all pools concurrently and return them ordered by connect latency
+/// Unreachable pools keep their relative order at the end of the list
+pub(crate) async fn rank_pools_by_latency<T: Clone>(
+    pools: Vec<(T, String)>,
+) -> Vec<(T, Option<Duration>)> {
+    let latencies = join_all(
+        pools
+            .iter()
+            .map(|(_, pool_url)| probe_stratum_latency(pool_url)),
+    )
+    .await;
+
+    for ((_, pool_url), latency) in pools.iter().zip(latencies.iter()) {
+        info!(target: LOG_TARGET_APP_LOGIC, "Stratum latency for {pool_url}: {latency:?}");
+    }
+
+    order_by_latency(
+        pools
+            .into_iter()
+            .map(|(pool, _)| pool)
+            .zip(latencies)
+            .collect(),
+    )
+}
+
+/// Order the failover pools by a latency ranking, pools the ranking doesn't cover keep their
+/// configured order after the ranked ones. The configured list itself is left untouched.
+pub(crate) fn apply_latency_ranking<T: PartialEq + Clone>(
+    failover_pools: &[T],
+    ranking: &[T],
+) -> Vec<T> {
+    ranking
+        .iter()
+        .filter(|pool| failover_pools.contains(pool))
+        .chain(failover_pools.iter().filter(|pool| !ranking.contains(pool)))
+        .cloned()
+        .collect()
+}
+
+fn order_by_latency<T>(mut pools: Vec<(T, Option<Duration>)>) -> Vec<(T, Option<Duration>)> {
+    // Stable sort keeps the configured order for equal or missing latencies
+    pools.sort_by_key(|(_, latency)| latency.unwrap_or(Duration::MAX));
+    pools
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor() -> PoolFailoverMonitor {
+        PoolFailoverMonitor::new(Duration::from_secs(120), Duration::from_secs(600))
+    }
+
+    #[test]
+    fn disconnect_triggers_only_after_threshold() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+
+        assert_eq!(monitor.observe_connection(false, start), None);
+        assert_eq!(
+            monitor.observe_connection(false, start + Duration::from_secs(60)),
+            None
+        );
+        assert_eq!(
+            monitor.observe_connection(false, start + Duration::from_secs(120)),
+            Some(PoolFailoverReason::Disconnected { seconds: 120 })
+        );
+        // Counting starts again after triggering
+        assert_eq!(
+            monitor.observe_connection(false, start + Duration::from_secs(130)),
+            None
+        );
+    }
+
+    #[test]
+    fn reconnect_clears_disconnect_timer() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+
+        monitor.observe_connection(false, start);
+        monitor.observe_connection(true, start + Duration::from_secs(100));
+        assert_eq!(
+            monitor.observe_connection(false, start + Duration::from_secs(150)),
+            None
+        );
+    }
+
+    #[test]
+    fn stalled_shares_trigger_after_threshold() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+
+        assert_eq!(monitor.observe_accepted_shares(10, start), None);
+        assert_eq!(
+            monitor.observe_accepted_shares(12, start + Duration::from_secs(300)),
+            None
+        );
+        assert_eq!(
+            monitor.observe_accepted_shares(12, start + Duration::from_secs(600)),
+            None
+        );
+        assert_eq!(
+            monitor.observe_accepted_shares(12, start + Duration::from_secs(900)),
+            Some(PoolFailoverReason::NoNewShares { seconds: 600 })
+        );
+    }
+
+    #[test]
+    fn share_counter_reset_counts_as_progress() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+
+        monitor.observe_accepted_shares(50, start);
+        assert_eq!(
+            monitor.observe_accepted_shares(0, start + Duration::from_secs(599)),
+            None
+        );
+        assert_eq!(
+            monitor.observe_accepted_shares(0, start + Duration::from_secs(1000)),
+            None
+        );
+    }
+
+    #[test]
+    fn next_failover_pool_wraps_and_skips_unavailable() {
+        let pools = vec!["a", "b", "c"];
+
+        assert_eq!(next_failover_pool(&pools, &"a", |_| true), Some("b"));
+        assert_eq!(next_failover_pool(&pools, &"c", |_| true), Some("a"));
+        assert_eq!(next_failover_pool(&pools, &"a", |p| *p != "b"), Some("c"));
+        assert_eq!(next_failover_pool(&pools, &"x", |_| true), Some("a"));
+        assert_eq!(next_failover_pool(&["a"], &"a", |_| true), None);
+        assert_eq!(next_failover_pool::<&str>(&[], &"a", |_| true), None);
+    }
+
+    #[test]
+    fn latency_ranking_reorders_only_configured_pools() {
+        let failover_pools = vec!["a", "b", "c", "d"];
+
+        assert_eq!(
+            apply_latency_ranking(&failover_pools, &["c", "x", "a"]),
+            vec!["c", "a", "b", "d"]
+        );
+        assert_eq!(apply_latency_ranking(&failover_pools, &[]), failover_pools);
+        assert_eq!(
+            apply_latency_ranking::<&str>(&[], &["a"]),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn order_by_latency_puts_unreachable_pools_last() {
+        let ordered = order_by_latency(vec![
+            ("a", None),
+            ("b", Some(Duration::from_millis(80))),
+            ("c", Some(Duration::from_millis(20))),
+            ("d", None),
+        ]);
+        let names: Vec<_> = ordered.into_iter().map(|(pool, _)| pool).collect();
+
+        assert_eq!(names, vec!["c", "b", "a", "d"]);
+    }
+}
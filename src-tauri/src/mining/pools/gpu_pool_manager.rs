@@ -20,9 +20,9 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, sync::LazyLock};
+use std::{collections::HashMap, sync::LazyLock, time::Duration};
 
-use log::info;
+use log::{error, info, warn};
 use tokio::{spawn, sync::RwLock};
 
 use crate::{
@@ -32,14 +32,22 @@ use crate::{
         pools::{BasePoolData, gpu_pools::GpuPool},
         trait_config::ConfigImpl,
     },
+    events::PoolFailoverPayload,
     events_emitter::EventsEmitter,
     mining::{
-        gpu::consts::GpuMinerType,
+        gpu::{
+            consts::{GpuMinerType, GpuMiningAlgorithm},
+            manager::GpuManager,
+        },
         pools::{
             PoolManagerInterfaceTrait, PoolStatus,
             adapters::{
                 PoolApiAdapters, kryptex_pool::KryptexPoolAdapter, lucky_pool::LuckyPoolAdapter,
             },
+            failover::{
+                PoolFailoverKind, PoolFailoverReason, apply_latency_ranking, next_failover_pool,
+                rank_pools_by_latency,
+            },
             pools_manager::PoolManager,
         },
     },
@@ -52,6 +60,9 @@ static INSTANCE: LazyLock<GpuPoolManager> = LazyLock::new(GpuPoolManager::new);
 
 pub struct GpuPoolManager {
     pool_status_manager: RwLock<PoolManager>,
+    /// Failover pools of every algorithm ordered by the last startup latency probe, kept out
+    /// of the config so the order the user picked is never overwritten
+    latency_ranking: RwLock<HashMap<GpuMiningAlgorithm, Vec<GpuPool>>>,
 }
 
 impl GpuPoolManager {
@@ -64,15 +75,32 @@ impl GpuPoolManager {
             pool_adapter,
             TasksTrackers::current().gpu_mining_phase.clone(),
             Self::construct_callback_for_pool_status_update(),
+            Self::construct_callback_for_pool_failover(),
         );
         Self {
             pool_status_manager: RwLock::new(pool_manager),
+            latency_ranking: RwLock::new(HashMap::new()),
         }
     }
+
+    pub async fn load_failover_thresholds(config_content: &ConfigPoolsContent) {
+        INSTANCE
+            .pool_status_manager
+            .read()
+            .await
+            .load_failover_thresholds(
+                Duration::from_secs(*config_content.pool_failover_disconnect_threshold_secs()),
+                Duration::from_secs(*config_content.pool_failover_missing_shares_threshold_secs()),
+            )
+            .await;
+    }
+
     pub async fn initialize_from_pool_config(config_content: &ConfigPoolsContent) {
         let gpu_pool_content = config_content.current_gpu_pool().clone();
         let pool_adapter = Self::resolve_pool_adapter(gpu_pool_content);
 
+        Self::load_failover_thresholds(config_content).await;
+
         if *config_content.gpu_pool_enabled() {
             INSTANCE
                 .pool_status_manager
@@ -115,7 +143,7 @@ impl GpuPoolManager {
                 .await;
         }
 
-        if miner.is_pool_supported(&current_pool_content.pool_type) {
+        if miner.is_pool_supported(&current_pool_content) {
             info!(target: LOG_TARGET_APP_LOGIC, "Current selected GPU pool '{}' supports the new miner type '{miner:?}', no pool switch needed", current_pool_content.pool_name);
         } else {
             info!(target: LOG_TARGET_APP_LOGIC, "Current selected GPU pool '{}' does not support the new miner type '{miner:?}', switching to default pool for that miner", current_pool_content.pool_name);
@@ -140,9 +168,123 @@ impl GpuPoolManager {
             };
         }
     }
+
+    /// Rank the failover pools of every algorithm by stratum connect latency and select the fastest reachable one
+    /// Only applied when the currently selected pool is part of the ranked list, so a manually picked pool is kept
+    /// The ranking stays in memory and is used for failover while `probe_pool_latency_on_startup` is enabled
+    pub async fn probe_failover_pools_latency() {
+        let config = ConfigPools::content().await;
+        let current_pool = config.current_gpu_pool_type().clone();
+
+        for (algorithm, failover_pools) in config.gpu_failover_pools() {
+            let pools_with_urls = failover_pools
+                .iter()
+                .filter_map(|pool| {
+                    config
+                        .gpu_pools()
+                        .get(pool)
+                        .map(|pool_data| (pool.clone(), pool_data.pool_url.clone()))
+                })
+                .collect::<Vec<_>>();
+            if pools_with_urls.is_empty() {
+                continue;
+            }
+
+            let ranked_pools = rank_pools_by_latency(pools_with_urls).await;
+            INSTANCE.latency_ranking.write().await.insert(
+                algorithm.clone(),
+                ranked_pools.iter().map(|(pool, _)| pool.clone()).collect(),
+            );
+
+            if let Some((fastest_pool, Some(latency))) = ranked_pools.first()
+                && *fastest_pool != current_pool
+                && failover_pools.contains(&current_pool)
+            {
+                let reason = PoolFailoverReason::LatencyProbe {
+                    latency_ms: u64::try_from(latency.as_millis()).unwrap_or(u64::MAX),
+                };
+                Self::switch_pool(current_pool.clone(), fastest_pool.clone(), reason).await;
+            }
+        }
+    }
+
+    /// Failover list of the algorithm in the configured order, or by latency when the user opted into latency ranking
+    async fn failover_order(
+        config: &ConfigPoolsContent,
+        algorithm: &GpuMiningAlgorithm,
+    ) -> Vec<GpuPool> {
+        let failover_pools = config.gpu_failover_pools_for(algorithm);
+        if !*config.probe_pool_latency_on_startup() {
+            return failover_pools;
+        }
+        match INSTANCE.latency_ranking.read().await.get(algorithm) {
+            Some(ranking) => apply_latency_ranking(&failover_pools, ranking),
+            None => failover_pools,
+        }
+    }
+
+    /// Move to the next pool from the failover list of the selected miner algorithm after the current one was reported unhealthy
+    async fn switch_to_next_failover_pool(reason: PoolFailoverReason) {
+        let config = ConfigPools::content().await;
+        if !*config.pool_failover_enabled() || !*config.gpu_pool_enabled() {
+            return;
+        }
+
+        let algorithm = GpuManager::read().await.selected_miner_algorithm();
+        let current_pool = config.current_gpu_pool_type().clone();
+        match next_failover_pool(
+            &Self::failover_order(&config, &algorithm).await,
+            &current_pool,
+            |pool| config.gpu_pools().contains_key(pool),
+        ) {
+            Some(next_pool) => Self::switch_pool(current_pool, next_pool, reason).await,
+            None => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Gpu pool {current_pool} is unhealthy ({reason:?}) but there is no other {algorithm:?} failover pool configured");
+            }
+        }
+    }
+
+    async fn switch_pool(from_pool: GpuPool, to_pool: GpuPool, reason: PoolFailoverReason) {
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching gpu pool from {from_pool} to {to_pool}, reason: {reason:?}");
+
+        if let Err(e) =
+            ConfigPools::update_field(ConfigPoolsContent::set_current_gpu_pool, to_pool.clone())
+                .await
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to save failover gpu pool: {e}");
+            return;
+        }
+        let config = ConfigPools::content().await;
+        Self::handle_new_selected_pool(config.current_gpu_pool()).await;
+
+        EventsEmitter::emit_pools_config_loaded(&config).await;
+        EventsEmitter::emit_pool_failover(PoolFailoverPayload {
+            kind: PoolFailoverKind::Gpu,
+            from_pool: from_pool.key_string(),
+            to_pool: to_pool.key_string(),
+            reason,
+        })
+        .await;
+
+        // Miner reads the pool url on start, so it has to be restarted to pick up the new pool
+        let mut gpu_manager = GpuManager::write().await;
+        if gpu_manager.is_running() {
+            if let Err(e) = gpu_manager.stop_mining().await {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to stop gpu miner for pool failover: {e}");
+                return;
+            }
+            if let Err(e) = gpu_manager.start_mining().await {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to restart gpu miner after pool failover: {e}");
+            }
+        }
+    }
 }
 
 impl PoolManagerInterfaceTrait<GpuPool> for GpuPoolManager {
+    async fn get_read_manager() -> tokio::sync::RwLockReadGuard<'static, PoolManager> {
+        INSTANCE.pool_status_manager.read().await
+    }
+
     async fn get_write_manager() -> tokio::sync::RwLockWriteGuard<'static, PoolManager> {
         INSTANCE.pool_status_manager.write().await
     }
@@ -162,16 +304,26 @@ impl PoolManagerInterfaceTrait<GpuPool> for GpuPoolManager {
         }
     }
 
+    fn construct_callback_for_pool_failover() -> impl Fn(PoolFailoverReason) + Send + Sync + 'static
+    {
+        move |reason: PoolFailoverReason| {
+            spawn(GpuPoolManager::switch_to_next_failover_pool(reason));
+        }
+    }
+
     fn resolve_pool_adapter(pool: BasePoolData<GpuPool>) -> PoolApiAdapters {
         match pool.pool_type {
-            GpuPool::LuckyPoolC29 => PoolApiAdapters::LuckyPool(LuckyPoolAdapter::new(
-                pool.pool_type.key_string(),
-                pool.stats_url,
-            )),
-            GpuPool::KryptexPoolC29 => PoolApiAdapters::Kryptex(KryptexPoolAdapter::new(
+            GpuPool::LuckyPoolC29 | GpuPool::LuckyPoolSHA3X => PoolApiAdapters::LuckyPool(
+                LuckyPoolAdapter::new(pool.pool_type.key_string(), pool.stats_url),
+            ),
+            GpuPool::KryptexPoolC29 | GpuPool::KryptexPoolSHA3X => PoolApiAdapters::Kryptex(
+                KryptexPoolAdapter::new(pool.pool_type.key_string(), pool.stats_url),
+            ),
+            GpuPool::Custom { .. } => PoolApiAdapters::for_custom_pool(
                 pool.pool_type.key_string(),
                 pool.stats_url,
-            )),
+                pool.stats_mapping,
+            ),
         }
     }
 }
@@ -24,19 +24,23 @@ use std::collections::HashMap;
 
 use serde::Serialize;
 use tari_common_types::tari_address::TariAddress;
-use tokio::sync::RwLockWriteGuard;
+use tokio::sync::{RwLockReadGuard, RwLockWriteGuard};
 
 use crate::{
     configs::pools::BasePoolData,
-    mining::pools::{adapters::PoolApiAdapters, pools_manager::PoolManager},
+    mining::pools::{
+        adapters::PoolApiAdapters, failover::PoolFailoverReason, pools_manager::PoolManager,
+    },
 };
 
 mod adapters;
 pub mod cpu_pool_manager;
+pub mod earnings_ledger;
+pub mod failover;
 pub mod gpu_pool_manager;
 pub mod pools_manager;
 
-#[derive(Clone, Debug, Serialize, Default)]
+#[derive(Clone, Debug, Serialize, Default, PartialEq)]
 pub(crate) struct PoolStatus {
     pub accepted_shares: u64,
     pub unpaid: f64,
@@ -47,6 +51,8 @@ pub(crate) struct PoolStatus {
 pub(crate) trait PoolManagerInterfaceTrait<T> {
     // =============== Getters ===============
 
+    async fn get_read_manager() -> RwLockReadGuard<'static, PoolManager>;
+
     async fn get_write_manager() -> RwLockWriteGuard<'static, PoolManager>;
 
     // =============== To be implemented by the specific pool manager (CPU/GPU) ===============
@@ -59,6 +65,13 @@ pub(crate) trait PoolManagerInterfaceTrait<T> {
     fn construct_callback_for_pool_status_update()
     -> impl Fn(HashMap<String, PoolStatus>, PoolStatus) + Send + Sync + 'static;
 
+    /// Callback to be called when the current pool is considered unhealthy
+    /// This should switch to the next pool from the failover list
+    /// This function is called from the stats task, so it should be non-blocking
+    /// ### Arguments
+    /// * `reason` - Why the current pool was considered unhealthy
+    fn construct_callback_for_pool_failover() -> impl Fn(PoolFailoverReason) + Send + Sync + 'static;
+
     /// Resolve the appropriate pool adapter based on the selected pool configuration
     /// ### Arguments
     /// * `pool` - The selected pool configuration
@@ -121,6 +134,22 @@ pub(crate) trait PoolManagerInterfaceTrait<T> {
         Self::get_write_manager().await.stop_background_task();
     }
 
+    /// Handle connection status reported by the miner process
+    /// This should be called on every miner status update when mining through a pool
+    /// ### Arguments
+    /// * `is_connected` - Whether the miner is currently connected to the pool
+    async fn handle_connection_status_change(is_connected: bool) {
+        Self::get_write_manager()
+            .await
+            .handle_connection_status(is_connected)
+            .await;
+    }
+
+    /// Last known statuses of every pool fetched so far, keyed by pool name
+    async fn get_pool_statuses() -> HashMap<String, PoolStatus> {
+        Self::get_read_manager().await.pool_statuses().await
+    }
+
     /// Force an immediate update of the current pool statuses
     /// This can be called whenever an immediate update is needed, e.g., after changing the
     /// selected pool or wallet address while not mining
@@ -25,7 +25,7 @@ use std::{collections::HashMap, sync::Arc};
 use log::{debug, info, warn};
 use tari_common_types::tari_address::TariAddress;
 use tokio::{
-    sync::{RwLock, mpsc},
+    sync::{Mutex, RwLock, mpsc},
     time::{Duration, Instant, interval},
 };
 
@@ -34,10 +34,15 @@ use crate::{
     mining::pools::{
         PoolStatus,
         adapters::{PoolApiAdapter, PoolApiAdapters},
+        earnings_ledger::PoolEarningsLedger,
+        failover::{PoolFailoverMonitor, PoolFailoverReason},
     },
     tasks_tracker::TaskTrackerUtil,
 };
 
+pub const DEFAULT_FAILOVER_DISCONNECT_THRESHOLD_SECS: u64 = 120;
+pub const DEFAULT_FAILOVER_MISSING_SHARES_THRESHOLD_SECS: u64 = 900;
+
 #[derive(Clone)]
 struct TaskState {
     pub pool_adapter: PoolApiAdapters,
@@ -47,6 +52,8 @@ struct TaskState {
     pub is_mining_active: bool,
     pub pool_stats_event_callback:
         Arc<dyn Fn(HashMap<String, PoolStatus>, PoolStatus) + Send + Sync + 'static>,
+    pub failover_monitor: Arc<Mutex<PoolFailoverMonitor>>,
+    pub pool_failover_callback: Arc<dyn Fn(PoolFailoverReason) + Send + Sync + 'static>,
 }
 
 impl TaskState {
@@ -58,6 +65,8 @@ impl TaskState {
         pool_stats_event_callback: Arc<
             dyn Fn(HashMap<String, PoolStatus>, PoolStatus) + Send + Sync + 'static,
         >,
+        failover_monitor: Arc<Mutex<PoolFailoverMonitor>>,
+        pool_failover_callback: Arc<dyn Fn(PoolFailoverReason) + Send + Sync + 'static>,
     ) -> Self {
         Self {
             pool_adapter,
@@ -66,6 +75,8 @@ impl TaskState {
             pool_statuses,
             is_mining_active,
             pool_stats_event_callback,
+            failover_monitor,
+            pool_failover_callback,
         }
     }
 }
@@ -86,6 +97,7 @@ pub enum PoolManagerThreadCommands {
 /// - 1-hour grace period after mining stops before task shuts down
 /// - Automatic updates when pool or mining address configuration changes
 /// - Integration with TasksTrackers for proper shutdown handling
+/// - Failover detection based on miner connection status and stalled accepted shares
 pub struct PoolManager {
     pool_adapter: PoolApiAdapters,
     cached_mining_address: Option<String>,
@@ -98,6 +110,9 @@ pub struct PoolManager {
     task_sender: Option<mpsc::UnboundedSender<PoolManagerThreadCommands>>,
     pool_stats_event_callback:
         Arc<dyn Fn(HashMap<String, PoolStatus>, PoolStatus) + Send + Sync + 'static>,
+    // Failover tracking
+    failover_monitor: Arc<Mutex<PoolFailoverMonitor>>,
+    pool_failover_callback: Arc<dyn Fn(PoolFailoverReason) + Send + Sync + 'static>,
 }
 
 impl PoolManager {
@@ -105,6 +120,7 @@ impl PoolManager {
         pool_adapter: PoolApiAdapters,
         task_tracker: Arc<TaskTrackerUtil>,
         callback: impl Fn(HashMap<String, PoolStatus>, PoolStatus) + Send + Sync + 'static,
+        failover_callback: impl Fn(PoolFailoverReason) + Send + Sync + 'static,
     ) -> Self {
         Self {
             pool_adapter,
@@ -115,10 +131,48 @@ impl PoolManager {
             task_sender: None,
             task_tracker,
             pool_stats_event_callback: Arc::new(callback),
+            failover_monitor: Arc::new(Mutex::new(PoolFailoverMonitor::new(
+                Duration::from_secs(DEFAULT_FAILOVER_DISCONNECT_THRESHOLD_SECS),
+                Duration::from_secs(DEFAULT_FAILOVER_MISSING_SHARES_THRESHOLD_SECS),
+            ))),
+            pool_failover_callback: Arc::new(failover_callback),
+        }
+    }
+
+    /// Update the thresholds used to decide when the current pool is considered unhealthy
+    pub async fn load_failover_thresholds(
+        &self,
+        disconnect_threshold: Duration,
+        missing_shares_threshold: Duration,
+    ) {
+        self.failover_monitor
+            .lock()
+            .await
+            .load_thresholds(disconnect_threshold, missing_shares_threshold);
+    }
+
+    /// Feed the miner connection status into the failover monitor
+    /// Only taken into account while mining is active
+    pub async fn handle_connection_status(&self, is_connected: bool) {
+        if !self.is_mining_active {
+            return;
+        }
+        let reason = self
+            .failover_monitor
+            .lock()
+            .await
+            .observe_connection(is_connected, Instant::now());
+        if let Some(reason) = reason {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Pool {} looks unhealthy: {reason:?}", self.pool_adapter.name());
+            (self.pool_failover_callback)(reason);
         }
     }
 
     pub async fn update_current_pool_status(&self) {
+        if !self.pool_adapter.supports_stats() {
+            debug!(target: LOG_TARGET_STATUSES, "Pool {} does not provide stats, skipping update", self.pool_adapter.name());
+            return;
+        }
         if let Some(address) = &self.cached_mining_address {
             let pool_status = self.pool_adapter.request_pool_status(address.clone()).await;
             match pool_status {
@@ -128,6 +182,7 @@ impl PoolManager {
                         statuses.insert(self.pool_adapter.name().to_string(), status.clone());
                         (self.pool_stats_event_callback)(statuses.clone(), status.clone());
                     }
+                    PoolEarningsLedger::record(self.pool_adapter.name(), address, &status).await;
                     info!(target: LOG_TARGET_STATUSES, "Updated pool status: {status:?}");
                 }
                 Err(e) => {
@@ -137,6 +192,11 @@ impl PoolManager {
         }
     }
 
+    /// Last known statuses of every pool fetched so far, keyed by pool name
+    pub async fn pool_statuses(&self) -> HashMap<String, PoolStatus> {
+        self.pool_stats.read().await.clone()
+    }
+
     /// Load a new pool adapter configuration
     /// This does not start the periodic task or fetch the status, it only updates the adapter used
     /// for future requests. To start fetching status, call `spawn_periodic_pool_status_update_task`.
@@ -151,6 +211,7 @@ impl PoolManager {
     pub async fn handle_pool_change(&mut self, adapter: PoolApiAdapters) {
         info!(target: LOG_TARGET_APP_LOGIC, "Updated pool configuration to: {adapter:?}");
         self.pool_adapter = adapter.clone();
+        self.failover_monitor.lock().await.reset();
 
         // No point in continuing the task after mining address changed when mining is not active there propably won't be any stats to fetch
         if !self.is_mining_active {
@@ -214,6 +275,8 @@ impl PoolManager {
         // Update local state
         info!(target: LOG_TARGET_APP_LOGIC, "Mining active status changed to: {is_active}");
         self.is_mining_active = is_active;
+        // Downtime while mining is stopped should not count towards failover
+        self.failover_monitor.lock().await.reset();
         // Send to task if running
         if let Some(sender) = &self.task_sender {
             if let Err(e) = sender.send(PoolManagerThreadCommands::UpdateMiningStatus(is_active)) {
@@ -273,6 +336,8 @@ impl PoolManager {
                 self.pool_stats.clone(),
                 self.is_mining_active,
                 self.pool_stats_event_callback.clone(),
+                self.failover_monitor.clone(),
+                self.pool_failover_callback.clone(),
             );
 
             let mut shutdown_signal = self.task_tracker.get_signal().await;
@@ -364,6 +429,9 @@ impl PoolManager {
 
     // Static version of periodic_update_logic for use in background task
     async fn periodic_update_logic_static(task_state: &mut TaskState) {
+        if !task_state.pool_adapter.supports_stats() {
+            return;
+        }
         let pool_status = task_state
             .pool_adapter
             .request_pool_status(task_state.cached_mining_address.clone())
@@ -375,7 +443,26 @@ impl PoolManager {
                     statuses.insert(task_state.pool_adapter.name().to_string(), status.clone());
                     (task_state.pool_stats_event_callback)(statuses.clone(), status.clone());
                 }
+                PoolEarningsLedger::record(
+                    task_state.pool_adapter.name(),
+                    &task_state.cached_mining_address,
+                    &status,
+                )
+                .await;
                 info!(target: LOG_TARGET_STATUSES, "Updated pool status: {status:?}");
+
+                if task_state.is_mining_active && task_state.pool_adapter.reports_accepted_shares()
+                {
+                    let reason = task_state
+                        .failover_monitor
+                        .lock()
+                        .await
+                        .observe_accepted_shares(status.accepted_shares, Instant::now());
+                    if let Some(reason) = reason {
+                        warn!(target: LOG_TARGET_APP_LOGIC, "Pool {} looks unhealthy: {reason:?}", task_state.pool_adapter.name());
+                        (task_state.pool_failover_callback)(reason);
+                    }
+                }
             }
             Err(e) => {
                 warn!(target: LOG_TARGET_STATUSES, "Failed to fetch pool status: {e}");
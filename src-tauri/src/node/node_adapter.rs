@@ -61,6 +61,8 @@ use crate::network_utils::{
 };
 
 const STALLED_BLOCK_TIMEOUT_SECS: u64 = 15 * 60;
+// Enough headers to find a block of every proof of work algorithm
+const NETWORK_DIFFICULTY_HEADERS_WINDOW: u64 = 50;
 
 #[async_trait]
 pub trait NodeAdapter {
@@ -236,6 +238,48 @@ impl NodeAdapterService {
         Ok(stats)
     }
 
+    /// Difficulty of the most recent block mined with each proof of work algorithm
+    pub async fn get_network_difficulties(&self) -> Result<PowDifficulties, Error> {
+        let mut grpc_client = BaseNodeGrpcClient::connect(self.connection_address.clone())
+            .await
+            .map_err(|e| anyhow!("Failed to connect to gRPC: {e}"))?;
+
+        let request = ListHeadersRequest {
+            from_height: 0,
+            num_headers: NETWORK_DIFFICULTY_HEADERS_WINDOW,
+            sorting: 0, // SORTING_DESC (newest first)
+        };
+        let response = grpc_client
+            .list_headers(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list headers: {e}"))?;
+
+        let mut stream = response.into_inner();
+        let mut difficulties = PowDifficulties::default();
+        while let Some(header_resp) = stream.next().await {
+            let header_resp = header_resp.map_err(|e| anyhow!("Stream error: {e}"))?;
+            let Some(pow_algo) = header_resp
+                .header
+                .as_ref()
+                .and_then(|header| header.pow.as_ref())
+                .map(|pow| pow.pow_algo)
+            else {
+                continue;
+            };
+            let difficulty = match pow_algo {
+                0 => &mut difficulties.randomx_m,
+                1 => &mut difficulties.sha3x,
+                2 => &mut difficulties.randomx_t,
+                3 => &mut difficulties.cuckaroo,
+                _ => continue,
+            };
+            // Headers come newest first, so the first one of each algorithm is the current difficulty
+            difficulty.get_or_insert(header_resp.difficulty);
+        }
+
+        Ok(difficulties)
+    }
+
     pub async fn get_identity(&self) -> Result<NodeIdentity, Error> {
         let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
         let id = client.identify(Empty {}).await?;
@@ -782,6 +826,14 @@ pub(crate) struct LocalBlockStats {
     pub timestamp: u64,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct PowDifficulties {
+    pub randomx_m: Option<u64>,
+    pub sha3x: Option<u64>,
+    pub randomx_t: Option<u64>,
+    pub cuckaroo: Option<u64>,
+}
+
 #[derive(Clone, Copy, Debug, Serialize)]
 pub(crate) struct BaseNodeStatus {
     pub block_reward: MicroMinotari,
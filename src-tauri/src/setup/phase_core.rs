@@ -168,6 +168,8 @@ impl SetupPhaseImpl for CoreSetupPhase {
                     .await
                     .set_app_handle(&self.app_handle);
 
+                state.auto_mining_mode_manager.start().await;
+
                 Ok(())
             })
             .await?;
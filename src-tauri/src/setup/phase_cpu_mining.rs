@@ -23,13 +23,17 @@
 use crate::{
     LOG_TARGET_APP_LOGIC, UniverseAppState,
     binaries::{Binaries, BinaryResolver},
-    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    configs::{config_core::ConfigCore, config_mining::ConfigMining, trait_config::ConfigImpl},
     hardware::hardware_status_monitor::HardwareStatusMonitor,
     internal_wallet::InternalWallet,
+    mining::cpu::{
+        consts::{CPU_MINERS_PRIORITY, CpuMinerType},
+        manager::CpuManager,
+    },
     mm_proxy_manager::StartConfig,
     progress_trackers::{
         progress_plans::SetupStep,
-        progress_stepper::{ProgressStepper, ProgressStepperBuilder},
+        progress_stepper::{IncrementalProgressTracker, ProgressStepper, ProgressStepperBuilder},
     },
     setup::{
         listeners::SetupFeature,
@@ -38,7 +42,7 @@ use crate::{
     tasks_tracker::TasksTrackers,
 };
 use anyhow::Error;
-use log::warn;
+use log::{error, warn};
 use std::sync::atomic::AtomicBool;
 use tari_shutdown::ShutdownSignal;
 use tauri::{AppHandle, Manager};
@@ -80,6 +84,54 @@ pub struct CpuMiningSetupPhase {
     timeout_watcher: TimeoutWatcher,
 }
 
+impl CpuMiningSetupPhase {
+    /// Initializes xmrig and the saved cpu miner and loads them into the cpu manager,
+    /// fails only when none of them could be initialized
+    async fn initialize_cpu_miner_binaries(
+        mut progress_tracker: Option<IncrementalProgressTracker>,
+    ) -> Result<(), Error> {
+        let saved_cpu_miner_type = ConfigMining::content().await.cpu_miner_type().clone();
+        let mut is_any_miner_succeeded = false;
+
+        for miner_type in CPU_MINERS_PRIORITY {
+            // Xmrig is always needed as it is the only miner that supports solo mining
+            // Alternative miners are only downloaded once the user selects them
+            if !miner_type.is_supported_on_current_platform()
+                || (*miner_type != CpuMinerType::Xmrig && *miner_type != saved_cpu_miner_type)
+            {
+                continue;
+            }
+
+            let initialization_result = BinaryResolver::current()
+                .initialize_binary(miner_type.binary(), progress_tracker.take())
+                .await;
+
+            let initialization_err = initialization_result.as_ref().err();
+
+            if initialization_result.is_ok() {
+                is_any_miner_succeeded = true;
+            } else {
+                error!(target: LOG_TARGET_APP_LOGIC, "{miner_type} initialization error: {:?}", initialization_err);
+            }
+
+            CpuManager::write()
+                .await
+                .load_miner(
+                    miner_type.clone(),
+                    initialization_result.is_ok(),
+                    initialization_err.map(|e| e.to_string()),
+                )
+                .await;
+        }
+
+        if !is_any_miner_succeeded {
+            return Err(anyhow::anyhow!("Failed to initialize any CPU miner binary"));
+        }
+
+        Ok(())
+    }
+}
+
 impl SetupPhaseImpl for CpuMiningSetupPhase {
     type AppConfiguration = CpuMiningSetupPhaseAppConfiguration;
 
@@ -176,10 +228,8 @@ impl SetupPhaseImpl for CpuMiningSetupPhase {
             progress_stepper.track_step_incrementally(SetupStep::BinariesCpuMiner);
 
         progress_stepper
-            .complete_step(SetupStep::BinariesCpuMiner, || async {
-                binary_resolver
-                    .initialize_binary(Binaries::Xmrig, cpu_miner_binary_progress_tracker)
-                    .await
+            .complete_step(SetupStep::BinariesCpuMiner, || {
+                Self::initialize_cpu_miner_binaries(cpu_miner_binary_progress_tracker)
             })
             .await?;
 
@@ -260,6 +310,11 @@ impl SetupPhaseImpl for CpuMiningSetupPhase {
                     .await
             })
             .await?;
+        HardwareStatusMonitor::current()
+            .start_thermal_throttle_loop()
+            .await;
+
+        CpuManager::write().await.load_saved_miner().await?;
 
         Ok(())
     }
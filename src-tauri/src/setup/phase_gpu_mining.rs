@@ -22,14 +22,14 @@
 
 use crate::{
     LOG_TARGET_APP_LOGIC,
-    binaries::{Binaries, BinaryResolver},
+    binaries::BinaryResolver,
     configs::{
         config_mining::{ConfigMining, ConfigMiningContent},
         trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
     hardware::hardware_status_monitor::HardwareStatusMonitor,
-    mining::gpu::{consts::GpuMinerType, manager::GpuManager},
+    mining::gpu::{consts::MINERS_PRIORITY, manager::GpuManager},
     progress_trackers::{
         progress_plans::SetupStep,
         progress_stepper::{ProgressStepper, ProgressStepperBuilder},
@@ -150,7 +150,10 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
     async fn setup_inner(&self) -> Result<(), Error> {
         // Check if any GPU miner is supported on this platform
         // If not (e.g., macOS), disable GPU mining and skip the entire phase
-        if !GpuMinerType::LolMiner.is_supported_on_current_platform() {
+        if !MINERS_PRIORITY
+            .iter()
+            .any(|miner_type| miner_type.is_supported_on_current_platform())
+        {
             info!(target: LOG_TARGET_APP_LOGIC, "GPU mining not supported on this platform, disabling GPU mining");
             ConfigMining::update_field(ConfigMiningContent::set_gpu_mining_enabled, false).await?;
             ConfigMining::update_field(ConfigMiningContent::set_is_gpu_mining_recommended, false)
@@ -164,40 +167,45 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
 
         let binary_resolver = BinaryResolver::current();
 
-        let lolminer_binary_progress_tracker =
+        // Only one tracker can be attached to the step, so progress is reported by the first miner in priority
+        let binary_progress_tracker =
             progress_stepper.track_step_incrementally(SetupStep::BinariesGpuMiner);
 
         progress_stepper
             .complete_step(SetupStep::BinariesGpuMiner, || async {
+                let mut binary_progress_tracker = binary_progress_tracker;
                 let mut is_any_miner_succeeded = false;
 
-                // LolMiner is supported on Windows | Linux
-                if GpuMinerType::LolMiner.is_supported_on_current_platform() {
-                    let lolminer_initialization_result = binary_resolver
-                        .initialize_binary(Binaries::LolMiner, lolminer_binary_progress_tracker)
+                for miner_type in MINERS_PRIORITY {
+                    if !miner_type.is_supported_on_current_platform() {
+                        continue;
+                    }
+
+                    let initialization_result = binary_resolver
+                        .initialize_binary(miner_type.binary(), binary_progress_tracker.take())
                         .await;
 
-                    let lolminer_err = lolminer_initialization_result.as_ref().err();
+                    let initialization_err = initialization_result.as_ref().err();
 
-                    if lolminer_initialization_result.is_ok() {
+                    if initialization_result.is_ok() {
                         is_any_miner_succeeded = true;
-                    }else {
-                        error!(target: LOG_TARGET_APP_LOGIC, "LolMiner initialization error: {:?}", lolminer_err);
+                    } else {
+                        error!(target: LOG_TARGET_APP_LOGIC, "{miner_type} initialization error: {:?}", initialization_err);
                     }
 
                     GpuManager::write()
                         .await
                         .load_miner(
-                            GpuMinerType::LolMiner,
-                            lolminer_initialization_result.is_ok(),
-                            lolminer_err.map(|e| e.to_string()),
+                            miner_type.clone(),
+                            initialization_result.is_ok(),
+                            initialization_err.map(|e| e.to_string()),
                         )
                         .await;
                 }
 
                 if !is_any_miner_succeeded {
                     return Err(anyhow::anyhow!(
-                        "Failed to initialize GPU miner binary: LolMiner"
+                        "Failed to initialize any GPU miner binary"
                     ));
                 }
 
@@ -272,6 +280,9 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
         let _unused = HardwareStatusMonitor::current()
             .decide_if_gpu_mining_is_recommended()
             .await;
+        HardwareStatusMonitor::current()
+            .start_thermal_throttle_loop()
+            .await;
         GpuManager::write().await.load_saved_miner().await?;
 
         Ok(())
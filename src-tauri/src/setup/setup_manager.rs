@@ -34,9 +34,13 @@ use crate::configs::config_mining::ConfigMiningContent;
 use crate::configs::config_pools::{ConfigPools, ConfigPoolsContent};
 use crate::configs::config_ui::WalletUIMode;
 use crate::configs::config_wallet::ConfigWalletContent;
+use crate::daemon;
+use crate::electricity_tariff::TariffScheduler;
 use crate::event_scheduler::EventScheduler;
 use crate::events::CriticalProblemPayload;
 use crate::internal_wallet::InternalWallet;
+use crate::mcp::audit::AuditLog;
+use crate::metrics_exporter::MetricsExporter;
 use crate::mining::cpu::manager::CpuManager;
 use crate::mining::gpu::manager::GpuManager;
 use crate::mining::pools::PoolManagerInterfaceTrait;
@@ -78,7 +82,10 @@ use tari_common::configuration::Network;
 use tauri::{AppHandle, Listener, Manager};
 use tokio::{
     select,
-    sync::{Mutex, RwLock, watch::Sender},
+    sync::{
+        Mutex, RwLock,
+        watch::{Receiver, Sender},
+    },
 };
 
 static INSTANCE: LazyLock<SetupManager> = LazyLock::new(SetupManager::new);
@@ -231,6 +238,16 @@ impl SetupManager {
             .expect("App handle is not initialized")
     }
 
+    pub fn subscribe_to_phase_status(&self, phase: &SetupPhase) -> Receiver<PhaseStatus> {
+        match phase {
+            SetupPhase::Core => self.core_phase_status.subscribe(),
+            SetupPhase::CpuMining => self.cpu_mining_phase_status.subscribe(),
+            SetupPhase::GpuMining => self.gpu_mining_phase_status.subscribe(),
+            SetupPhase::Node => self.node_phase_status.subscribe(),
+            SetupPhase::Wallet => self.wallet_phase_status.subscribe(),
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     async fn pre_setup(&self, app_handle: AppHandle) {
         info!(target: LOG_TARGET_APP_LOGIC, "Pre Setup");
@@ -321,10 +338,13 @@ impl SetupManager {
         ConfigPools::initialize(app_handle.clone()).await;
         ConfigMcp::initialize(app_handle.clone()).await;
 
+        AuditLog::initialize().await;
+
         // Initialize MCP server with node status receiver for chain tools
         crate::mcp::server::McpServerManager::initialize(
             state.node_status_watch_rx.clone(),
             state.wallet_manager.clone(),
+            state.wallet_state_watch_rx.clone(),
         )
         .await;
 
@@ -336,6 +356,19 @@ impl SetupManager {
             warn!(target: LOG_TARGET_APP_LOGIC, "Failed to auto-start MCP server: {e}");
         }
 
+        if *ConfigCore::content().await.metrics_exporter_enabled()
+            && let Err(e) = MetricsExporter::start().await
+        {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to start metrics exporter: {e}");
+        }
+
+        // The daemon starts the control socket itself
+        if !daemon::is_daemon_requested(&app_handle)
+            && *ConfigCore::content().await.control_socket_enabled()
+        {
+            daemon::start_control_socket(app_handle.clone()).await;
+        }
+
         let _ = check_data_import(app_handle.clone()).await.map_err(|e| {
             error!(target: LOG_TARGET_APP_LOGIC, "Error in data import: {e}");
         });
@@ -344,10 +377,12 @@ impl SetupManager {
         });
 
         // Initialize after configs are loaded as its reads mining mode from config
-        SystemTrayManager::write()
-            .await
-            .initialize_tray(&app_handle)
-            .await;
+        if !daemon::is_daemon_requested(&app_handle) {
+            SystemTrayManager::write()
+                .await
+                .initialize_tray(&app_handle)
+                .await;
+        }
 
         BatteryStatus::start_battery_listener().await;
 
@@ -537,6 +572,7 @@ impl SetupManager {
             .unwrap_or_else(|e| {
                 error!(target: LOG_TARGET_APP_LOGIC, "Failed to start event scheduler listener: {e}");
             });
+        TariffScheduler::start_watcher().await;
 
         info!(target: LOG_TARGET_APP_LOGIC, "Pre Setup Finished");
     }
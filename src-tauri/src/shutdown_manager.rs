@@ -200,6 +200,22 @@ impl ShutdownManager {
         self.execute_shutdown_sequence().await;
     }
 
+    /// Used when there is no frontend to answer the shutdown dialogs, e.g. in daemon mode
+    pub async fn initialize_direct_shutdown(&self) {
+        if self.is_shutdown_in_progress().await {
+            return;
+        }
+
+        self.shutdown_sequence
+            .write()
+            .await
+            .push(ShutdownStep::Exit);
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Initialized direct shutdown sequence: {:?}", *self.shutdown_sequence.read().await);
+
+        self.execute_shutdown_sequence().await;
+    }
+
     async fn execute_shutdown_sequence(&self) {
         spawn(async move {
             loop {
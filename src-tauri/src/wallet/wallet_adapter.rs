@@ -183,13 +183,14 @@ impl WalletAdapter {
         Ok(transactions)
     }
 
+    /// Returns the id of the broadcast transaction
     pub async fn send_one_sided_to_stealth_address(
         &self,
         amount: u64,
         address: String,
         payment_id: Option<String>,
         app_handle: &tauri::AppHandle,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<String, anyhow::Error> {
         let tx_service = TransactionService::new(self, app_handle);
 
         let (unsigned_tx_file, tx_id) = tx_service
@@ -199,7 +200,10 @@ impl WalletAdapter {
             .sign_one_sided_tx(unsigned_tx_file, tx_id.clone())
             .await;
         match sign_result {
-            Ok(signed_tx_file) => tx_service.broadcast_one_sided_tx(signed_tx_file).await,
+            Ok(signed_tx_file) => tx_service
+                .broadcast_one_sided_tx(signed_tx_file)
+                .await
+                .map(|()| tx_id),
             Err(e) => {
                 let cancel_res = tx_service.cancel_transaction(tx_id).await;
                 if let Err(cancel_err) = cancel_res {
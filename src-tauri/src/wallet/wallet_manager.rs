@@ -272,13 +272,14 @@ impl WalletManager {
             })
     }
 
+    /// Returns the id of the broadcast transaction
     pub async fn send_one_sided_to_stealth_address(
         &self,
         amount_str: String,
         destination: String,
         payment_id: Option<String>,
         app_handle: &tauri::AppHandle,
-    ) -> Result<(), WalletManagerError> {
+    ) -> Result<String, WalletManagerError> {
         let process_watcher = self.watcher.read().await;
         if !process_watcher.is_running() {
             return Err(WalletManagerError::WalletNotStarted);
@@ -34,8 +34,10 @@ const getSortingIndex = (mode: MiningModeType) => {
             return 'd';
         case MiningModeType.User:
             return 'e';
-        default:
+        case MiningModeType.Auto:
             return 'f';
+        default:
+            return 'g';
     }
 };
 
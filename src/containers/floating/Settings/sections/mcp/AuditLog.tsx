@@ -7,9 +7,9 @@ import {
     SettingsGroupWrapper,
 } from '../../components/SettingsGroup.styles';
 import { useConfigMcpStore } from '@app/store/useAppConfigStore';
-import { McpAuditEntry, useMcpStore } from '@app/store/useMcpStore';
+import { McpAuditEntry, McpAuditVerification, useMcpStore } from '@app/store/useMcpStore';
 import { invoke } from '@tauri-apps/api/core';
-import { useCallback, useEffect } from 'react';
+import { useCallback, useEffect, useState } from 'react';
 
 const tierColors: Record<string, string> = {
     read: '#4a9eff',
@@ -29,6 +29,7 @@ export default function AuditLog() {
     const { t } = useTranslation(['settings'], { useSuspense: false });
     const enabled = useConfigMcpStore((s) => s.enabled);
     const entries = useMcpStore((s) => s.auditEntries);
+    const [verification, setVerification] = useState<McpAuditVerification | null>(null);
 
     useEffect(() => {
         if (!enabled) return;
@@ -54,6 +55,29 @@ export default function AuditLog() {
         }
     }, []);
 
+    const handleVerify = useCallback(async () => {
+        try {
+            setVerification(await invoke<McpAuditVerification>('verify_mcp_audit_log'));
+        } catch (e) {
+            console.error('Failed to verify audit log:', e);
+        }
+    }, []);
+
+    const handleExportBundle = useCallback(async () => {
+        try {
+            const data = await invoke<string>('export_mcp_audit_bundle');
+            const blob = new Blob([data], { type: 'application/json' });
+            const url = URL.createObjectURL(blob);
+            const a = document.createElement('a');
+            a.href = url;
+            a.download = 'mcp_audit_bundle.json';
+            a.click();
+            URL.revokeObjectURL(url);
+        } catch (e) {
+            console.error('Failed to export signed audit bundle:', e);
+        }
+    }, []);
+
     if (!enabled) return null;
 
     return (
@@ -94,9 +118,24 @@ export default function AuditLog() {
                         )}
                     </div>
                     {entries.length > 0 && (
-                        <button onClick={handleExport} style={{ fontSize: 11, marginTop: 4, cursor: 'pointer' }}>
-                            {t('mcp.audit-log.export')}
-                        </button>
+                        <div style={{ display: 'flex', gap: 8, marginTop: 4 }}>
+                            <button onClick={handleExport} style={{ fontSize: 11, cursor: 'pointer' }}>
+                                {t('mcp.audit-log.export')}
+                            </button>
+                            <button onClick={handleExportBundle} style={{ fontSize: 11, cursor: 'pointer' }}>
+                                {t('mcp.audit-log.export-signed')}
+                            </button>
+                            <button onClick={handleVerify} style={{ fontSize: 11, cursor: 'pointer' }}>
+                                {t('mcp.audit-log.verify')}
+                            </button>
+                        </div>
+                    )}
+                    {verification && (
+                        <Typography variant="p" style={{ fontSize: 11, marginTop: 4 }}>
+                            {verification.first_broken_link
+                                ? t('mcp.audit-log.verify-broken', verification.first_broken_link)
+                                : t('mcp.audit-log.verify-valid', { count: verification.entries_checked })}
+                        </Typography>
                     )}
                 </SettingsGroupContent>
             </SettingsGroup>
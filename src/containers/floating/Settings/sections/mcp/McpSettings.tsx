@@ -3,6 +3,7 @@ import TokenDisplay from './TokenDisplay';
 import AgentConfig from './AgentConfig';
 import TransactionSettings from './TransactionSettings';
 import AuditLog from './AuditLog';
+import StdioSettings from './StdioSettings';
 import AdvancedSettings from './AdvancedSettings';
 
 export const McpSettings = () => {
@@ -12,6 +13,7 @@ export const McpSettings = () => {
             <TokenDisplay />
             <AgentConfig />
             <TransactionSettings />
+            <StdioSettings />
             <AuditLog />
             <AdvancedSettings />
         </>
@@ -0,0 +1,50 @@
+/**
+ * @vitest-environment jsdom
+ */
+import { describe, it, expect, beforeEach } from 'vitest';
+import { render, screen } from '@app/test/test-utils';
+import { useConfigMcpStore } from '@app/store/useAppConfigStore';
+import StdioSettings from './StdioSettings';
+
+describe('StdioSettings', () => {
+    beforeEach(() => {
+        useConfigMcpStore.setState({ enabled: false, stdio_enabled: false, client_tokens: [] });
+    });
+
+    it('returns null when MCP is disabled', () => {
+        const { container } = render(<StdioSettings />);
+        expect(container.firstChild).toBeNull();
+    });
+
+    it('toggle reflects state', () => {
+        useConfigMcpStore.setState({ enabled: true, stdio_enabled: false });
+        render(<StdioSettings />);
+        expect(screen.getByText('mcp.stdio.title')).toBeInTheDocument();
+        expect(screen.getByTestId('mcp-stdio-toggle')).not.toBeChecked();
+    });
+
+    it('cannot be enabled without a named client', () => {
+        useConfigMcpStore.setState({ enabled: true, stdio_enabled: false });
+        render(<StdioSettings />);
+        expect(screen.getByText('mcp.stdio.no-clients')).toBeInTheDocument();
+        expect(screen.getByTestId('mcp-stdio-toggle')).toBeDisabled();
+    });
+
+    it('shows the client the transport is bound to', () => {
+        useConfigMcpStore.setState({
+            enabled: true,
+            stdio_enabled: true,
+            stdio_client: 'desktop-agent',
+            client_tokens: [
+                {
+                    name: 'desktop-agent',
+                    scopes: ['read'],
+                    created_at: { secs_since_epoch: 0, nanos_since_epoch: 0 },
+                },
+            ],
+        });
+        render(<StdioSettings />);
+        expect(screen.getByTestId('mcp-stdio-client')).toHaveTextContent('desktop-agent');
+        expect(screen.getByTestId('mcp-stdio-toggle')).toBeEnabled();
+    });
+});
@@ -0,0 +1,130 @@
+import { useEffect, useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Typography } from '@app/components/elements/Typography';
+import { ToggleSwitch } from '@app/components/elements/inputs/switch/ToggleSwitch';
+import { Select } from '@app/components/elements/inputs/Select';
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles';
+import { useConfigMcpStore } from '@app/store/useAppConfigStore';
+import { invoke } from '@tauri-apps/api/core';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import styled from 'styled-components';
+
+export default function StdioSettings() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const enabled = useConfigMcpStore((s) => s.enabled);
+    const stdioEnabled = useConfigMcpStore((s) => s.stdio_enabled);
+    const stdioClient = useConfigMcpStore((s) => s.stdio_client);
+    const clientTokens = useConfigMcpStore((s) => s.client_tokens);
+    const [loading, setLoading] = useState(false);
+    const [command, setCommand] = useState<string[] | null>(null);
+    const [selectedClient, setSelectedClient] = useState<string | undefined>(stdioClient);
+
+    const clientOptions = useMemo(
+        () => clientTokens.map((client) => ({ label: client.name, value: client.name })),
+        [clientTokens]
+    );
+    const clientName = selectedClient ?? clientOptions[0]?.value;
+
+    useEffect(() => {
+        if (!enabled || !stdioEnabled) return;
+        invoke('get_mcp_stdio_command')
+            .then(setCommand)
+            .catch((e) => console.error('Failed to get MCP stdio command:', e));
+    }, [enabled, stdioEnabled]);
+
+    const updateStdio = async (checked: boolean, name?: string) => {
+        setLoading(true);
+        try {
+            await invoke('set_mcp_stdio_enabled', { enabled: checked, clientName: name });
+            useConfigMcpStore.setState((c) => ({
+                ...c,
+                stdio_enabled: checked,
+                stdio_client: checked ? name : c.stdio_client,
+            }));
+        } catch (e) {
+            addToast({ title: 'Failed to update stdio setting', text: String(e), type: 'error' });
+        } finally {
+            setLoading(false);
+        }
+    };
+
+    const handleClientChange = (name: string) => {
+        setSelectedClient(name);
+        if (stdioEnabled) {
+            void updateStdio(true, name);
+        }
+    };
+
+    if (!enabled) return null;
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('mcp.stdio.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography variant="p">{t('mcp.stdio.description')}</Typography>
+                    {clientOptions.length > 0 ? (
+                        <ClientSelect data-testid="mcp-stdio-client">
+                            <Typography variant="p">{t('mcp.stdio.client')}</Typography>
+                            <Select
+                                options={clientOptions}
+                                selectedValue={clientName}
+                                onChange={handleClientChange}
+                                disabled={loading}
+                                variant="bordered"
+                                forceHeight={36}
+                            />
+                        </ClientSelect>
+                    ) : (
+                        <Typography variant="p">{t('mcp.stdio.no-clients')}</Typography>
+                    )}
+                    {stdioEnabled && command && (
+                        <CodeBlock data-testid="mcp-stdio-command">
+                            <code>{JSON.stringify({ command: command[0], args: command.slice(1) }, null, 2)}</code>
+                        </CodeBlock>
+                    )}
+                </SettingsGroupContent>
+                <SettingsGroupAction>
+                    <ToggleSwitch
+                        checked={stdioEnabled}
+                        onChange={(e) => updateStdio(e.target.checked, clientName)}
+                        disabled={loading || (!stdioEnabled && !clientName)}
+                        data-testid="mcp-stdio-toggle"
+                    />
+                </SettingsGroupAction>
+            </SettingsGroup>
+        </SettingsGroupWrapper>
+    );
+}
+
+const ClientSelect = styled.div`
+    display: flex;
+    flex-direction: column;
+    gap: 6px;
+    margin-top: 8px;
+`;
+
+const CodeBlock = styled.pre`
+    font-size: 11px;
+    line-height: 1.5;
+    padding: 10px 12px;
+    border-radius: 6px;
+    background: ${({ theme }) => theme.palette.background.default};
+    border: 1px solid ${({ theme }) => theme.palette.divider};
+    overflow-x: auto;
+    margin: 8px 0 0;
+    white-space: pre-wrap;
+    word-break: break-all;
+
+    code {
+        font-family: 'SF Mono', 'Fira Code', 'Cascadia Code', monospace;
+    }
+`;
@@ -21,6 +21,7 @@ import {
     handleAvailableMinersChanged,
     handleCpuMinerControlsStateChanged,
     handleGpuMinerControlsStateChanged,
+    handleSelectedCpuMinerChanged,
     handleSelectedMinerChanged,
     setShowEcoAlert,
 } from '@app/store/actions/miningStoreActions';
@@ -130,6 +131,13 @@ const useTauriEventsListener = () => {
                         case 'GpuPoolsStatsUpdate':
                             setGpuPoolStats(event.payload);
                             break;
+                        case 'PoolFailover':
+                            // Selected pool itself is synced through ConfigPoolsLoaded
+                            console.info('PoolFailover', event.payload);
+                            break;
+                        case 'ThermalThrottle':
+                            console.info('ThermalThrottle', event.payload);
+                            break;
                         case 'NewBlockHeight': {
                             const current = useBlockchainVisualisationStore.getState().latestBlockPayload?.block_height;
                             if (!current || current < event.payload.block_height) {
@@ -180,6 +188,9 @@ const useTauriEventsListener = () => {
                         case 'UpdateSelectedMiner':
                             handleSelectedMinerChanged(event.payload);
                             break;
+                        case 'UpdateSelectedCpuMiner':
+                            handleSelectedCpuMinerChanged(event.payload);
+                            break;
                         case 'AvailableMiners':
                             handleAvailableMinersChanged(event.payload);
                             break;
@@ -29,6 +29,7 @@ import {
     ConfigPools,
     ConfigUI,
     ConfigWallet,
+    CpuMinerType,
     CpuPools,
     FeedbackPrompts,
     GpuDeviceSettings,
@@ -184,6 +185,17 @@ export const setCpuMiningEnabled = async (enabled: boolean) => {
         }
     }
 };
+export const setCpuMinerType = async (cpuMinerType: CpuMinerType) => {
+    const previousCpuMinerType = useConfigMiningStore.getState().cpu_miner_type;
+    useConfigMiningStore.setState((c) => ({ ...c, cpu_miner_type: cpuMinerType }));
+    try {
+        await invoke('set_cpu_miner_type', { cpuMinerType });
+    } catch (e) {
+        console.error('Could not set CPU miner type', e);
+        setError('Could not change CPU miner');
+        useConfigMiningStore.setState((c) => ({ ...c, cpu_miner_type: previousCpuMinerType }));
+    }
+};
 export const setGpuMiningEnabled = async (enabled: boolean) => {
     useConfigMiningStore.setState((c) => ({ ...c, gpu_mining_enabled: enabled }));
     const anyMiningInitiated =
@@ -9,7 +9,7 @@ import { useConfigMiningStore } from '../useAppConfigStore.ts';
 import { Network } from '@app/utils/network.ts';
 import { setupStoreSelectors } from '../selectors/setupStoreSelectors.ts';
 import { GpuMiner, GpuMinerType, MinerControlsState } from '@app/types/events-payloads.ts';
-import { MiningModeType } from '@app/types/configs.ts';
+import { CpuMinerType, MiningModeType } from '@app/types/configs.ts';
 import { useAirdropStore } from '@app/store';
 import { FEATURE_FLAGS } from '@app/store/consts.ts';
 import { TimeUnit } from '@app/types/mining/schedule.ts';
@@ -175,6 +175,10 @@ export const handleSelectedMinerChanged = (miner: GpuMinerType) => {
     useMiningStore.setState({ selectedMiner: miner });
 };
 
+export const handleSelectedCpuMinerChanged = (miner: CpuMinerType) => {
+    useMiningStore.setState({ selectedCpuMiner: miner });
+};
+
 export const handleAvailableMinersChanged = (miners: Record<GpuMinerType, GpuMiner>) => {
     useMiningStore.setState({ availableMiners: miners });
 };
@@ -7,6 +7,7 @@ import {
     ConfigPools,
     ConfigUI,
     ConfigWallet,
+    CpuMinerType,
     PauseOnBatteryModeState,
 } from '@app/types/configs';
 import { WalletUIMode } from '@app/types/events-payloads';
@@ -26,6 +27,7 @@ const configWalletInitialState: ConfigWallet = {
 const configMininigInitialState: ConfigMining = {
     created_at: '',
     cpu_mining_enabled: true,
+    cpu_miner_type: CpuMinerType.Xmrig,
     gpu_mining_enabled: true,
     mine_on_app_start: false,
     mining_modes: {},
@@ -34,6 +36,15 @@ const configMininigInitialState: ConfigMining = {
     is_gpu_mining_recommended: true,
     eco_alert_needed: false,
     pause_on_battery_mode: PauseOnBatteryModeState.Enabled,
+    thermal_throttle_enabled: false,
+    cpu_max_temperature: 85,
+    gpu_max_temperature: 83,
+    thermal_throttle_hysteresis: 8,
+    thermal_throttle_cooldown_secs: 120,
+    auto_mode_electricity_price_per_kwh: 0,
+    auto_mode_xtm_price: 0,
+    auto_mode_cpu_watts: 65,
+    auto_mode_gpu_watts: 200,
 };
 
 const configUIInitialState: UIConfigStoreState = {
@@ -60,6 +71,12 @@ const configPoolsInitialState: ConfigPools = {
     gpu_pools: undefined,
     current_cpu_pool: undefined,
     current_gpu_pool: undefined,
+    pool_failover_enabled: false,
+    pool_failover_disconnect_threshold_secs: 120,
+    pool_failover_missing_shares_threshold_secs: 900,
+    probe_pool_latency_on_startup: false,
+    cpu_failover_pools: undefined,
+    gpu_failover_pools: undefined,
 };
 
 const configBEInMemoryInitialState: ConfigBackendInMemory = {
@@ -99,6 +116,10 @@ const configMcpInitialState: ConfigMcp = {
     read_tier_enabled: true,
     control_tier_enabled: true,
     rate_limit_transaction: 5,
+    client_tokens: [],
+    stdio_enabled: false,
+    allowlisted_addresses: [],
+    denylisted_addresses: [],
 };
 
 export const useConfigMcpStore = create<ConfigMcp>()(() => ({
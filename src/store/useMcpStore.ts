@@ -9,6 +9,22 @@ export interface McpAuditEntry {
     duration_ms?: number;
     client_info?: string;
     details?: string;
+    prev_hash?: string;
+}
+
+export interface McpAuditVerification {
+    valid: boolean;
+    files_checked: string[];
+    entries_checked: number;
+    head_hash?: string;
+    first_broken_link?: { file: string; line: number; reason: string };
+}
+
+export interface McpSpendingSummary {
+    spent_last_day: number;
+    spent_last_week: number;
+    daily_budget?: number;
+    weekly_budget?: number;
 }
 
 export interface McpPendingTransaction {
@@ -1,6 +1,7 @@
 import { create } from 'zustand';
 import { Network } from '@app/utils/network';
 import { GpuMiner, GpuMinerType } from '@app/types/events-payloads';
+import { CpuMinerType } from '@app/types/configs';
 
 export interface SessionMiningTime {
     startTimestamp?: number;
@@ -27,6 +28,7 @@ export interface MiningStoreState {
     network?: Network;
     availableMiners?: Record<GpuMinerType, GpuMiner>;
     selectedMiner?: GpuMinerType;
+    selectedCpuMiner?: CpuMinerType; // Differs from the configured miner while it can't mine with the current connection
     sessionMiningTime: SessionMiningTime;
     showEcoAlert: boolean;
     selectedResumeDuration?: ResumeMiningTime;
@@ -51,6 +53,7 @@ const initialState: MiningStoreState = {
     network: undefined,
     availableMiners: undefined,
     selectedMiner: undefined,
+    selectedCpuMiner: undefined,
     showEcoAlert: false,
 };
 
@@ -68,6 +68,26 @@ export interface PoolStats {
     min_payout: number;
 }
 
+export interface DailyPoolEarnings {
+    date: string;
+    pool: string;
+    address: string;
+    earned: number;
+    paid_out: number;
+    accepted_shares: number;
+}
+
+export interface PoolPayout {
+    timestamp: { secs_since_epoch: number; nanos_since_epoch: number };
+    pool: string;
+    address: string;
+    amount: number;
+    unpaid_before: number;
+    unpaid_after: number;
+    balance_before: number;
+    balance_after: number;
+}
+
 interface CpuMinerConnectionStatus {
     is_connected: boolean;
 }
@@ -8,6 +8,8 @@ import {
     MinerControlsState,
     NewBlockHeightPayload,
     NodeTypeUpdatePayload,
+    PoolFailoverPayload,
+    ThermalThrottlePayload,
     ProgressTrackerUpdatePayload,
     SetupPhase,
     ShowReleaseNotesPayload,
@@ -23,7 +25,15 @@ import {
     SystemDependency,
     WalletBalance,
 } from './app-status.ts';
-import { ConfigMcp, ConfigMining, ConfigPools, ConfigUI, ConfigWallet, GpuDeviceSettings } from './configs.ts';
+import {
+    ConfigMcp,
+    ConfigMining,
+    ConfigPools,
+    ConfigUI,
+    ConfigWallet,
+    CpuMinerType,
+    GpuDeviceSettings,
+} from './configs.ts';
 import { DisabledPhasesPayload } from '@app/store/actions/setupStoreActions.ts';
 import { AppModuleState } from '@app/store/types/setup.ts';
 import { ConfigCore } from '@app/types/config/core.ts';
@@ -150,6 +160,14 @@ export type BackendStateUpdateEvent =
           event_type: 'GpuPoolsStatsUpdate';
           payload: Record<string, PoolStats>;
       }
+    | {
+          event_type: 'PoolFailover';
+          payload: PoolFailoverPayload;
+      }
+    | {
+          event_type: 'ThermalThrottle';
+          payload: ThermalThrottlePayload;
+      }
     | {
           event_type: 'ExchangeIdChanged';
           payload: string;
@@ -198,6 +216,10 @@ export type BackendStateUpdateEvent =
           event_type: 'UpdateSelectedMiner';
           payload: GpuMinerType;
       }
+    | {
+          event_type: 'UpdateSelectedCpuMiner';
+          payload: CpuMinerType;
+      }
     | {
           event_type: 'AvailableMiners';
           payload: Record<GpuMinerType, GpuMiner>;
@@ -25,6 +25,18 @@ export interface ConfigCore {
     use_tor: boolean;
     shutdown_mode: ShutdownMode;
     node_data_directory?: string;
+    tariff_file_path?: string | null;
+    tariff_cheap_price_per_kwh: number;
+    tariff_expensive_price_per_kwh: number;
+    tariff_expensive_action: TariffExpensiveAction;
+    metrics_exporter_enabled: boolean;
+    metrics_exporter_bind_address: string;
+    metrics_exporter_port: number;
+    control_socket_enabled: boolean;
+}
+export enum TariffExpensiveAction {
+    Eco = 'Eco',
+    Stop = 'Stop',
 }
 export enum ShutdownMode {
     Direct = 'Direct',
@@ -8,6 +8,14 @@ export interface ConfigWallet {
     keyring_accessed: boolean;
     last_known_balance?: number;
 }
+export interface AddressBookContact {
+    name: string;
+    address: string;
+    payment_id?: string;
+}
+
+export type AddressBookFormat = 'csv' | 'json';
+
 export interface ConfigUI {
     created_at: string;
     display_mode: string;
@@ -50,17 +58,38 @@ export interface ConfigMining {
     mining_modes: Record<string, MiningMode>;
     gpu_devices_settings: Record<number, GpuDeviceSettings>;
     cpu_mining_enabled: boolean;
+    cpu_miner_type: CpuMinerType;
     is_gpu_mining_recommended: boolean;
     eco_alert_needed: boolean;
     mode_mining_times?: MiningModeTimes;
     pause_on_battery_mode: PauseOnBatteryModeState;
+    thermal_throttle_enabled?: boolean;
+    cpu_max_temperature?: number; // Celsius
+    gpu_max_temperature?: number; // Celsius
+    thermal_throttle_hysteresis?: number; // Degrees below the ceiling before usage is restored
+    thermal_throttle_cooldown_secs?: number;
+    auto_mode_electricity_price_per_kwh?: number;
+    auto_mode_xtm_price?: number; // Same currency as the electricity price
+    auto_mode_cpu_watts?: number; // Power draw at full usage
+    auto_mode_gpu_watts?: number; // Power draw at full usage
 }
 
 export interface ConfigMiningSelectors {
     getSelectedMiningMode: () => MiningMode | undefined;
 }
 
-export interface GpuDeviceSettings {
+export enum CpuMinerType {
+    Xmrig = 'Xmrig',
+    SrbMiner = 'SrbMiner',
+}
+
+export interface GpuDeviceOverrides {
+    algorithm?: 'C29' | 'SHA3X' | null; // Device only mines with this algorithm
+    pool?: GpuPools | CustomPoolKey | null; // Device only mines while this pool is selected, ignored when solo mining
+    max_temperature?: number | null; // Celsius, only applied by miners with the TemperatureLimit feature
+}
+
+export interface GpuDeviceSettings extends GpuDeviceOverrides {
     device_id: number;
     is_excluded: boolean;
 }
@@ -71,6 +100,7 @@ export enum MiningModeType {
     Ludicrous = 'Ludicrous',
     Custom = 'Custom',
     User = 'User',
+    Auto = 'Auto',
 }
 
 export interface MiningMode {
@@ -86,18 +116,27 @@ export interface ConfigPools {
     created_at: string;
     // ======= Gpu Pool =======
     gpu_pool_enabled: boolean; // Whether GPU pool mining is enabled | defaults to true
-    current_gpu_pool?: GpuPools; // Name of the selected GPU pool => defaults to LuckyPool
-    gpu_pools?: Record<GpuPools, BasePoolData>; // Available GPU pools
+    current_gpu_pool?: GpuPools | CustomPoolKey; // Name of the selected GPU pool => defaults to LuckyPool
+    gpu_pools?: Record<GpuPools | CustomPoolKey, BasePoolData>; // Available GPU pools
     // ======= Cpu Pool =======
     // When false we are solo mining with xmrig and mmproxy if true we are pool mining with xmrig
     cpu_pool_enabled: boolean; // Whether CPU pool mining is enabled | defaults to true
-    current_cpu_pool?: CpuPools; // Name of the selected CPU pool => defaults to LuckyPool
-    cpu_pools?: Record<CpuPools, BasePoolData>; // Available CPU pools
+    current_cpu_pool?: CpuPools | CustomPoolKey; // Name of the selected CPU pool => defaults to LuckyPool
+    cpu_pools?: Record<CpuPools | CustomPoolKey, BasePoolData>; // Available CPU pools
+    // ======= Pool failover =======
+    pool_failover_enabled: boolean; // Whether to switch pools automatically when the current one is unhealthy | defaults to false
+    pool_failover_disconnect_threshold_secs: number;
+    pool_failover_missing_shares_threshold_secs: number;
+    probe_pool_latency_on_startup: boolean; // Rank failover pools by stratum connect latency on startup and fail over in that order, the configured order is kept | defaults to false
+    cpu_failover_pools?: (CpuPools | CustomPoolKey)[];
+    gpu_failover_pools?: Partial<Record<'C29' | 'SHA3X', (GpuPools | CustomPoolKey)[]>>;
 }
 
 export enum GpuPools {
     LuckyPoolC29 = 'LuckyPoolC29',
     KryptexPoolC29 = 'KryptexPoolC29',
+    LuckyPoolSHA3X = 'LuckyPoolSHA3X',
+    KryptexPoolSHA3X = 'KryptexPoolSHA3X',
 }
 
 export enum CpuPools {
@@ -110,14 +149,42 @@ export enum PoolOrigin {
     SupportXTM = 'SupportXTM',
     LuckyPool = 'LuckyPool',
     Kryptex = 'Kryptex',
+    Custom = 'Custom',
+}
+
+// Custom pools are keyed as `Custom:<id>` next to the built-in pools
+export type CustomPoolKey = `Custom:${string}`;
+
+// Either a single JSON pointer (RFC 6901) or several pointers summed together and multiplied by scale
+export type PoolStatsField = string | { pointers: string[]; scale?: number };
+
+// Declarative mapping of a pool stats response
+export interface PoolStatsMapping {
+    accepted_shares?: PoolStatsField;
+    unpaid?: PoolStatsField;
+    balance?: PoolStatsField;
+    min_payout?: PoolStatsField;
+    empty_response_marker?: string; // Responses containing this text mean the address has no mining history yet
 }
 
 export interface BasePoolData {
     pool_name: string;
-    pool_type: GpuPools | CpuPools;
+    pool_type: GpuPools | CpuPools | CustomPoolKey;
     pool_origin: PoolOrigin;
     pool_url: string;
     stats_url: string;
+    worker_name?: string;
+    stats_mapping?: PoolStatsMapping;
+    algorithm?: 'C29' | 'SHA3X' | null; // Only used by custom GPU pools, required when adding one
+}
+
+export interface CustomPoolDefinition {
+    pool_name: string;
+    pool_url: string;
+    worker_name?: string;
+    stats_url?: string;
+    stats_mapping?: PoolStatsMapping;
+    algorithm?: 'C29' | 'SHA3X' | null; // Only used by custom GPU pools, required when adding one
 }
 
 export interface ConfigBackendInMemory {
@@ -128,6 +195,15 @@ export interface ConfigBackendInMemory {
     bridge_backend_api_url: string;
 }
 
+export interface McpClientToken {
+    name: string;
+    scopes: string[];
+    created_at: { secs_since_epoch: number; nanos_since_epoch: number };
+    expires_at?: { secs_since_epoch: number; nanos_since_epoch: number };
+    max_transaction_amount?: number;
+    rate_limit_per_minute?: number;
+}
+
 export interface ConfigMcp {
     enabled: boolean;
     bearer_token_redacted?: string;
@@ -140,4 +216,12 @@ export interface ConfigMcp {
     read_tier_enabled: boolean;
     control_tier_enabled: boolean;
     rate_limit_transaction: number;
+    client_tokens: McpClientToken[];
+    stdio_enabled: boolean;
+    stdio_client?: string;
+    daily_budget?: number;
+    weekly_budget?: number;
+    allowlist_confirmation_threshold?: number;
+    allowlisted_addresses: string[];
+    denylisted_addresses: string[];
 }
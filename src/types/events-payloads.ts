@@ -92,6 +92,7 @@ export interface ProgressTrackerUpdatePayload {
 
 export enum GpuMinerType {
     LolMiner = 'LolMiner',
+    Graxil = 'Graxil',
 }
 
 export enum GpuMinerFeature {
@@ -100,10 +101,12 @@ export enum GpuMinerFeature {
     DeviceExclusion = 'DeviceExclusion',
     MiningIntensity = 'MiningIntensity',
     EngineSelection = 'EngineSelection',
+    TemperatureLimit = 'TemperatureLimit',
 }
 
 export enum MiningAlgorithm {
     C29 = 'C29',
+    SHA3X = 'SHA3X',
     RandomX = 'RandomX',
 }
 
@@ -122,3 +125,23 @@ export interface GpuMiner {
     is_healthy: boolean;
     last_error?: string;
 }
+
+export type PoolFailoverReason =
+    | { type: 'Disconnected'; seconds: number }
+    | { type: 'NoNewShares'; seconds: number }
+    | { type: 'LatencyProbe'; latency_ms: number };
+
+export interface PoolFailoverPayload {
+    kind: 'Cpu' | 'Gpu';
+    from_pool: string;
+    to_pool: string;
+    reason: PoolFailoverReason;
+}
+
+export interface ThermalThrottlePayload {
+    device_kind: 'Cpu' | 'Gpu';
+    is_throttled: boolean;
+    temperature: number;
+    max_temperature: number;
+    usage_percentage: number;
+}
@@ -5,14 +5,32 @@ import {
     BridgeEnvs,
     TariAddressVariants,
     BaseNodeStatus,
+    DailyPoolEarnings,
+    PoolPayout,
 } from './app-status';
 import { PaperWalletDetails } from './app-status.ts';
 import { LocalBlockStats } from './mining/blocks.ts';
 import { displayMode } from '../store/types.ts';
-import { BasePoolData, ConfigBackendInMemory, PauseOnBatteryModeState } from './configs.ts';
+import { McpSpendingSummary } from '../store/useMcpStore.ts';
+import {
+    AddressBookContact,
+    AddressBookFormat,
+    BasePoolData,
+    ConfigBackendInMemory,
+    CpuMinerType,
+    CustomPoolDefinition,
+    GpuDeviceOverrides,
+    PauseOnBatteryModeState,
+} from './configs.ts';
 import { ExchangeMiner } from './exchange';
 import { ActiveTapplet } from './tapplets/tapplet.types';
-import { SchedulerEventTiming, SchedulerEventType } from './mining/schedule.ts';
+import {
+    SchedulerEventTiming,
+    SchedulerEventType,
+    SchedulerExecutionRecord,
+    SchedulerMissedRunPolicy,
+} from './mining/schedule.ts';
+import { TariffExpensiveAction } from './config/core.ts';
 import { Language } from '../i18initializer.ts';
 
 declare module '@tauri-apps/api/core' {
@@ -20,6 +38,17 @@ declare module '@tauri-apps/api/core' {
         param: 'send_one_sided_to_stealth_address',
         payload: { amount: string; destination: string; paymentId?: string }
     ): Promise<void>;
+    function invoke(param: 'get_address_book_contacts'): Promise<AddressBookContact[]>;
+    function invoke(
+        param: 'save_address_book_contact',
+        payload: { contact: AddressBookContact; previousName?: string }
+    ): Promise<void>;
+    function invoke(param: 'remove_address_book_contact', payload: { name: string }): Promise<void>;
+    function invoke(
+        param: 'import_address_book',
+        payload: { contents: string; format: AddressBookFormat }
+    ): Promise<number>;
+    function invoke(param: 'export_address_book', payload: { format: AddressBookFormat }): Promise<string>;
     function invoke(
         param: 'set_should_always_use_system_language',
         payload: { shouldAlwaysUseSystemLanguage: boolean }
@@ -56,6 +85,7 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'reset_settings', payload: { resetWallet: boolean }): Promise<string>;
     function invoke(param: 'set_gpu_mining_enabled', payload: { enabled: boolean }): Promise<void>;
     function invoke(param: 'set_cpu_mining_enabled', payload: { enabled: boolean }): Promise<void>;
+    function invoke(param: 'set_cpu_miner_type', payload: { cpuMinerType: CpuMinerType }): Promise<void>;
     function invoke(param: 'exit_application'): Promise<string>;
     function invoke(param: 'restart_application'): Promise<string>;
     function invoke(param: 'set_use_tor', payload: { useTor: boolean }): Promise<void>;
@@ -81,6 +111,42 @@ declare module '@tauri-apps/api/core' {
         param: 'toggle_device_exclusion',
         payload: { device_index: number; excluded: boolean }
     ): Promise<void>;
+    function invoke(
+        param: 'set_thermal_throttle_settings',
+        payload: {
+            enabled: boolean;
+            cpuMaxTemperature: number;
+            gpuMaxTemperature: number;
+            hysteresis: number;
+            cooldownSecs: number;
+        }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_auto_mining_mode_settings',
+        payload: {
+            electricityPricePerKwh: number;
+            xtmPrice: number;
+            cpuWatts: number;
+            gpuWatts: number;
+        }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_tariff_schedule_settings',
+        payload: {
+            filePath?: string | null;
+            cheapPricePerKwh: number;
+            expensivePricePerKwh: number;
+            expensiveAction: TariffExpensiveAction;
+        }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_metrics_exporter_settings',
+        payload: { enabled: boolean; bindAddress: string; port: number }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_gpu_device_overrides',
+        payload: { deviceIndex: number; overrides: GpuDeviceOverrides }
+    ): Promise<void>;
     function invoke(
         param: 'set_show_experimental_settings',
         payload: { showExperimentalSettings: boolean }
@@ -134,6 +200,37 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'update_selected_gpu_pool', payload: { updated_config: BasePoolData }): Promise<void>;
     function invoke(param: 'reset_gpu_pool_config', payload: { gpuPoolType: string }): Promise<void>;
     function invoke(param: 'reset_cpu_pool_config', payload: { cpuPoolType: string }): Promise<void>;
+    function invoke(param: 'add_custom_cpu_pool', payload: { pool: CustomPoolDefinition }): Promise<string>;
+    function invoke(param: 'add_custom_gpu_pool', payload: { pool: CustomPoolDefinition }): Promise<string>;
+    function invoke(
+        param: 'edit_custom_cpu_pool',
+        payload: { cpuPoolType: string; pool: CustomPoolDefinition }
+    ): Promise<void>;
+    function invoke(
+        param: 'edit_custom_gpu_pool',
+        payload: { gpuPoolType: string; pool: CustomPoolDefinition }
+    ): Promise<void>;
+    function invoke(param: 'delete_custom_cpu_pool', payload: { cpuPoolType: string }): Promise<void>;
+    function invoke(param: 'delete_custom_gpu_pool', payload: { gpuPoolType: string }): Promise<void>;
+    function invoke(
+        param: 'set_pool_failover_settings',
+        payload: {
+            enabled: boolean;
+            disconnectThresholdSecs: number;
+            missingSharesThresholdSecs: number;
+            probeLatencyOnStartup: boolean;
+        }
+    ): Promise<void>;
+    function invoke(param: 'set_cpu_failover_pools', payload: { cpuPools: string[] }): Promise<void>;
+    function invoke(
+        param: 'set_gpu_failover_pools',
+        payload: { algorithm: 'C29' | 'SHA3X'; gpuPools: string[] }
+    ): Promise<void>;
+    function invoke(
+        param: 'get_pool_daily_earnings',
+        payload: { pool?: string; days?: number }
+    ): Promise<DailyPoolEarnings[]>;
+    function invoke(param: 'get_pool_payout_history', payload: { pool?: string }): Promise<PoolPayout[]>;
     function invoke(param: 'restart_phases', payload: { phases: SetupPhase[] }): Promise<void>;
     function invoke(param: 'list_connected_peers'): Promise<string[]>;
 
@@ -150,10 +247,54 @@ declare module '@tauri-apps/api/core' {
     // Scheduler commands
     function invoke(
         param: 'add_scheduler_event',
-        payload: { eventId: string; eventTime: SchedulerEventTiming; eventType: SchedulerEventType }
+        payload: {
+            eventId: string;
+            eventTime: SchedulerEventTiming;
+            eventType: SchedulerEventType;
+            missedRunPolicy?: SchedulerMissedRunPolicy;
+        }
     ): Promise<void>;
     function invoke(param: 'remove_scheduler_event', payload: { eventId: string }): Promise<void>;
     function invoke(param: 'pause_scheduler_event', payload: { eventId: string }): Promise<void>;
     function invoke(param: 'resume_scheduler_event', payload: { eventId: string }): Promise<void>;
+    function invoke(
+        param: 'set_scheduler_event_missed_run_policy',
+        payload: { eventId: string; missedRunPolicy: SchedulerMissedRunPolicy }
+    ): Promise<void>;
+    function invoke(
+        param: 'get_scheduler_history',
+        payload: { eventId?: string; limit?: number }
+    ): Promise<SchedulerExecutionRecord[]>;
     function invoke(param: 'set_custom_node_directory', payload: { path: string }): Promise<void>;
+    function invoke(
+        param: 'create_mcp_client',
+        payload: {
+            name: string;
+            scopes: string[];
+            expiryDays?: number;
+            maxTransactionAmount?: number;
+            rateLimitPerMinute?: number;
+            pin?: string;
+        }
+    ): Promise<string>;
+    function invoke(param: 'revoke_mcp_client', payload: { name: string }): Promise<void>;
+    function invoke(
+        param: 'set_mcp_spending_limits',
+        payload: {
+            dailyBudget?: number;
+            weeklyBudget?: number;
+            allowlistConfirmationThreshold?: number;
+            pin: string;
+        }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_mcp_address_lists',
+        payload: { allowlist: string[]; denylist: string[]; pin: string }
+    ): Promise<void>;
+    function invoke(param: 'get_mcp_spending_summary'): Promise<McpSpendingSummary>;
+    function invoke(
+        param: 'set_mcp_stdio_enabled',
+        payload: { enabled: boolean; clientName?: string }
+    ): Promise<void>;
+    function invoke(param: 'get_mcp_stdio_command'): Promise<string[]>;
 }
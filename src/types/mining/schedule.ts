@@ -2,8 +2,20 @@ export const TIME_PERIOD = ['AM', 'PM'] as const;
 type TimePeriodTuple = typeof TIME_PERIOD;
 export type TimePeriod = TimePeriodTuple[number];
 
-export type SchedulerEventType = 'ResumeMining' | { Mine: { mining_mode: string } };
-export type SchedulerEventTiming = { In: InTime } | { Between: BetweenTime };
+export type SchedulerEventType =
+    | 'ResumeMining'
+    | 'StopMining'
+    | 'RestartNode'
+    | { Mine: { mining_mode: string } }
+    | { SwitchPool: { cpu_pool: string | null; gpu_pool: string | null } }
+    | { SetMiningDevices: { cpu_enabled: boolean; gpu_enabled: boolean } };
+export type SchedulerEventTiming =
+    | { In: InTime }
+    | { Between: BetweenTime }
+    | { Cron: CronTime }
+    | { Weekly: WeeklyTime }
+    | { DateRange: DateRangeTime }
+    | { Idle: IdleTime };
 
 export interface TimeParts {
     hour: number;
@@ -31,6 +43,41 @@ export interface BetweenTime {
     end_period: TimePeriod;
 }
 
+export interface CronTime {
+    start_cron: string;
+    end_cron: string;
+    timezone?: string | null; // IANA name, local timezone when not set
+}
+
+// Bit mask of weekdays, Monday is bit 0 and Sunday is bit 6
+export enum Weekday {
+    Monday = 1 << 0,
+    Tuesday = 1 << 1,
+    Wednesday = 1 << 2,
+    Thursday = 1 << 3,
+    Friday = 1 << 4,
+    Saturday = 1 << 5,
+    Sunday = 1 << 6,
+}
+
+export interface WeeklyTime {
+    weekdays: number;
+    start_hour: number; // 0-23
+    start_minute: number;
+    end_hour: number; // 0-23, ends the following day when not after the start
+    end_minute: number;
+    timezone?: string | null; // IANA name, local timezone when not set
+}
+
+export interface DateRangeTime {
+    start: string; // RFC 3339
+    end: string; // RFC 3339
+}
+
+export interface IdleTime {
+    idle_minutes: number;
+}
+
 export enum SchedulerEventState {
     Active = 'Active',
     Paused = 'Paused',
@@ -38,9 +85,26 @@ export enum SchedulerEventState {
     Completed = 'Completed',
 }
 
+// What to do at startup about windows that started while the app was closed
+export enum SchedulerMissedRunPolicy {
+    Skip = 'Skip',
+    CatchUp = 'CatchUp',
+}
+
 export interface SchedulerEvent {
     id: string;
     event_type: SchedulerEventType;
     timing: SchedulerEventTiming;
     state: SchedulerEventState;
+    missed_run_policy?: SchedulerMissedRunPolicy;
+}
+
+export interface SchedulerExecutionRecord {
+    event_id: string;
+    fired_at: { secs_since_epoch: number; nanos_since_epoch: number };
+    scheduled_at: { secs_since_epoch: number; nanos_since_epoch: number } | null;
+    phase: 'Enter' | 'Exit';
+    action: SchedulerEventType;
+    outcome: 'Succeeded' | 'Failed' | 'Missed';
+    error: string | null;
 }
@@ -128,6 +128,7 @@ interface Hashrate {
 
 const HASHRATE_BASE_UNITS: Record<MiningAlgorithm, 'G' | 'H'> = {
     [MiningAlgorithm.C29]: 'G',
+    [MiningAlgorithm.SHA3X]: 'H',
     [MiningAlgorithm.RandomX]: 'H',
 };
 